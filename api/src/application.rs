@@ -1,4 +1,6 @@
 pub mod auth;
+pub mod client_ip;
 pub mod decoded_token;
 pub mod http;
 pub mod url;
+pub mod user_agent;
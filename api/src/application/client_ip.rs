@@ -0,0 +1,72 @@
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+};
+
+use axum::extract::{ConnectInfo, FromRequestParts};
+use ipnet::IpNet;
+
+use crate::application::http::server::app_state::AppState;
+
+/// Address of the client that sent the request: the peer address of the
+/// connection. When the peer is one of the configured trusted proxies, the
+/// `X-Forwarded-For` chain is walked back to the first untrusted hop, then
+/// the `X-Real-IP` header is used.
+#[derive(Debug, Clone)]
+pub struct ClientIp(pub Option<String>);
+
+impl FromRequestParts<AppState> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+
+        let trusted_proxies = &state.args.server.trusted_proxies;
+
+        let ip = match peer {
+            Some(peer) if is_trusted(trusted_proxies, &peer) => {
+                forwarded_ip(&parts.headers, trusted_proxies).unwrap_or(peer)
+            }
+            Some(peer) => peer,
+            None => return Ok(ClientIp(None)),
+        };
+
+        Ok(ClientIp(Some(ip.to_string())))
+    }
+}
+
+fn is_trusted(trusted_proxies: &[IpNet], ip: &IpAddr) -> bool {
+    trusted_proxies.iter().any(|proxy| proxy.contains(ip))
+}
+
+fn forwarded_ip(headers: &axum::http::HeaderMap, trusted_proxies: &[IpNet]) -> Option<IpAddr> {
+    let forwarded: Vec<IpAddr> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|h| h.to_str().ok())
+        .flat_map(|h| h.split(','))
+        .filter_map(|ip| ip.trim().parse().ok())
+        .collect();
+
+    // Every proxy appends the address it received the request from, so the
+    // client is the last hop that is not one of our proxies.
+    let client = forwarded
+        .iter()
+        .rev()
+        .find(|ip| !is_trusted(trusted_proxies, ip))
+        .or(forwarded.first())
+        .copied();
+
+    client.or_else(|| {
+        headers
+            .get("x-real-ip")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|ip| ip.trim().parse().ok())
+    })
+}
@@ -1,7 +1,10 @@
 pub mod authentication;
 pub mod client;
+pub mod client_scope;
 
 pub mod error;
+pub mod event;
+pub mod group;
 pub mod health;
 pub mod realm;
 pub mod role;
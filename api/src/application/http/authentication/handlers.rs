@@ -1,5 +1,14 @@
 pub mod auth;
 pub mod authentificate;
+pub mod consent;
+pub mod execute_action_token;
+pub mod forgot_password;
+pub mod get_action_token;
 pub mod get_certs;
+pub mod introspect;
+pub mod logout;
 pub mod openid_configuration;
+pub mod register;
+pub mod revoke;
 pub mod token;
+pub mod userinfo;
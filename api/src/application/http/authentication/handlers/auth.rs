@@ -33,6 +33,12 @@ pub struct AuthRequest {
     pub scope: Option<String>,
     #[serde(default)]
     pub state: Option<String>,
+    #[serde(default)]
+    pub nonce: Option<String>,
+    #[serde(default)]
+    pub code_challenge: Option<String>,
+    #[serde(default)]
+    pub code_challenge_method: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema, PartialEq, Eq)]
@@ -71,6 +77,9 @@ pub async fn auth(
             response_type: params.response_type,
             scope: params.scope,
             state: params.state,
+            nonce: params.nonce,
+            code_challenge: params.code_challenge,
+            code_challenge_method: params.code_challenge_method,
         })
         .await
         .map_err(|e| ApiError::InternalServerError(e.to_string()))?;
@@ -83,13 +92,13 @@ pub async fn auth(
     );
 
     let cookie_value = format!(
-        "session_code={}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=3600",
-        result.session.id
+        "session_code={}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age={}",
+        result.session.id, result.session_lifespan
     );
 
     let session_cookie = format!(
-        "FERRISKEY_SESSION={}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=3600",
-        result.session.id
+        "FERRISKEY_SESSION={}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age={}",
+        result.session.id, result.session_lifespan
     );
 
     let mut headers = HeaderMap::new();
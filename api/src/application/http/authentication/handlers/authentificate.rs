@@ -1,8 +1,10 @@
+use crate::application::client_ip::ClientIp;
 use crate::application::decoded_token::OptionalToken;
 use crate::application::http::server::api_entities::api_error::{ApiError, ValidateJson};
 use crate::application::http::server::api_entities::response::Response;
 use crate::application::http::server::app_state::AppState;
 use crate::application::url::FullUrl;
+use crate::application::user_agent::UserAgent;
 use axum::extract::{Path, Query, State};
 use axum_cookie::CookieManager;
 
@@ -26,6 +28,7 @@ pub enum AuthenticationStatus {
     Success,
     RequiresActions,
     RequiresOtpChallenge,
+    RequiresConsent,
     Failed,
 }
 
@@ -77,9 +80,16 @@ impl From<AuthenticateOutput> for AuthenticateResponse {
                 token: result.temporary_token,
                 message: Some("OTP verification required".to_string()),
             },
+            AuthenticationStepStatus::RequiresConsent => AuthenticateResponse {
+                status: AuthenticationStatus::RequiresConsent,
+                url: None,
+                required_actions: None,
+                token: None,
+                message: Some("User consent required".to_string()),
+            },
             AuthenticationStepStatus::Failed => AuthenticateResponse {
                 status: AuthenticationStatus::Failed,
-                url: None,
+                url: result.redirect_url,
                 required_actions: None,
                 token: None,
                 message: Some("Authentication failed".to_string()),
@@ -101,10 +111,13 @@ impl From<AuthenticateOutput> for AuthenticateResponse {
         (status = 200, body = AuthenticateResponse)
     )
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn authenticate(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     FullUrl(_, base_url): FullUrl,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
     OptionalToken(optional_token): OptionalToken,
     Query(query): Query<AuthenticateQueryParams>,
     cookie: CookieManager,
@@ -143,6 +156,8 @@ pub async fn authenticate(
             base_url.clone(),
             username,
             password,
+            ip_address,
+            user_agent,
         )
     };
     let result = state.service.authenticate(authenticate_params).await?;
@@ -0,0 +1,97 @@
+use crate::application::http::authentication::handlers::authentificate::AuthenticateResponse;
+use crate::application::http::server::api_entities::api_error::{ApiError, ValidateJson};
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::extract::{Path, State};
+use axum_cookie::CookieManager;
+use ferriskey_core::domain::authentication::{
+    entities::{ConsentInput, ConsentRequest, GetConsentInput},
+    ports::AuthService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ConsentPayload {
+    pub approve: bool,
+}
+
+pub(crate) fn session_code(cookie: &CookieManager) -> Result<Uuid, ApiError> {
+    let session_code = cookie
+        .get("FERRISKEY_SESSION")
+        .ok_or_else(|| ApiError::Unauthorized("Missing session cookie".to_string()))?;
+
+    Uuid::parse_str(session_code.value())
+        .map_err(|_| ApiError::Unauthorized("Invalid session cookie".to_string()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/login-actions/consent",
+    tag = "auth",
+    summary = "Get the pending consent request",
+    description = "Returns the client and the client scopes the authenticated user still has to approve before an authorization code is issued.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = ConsentRequest),
+        (status = 401, description = "Missing session cookie"),
+        (status = 404, description = "No session waiting for consent"),
+    )
+)]
+pub async fn get_consent(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    cookie: CookieManager,
+) -> Result<Response<ConsentRequest>, ApiError> {
+    let session_code = session_code(&cookie)?;
+
+    let consent = state
+        .service
+        .get_consent(GetConsentInput {
+            realm_name,
+            session_code,
+        })
+        .await?;
+
+    Ok(Response::OK(consent))
+}
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/consent",
+    tag = "auth",
+    summary = "Approve or deny the pending consent request",
+    description = "Records the user's approval of the requested client scopes and returns the redirect URL carrying the authorization code. Denying returns a redirect URL carrying an access_denied error.",
+    request_body = ConsentPayload,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = AuthenticateResponse),
+        (status = 401, description = "Missing session cookie"),
+        (status = 404, description = "No session waiting for consent"),
+    )
+)]
+pub async fn consent(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    cookie: CookieManager,
+    ValidateJson(payload): ValidateJson<ConsentPayload>,
+) -> Result<Response<AuthenticateResponse>, ApiError> {
+    let session_code = session_code(&cookie)?;
+
+    let result = state
+        .service
+        .consent(ConsentInput {
+            realm_name,
+            session_code,
+            approve: payload.approve,
+        })
+        .await?;
+
+    Ok(Response::OK(result.into()))
+}
@@ -0,0 +1,49 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::action_token::{
+    entities::{ActionTokenResult, ExecuteActionTokenInput},
+    ports::ActionTokenService,
+};
+
+use crate::application::http::{
+    authentication::validators::ExecuteActionTokenValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/action-token",
+    tag = "auth",
+    summary = "Use an action token",
+    description = "Verifies the email address, sets the new password or completes the required actions the token was issued for. A token can only be used once.",
+    request_body = ExecuteActionTokenValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = ActionTokenResult),
+        (status = 400, description = "Missing password or password policy violation"),
+        (status = 401, description = "Invalid, used or expired token"),
+    )
+)]
+pub async fn execute_action_token(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    ValidateJson(payload): ValidateJson<ExecuteActionTokenValidator>,
+) -> Result<Response<ActionTokenResult>, ApiError> {
+    let result = state
+        .service
+        .execute_action_token(ExecuteActionTokenInput {
+            realm_name,
+            token: payload.key,
+            password: payload.password,
+        })
+        .await?;
+
+    Ok(Response::OK(result))
+}
@@ -0,0 +1,60 @@
+use axum::extract::{Path, State};
+use ferriskey_core::domain::action_token::{
+    entities::ForgotPasswordInput, ports::ActionTokenService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::{
+    http::{
+        authentication::validators::ForgotPasswordValidator,
+        server::{
+            api_entities::{
+                api_error::{ApiError, ValidateJson},
+                response::Response,
+            },
+            app_state::AppState,
+        },
+    },
+    url::FullUrl,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ForgotPasswordResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/reset-credentials",
+    tag = "auth",
+    summary = "Request a password reset email",
+    description = "Emails a link to choose a new password to the user matching the username or email address. The response is the same whether or not such a user exists.",
+    request_body = ForgotPasswordValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 202, body = ForgotPasswordResponse),
+    )
+)]
+pub async fn forgot_password(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    ValidateJson(payload): ValidateJson<ForgotPasswordValidator>,
+) -> Result<Response<ForgotPasswordResponse>, ApiError> {
+    state
+        .service
+        .forgot_password(ForgotPasswordInput {
+            realm_name,
+            username: payload.username,
+            webapp_url: state.args.webapp_url.clone(),
+            base_url,
+        })
+        .await?;
+
+    Ok(Response::Accepted(ForgotPasswordResponse {
+        message: "If the account exists, an email has been sent to its address".to_string(),
+    }))
+}
@@ -0,0 +1,47 @@
+use axum::extract::{Path, Query, State};
+use ferriskey_core::domain::action_token::{
+    entities::{ActionTokenDetails, GetActionTokenInput},
+    ports::ActionTokenService,
+};
+use validator::Validate;
+
+use crate::application::http::{
+    authentication::validators::ActionTokenQuery,
+    server::{
+        api_entities::{api_error::ApiError, response::Response},
+        app_state::AppState,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/login-actions/action-token",
+    tag = "auth",
+    summary = "Inspect an action token",
+    description = "Returns what the action token received by email lets its holder do, without using it.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ActionTokenQuery,
+    ),
+    responses(
+        (status = 200, body = ActionTokenDetails),
+        (status = 401, description = "Invalid, used or expired token"),
+    )
+)]
+pub async fn get_action_token(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Query(params): Query<ActionTokenQuery>,
+) -> Result<Response<ActionTokenDetails>, ApiError> {
+    params.validate()?;
+
+    let details = state
+        .service
+        .get_action_token(GetActionTokenInput {
+            realm_name,
+            token: params.key,
+        })
+        .await?;
+
+    Ok(Response::OK(details))
+}
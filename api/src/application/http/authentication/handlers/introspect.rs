@@ -0,0 +1,65 @@
+use crate::application::http::authentication::validators::{
+    IntrospectRequestValidator, client_credentials,
+};
+use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Form,
+    extract::{Path, State},
+};
+use axum_extra::{
+    TypedHeader,
+    headers::{Authorization, authorization::Basic},
+};
+use ferriskey_core::domain::authentication::{
+    entities::{IntrospectTokenInput, TokenIntrospection},
+    ports::AuthService,
+};
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/protocol/openid-connect/token/introspect",
+    tag = "auth",
+    summary = "Introspect a token",
+    description = "Returns the state of an access or refresh token as defined by RFC 7662. The caller must authenticate as a confidential client, either with HTTP Basic authentication or with client_id and client_secret form parameters.",
+    request_body(
+        content = IntrospectRequestValidator,
+        content_type = "application/x-www-form-urlencoded",
+    ),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = TokenIntrospection),
+        (status = 401, description = "Invalid client credentials"),
+    )
+)]
+pub async fn introspect_token(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    basic: Option<TypedHeader<Authorization<Basic>>>,
+    Form(payload): Form<IntrospectRequestValidator>,
+) -> Result<Response<TokenIntrospection>, ApiError> {
+    payload.validate()?;
+
+    let (client_id, client_secret) = client_credentials(
+        basic.map(|TypedHeader(Authorization(basic))| basic),
+        payload.client_id,
+        payload.client_secret,
+    )
+    .ok_or_else(|| ApiError::Unauthorized("client_id is required".to_string()))?;
+
+    state
+        .service
+        .introspect_token(IntrospectTokenInput {
+            realm_name,
+            client_id,
+            client_secret,
+            token: payload.token,
+        })
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,77 @@
+use crate::application::client_ip::ClientIp;
+use crate::application::http::authentication::validators::LogoutRequestValidator;
+use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::app_state::AppState;
+use crate::application::url::FullUrl;
+use crate::application::user_agent::UserAgent;
+use axum::{
+    Form,
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header::SET_COOKIE},
+    response::{IntoResponse, Redirect},
+};
+use axum_cookie::CookieManager;
+use ferriskey_core::domain::authentication::{entities::LogoutInput, ports::AuthService};
+use uuid::Uuid;
+
+#[utoipa::path(
+    method(get, post),
+    path = "/protocol/openid-connect/logout",
+    tag = "auth",
+    summary = "Logout",
+    description = "Ends the user's session as defined by OpenID Connect RP-Initiated Logout, with the parameters in the query string or, when posted, in a form body. All refresh tokens of the user identified by id_token_hint, or else by the session cookie, are revoked and pending authentication sessions are invalidated. When post_logout_redirect_uri is provided it must match one of the client's redirect URIs.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        LogoutRequestValidator
+    ),
+    responses(
+        (status = 303, description = "Redirects to the post logout redirect URI"),
+        (status = 204, description = "Logged out"),
+        (status = 400, description = "Invalid post logout redirect URI"),
+        (status = 401, description = "Invalid id_token_hint"),
+    )
+)]
+pub async fn logout(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
+    FullUrl(_, base_url): FullUrl,
+    cookie: CookieManager,
+    Form(params): Form<LogoutRequestValidator>,
+) -> Result<impl IntoResponse, ApiError> {
+    let session_code = cookie
+        .get("FERRISKEY_SESSION")
+        .and_then(|session_code| Uuid::parse_str(session_code.value()).ok());
+
+    let output = state
+        .service
+        .logout(LogoutInput {
+            realm_name,
+            client_id: params.client_id,
+            id_token_hint: params.id_token_hint,
+            session_code,
+            post_logout_redirect_uri: params.post_logout_redirect_uri,
+            state: params.state,
+            base_url,
+            ip_address,
+            user_agent,
+        })
+        .await
+        .map_err(ApiError::from)?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        SET_COOKIE,
+        HeaderValue::from_static(
+            "FERRISKEY_SESSION=; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=0",
+        ),
+    );
+
+    let response = match output.redirect_url {
+        Some(url) => (headers, Redirect::to(&url)).into_response(),
+        None => (headers, StatusCode::NO_CONTENT).into_response(),
+    };
+
+    Ok(response)
+}
@@ -1,6 +1,13 @@
+use crate::application::http::server::api_entities::api_error::ApiError;
 use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
 use axum::http::Request;
-use axum::{body::Body, extract::Path};
+use axum::{
+    body::Body,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::ports::AuthService;
+use ferriskey_core::domain::client_scope::ports::ClientScopeService;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -11,8 +18,13 @@ pub struct GetOpenIdConfigurationResponse {
     pub token_endpoint: String,
     pub introspection_endpoint: String,
     pub userinfo_endpoint: String,
+    pub revocation_endpoint: String,
+    pub end_session_endpoint: String,
     pub jwks_uri: String,
     pub grant_types_supported: Vec<String>,
+    pub code_challenge_methods_supported: Vec<String>,
+    pub scopes_supported: Vec<String>,
+    pub id_token_signing_alg_values_supported: Vec<String>,
 }
 
 #[utoipa::path(
@@ -30,8 +42,9 @@ pub struct GetOpenIdConfigurationResponse {
 )]
 pub async fn get_openid_configuration(
     Path(realm_name): Path<String>,
+    State(state): State<AppState>,
     req: Request<Body>,
-) -> Result<Response<GetOpenIdConfigurationResponse>, String> {
+) -> Result<Response<GetOpenIdConfigurationResponse>, ApiError> {
     // Here you would typically fetch the issuer from a database or configuration
     let host = req
         .headers()
@@ -45,6 +58,16 @@ pub async fn get_openid_configuration(
             .unwrap_or("http")
     });
 
+    let scopes_supported = state
+        .service
+        .get_supported_scopes(realm_name.clone())
+        .await?;
+
+    let signing_algorithm = state
+        .service
+        .get_signing_algorithm(realm_name.clone())
+        .await?;
+
     let base_url = format!("{scheme}://{host}");
     let issuer = format!("{base_url}/realms/{realm_name}");
 
@@ -54,6 +77,8 @@ pub async fn get_openid_configuration(
         token_endpoint: format!("{issuer}/protocol/openid-connect/token"),
         introspection_endpoint: format!("{issuer}/protocol/openid-connect/token/introspect"),
         userinfo_endpoint: format!("{issuer}/protocol/openid-connect/userinfo"),
+        revocation_endpoint: format!("{issuer}/protocol/openid-connect/revoke"),
+        end_session_endpoint: format!("{issuer}/protocol/openid-connect/logout"),
         jwks_uri: format!("{issuer}/protocol/openid-connect/certs"),
         grant_types_supported: vec![
             "authorization_code".to_string(),
@@ -61,5 +86,8 @@ pub async fn get_openid_configuration(
             "client_credentials".to_string(),
             "password".to_string(),
         ],
+        code_challenge_methods_supported: vec!["S256".to_string(), "plain".to_string()],
+        scopes_supported,
+        id_token_signing_alg_values_supported: vec![signing_algorithm.to_string()],
     }))
 }
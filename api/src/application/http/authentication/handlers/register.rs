@@ -0,0 +1,65 @@
+use axum::extract::{Path, State};
+use axum_cookie::CookieManager;
+use ferriskey_core::domain::authentication::{entities::RegisterInput, ports::AuthService};
+
+use crate::application::{
+    http::{
+        authentication::{
+            handlers::{authentificate::AuthenticateResponse, consent::session_code},
+            validators::RegisterValidator,
+        },
+        server::{
+            api_entities::{
+                api_error::{ApiError, ValidateJson},
+                response::Response,
+            },
+            app_state::AppState,
+        },
+    },
+    url::FullUrl,
+};
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/registration",
+    tag = "auth",
+    summary = "Register a new user",
+    description = "Creates an account from the login session when the realm allows registration, then continues the login of the new user. Realms requiring email verification send a verification link and return the verify_email required action.",
+    request_body = RegisterValidator,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = AuthenticateResponse),
+        (status = 401, description = "Missing session cookie"),
+        (status = 403, description = "Registration is disabled"),
+        (status = 400, description = "Username or email already in use"),
+    )
+)]
+pub async fn register(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    FullUrl(_, base_url): FullUrl,
+    cookie: CookieManager,
+    ValidateJson(payload): ValidateJson<RegisterValidator>,
+) -> Result<Response<AuthenticateResponse>, ApiError> {
+    let session_code = session_code(&cookie)?;
+
+    let result = state
+        .service
+        .register(RegisterInput {
+            realm_name,
+            session_code,
+            base_url,
+            webapp_url: state.args.webapp_url.clone(),
+            username: payload.username,
+            email: payload.email,
+            firstname: payload.firstname,
+            lastname: payload.lastname,
+            password: payload.password,
+            attributes: payload.attributes.unwrap_or_default(),
+        })
+        .await?;
+
+    Ok(Response::OK(result.into()))
+}
@@ -0,0 +1,63 @@
+use crate::application::http::authentication::validators::{
+    RevokeRequestValidator, client_credentials,
+};
+use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Form,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use axum_extra::{
+    TypedHeader,
+    headers::{Authorization, authorization::Basic},
+};
+use ferriskey_core::domain::authentication::{entities::RevokeTokenInput, ports::AuthService};
+use validator::Validate;
+
+#[utoipa::path(
+    post,
+    path = "/protocol/openid-connect/revoke",
+    tag = "auth",
+    summary = "Revoke a token",
+    description = "Revokes the refresh tokens issued to the calling client for the token's subject, as defined by RFC 7009. Unknown, invalid or expired tokens are ignored.",
+    request_body(
+        content = RevokeRequestValidator,
+        content_type = "application/x-www-form-urlencoded",
+    ),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, description = "Token revoked"),
+        (status = 401, description = "Invalid client credentials"),
+    )
+)]
+pub async fn revoke_token(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    basic: Option<TypedHeader<Authorization<Basic>>>,
+    Form(payload): Form<RevokeRequestValidator>,
+) -> Result<StatusCode, ApiError> {
+    payload.validate()?;
+
+    let (client_id, client_secret) = client_credentials(
+        basic.map(|TypedHeader(Authorization(basic))| basic),
+        payload.client_id,
+        payload.client_secret,
+    )
+    .ok_or_else(|| ApiError::Unauthorized("client_id is required".to_string()))?;
+
+    state
+        .service
+        .revoke_token(RevokeTokenInput {
+            realm_name,
+            client_id,
+            client_secret,
+            token: payload.token,
+        })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(StatusCode::OK)
+}
@@ -1,8 +1,10 @@
+use crate::application::client_ip::ClientIp;
 use crate::application::http::authentication::validators::TokenRequestValidator;
 use crate::application::http::server::api_entities::api_error::ApiError;
 use crate::application::http::server::api_entities::response::Response;
 use crate::application::http::server::app_state::AppState;
 use crate::application::url::FullUrl;
+use crate::application::user_agent::UserAgent;
 use axum::{
     Form,
     extract::{Path, State},
@@ -25,6 +27,8 @@ pub async fn exchange_token(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     FullUrl(_, base_url): FullUrl,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
     Form(payload): Form<TokenRequestValidator>,
 ) -> Result<Response<JwtToken>, ApiError> {
     state
@@ -39,6 +43,10 @@ pub async fn exchange_token(
             refresh_token: payload.refresh_token,
             base_url,
             grant_type: payload.grant_type,
+            scope: payload.scope,
+            code_verifier: payload.code_verifier,
+            ip_address,
+            user_agent,
         })
         .await
         .map(Response::OK)
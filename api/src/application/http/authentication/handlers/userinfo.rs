@@ -0,0 +1,45 @@
+use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::extract::{Path, State};
+use axum_extra::{
+    TypedHeader,
+    headers::{Authorization, authorization::Bearer},
+};
+use ferriskey_core::domain::authentication::{
+    entities::{GetUserInfoInput, UserInfo},
+    ports::AuthService,
+};
+
+#[utoipa::path(
+    get,
+    path = "/protocol/openid-connect/userinfo",
+    tag = "auth",
+    summary = "Get user info",
+    description = "Returns the claims about the authenticated end-user, as defined by OpenID Connect. The claims returned depend on the scopes granted to the access token.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    security(
+        ("Authorization" = ["Bearer"]),
+    ),
+    responses(
+        (status = 200, body = UserInfo),
+        (status = 401, description = "Invalid or expired access token"),
+    )
+)]
+pub async fn get_userinfo(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    TypedHeader(Authorization(bearer)): TypedHeader<Authorization<Bearer>>,
+) -> Result<Response<UserInfo>, ApiError> {
+    state
+        .service
+        .get_userinfo(GetUserInfoInput {
+            realm_name,
+            token: bearer.token().to_string(),
+        })
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -7,9 +7,18 @@ use utoipa::OpenApi;
 use super::handlers::{
     auth::{__path_auth, auth},
     authentificate::{__path_authenticate, authenticate},
+    consent::{__path_consent, __path_get_consent, consent, get_consent},
+    execute_action_token::{__path_execute_action_token, execute_action_token},
+    forgot_password::{__path_forgot_password, forgot_password},
+    get_action_token::{__path_get_action_token, get_action_token},
     get_certs::{__path_get_certs, get_certs},
+    introspect::{__path_introspect_token, introspect_token},
+    logout::{__path_logout, logout},
     openid_configuration::{__path_get_openid_configuration, get_openid_configuration},
+    register::{__path_register, register},
+    revoke::{__path_revoke_token, revoke_token},
     token::{__path_exchange_token, exchange_token},
+    userinfo::{__path_get_userinfo, get_userinfo},
 };
 use crate::application::http::server::app_state::AppState;
 
@@ -17,9 +26,19 @@ use crate::application::http::server::app_state::AppState;
 #[openapi(paths(
     exchange_token,
     authenticate,
+    get_consent,
+    consent,
     get_certs,
     auth,
-    get_openid_configuration
+    get_openid_configuration,
+    get_userinfo,
+    introspect_token,
+    revoke_token,
+    logout,
+    forgot_password,
+    get_action_token,
+    execute_action_token,
+    register
 ))]
 pub struct AuthenticationApiDoc;
 
@@ -29,6 +48,18 @@ pub fn authentication_routes(root_path: &str) -> Router<AppState> {
             &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/token"),
             post(exchange_token),
         )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/token/introspect"),
+            post(introspect_token),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/revoke"),
+            post(revoke_token),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/logout"),
+            get(logout).post(logout),
+        )
         .route(
             &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/auth"),
             get(auth),
@@ -37,6 +68,22 @@ pub fn authentication_routes(root_path: &str) -> Router<AppState> {
             &format!("{root_path}/realms/{{realm_name}}/login-actions/authenticate"),
             post(authenticate),
         )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/login-actions/consent"),
+            get(get_consent).post(consent),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/login-actions/reset-credentials"),
+            post(forgot_password),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/login-actions/action-token"),
+            get(get_action_token).post(execute_action_token),
+        )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/login-actions/registration"),
+            post(register),
+        )
         .route(
             &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/certs"),
             get(get_certs),
@@ -45,4 +92,8 @@ pub fn authentication_routes(root_path: &str) -> Router<AppState> {
             &format!("{root_path}/realms/{{realm_name}}/.well-known/openid-configuration"),
             get(get_openid_configuration),
         )
+        .route(
+            &format!("{root_path}/realms/{{realm_name}}/protocol/openid-connect/userinfo"),
+            get(get_userinfo).post(get_userinfo),
+        )
 }
@@ -1,6 +1,7 @@
-use ferriskey_core::domain::authentication::entities::GrantType;
+use axum_extra::headers::authorization::Basic;
+use ferriskey_core::domain::{authentication::entities::GrantType, user::entities::UserAttributes};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -26,4 +27,128 @@ pub struct TokenRequestValidator {
 
     #[serde(default)]
     pub refresh_token: Option<String>,
+
+    #[serde(default)]
+    pub scope: Option<String>,
+
+    #[serde(default)]
+    pub code_verifier: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct IntrospectRequestValidator {
+    #[validate(length(min = 1, message = "token is required"))]
+    #[serde(default)]
+    pub token: String,
+
+    #[serde(default)]
+    pub token_type_hint: Option<String>,
+
+    #[serde(default)]
+    pub client_id: Option<String>,
+
+    #[serde(default)]
+    pub client_secret: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RevokeRequestValidator {
+    #[validate(length(min = 1, message = "token is required"))]
+    #[serde(default)]
+    pub token: String,
+
+    #[serde(default)]
+    pub token_type_hint: Option<String>,
+
+    #[serde(default)]
+    pub client_id: Option<String>,
+
+    #[serde(default)]
+    pub client_secret: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LogoutRequestValidator {
+    #[serde(default)]
+    pub id_token_hint: Option<String>,
+
+    #[serde(default)]
+    pub post_logout_redirect_uri: Option<String>,
+
+    #[serde(default)]
+    pub client_id: Option<String>,
+
+    #[serde(default)]
+    pub state: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ForgotPasswordValidator {
+    /// Username or email address of the account.
+    #[validate(length(min = 1, message = "username is required"))]
+    #[serde(default)]
+    pub username: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ActionTokenQuery {
+    /// Action token received by email.
+    #[validate(length(min = 1, message = "key is required"))]
+    #[serde(default)]
+    pub key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ExecuteActionTokenValidator {
+    #[validate(length(min = 1, message = "key is required"))]
+    #[serde(default)]
+    pub key: String,
+
+    /// New password, required by tokens resetting the password.
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RegisterValidator {
+    #[validate(length(min = 1, message = "username is required"))]
+    #[serde(default)]
+    pub username: String,
+
+    #[validate(email(message = "email must be an email address"))]
+    #[serde(default)]
+    pub email: String,
+
+    #[validate(length(min = 1, message = "firstname is required"))]
+    #[serde(default)]
+    pub firstname: String,
+
+    #[validate(length(min = 1, message = "lastname is required"))]
+    #[serde(default)]
+    pub lastname: String,
+
+    #[validate(length(min = 1, message = "password is required"))]
+    #[serde(default)]
+    pub password: String,
+
+    #[serde(default)]
+    pub attributes: Option<UserAttributes>,
+}
+
+/// Resolves the calling client from HTTP Basic authentication, falling back
+/// to the `client_id`/`client_secret` form parameters.
+pub fn client_credentials(
+    basic: Option<Basic>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+) -> Option<(String, Option<String>)> {
+    match basic {
+        Some(basic) => Some((
+            basic.username().to_string(),
+            Some(basic.password().to_string()),
+        )),
+        None => client_id.map(|client_id| (client_id, client_secret)),
+    }
 }
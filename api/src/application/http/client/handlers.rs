@@ -1,11 +1,18 @@
+pub mod assign_client_scope;
 pub mod create_client;
+pub mod create_protocol_mapper;
 pub mod create_redirect_uri;
 pub mod create_role;
 pub mod delete_client;
+pub mod delete_protocol_mapper;
 pub mod delete_redirect_uri;
 pub mod get_client;
 pub mod get_client_roles;
+pub mod get_client_scope_mappings;
 pub mod get_clients;
+pub mod get_protocol_mappers;
 pub mod get_redirect_uris;
+pub mod remove_client_scope;
 pub mod update_client;
+pub mod update_protocol_mapper;
 pub mod update_redirect_uri;
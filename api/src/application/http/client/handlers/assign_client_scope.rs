@@ -0,0 +1,58 @@
+use crate::application::http::{
+    client::validators::AssignClientScopeValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::AssignClientScopeInput, ports::ClientScopeService,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    put,
+    path = "/{client_id}/client-scopes/{scope_id}",
+    summary = "Link a client scope to a client",
+    description = "Links a client scope to a client as a default or optional scope. Linking an already linked scope changes its type.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+    ),
+    tag = "client",
+    request_body = AssignClientScopeValidator,
+    responses(
+        (status = 200, description = "Client scope linked successfully"),
+        (status = 404, description = "Client or client scope not found"),
+    ),
+)]
+pub async fn assign_client_scope(
+    Path((realm_name, client_id, scope_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<AssignClientScopeValidator>,
+) -> Result<Response<()>, ApiError> {
+    state
+        .service
+        .assign_client_scope(
+            identity,
+            AssignClientScopeInput {
+                realm_name,
+                client_id,
+                scope_id,
+                default_scope: payload.default_scope,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(|_| Response::OK(()))
+}
@@ -0,0 +1,64 @@
+use crate::application::http::{
+    client::validators::CreateProtocolMapperValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::entities::protocol_mapper::{
+    ProtocolMapper, ProtocolMapperTargets,
+};
+use ferriskey_core::domain::client::{entities::CreateProtocolMapperInput, ports::ClientService};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/{client_id}/protocol-mappers",
+    summary = "Create a protocol mapper for a client",
+    description = "Adds a protocol mapper to a client. The mapper is evaluated for every token issued to the client and applies to the access token, ID token, userinfo and introspection responses unless disabled for some of them.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    tag = "client",
+    request_body = CreateProtocolMapperValidator,
+    responses(
+        (status = 201, body = ProtocolMapper, description = "Protocol mapper created successfully"),
+        (status = 400, description = "Invalid mapper configuration or name already used"),
+    ),
+)]
+pub async fn create_protocol_mapper(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateProtocolMapperValidator>,
+) -> Result<Response<ProtocolMapper>, ApiError> {
+    state
+        .service
+        .create_protocol_mapper(
+            identity,
+            CreateProtocolMapperInput {
+                realm_name,
+                client_id,
+                name: payload.name,
+                config: payload.config,
+                targets: ProtocolMapperTargets {
+                    access_token: payload.access_token.unwrap_or(true),
+                    id_token: payload.id_token.unwrap_or(true),
+                    userinfo: payload.userinfo.unwrap_or(true),
+                    introspection: payload.introspection.unwrap_or(true),
+                },
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::Created)
+}
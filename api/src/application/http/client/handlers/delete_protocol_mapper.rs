@@ -0,0 +1,47 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::{entities::DeleteProtocolMapperInput, ports::ClientService};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/{client_id}/protocol-mappers/{mapper_id}",
+    summary = "Delete a protocol mapper of a client",
+    description = "Removes a protocol mapper from a client. Tokens issued afterwards no longer carry its claims.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+        ("mapper_id" = Uuid, Path, description = "Protocol mapper ID"),
+    ),
+    tag = "client",
+    responses(
+        (status = 200, description = "Protocol mapper deleted successfully"),
+        (status = 404, description = "Protocol mapper not found"),
+    ),
+)]
+pub async fn delete_protocol_mapper(
+    Path((realm_name, client_id, mapper_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<()>, ApiError> {
+    state
+        .service
+        .delete_protocol_mapper(
+            identity,
+            DeleteProtocolMapperInput {
+                realm_name,
+                client_id,
+                mapper_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(|_| Response::OK(()))
+}
@@ -0,0 +1,57 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::{ClientScopeMapping, GetClientScopeMappingsInput},
+    ports::ClientScopeService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetClientScopeMappingsResponse {
+    pub data: Vec<ClientScopeMapping>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{client_id}/client-scopes",
+    summary = "Get the client scopes of a client",
+    description = "Retrieves the default and optional client scopes linked to a client.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    tag = "client",
+    responses(
+        (status = 200, body = GetClientScopeMappingsResponse),
+        (status = 404, description = "Client not found"),
+    ),
+)]
+pub async fn get_client_scope_mappings(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetClientScopeMappingsResponse>, ApiError> {
+    let mappings = state
+        .service
+        .get_client_scope_mappings(
+            identity,
+            GetClientScopeMappingsInput {
+                realm_name,
+                client_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetClientScopeMappingsResponse {
+        data: mappings,
+    }))
+}
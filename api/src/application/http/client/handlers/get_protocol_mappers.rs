@@ -0,0 +1,45 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::entities::protocol_mapper::ProtocolMapper;
+use ferriskey_core::domain::client::{entities::GetProtocolMappersInput, ports::ClientService};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "/{client_id}/protocol-mappers",
+    summary = "Get protocol mappers for a client",
+    description = "Retrieves the protocol mappers of a client. Protocol mappers add claims such as roles, groups or user attributes to the tokens issued to the client.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    tag = "client",
+    responses(
+        (status = 200, body = Vec<ProtocolMapper>),
+    ),
+)]
+pub async fn get_protocol_mappers(
+    Path((realm_name, client_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<ProtocolMapper>>, ApiError> {
+    state
+        .service
+        .get_protocol_mappers(
+            identity,
+            GetProtocolMappersInput {
+                realm_name,
+                client_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::OK)
+}
@@ -0,0 +1,48 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::RemoveClientScopeInput, ports::ClientScopeService,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/{client_id}/client-scopes/{scope_id}",
+    summary = "Unlink a client scope from a client",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+    ),
+    tag = "client",
+    responses(
+        (status = 200, description = "Client scope unlinked successfully"),
+        (status = 404, description = "Client scope not linked to the client"),
+    ),
+)]
+pub async fn remove_client_scope(
+    Path((realm_name, client_id, scope_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<()>, ApiError> {
+    state
+        .service
+        .remove_client_scope(
+            identity,
+            RemoveClientScopeInput {
+                realm_name,
+                client_id,
+                scope_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(|_| Response::OK(()))
+}
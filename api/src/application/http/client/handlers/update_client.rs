@@ -53,6 +53,11 @@ pub async fn update_client(
                     client_id: payload.client_id,
                     enabled: payload.enabled,
                     direct_access_grants_enabled: payload.direct_access_grants_enabled,
+                    pkce_required: payload.pkce_required,
+                    access_token_lifespan: payload.access_token_lifespan,
+                    refresh_token_lifespan: payload.refresh_token_lifespan,
+                    auth_code_lifespan: payload.auth_code_lifespan,
+                    consent_required: payload.consent_required,
                 },
             },
         )
@@ -5,16 +5,23 @@ use axum::{
 use utoipa::OpenApi;
 
 use super::handlers::{
+    assign_client_scope::{__path_assign_client_scope, assign_client_scope},
     create_client::{__path_create_client, create_client},
+    create_protocol_mapper::{__path_create_protocol_mapper, create_protocol_mapper},
     create_redirect_uri::{__path_create_redirect_uri, create_redirect_uri},
     create_role::{__path_create_role, create_role},
     delete_client::{__path_delete_client, delete_client},
+    delete_protocol_mapper::{__path_delete_protocol_mapper, delete_protocol_mapper},
     delete_redirect_uri::{__path_delete_redirect_uri, delete_redirect_uri},
     get_client::{__path_get_client, get_client},
     get_client_roles::{__path_get_client_roles, get_client_roles},
+    get_client_scope_mappings::{__path_get_client_scope_mappings, get_client_scope_mappings},
     get_clients::{__path_get_clients, get_clients},
+    get_protocol_mappers::{__path_get_protocol_mappers, get_protocol_mappers},
     get_redirect_uris::{__path_get_redirect_uris, get_redirect_uris},
+    remove_client_scope::{__path_remove_client_scope, remove_client_scope},
     update_client::{__path_update_client, update_client},
+    update_protocol_mapper::{__path_update_protocol_mapper, update_protocol_mapper},
     update_redirect_uri::{__path_update_redirect_uri, update_redirect_uri},
 };
 use crate::application::{auth::auth, http::server::app_state::AppState};
@@ -32,7 +39,14 @@ use crate::application::{auth::auth, http::server::app_state::AppState};
         update_client,
         update_redirect_uri,
         delete_redirect_uri,
-        get_client_roles
+        get_client_roles,
+        get_protocol_mappers,
+        create_protocol_mapper,
+        update_protocol_mapper,
+        delete_protocol_mapper,
+        get_client_scope_mappings,
+        assign_client_scope,
+        remove_client_scope
     ),
 
     tags(
@@ -120,5 +134,54 @@ pub fn client_routes(state: AppState) -> Router<AppState> {
             ),
             get(get_client_roles),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/protocol-mappers",
+                state.args.server.root_path
+            ),
+            get(get_protocol_mappers),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/protocol-mappers",
+                state.args.server.root_path
+            ),
+            post(create_protocol_mapper),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/protocol-mappers/{{mapper_id}}",
+                state.args.server.root_path
+            ),
+            put(update_protocol_mapper),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/protocol-mappers/{{mapper_id}}",
+                state.args.server.root_path
+            ),
+            delete(delete_protocol_mapper),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/client-scopes",
+                state.args.server.root_path
+            ),
+            get(get_client_scope_mappings),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/client-scopes/{{scope_id}}",
+                state.args.server.root_path
+            ),
+            put(assign_client_scope),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/clients/{{client_id}}/client-scopes/{{scope_id}}",
+                state.args.server.root_path
+            ),
+            delete(remove_client_scope),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
@@ -1,3 +1,4 @@
+use ferriskey_core::domain::client::entities::protocol_mapper::ProtocolMapperConfig;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
@@ -39,6 +40,27 @@ pub struct UpdateClientValidator {
 
     #[serde(default)]
     pub direct_access_grants_enabled: Option<bool>,
+
+    #[serde(default)]
+    pub pkce_required: Option<bool>,
+
+    /// Access token lifespan in seconds, `0` to use the realm setting.
+    #[validate(range(min = 0, message = "access_token_lifespan must not be negative"))]
+    #[serde(default)]
+    pub access_token_lifespan: Option<i32>,
+
+    /// Refresh token lifespan in seconds, `0` to use the realm setting.
+    #[validate(range(min = 0, message = "refresh_token_lifespan must not be negative"))]
+    #[serde(default)]
+    pub refresh_token_lifespan: Option<i32>,
+
+    /// Authorization code lifespan in seconds, `0` to use the realm setting.
+    #[validate(range(min = 0, message = "auth_code_lifespan must not be negative"))]
+    #[serde(default)]
+    pub auth_code_lifespan: Option<i32>,
+
+    #[serde(default)]
+    pub consent_required: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -55,3 +77,47 @@ pub struct UpdateRedirectUriValidator {
     #[serde(default)]
     pub enabled: bool,
 }
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateProtocolMapperValidator {
+    #[validate(length(min = 1, max = 255, message = "name is required"))]
+    #[serde(default)]
+    pub name: String,
+    pub config: ProtocolMapperConfig,
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub access_token: Option<bool>,
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub id_token: Option<bool>,
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub userinfo: Option<bool>,
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub introspection: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateProtocolMapperValidator {
+    #[validate(length(min = 1, max = 255, message = "name must not be empty"))]
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub config: Option<ProtocolMapperConfig>,
+    #[serde(default)]
+    pub access_token: Option<bool>,
+    #[serde(default)]
+    pub id_token: Option<bool>,
+    #[serde(default)]
+    pub userinfo: Option<bool>,
+    #[serde(default)]
+    pub introspection: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AssignClientScopeValidator {
+    /// Default scopes are always granted, optional ones only when requested.
+    #[serde(default)]
+    pub default_scope: bool,
+}
@@ -0,0 +1,9 @@
+pub mod create_client_scope;
+pub mod create_client_scope_protocol_mapper;
+pub mod delete_client_scope;
+pub mod delete_client_scope_protocol_mapper;
+pub mod get_client_scope;
+pub mod get_client_scope_protocol_mappers;
+pub mod get_client_scopes;
+pub mod update_client_scope;
+pub mod update_client_scope_protocol_mapper;
@@ -0,0 +1,69 @@
+use crate::application::http::{
+    client_scope::validators::CreateClientScopeValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::{ClientScope, CreateClientScopeInput},
+    ports::ClientScopeService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct CreateClientScopeResponse {
+    pub data: ClientScope,
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "client-scope",
+    summary = "Create a client scope in a realm",
+    description = "Creates a client scope. Scopes with a `default` or `optional` type are linked to the clients created afterwards in the realm.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body = CreateClientScopeValidator,
+    responses(
+        (status = 201, body = CreateClientScopeResponse, description = "Client scope created successfully"),
+        (status = 400, description = "A client scope with the same name already exists"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+    ),
+)]
+pub async fn create_client_scope(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateClientScopeValidator>,
+) -> Result<Response<CreateClientScopeResponse>, ApiError> {
+    let client_scope = state
+        .service
+        .create_client_scope(
+            identity,
+            CreateClientScopeInput {
+                realm_name,
+                name: payload.name,
+                description: payload.description,
+                include_in_token_scope: payload.include_in_token_scope.unwrap_or(true),
+                display_on_consent_screen: payload.display_on_consent_screen.unwrap_or(true),
+                consent_text: payload.consent_text,
+                default_type: payload.default_type,
+            },
+        )
+        .await?;
+
+    Ok(Response::Created(CreateClientScopeResponse {
+        data: client_scope,
+    }))
+}
@@ -0,0 +1,67 @@
+use crate::application::http::{
+    client::validators::CreateProtocolMapperValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::entities::protocol_mapper::{
+    ProtocolMapper, ProtocolMapperTargets,
+};
+use ferriskey_core::domain::client_scope::{
+    entities::CreateClientScopeProtocolMapperInput, ports::ClientScopeService,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/{scope_id}/protocol-mappers",
+    tag = "client-scope",
+    summary = "Create a protocol mapper for a client scope",
+    description = "Adds a protocol mapper to a client scope. The mapper is evaluated for every token whose granted scopes include the client scope.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+    ),
+    request_body = CreateProtocolMapperValidator,
+    responses(
+        (status = 201, body = ProtocolMapper, description = "Protocol mapper created successfully"),
+        (status = 400, description = "Invalid mapper configuration or name already used"),
+        (status = 404, description = "Client scope not found"),
+    ),
+)]
+pub async fn create_client_scope_protocol_mapper(
+    Path((realm_name, scope_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateProtocolMapperValidator>,
+) -> Result<Response<ProtocolMapper>, ApiError> {
+    state
+        .service
+        .create_client_scope_protocol_mapper(
+            identity,
+            CreateClientScopeProtocolMapperInput {
+                realm_name,
+                scope_id,
+                name: payload.name,
+                config: payload.config,
+                targets: ProtocolMapperTargets {
+                    access_token: payload.access_token.unwrap_or(true),
+                    id_token: payload.id_token.unwrap_or(true),
+                    userinfo: payload.userinfo.unwrap_or(true),
+                    introspection: payload.introspection.unwrap_or(true),
+                },
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::Created)
+}
@@ -0,0 +1,61 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::DeleteClientScopeInput, ports::ClientScopeService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteClientScopeResponse {
+    pub message: String,
+    pub realm_name: String,
+    pub scope_id: Uuid,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{scope_id}",
+    tag = "client-scope",
+    summary = "Delete a client scope",
+    description = "Deletes a client scope together with its protocol mappers and its links to clients.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+    ),
+    responses(
+        (status = 200, body = DeleteClientScopeResponse, description = "Client scope deleted successfully"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Client scope not found"),
+    ),
+)]
+pub async fn delete_client_scope(
+    Path((realm_name, scope_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteClientScopeResponse>, ApiError> {
+    state
+        .service
+        .delete_client_scope(
+            identity,
+            DeleteClientScopeInput {
+                realm_name: realm_name.clone(),
+                scope_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(DeleteClientScopeResponse {
+        message: format!("Client scope {scope_id} in realm {realm_name} deleted successfully"),
+        realm_name,
+        scope_id,
+    }))
+}
@@ -0,0 +1,48 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::DeleteClientScopeProtocolMapperInput, ports::ClientScopeService,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    path = "/{scope_id}/protocol-mappers/{mapper_id}",
+    tag = "client-scope",
+    summary = "Delete a protocol mapper of a client scope",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+        ("mapper_id" = Uuid, Path, description = "Protocol mapper ID"),
+    ),
+    responses(
+        (status = 200, description = "Protocol mapper deleted successfully"),
+        (status = 404, description = "Protocol mapper not found"),
+    ),
+)]
+pub async fn delete_client_scope_protocol_mapper(
+    Path((realm_name, scope_id, mapper_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<()>, ApiError> {
+    state
+        .service
+        .delete_client_scope_protocol_mapper(
+            identity,
+            DeleteClientScopeProtocolMapperInput {
+                realm_name,
+                scope_id,
+                mapper_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(|_| Response::OK(()))
+}
@@ -0,0 +1,55 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::{ClientScope, GetClientScopeInput},
+    ports::ClientScopeService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetClientScopeResponse {
+    pub data: ClientScope,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{scope_id}",
+    tag = "client-scope",
+    summary = "Get a client scope",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+    ),
+    responses(
+        (status = 200, body = GetClientScopeResponse),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Client scope not found"),
+    ),
+)]
+pub async fn get_client_scope(
+    Path((realm_name, scope_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetClientScopeResponse>, ApiError> {
+    let client_scope = state
+        .service
+        .get_client_scope(
+            identity,
+            GetClientScopeInput {
+                realm_name,
+                scope_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetClientScopeResponse { data: client_scope }))
+}
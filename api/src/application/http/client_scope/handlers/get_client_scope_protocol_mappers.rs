@@ -0,0 +1,47 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::entities::protocol_mapper::ProtocolMapper;
+use ferriskey_core::domain::client_scope::{
+    entities::GetClientScopeInput, ports::ClientScopeService,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    get,
+    path = "/{scope_id}/protocol-mappers",
+    tag = "client-scope",
+    summary = "Get the protocol mappers of a client scope",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+    ),
+    responses(
+        (status = 200, body = Vec<ProtocolMapper>),
+        (status = 404, description = "Client scope not found"),
+    ),
+)]
+pub async fn get_client_scope_protocol_mappers(
+    Path((realm_name, scope_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<Vec<ProtocolMapper>>, ApiError> {
+    state
+        .service
+        .get_client_scope_protocol_mappers(
+            identity,
+            GetClientScopeInput {
+                realm_name,
+                scope_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::OK)
+}
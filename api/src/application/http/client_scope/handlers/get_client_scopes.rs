@@ -0,0 +1,45 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{entities::ClientScope, ports::ClientScopeService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetClientScopesResponse {
+    pub data: Vec<ClientScope>,
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "client-scope",
+    summary = "Get all client scopes of a realm",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = GetClientScopesResponse),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+    ),
+)]
+pub async fn get_client_scopes(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetClientScopesResponse>, ApiError> {
+    let client_scopes = state
+        .service
+        .get_client_scopes(identity, realm_name)
+        .await?;
+
+    Ok(Response::OK(GetClientScopesResponse {
+        data: client_scopes,
+    }))
+}
@@ -0,0 +1,73 @@
+use crate::application::http::{
+    client_scope::validators::UpdateClientScopeValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::{ClientScope, UpdateClientScopeInput},
+    ports::ClientScopeService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct UpdateClientScopeResponse {
+    pub data: ClientScope,
+}
+
+#[utoipa::path(
+    put,
+    path = "/{scope_id}",
+    tag = "client-scope",
+    summary = "Update a client scope",
+    description = "Updates a client scope. Omitted fields are left unchanged.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+    ),
+    request_body = UpdateClientScopeValidator,
+    responses(
+        (status = 200, body = UpdateClientScopeResponse),
+        (status = 400, description = "A client scope with the same name already exists"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Client scope not found"),
+    ),
+)]
+pub async fn update_client_scope(
+    Path((realm_name, scope_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateClientScopeValidator>,
+) -> Result<Response<UpdateClientScopeResponse>, ApiError> {
+    let client_scope = state
+        .service
+        .update_client_scope(
+            identity,
+            UpdateClientScopeInput {
+                realm_name,
+                scope_id,
+                name: payload.name,
+                description: payload.description,
+                include_in_token_scope: payload.include_in_token_scope,
+                display_on_consent_screen: payload.display_on_consent_screen,
+                consent_text: payload.consent_text,
+                default_type: payload.default_type,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(UpdateClientScopeResponse {
+        data: client_scope,
+    }))
+}
@@ -0,0 +1,64 @@
+use crate::application::http::{
+    client::validators::UpdateProtocolMapperValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client::entities::protocol_mapper::ProtocolMapper;
+use ferriskey_core::domain::client_scope::{
+    entities::UpdateClientScopeProtocolMapperInput, ports::ClientScopeService,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    put,
+    path = "/{scope_id}/protocol-mappers/{mapper_id}",
+    tag = "client-scope",
+    summary = "Update a protocol mapper of a client scope",
+    description = "Updates the name, configuration or token types of a protocol mapper. Omitted fields are left unchanged.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("scope_id" = Uuid, Path, description = "Client scope ID"),
+        ("mapper_id" = Uuid, Path, description = "Protocol mapper ID"),
+    ),
+    request_body = UpdateProtocolMapperValidator,
+    responses(
+        (status = 200, body = ProtocolMapper),
+        (status = 404, description = "Protocol mapper not found"),
+    ),
+)]
+pub async fn update_client_scope_protocol_mapper(
+    Path((realm_name, scope_id, mapper_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateProtocolMapperValidator>,
+) -> Result<Response<ProtocolMapper>, ApiError> {
+    state
+        .service
+        .update_client_scope_protocol_mapper(
+            identity,
+            UpdateClientScopeProtocolMapperInput {
+                realm_name,
+                scope_id,
+                mapper_id,
+                name: payload.name,
+                config: payload.config,
+                access_token: payload.access_token,
+                id_token: payload.id_token,
+                userinfo: payload.userinfo,
+                introspection: payload.introspection,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(Response::OK)
+}
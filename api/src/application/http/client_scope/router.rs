@@ -0,0 +1,109 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get, post, put},
+};
+use utoipa::OpenApi;
+
+use crate::application::{auth::auth, http::server::app_state::AppState};
+
+use super::handlers::{
+    create_client_scope::{__path_create_client_scope, create_client_scope},
+    create_client_scope_protocol_mapper::{
+        __path_create_client_scope_protocol_mapper, create_client_scope_protocol_mapper,
+    },
+    delete_client_scope::{__path_delete_client_scope, delete_client_scope},
+    delete_client_scope_protocol_mapper::{
+        __path_delete_client_scope_protocol_mapper, delete_client_scope_protocol_mapper,
+    },
+    get_client_scope::{__path_get_client_scope, get_client_scope},
+    get_client_scope_protocol_mappers::{
+        __path_get_client_scope_protocol_mappers, get_client_scope_protocol_mappers,
+    },
+    get_client_scopes::{__path_get_client_scopes, get_client_scopes},
+    update_client_scope::{__path_update_client_scope, update_client_scope},
+    update_client_scope_protocol_mapper::{
+        __path_update_client_scope_protocol_mapper, update_client_scope_protocol_mapper,
+    },
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_client_scopes,
+    create_client_scope,
+    get_client_scope,
+    update_client_scope,
+    delete_client_scope,
+    get_client_scope_protocol_mappers,
+    create_client_scope_protocol_mapper,
+    update_client_scope_protocol_mapper,
+    delete_client_scope_protocol_mapper,
+))]
+pub struct ClientScopeApiDoc;
+
+pub fn client_scope_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes",
+                state.args.server.root_path
+            ),
+            get(get_client_scopes),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes",
+                state.args.server.root_path
+            ),
+            post(create_client_scope),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes/{{scope_id}}",
+                state.args.server.root_path
+            ),
+            get(get_client_scope),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes/{{scope_id}}",
+                state.args.server.root_path
+            ),
+            put(update_client_scope),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes/{{scope_id}}",
+                state.args.server.root_path
+            ),
+            delete(delete_client_scope),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes/{{scope_id}}/protocol-mappers",
+                state.args.server.root_path
+            ),
+            get(get_client_scope_protocol_mappers),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes/{{scope_id}}/protocol-mappers",
+                state.args.server.root_path
+            ),
+            post(create_client_scope_protocol_mapper),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes/{{scope_id}}/protocol-mappers/{{mapper_id}}",
+                state.args.server.root_path
+            ),
+            put(update_client_scope_protocol_mapper),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/client-scopes/{{scope_id}}/protocol-mappers/{{mapper_id}}",
+                state.args.server.root_path
+            ),
+            delete(delete_client_scope_protocol_mapper),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
@@ -0,0 +1,50 @@
+use ferriskey_core::domain::client_scope::entities::ClientScopeDefaultType;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateClientScopeValidator {
+    #[validate(length(min = 1, max = 255, message = "name is required"))]
+    #[serde(default)]
+    pub name: String,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub include_in_token_scope: Option<bool>,
+
+    /// Defaults to `true`.
+    #[serde(default)]
+    pub display_on_consent_screen: Option<bool>,
+
+    #[serde(default)]
+    pub consent_text: Option<String>,
+
+    #[serde(default)]
+    pub default_type: ClientScopeDefaultType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateClientScopeValidator {
+    #[validate(length(min = 1, max = 255, message = "name must not be empty"))]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub include_in_token_scope: Option<bool>,
+
+    #[serde(default)]
+    pub display_on_consent_screen: Option<bool>,
+
+    #[serde(default)]
+    pub consent_text: Option<String>,
+
+    #[serde(default)]
+    pub default_type: Option<ClientScopeDefaultType>,
+}
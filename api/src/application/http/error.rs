@@ -1,6 +1,6 @@
 use ferriskey_core::domain::common::entities::app_errors::CoreError;
 
-use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::api_entities::api_error::{ApiError, ValidationError};
 
 impl From<CoreError> for ApiError {
     fn from(error: CoreError) -> Self {
@@ -99,12 +99,43 @@ impl From<CoreError> for ApiError {
             CoreError::WebhookRealmNotFound => {
                 Self::NotFound("Realm not found for webhook".to_string())
             }
+            CoreError::InvalidWebhook(msg) => Self::BadRequest(format!("Invalid webhook: {msg}")),
             CoreError::CreateClientError => {
                 Self::InternalServerError("Failed to create client".to_string())
             }
             CoreError::ServiceUnavailable(msg) => Self::ServiceUnavailable(msg),
             CoreError::RecoveryCodeGenError(msg) => Self::BadRequest(msg),
             CoreError::RecoveryCodeBurnError(msg) => Self::BadRequest(msg),
+            CoreError::AccountLocked => {
+                Self::Forbidden("Account is temporarily locked".to_string())
+            }
+            CoreError::EmailNotVerified => {
+                Self::Forbidden("Email address is not verified".to_string())
+            }
+            CoreError::PasswordPolicyViolation(violations) => Self::UnProcessableEntity(
+                violations
+                    .into_iter()
+                    .map(|violation| ValidationError {
+                        message: violation.to_string(),
+                        field: "password".to_string(),
+                    })
+                    .collect(),
+            ),
+            CoreError::UserAttributeViolation(violations) => Self::UnProcessableEntity(
+                violations
+                    .into_iter()
+                    .map(|violation| ValidationError {
+                        message: violation.to_string(),
+                        field: format!("attributes.{}", violation.attribute()),
+                    })
+                    .collect(),
+            ),
+            CoreError::EmailNotConfigured => {
+                Self::BadRequest("No SMTP server is configured for the realm".to_string())
+            }
+            CoreError::EmailDeliveryFailed(msg) => {
+                Self::ServiceUnavailable(format!("Failed to deliver email: {msg}"))
+            }
         }
     }
 }
@@ -0,0 +1,4 @@
+pub mod delete_admin_events;
+pub mod delete_login_events;
+pub mod get_admin_events;
+pub mod get_login_events;
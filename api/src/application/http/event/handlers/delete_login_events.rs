@@ -0,0 +1,55 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::event::{entities::ClearEventsInput, ports::EventService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteLoginEventsResponse {
+    pub message: String,
+    pub realm_name: String,
+    pub count: u64,
+}
+
+#[utoipa::path(
+    delete,
+    path = "",
+    tag = "event",
+    summary = "Clear the login events of a realm",
+    description = "Deletes every login event recorded for a realm, regardless of its retention period.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = DeleteLoginEventsResponse, description = "Login events deleted successfully"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+    ),
+)]
+pub async fn delete_login_events(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteLoginEventsResponse>, ApiError> {
+    let count = state
+        .service
+        .clear_login_events(
+            identity,
+            ClearEventsInput {
+                realm_name: realm_name.clone(),
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(DeleteLoginEventsResponse {
+        message: format!("{count} login events deleted in realm {realm_name}"),
+        realm_name,
+        count,
+    }))
+}
@@ -0,0 +1,61 @@
+use crate::application::http::{
+    event::validators::GetAdminEventsValidator,
+    server::{
+        api_entities::{api_error::ApiError, response::Response},
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::event::{
+    entities::{AdminEventPage, AdminEventQuery, GetAdminEventsInput},
+    ports::EventService,
+};
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "/admin",
+    tag = "event",
+    summary = "Search admin events in a realm",
+    description = "Retrieves one page of the administrative changes made to a realm matching the filters, most recent first, along with the total number of matching events.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetAdminEventsValidator,
+    ),
+    responses(
+        (status = 200, description = "Admin events retrieved successfully", body = AdminEventPage),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 422, description = "Invalid query parameters"),
+    )
+)]
+pub async fn get_admin_events(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(params): Query<GetAdminEventsValidator>,
+) -> Result<Response<AdminEventPage>, ApiError> {
+    params.validate()?;
+
+    let defaults = AdminEventQuery::default();
+    let query = AdminEventQuery {
+        operation: params.operation,
+        resource_type: params.resource_type,
+        resource_id: params.resource_id,
+        actor_id: params.actor_id,
+        from: params.from,
+        to: params.to,
+        offset: params.offset.unwrap_or(defaults.offset),
+        limit: params.limit.unwrap_or(defaults.limit),
+    };
+
+    let page = state
+        .service
+        .get_admin_events(identity, GetAdminEventsInput { realm_name, query })
+        .await?;
+
+    Ok(Response::OK(page))
+}
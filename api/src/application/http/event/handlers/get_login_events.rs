@@ -0,0 +1,61 @@
+use crate::application::http::{
+    event::validators::GetLoginEventsValidator,
+    server::{
+        api_entities::{api_error::ApiError, response::Response},
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, Query, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::event::{
+    entities::{GetLoginEventsInput, LoginEventPage, LoginEventQuery},
+    ports::EventService,
+};
+use validator::Validate;
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "event",
+    summary = "Search login events in a realm",
+    description = "Retrieves one page of the login events of a realm matching the filters, most recent first, along with the total number of matching events.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        GetLoginEventsValidator,
+    ),
+    responses(
+        (status = 200, description = "Login events retrieved successfully", body = LoginEventPage),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 422, description = "Invalid query parameters"),
+    )
+)]
+pub async fn get_login_events(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    Query(params): Query<GetLoginEventsValidator>,
+) -> Result<Response<LoginEventPage>, ApiError> {
+    params.validate()?;
+
+    let defaults = LoginEventQuery::default();
+    let query = LoginEventQuery {
+        event_type: params.event_type,
+        user_id: params.user_id,
+        client_id: params.client_id,
+        ip_address: params.ip_address,
+        from: params.from,
+        to: params.to,
+        offset: params.offset.unwrap_or(defaults.offset),
+        limit: params.limit.unwrap_or(defaults.limit),
+    };
+
+    let page = state
+        .service
+        .get_login_events(identity, GetLoginEventsInput { realm_name, query })
+        .await?;
+
+    Ok(Response::OK(page))
+}
@@ -0,0 +1,56 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get},
+};
+use utoipa::OpenApi;
+
+use crate::application::{auth::auth, http::server::app_state::AppState};
+
+use super::handlers::{
+    delete_admin_events::{__path_delete_admin_events, delete_admin_events},
+    delete_login_events::{__path_delete_login_events, delete_login_events},
+    get_admin_events::{__path_get_admin_events, get_admin_events},
+    get_login_events::{__path_get_login_events, get_login_events},
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_login_events,
+    delete_login_events,
+    get_admin_events,
+    delete_admin_events,
+))]
+pub struct EventApiDoc;
+
+pub fn event_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/events",
+                state.args.server.root_path
+            ),
+            get(get_login_events),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/events",
+                state.args.server.root_path
+            ),
+            delete(delete_login_events),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/events/admin",
+                state.args.server.root_path
+            ),
+            get(get_admin_events),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/events/admin",
+                state.args.server.root_path
+            ),
+            delete(delete_admin_events),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
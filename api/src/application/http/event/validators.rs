@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+use ferriskey_core::domain::event::entities::{
+    LoginEventType, MAX_EVENT_PAGE_SIZE, OperationType, ResourceType,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetLoginEventsValidator {
+    #[serde(default, rename = "type")]
+    #[param(rename = "type")]
+    pub event_type: Option<LoginEventType>,
+
+    #[serde(default)]
+    pub user_id: Option<Uuid>,
+
+    /// Client ID of the application the event happened through.
+    #[serde(default)]
+    pub client_id: Option<String>,
+
+    #[serde(default)]
+    pub ip_address: Option<String>,
+
+    /// Only events recorded at or after this instant.
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// Only events recorded before this instant.
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub offset: Option<u64>,
+
+    #[validate(range(min = 1, max = MAX_EVENT_PAGE_SIZE, message = "limit must be between 1 and 100"))]
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetAdminEventsValidator {
+    #[serde(default)]
+    pub operation: Option<OperationType>,
+
+    #[serde(default)]
+    pub resource_type: Option<ResourceType>,
+
+    #[serde(default)]
+    pub resource_id: Option<Uuid>,
+
+    /// ID of the user or client that performed the change.
+    #[serde(default)]
+    pub actor_id: Option<Uuid>,
+
+    /// Only events recorded at or after this instant.
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+
+    /// Only events recorded before this instant.
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub offset: Option<u64>,
+
+    #[validate(range(min = 1, max = MAX_EVENT_PAGE_SIZE, message = "limit must be between 1 and 100"))]
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
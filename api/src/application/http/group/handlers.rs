@@ -0,0 +1,12 @@
+pub mod add_group_member;
+pub mod assign_group_role;
+pub mod create_group;
+pub mod delete_group;
+pub mod get_group;
+pub mod get_group_children;
+pub mod get_group_members;
+pub mod get_group_roles;
+pub mod get_groups;
+pub mod remove_group_member;
+pub mod unassign_group_role;
+pub mod update_group;
@@ -0,0 +1,60 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{entities::GroupRoleInput, ports::GroupService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct AssignGroupRoleResponse {
+    pub message: String,
+    pub realm_name: String,
+    pub group_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{group_id}/roles/{role_id}",
+    tag = "group",
+    summary = "Map a role to a group",
+    responses(
+        (status = 200, body = AssignGroupRoleResponse, description = "Role mapped to the group"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group or role not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("role_id" = Uuid, Path, description = "Role ID"),
+    ),
+)]
+pub async fn assign_group_role(
+    Path((realm_name, group_id, role_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AssignGroupRoleResponse>, ApiError> {
+    state
+        .service
+        .assign_group_role(
+            identity,
+            GroupRoleInput {
+                realm_name: realm_name.clone(),
+                group_id,
+                role_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(AssignGroupRoleResponse {
+        message: format!("Role {role_id} assigned to group {group_id} in realm {realm_name}"),
+        realm_name,
+        group_id,
+    }))
+}
@@ -0,0 +1,66 @@
+use crate::application::http::{
+    group::validators::CreateGroupValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{
+    entities::{CreateGroupInput, Group},
+    ports::GroupService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct CreateGroupResponse {
+    pub data: Group,
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "group",
+    summary = "Create a group in a realm",
+    description = "Creates a top-level group, or a subgroup when `parent_id` is set.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    request_body = CreateGroupValidator,
+    responses(
+        (status = 201, body = CreateGroupResponse, description = "Group created successfully"),
+        (status = 400, description = "A sibling group with the same name already exists"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Parent group not found"),
+    ),
+)]
+pub async fn create_group(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<CreateGroupValidator>,
+) -> Result<Response<CreateGroupResponse>, ApiError> {
+    let group = state
+        .service
+        .create_group(
+            identity,
+            CreateGroupInput {
+                realm_name,
+                parent_id: payload.parent_id,
+                name: payload.name,
+                description: payload.description,
+                attributes: payload.attributes,
+            },
+        )
+        .await?;
+
+    Ok(Response::Created(CreateGroupResponse { data: group }))
+}
@@ -0,0 +1,59 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{entities::DeleteGroupInput, ports::GroupService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteGroupResponse {
+    pub message: String,
+    pub realm_name: String,
+    pub group_id: Uuid,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{group_id}",
+    tag = "group",
+    summary = "Delete a group",
+    description = "Deletes a group together with its subgroups, memberships and role mappings.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, body = DeleteGroupResponse, description = "Group deleted successfully"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group not found"),
+    ),
+)]
+pub async fn delete_group(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteGroupResponse>, ApiError> {
+    state
+        .service
+        .delete_group(
+            identity,
+            DeleteGroupInput {
+                realm_name: realm_name.clone(),
+                group_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(DeleteGroupResponse {
+        message: format!("Group {group_id} in realm {realm_name} deleted successfully"),
+        realm_name,
+        group_id,
+    }))
+}
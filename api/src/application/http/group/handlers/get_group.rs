@@ -0,0 +1,55 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{
+    entities::{GetGroupInput, Group},
+    ports::GroupService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetGroupResponse {
+    pub data: Group,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{group_id}",
+    tag = "group",
+    summary = "Get a group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, body = GetGroupResponse),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group not found"),
+    ),
+)]
+pub async fn get_group(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetGroupResponse>, ApiError> {
+    let group = state
+        .service
+        .get_group(
+            identity,
+            GetGroupInput {
+                realm_name,
+                group_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetGroupResponse { data: group }))
+}
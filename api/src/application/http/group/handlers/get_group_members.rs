@@ -0,0 +1,53 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{entities::GetGroupInput, ports::GroupService};
+use ferriskey_core::domain::user::entities::User;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetGroupMembersResponse {
+    pub data: Vec<User>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{group_id}/members",
+    tag = "group",
+    summary = "Get the direct members of a group",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, body = GetGroupMembersResponse),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group not found"),
+    ),
+)]
+pub async fn get_group_members(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetGroupMembersResponse>, ApiError> {
+    let users = state
+        .service
+        .get_group_members(
+            identity,
+            GetGroupInput {
+                realm_name,
+                group_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetGroupMembersResponse { data: users }))
+}
@@ -0,0 +1,54 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{entities::GetGroupInput, ports::GroupService};
+use ferriskey_core::domain::role::entities::Role;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetGroupRolesResponse {
+    pub data: Vec<Role>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{group_id}/roles",
+    tag = "group",
+    summary = "Get the roles mapped to a group",
+    description = "Lists the roles mapped directly to the group. Members also inherit the roles of its ancestors.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    responses(
+        (status = 200, body = GetGroupRolesResponse),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group not found"),
+    ),
+)]
+pub async fn get_group_roles(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetGroupRolesResponse>, ApiError> {
+    let roles = state
+        .service
+        .get_group_roles(
+            identity,
+            GetGroupInput {
+                realm_name,
+                group_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetGroupRolesResponse { data: roles }))
+}
@@ -0,0 +1,41 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{entities::Group, ports::GroupService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetGroupsResponse {
+    pub data: Vec<Group>,
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "group",
+    summary = "Get all groups of a realm",
+    description = "Retrieves every group of the realm as a flat list. Use `parent_id` to rebuild the hierarchy.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = GetGroupsResponse),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+    ),
+)]
+pub async fn get_groups(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetGroupsResponse>, ApiError> {
+    let groups = state.service.get_groups(identity, realm_name).await?;
+
+    Ok(Response::OK(GetGroupsResponse { data: groups }))
+}
@@ -0,0 +1,60 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{entities::GroupMemberInput, ports::GroupService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct RemoveGroupMemberResponse {
+    pub message: String,
+    pub realm_name: String,
+    pub group_id: Uuid,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{group_id}/members/{user_id}",
+    tag = "group",
+    summary = "Remove a user from a group",
+    responses(
+        (status = 200, body = RemoveGroupMemberResponse, description = "User removed from the group"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group or membership not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+)]
+pub async fn remove_group_member(
+    Path((realm_name, group_id, user_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RemoveGroupMemberResponse>, ApiError> {
+    state
+        .service
+        .remove_group_member(
+            identity,
+            GroupMemberInput {
+                realm_name: realm_name.clone(),
+                group_id,
+                user_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(RemoveGroupMemberResponse {
+        message: format!("User {user_id} removed from group {group_id} in realm {realm_name}"),
+        realm_name,
+        group_id,
+    }))
+}
@@ -0,0 +1,60 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{entities::GroupRoleInput, ports::GroupService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct UnassignGroupRoleResponse {
+    pub message: String,
+    pub realm_name: String,
+    pub group_id: Uuid,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{group_id}/roles/{role_id}",
+    tag = "group",
+    summary = "Remove a role mapping from a group",
+    responses(
+        (status = 200, body = UnassignGroupRoleResponse, description = "Role mapping removed from the group"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group or role mapping not found"),
+    ),
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+        ("role_id" = Uuid, Path, description = "Role ID"),
+    ),
+)]
+pub async fn unassign_group_role(
+    Path((realm_name, group_id, role_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<UnassignGroupRoleResponse>, ApiError> {
+    state
+        .service
+        .unassign_group_role(
+            identity,
+            GroupRoleInput {
+                realm_name: realm_name.clone(),
+                group_id,
+                role_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(UnassignGroupRoleResponse {
+        message: format!("Role {role_id} unassigned from group {group_id} in realm {realm_name}"),
+        realm_name,
+        group_id,
+    }))
+}
@@ -0,0 +1,68 @@
+use crate::application::http::{
+    group::validators::UpdateGroupValidator,
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{
+    entities::{Group, UpdateGroupInput},
+    ports::GroupService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct UpdateGroupResponse {
+    pub data: Group,
+}
+
+#[utoipa::path(
+    put,
+    path = "/{group_id}",
+    tag = "group",
+    summary = "Update a group",
+    description = "Updates the name, description or attributes of a group. Omitted fields are left unchanged; `attributes` replaces the whole map.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("group_id" = Uuid, Path, description = "Group ID"),
+    ),
+    request_body = UpdateGroupValidator,
+    responses(
+        (status = 200, body = UpdateGroupResponse),
+        (status = 400, description = "A sibling group with the same name already exists"),
+        (status = 403, description = "Forbidden - insufficient permissions"),
+        (status = 404, description = "Group not found"),
+    ),
+)]
+pub async fn update_group(
+    Path((realm_name, group_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateGroupValidator>,
+) -> Result<Response<UpdateGroupResponse>, ApiError> {
+    let group = state
+        .service
+        .update_group(
+            identity,
+            UpdateGroupInput {
+                realm_name,
+                group_id,
+                name: payload.name,
+                description: payload.description,
+                attributes: payload.attributes,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(UpdateGroupResponse { data: group }))
+}
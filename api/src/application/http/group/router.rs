@@ -0,0 +1,128 @@
+use axum::{
+    Router, middleware,
+    routing::{delete, get, post, put},
+};
+use utoipa::OpenApi;
+
+use crate::application::{auth::auth, http::server::app_state::AppState};
+
+use super::handlers::{
+    add_group_member::{__path_add_group_member, add_group_member},
+    assign_group_role::{__path_assign_group_role, assign_group_role},
+    create_group::{__path_create_group, create_group},
+    delete_group::{__path_delete_group, delete_group},
+    get_group::{__path_get_group, get_group},
+    get_group_children::{__path_get_group_children, get_group_children},
+    get_group_members::{__path_get_group_members, get_group_members},
+    get_group_roles::{__path_get_group_roles, get_group_roles},
+    get_groups::{__path_get_groups, get_groups},
+    remove_group_member::{__path_remove_group_member, remove_group_member},
+    unassign_group_role::{__path_unassign_group_role, unassign_group_role},
+    update_group::{__path_update_group, update_group},
+};
+
+#[derive(OpenApi)]
+#[openapi(paths(
+    get_groups,
+    create_group,
+    get_group,
+    update_group,
+    delete_group,
+    get_group_children,
+    get_group_members,
+    add_group_member,
+    remove_group_member,
+    get_group_roles,
+    assign_group_role,
+    unassign_group_role,
+))]
+pub struct GroupApiDoc;
+
+pub fn group_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups",
+                state.args.server.root_path
+            ),
+            get(get_groups),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups",
+                state.args.server.root_path
+            ),
+            post(create_group),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}",
+                state.args.server.root_path
+            ),
+            get(get_group),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}",
+                state.args.server.root_path
+            ),
+            put(update_group),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}",
+                state.args.server.root_path
+            ),
+            delete(delete_group),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/children",
+                state.args.server.root_path
+            ),
+            get(get_group_children),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/members",
+                state.args.server.root_path
+            ),
+            get(get_group_members),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/members/{{user_id}}",
+                state.args.server.root_path
+            ),
+            post(add_group_member),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/members/{{user_id}}",
+                state.args.server.root_path
+            ),
+            delete(remove_group_member),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/roles",
+                state.args.server.root_path
+            ),
+            get(get_group_roles),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/roles/{{role_id}}",
+                state.args.server.root_path
+            ),
+            post(assign_group_role),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/groups/{{group_id}}/roles/{{role_id}}",
+                state.args.server.root_path
+            ),
+            delete(unassign_group_role),
+        )
+        .layer(middleware::from_fn_with_state(state.clone(), auth))
+}
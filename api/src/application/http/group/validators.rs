@@ -0,0 +1,34 @@
+use ferriskey_core::domain::group::entities::GroupAttributes;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateGroupValidator {
+    #[validate(length(min = 1, max = 255, message = "name is required"))]
+    #[serde(default)]
+    pub name: String,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+
+    #[serde(default)]
+    pub attributes: GroupAttributes,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateGroupValidator {
+    #[validate(length(min = 1, max = 255, message = "name must not be empty"))]
+    #[serde(default)]
+    pub name: Option<String>,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub attributes: Option<GroupAttributes>,
+}
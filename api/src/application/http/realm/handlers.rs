@@ -1,7 +1,17 @@
+pub mod add_default_role;
 pub mod create_realm;
 pub mod delete_realm;
+pub mod delete_smtp_server;
+pub mod get_default_roles;
 pub mod get_realm;
+pub mod get_realm_keys;
+pub mod get_smtp_server;
 pub mod get_user_realm_settings;
 pub mod get_user_realms;
+pub mod remove_default_role;
+pub mod retire_realm_key;
+pub mod rotate_realm_key;
+pub mod send_test_email;
 pub mod update_realm;
 pub mod update_realm_setting;
+pub mod update_smtp_server;
@@ -0,0 +1,53 @@
+use crate::application::http::realm::handlers::get_default_roles::DefaultRolesResponse;
+use crate::application::http::server::api_entities::{api_error::ApiError, response::Response};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    role::{entities::DefaultRoleInput, ports::RoleService},
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/{name}/default-roles/{role_id}",
+    tag = "realm",
+    summary = "Add a default role to a realm",
+    description = "Includes a role in the default role of the realm. Existing users holding the default role are granted it as well.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+        ("role_id" = Uuid, Path, description = "Role ID"),
+    ),
+    responses(
+        (status = 200, body = DefaultRolesResponse),
+        (status = 404, description = "Role not found")
+    ),
+)]
+pub async fn add_default_role(
+    Path((name, role_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DefaultRolesResponse>, ApiError> {
+    state
+        .service
+        .add_default_role(
+            identity.clone(),
+            DefaultRoleInput {
+                realm_name: name.clone(),
+                role_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    let roles = state
+        .service
+        .get_default_roles(identity, name)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DefaultRolesResponse { data: roles }))
+}
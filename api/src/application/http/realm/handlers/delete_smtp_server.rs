@@ -0,0 +1,51 @@
+use crate::application::http::server::api_entities::{api_error::ApiError, response::Response};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email::{entities::DeleteSmtpServerInput, ports::EmailService},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DeleteSmtpServerResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{name}/smtp-server",
+    tag = "realm",
+    summary = "Remove the SMTP server of a realm",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = DeleteSmtpServerResponse),
+        (status = 404, description = "No SMTP server is configured for the realm")
+    ),
+)]
+pub async fn delete_smtp_server(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DeleteSmtpServerResponse>, ApiError> {
+    state
+        .service
+        .delete_smtp_server(
+            identity,
+            DeleteSmtpServerInput {
+                realm_name: name.clone(),
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DeleteSmtpServerResponse {
+        message: format!("SMTP server of realm {name} removed"),
+    }))
+}
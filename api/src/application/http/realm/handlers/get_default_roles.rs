@@ -0,0 +1,44 @@
+use crate::application::http::server::api_entities::{api_error::ApiError, response::Response};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    role::{entities::Role, ports::RoleService},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct DefaultRolesResponse {
+    pub data: Vec<Role>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{name}/default-roles",
+    tag = "realm",
+    summary = "Get the default roles of a realm",
+    description = "Lists the roles included in the default role of the realm, which is assigned to every new user.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = DefaultRolesResponse)
+    ),
+)]
+pub async fn get_default_roles(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<DefaultRolesResponse>, ApiError> {
+    let roles = state
+        .service
+        .get_default_roles(identity, name)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(DefaultRolesResponse { data: roles }))
+}
@@ -0,0 +1,45 @@
+use crate::application::http::server::api_entities::{api_error::ApiError, response::Response};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    jwt::entities::RealmKey,
+    realm::ports::{GetRealmKeysInput, RealmService},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RealmKeysResponse {
+    pub data: Vec<RealmKey>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{name}/keys",
+    tag = "realm",
+    summary = "Get the signing keys of a realm",
+    description = "Lists the active, passive and retired signing keys of a realm, newest version first.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = RealmKeysResponse)
+    ),
+)]
+pub async fn get_realm_keys(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RealmKeysResponse>, ApiError> {
+    let keys = state
+        .service
+        .get_realm_keys(identity, GetRealmKeysInput { realm_name: name })
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(RealmKeysResponse { data: keys }))
+}
@@ -0,0 +1,40 @@
+use crate::application::http::server::api_entities::{api_error::ApiError, response::Response};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email::{
+        entities::{GetSmtpServerInput, SmtpServer},
+        ports::EmailService,
+    },
+};
+
+#[utoipa::path(
+    get,
+    path = "/{name}/smtp-server",
+    tag = "realm",
+    summary = "Get the SMTP server of a realm",
+    description = "Returns the SMTP server the realm sends its emails through. The password is never returned.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = SmtpServer),
+        (status = 404, description = "No SMTP server is configured for the realm")
+    ),
+)]
+pub async fn get_smtp_server(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<SmtpServer>, ApiError> {
+    state
+        .service
+        .get_smtp_server(identity, GetSmtpServerInput { realm_name: name })
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
@@ -0,0 +1,45 @@
+use crate::application::http::server::api_entities::{api_error::ApiError, response::Response};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    jwt::entities::RealmKey,
+    realm::ports::{RealmService, RetireRealmKeyInput},
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    post,
+    path = "/{name}/keys/{key_id}/retire",
+    tag = "realm",
+    summary = "Retire a signing key of a realm",
+    description = "Retires a signing key so that tokens it signed are no longer accepted. Retiring the active key rotates it first.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+        ("key_id" = Uuid, Path, description = "Key ID"),
+    ),
+    responses(
+        (status = 200, body = RealmKey)
+    ),
+)]
+pub async fn retire_realm_key(
+    Path((name, key_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RealmKey>, ApiError> {
+    state
+        .service
+        .retire_realm_key(
+            identity,
+            RetireRealmKeyInput {
+                realm_name: name,
+                key_id,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
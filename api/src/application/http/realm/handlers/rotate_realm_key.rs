@@ -0,0 +1,37 @@
+use crate::application::http::server::api_entities::{api_error::ApiError, response::Response};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    jwt::entities::RealmKey,
+    realm::ports::{RealmService, RotateRealmKeyInput},
+};
+
+#[utoipa::path(
+    post,
+    path = "/{name}/keys/rotate",
+    tag = "realm",
+    summary = "Rotate the signing key of a realm",
+    description = "Generates a new active signing key. The previous key becomes passive and keeps verifying the tokens it signed until it is retired.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 201, body = RealmKey)
+    ),
+)]
+pub async fn rotate_realm_key(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RealmKey>, ApiError> {
+    state
+        .service
+        .rotate_realm_key(identity, RotateRealmKeyInput { realm_name: name })
+        .await
+        .map(Response::Created)
+        .map_err(ApiError::from)
+}
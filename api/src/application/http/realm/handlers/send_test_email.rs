@@ -0,0 +1,60 @@
+use crate::application::http::realm::validators::SendTestEmailValidator;
+use crate::application::http::server::api_entities::{
+    api_error::{ApiError, ValidateJson},
+    response::Response,
+};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email::{entities::SendTestEmailInput, ports::EmailService},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct SendTestEmailResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{name}/smtp-server/test",
+    tag = "realm",
+    summary = "Send a test email",
+    description = "Sends a test email with the email settings of the realm, to the given address or to the caller.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+    ),
+    request_body = SendTestEmailValidator,
+    responses(
+        (status = 200, body = SendTestEmailResponse),
+        (status = 400, description = "No SMTP server is configured or no recipient is known"),
+        (status = 503, description = "The SMTP server rejected or could not deliver the email")
+    ),
+)]
+pub async fn send_test_email(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<SendTestEmailValidator>,
+) -> Result<Response<SendTestEmailResponse>, ApiError> {
+    state
+        .service
+        .send_test_email(
+            identity,
+            SendTestEmailInput {
+                realm_name: name,
+                to: payload.to,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(SendTestEmailResponse {
+        message: "Test email sent".to_string(),
+    }))
+}
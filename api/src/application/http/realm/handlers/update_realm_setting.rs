@@ -9,7 +9,6 @@ use crate::application::http::server::app_state::AppState;
 use axum::extract::{Path, State};
 
 use ferriskey_core::domain::authentication::value_objects::Identity;
-use ferriskey_core::domain::realm::entities::Realm;
 use ferriskey_core::domain::realm::entities::RealmSetting;
 
 #[utoipa::path(
@@ -22,7 +21,7 @@ use ferriskey_core::domain::realm::entities::RealmSetting;
         ("name" = String, Path, description = "Realm name"),
     ),
     responses(
-        (status = 200, body = Realm)
+        (status = 200, body = RealmSetting)
     ),
     request_body = UpdateRealmSettingValidator
 )]
@@ -39,6 +38,22 @@ pub async fn update_realm_setting(
             UpdateRealmSettingInput {
                 realm_name: name,
                 algorithm: payload.default_signing_algorithm,
+                access_token_lifespan: payload.access_token_lifespan,
+                refresh_token_lifespan: payload.refresh_token_lifespan,
+                session_lifespan: payload.session_lifespan,
+                auth_code_lifespan: payload.auth_code_lifespan,
+                refresh_token_rotation: payload.refresh_token_rotation,
+                key_rotation_interval: payload.key_rotation_interval,
+                key_retirement_delay: payload.key_retirement_delay,
+                password_policy: payload.password_policy,
+                brute_force_protection: payload.brute_force_protection,
+                user_profile: payload.user_profile,
+                default_locale: payload.default_locale,
+                registration_allowed: payload.registration_allowed,
+                verify_email: payload.verify_email,
+                webhook_failure_threshold: payload.webhook_failure_threshold,
+                login_event_retention: payload.login_event_retention,
+                admin_event_retention: payload.admin_event_retention,
             },
         )
         .await
@@ -0,0 +1,58 @@
+use crate::application::http::realm::validators::UpdateSmtpServerValidator;
+use crate::application::http::server::api_entities::{
+    api_error::{ApiError, ValidateJson},
+    response::Response,
+};
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    authentication::value_objects::Identity,
+    email::{
+        entities::{SmtpServer, UpdateSmtpServerInput},
+        ports::EmailService,
+    },
+};
+
+#[utoipa::path(
+    put,
+    path = "/{name}/smtp-server",
+    tag = "realm",
+    summary = "Configure the SMTP server of a realm",
+    description = "Creates or replaces the SMTP server the realm sends its emails through. The stored password is kept when none is given.",
+    params(
+        ("name" = String, Path, description = "Realm name"),
+    ),
+    request_body = UpdateSmtpServerValidator,
+    responses(
+        (status = 200, body = SmtpServer)
+    ),
+)]
+pub async fn update_smtp_server(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateSmtpServerValidator>,
+) -> Result<Response<SmtpServer>, ApiError> {
+    state
+        .service
+        .update_smtp_server(
+            identity,
+            UpdateSmtpServerInput {
+                realm_name: name,
+                host: payload.host,
+                port: payload.port,
+                encryption: payload.encryption,
+                from_address: payload.from_address,
+                from_display_name: payload.from_display_name,
+                reply_to: payload.reply_to,
+                username: payload.username,
+                password: payload.password,
+            },
+        )
+        .await
+        .map(Response::OK)
+        .map_err(ApiError::from)
+}
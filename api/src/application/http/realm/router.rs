@@ -1,13 +1,43 @@
 use super::handlers::get_user_realms::{__path_get_user_realms, get_user_realms};
 use crate::application::auth::auth;
+use crate::application::http::realm::handlers::add_default_role::{
+    __path_add_default_role, add_default_role,
+};
 use crate::application::http::realm::handlers::create_realm::{__path_create_realm, create_realm};
 use crate::application::http::realm::handlers::delete_realm::{__path_delete_realm, delete_realm};
+use crate::application::http::realm::handlers::delete_smtp_server::{
+    __path_delete_smtp_server, delete_smtp_server,
+};
+use crate::application::http::realm::handlers::get_default_roles::{
+    __path_get_default_roles, get_default_roles,
+};
 use crate::application::http::realm::handlers::get_realm::{__path_get_realm, get_realm};
+use crate::application::http::realm::handlers::get_realm_keys::{
+    __path_get_realm_keys, get_realm_keys,
+};
+use crate::application::http::realm::handlers::get_smtp_server::{
+    __path_get_smtp_server, get_smtp_server,
+};
 use crate::application::http::realm::handlers::get_user_realm_settings::get_user_realm_settings;
+use crate::application::http::realm::handlers::remove_default_role::{
+    __path_remove_default_role, remove_default_role,
+};
+use crate::application::http::realm::handlers::retire_realm_key::{
+    __path_retire_realm_key, retire_realm_key,
+};
+use crate::application::http::realm::handlers::rotate_realm_key::{
+    __path_rotate_realm_key, rotate_realm_key,
+};
+use crate::application::http::realm::handlers::send_test_email::{
+    __path_send_test_email, send_test_email,
+};
 use crate::application::http::realm::handlers::update_realm::{__path_update_realm, update_realm};
 use crate::application::http::realm::handlers::update_realm_setting::{
     __path_update_realm_setting, update_realm_setting,
 };
+use crate::application::http::realm::handlers::update_smtp_server::{
+    __path_update_smtp_server, update_smtp_server,
+};
 use crate::application::http::server::app_state::AppState;
 use axum::routing::{delete, get, post, put};
 use axum::{Router, middleware};
@@ -21,6 +51,16 @@ use utoipa::OpenApi;
     delete_realm,
     update_realm_setting,
     get_user_realms,
+    get_realm_keys,
+    rotate_realm_key,
+    retire_realm_key,
+    get_default_roles,
+    add_default_role,
+    remove_default_role,
+    get_smtp_server,
+    update_smtp_server,
+    delete_smtp_server,
+    send_test_email,
 ))]
 pub struct RealmApiDoc;
 
@@ -63,5 +103,72 @@ pub fn realm_routes(state: AppState) -> Router<AppState> {
             ),
             put(update_realm_setting),
         )
+        .route(
+            &format!("{}/realms/{{realm_name}}/keys", state.args.server.root_path),
+            get(get_realm_keys),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/keys/rotate",
+                state.args.server.root_path
+            ),
+            post(rotate_realm_key),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/keys/{{key_id}}/retire",
+                state.args.server.root_path
+            ),
+            post(retire_realm_key),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/default-roles",
+                state.args.server.root_path
+            ),
+            get(get_default_roles),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/default-roles/{{role_id}}",
+                state.args.server.root_path
+            ),
+            post(add_default_role),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/default-roles/{{role_id}}",
+                state.args.server.root_path
+            ),
+            delete(remove_default_role),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/smtp-server",
+                state.args.server.root_path
+            ),
+            get(get_smtp_server),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/smtp-server",
+                state.args.server.root_path
+            ),
+            put(update_smtp_server),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/smtp-server",
+                state.args.server.root_path
+            ),
+            delete(delete_smtp_server),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/smtp-server/test",
+                state.args.server.root_path
+            ),
+            post(send_test_email),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
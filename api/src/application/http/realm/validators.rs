@@ -1,3 +1,7 @@
+use ferriskey_core::domain::brute_force::entities::BruteForcePolicy;
+use ferriskey_core::domain::credential::entities::PasswordPolicy;
+use ferriskey_core::domain::email::entities::SmtpEncryption;
+use ferriskey_core::domain::user::entities::UserProfile;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
@@ -17,6 +21,125 @@ pub struct UpdateRealmValidator {
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateRealmSettingValidator {
-    #[validate(length(min = 1, message = "default_signing_algorithm is required"))]
-    pub default_signing_algorithm: String,
+    /// One of `RS256`, `PS256`, `ES256`, `ES384` or `EdDSA`.
+    #[validate(length(min = 1, message = "default_signing_algorithm must not be empty"))]
+    #[serde(default)]
+    pub default_signing_algorithm: Option<String>,
+
+    /// Access token lifespan in seconds.
+    #[validate(range(min = 1, message = "access_token_lifespan must be positive"))]
+    #[serde(default)]
+    pub access_token_lifespan: Option<i32>,
+
+    /// Refresh token lifespan in seconds.
+    #[validate(range(min = 1, message = "refresh_token_lifespan must be positive"))]
+    #[serde(default)]
+    pub refresh_token_lifespan: Option<i32>,
+
+    /// User session lifespan in seconds.
+    #[validate(range(min = 1, message = "session_lifespan must be positive"))]
+    #[serde(default)]
+    pub session_lifespan: Option<i32>,
+
+    /// Authorization code lifespan in seconds.
+    #[validate(range(min = 1, message = "auth_code_lifespan must be positive"))]
+    #[serde(default)]
+    pub auth_code_lifespan: Option<i32>,
+
+    /// Revoke refresh tokens on use and detect reuse of rotated tokens.
+    #[serde(default)]
+    pub refresh_token_rotation: Option<bool>,
+
+    /// Age after which the signing key is rotated in seconds, `0` to disable.
+    #[validate(range(min = 0, message = "key_rotation_interval must not be negative"))]
+    #[serde(default)]
+    pub key_rotation_interval: Option<i32>,
+
+    /// Time a rotated key keeps verifying tokens before it is retired, in seconds.
+    #[validate(range(min = 0, message = "key_retirement_delay must not be negative"))]
+    #[serde(default)]
+    pub key_retirement_delay: Option<i32>,
+
+    /// Replaces the password policy of the realm.
+    #[serde(default)]
+    pub password_policy: Option<PasswordPolicy>,
+
+    /// Replaces the brute-force protection settings of the realm.
+    #[serde(default)]
+    pub brute_force_protection: Option<BruteForcePolicy>,
+
+    /// Replaces the schema of the custom user attributes of the realm.
+    #[serde(default)]
+    pub user_profile: Option<UserProfile>,
+
+    /// Locale of the emails sent to users without a supported `locale` attribute.
+    #[serde(default)]
+    pub default_locale: Option<String>,
+
+    /// Lets users sign up from the login page.
+    #[serde(default)]
+    pub registration_allowed: Option<bool>,
+
+    /// Requires users to verify their email address before they can log in.
+    #[serde(default)]
+    pub verify_email: Option<bool>,
+
+    /// Consecutive failed attempts after which a webhook is disabled, `0` to
+    /// never disable them.
+    #[validate(range(min = 0, message = "webhook_failure_threshold must not be negative"))]
+    #[serde(default)]
+    pub webhook_failure_threshold: Option<i32>,
+
+    /// Age after which login events are deleted, in seconds, `0` to keep
+    /// them forever.
+    #[validate(range(min = 0, message = "login_event_retention must not be negative"))]
+    #[serde(default)]
+    pub login_event_retention: Option<i32>,
+
+    /// Age after which admin events are deleted, in seconds, `0` to keep
+    /// them forever.
+    #[validate(range(min = 0, message = "admin_event_retention must not be negative"))]
+    #[serde(default)]
+    pub admin_event_retention: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateSmtpServerValidator {
+    #[validate(length(min = 1, message = "host is required"))]
+    #[serde(default)]
+    pub host: String,
+
+    #[validate(range(min = 1, message = "port must be positive"))]
+    #[serde(default)]
+    pub port: u16,
+
+    #[serde(default)]
+    pub encryption: SmtpEncryption,
+
+    #[validate(email(message = "from_address must be an email address"))]
+    #[serde(default)]
+    pub from_address: String,
+
+    #[serde(default)]
+    pub from_display_name: Option<String>,
+
+    #[validate(email(message = "reply_to must be an email address"))]
+    #[serde(default)]
+    pub reply_to: Option<String>,
+
+    /// Authenticates with the server when set.
+    #[serde(default)]
+    pub username: Option<String>,
+
+    /// Keeps the stored password when omitted.
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SendTestEmailValidator {
+    /// Recipient of the test email, the caller's email address by default.
+    #[validate(email(message = "to must be an email address"))]
+    #[serde(default)]
+    pub to: Option<String>,
 }
@@ -1,5 +1,8 @@
+pub mod add_composite_role;
 pub mod delete_role;
+pub mod get_composite_roles;
 pub mod get_role;
 pub mod get_roles;
+pub mod remove_composite_role;
 pub mod update_role;
 pub mod update_role_permissions;
@@ -0,0 +1,62 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::role::entities::CompositeRoleInput;
+use ferriskey_core::domain::role::ports::RoleService;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct AddCompositeRoleResponse {
+    pub message: String,
+    pub role_id: Uuid,
+    pub child_role_id: Uuid,
+}
+
+#[utoipa::path(
+    post,
+    summary = "Add a child role to a composite role",
+    description = "Includes a role of the same realm in a composite role. Adding a role that already includes the composite role, directly or transitively, is rejected.",
+    path = "/{role_id}/composites/{child_role_id}",
+    tag = "role",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("role_id" = Uuid, Path, description = "Composite role ID"),
+        ("child_role_id" = Uuid, Path, description = "Child role ID"),
+    ),
+    responses(
+        (status = 200, body = AddCompositeRoleResponse),
+        (status = 400, description = "The child role would create a cycle"),
+        (status = 404, description = "Role not found")
+    )
+)]
+pub async fn add_composite_role(
+    Path((realm_name, role_id, child_role_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<AddCompositeRoleResponse>, ApiError> {
+    state
+        .service
+        .add_composite_role(
+            identity,
+            CompositeRoleInput {
+                realm_name,
+                role_id,
+                child_role_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(AddCompositeRoleResponse {
+        message: format!("Role {child_role_id} added to composite role {role_id}"),
+        role_id,
+        child_role_id,
+    }))
+}
@@ -0,0 +1,53 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::role::entities::{GetCompositeRolesInput, Role};
+use ferriskey_core::domain::role::ports::RoleService;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetCompositeRolesResponse {
+    pub data: Vec<Role>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get the child roles of a composite role",
+    description = "Lists the roles directly included in a composite role. Users holding the composite role are granted these roles as well, transitively.",
+    path = "/{role_id}/composites",
+    tag = "role",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("role_id" = Uuid, Path, description = "Role ID")
+    ),
+    responses(
+        (status = 200, body = GetCompositeRolesResponse),
+        (status = 404, description = "Role not found")
+    )
+)]
+pub async fn get_composite_roles(
+    Path((realm_name, role_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetCompositeRolesResponse>, ApiError> {
+    let roles = state
+        .service
+        .get_composite_roles(
+            identity,
+            GetCompositeRolesInput {
+                realm_name,
+                role_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetCompositeRolesResponse { data: roles }))
+}
@@ -0,0 +1,60 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::role::entities::CompositeRoleInput;
+use ferriskey_core::domain::role::ports::RoleService;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RemoveCompositeRoleResponse {
+    pub message: String,
+    pub role_id: Uuid,
+    pub child_role_id: Uuid,
+}
+
+#[utoipa::path(
+    delete,
+    summary = "Remove a child role from a composite role",
+    path = "/{role_id}/composites/{child_role_id}",
+    tag = "role",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("role_id" = Uuid, Path, description = "Composite role ID"),
+        ("child_role_id" = Uuid, Path, description = "Child role ID"),
+    ),
+    responses(
+        (status = 200, body = RemoveCompositeRoleResponse),
+        (status = 404, description = "Role not found or not part of the composite role")
+    )
+)]
+pub async fn remove_composite_role(
+    Path((realm_name, role_id, child_role_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RemoveCompositeRoleResponse>, ApiError> {
+    state
+        .service
+        .remove_composite_role(
+            identity,
+            CompositeRoleInput {
+                realm_name,
+                role_id,
+                child_role_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(RemoveCompositeRoleResponse {
+        message: format!("Role {child_role_id} removed from composite role {role_id}"),
+        role_id,
+        child_role_id,
+    }))
+}
@@ -1,21 +1,33 @@
 use axum::{
     Router, middleware,
-    routing::{delete, get, patch, put},
+    routing::{delete, get, patch, post, put},
 };
 use utoipa::OpenApi;
 
 use crate::application::{auth::auth, http::server::app_state::AppState};
 
 use super::handlers::{
+    add_composite_role::{__path_add_composite_role, add_composite_role},
     delete_role::{__path_delete_role, delete_role},
+    get_composite_roles::{__path_get_composite_roles, get_composite_roles},
     get_role::{__path_get_role, get_role},
     get_roles::{__path_get_roles, get_roles},
+    remove_composite_role::{__path_remove_composite_role, remove_composite_role},
     update_role::{__path_update_role, update_role},
     update_role_permissions::{__path_update_role_permissions, update_role_permissions},
 };
 
 #[derive(OpenApi)]
-#[openapi(paths(get_roles, get_role, update_role, update_role_permissions, delete_role))]
+#[openapi(paths(
+    get_roles,
+    get_role,
+    update_role,
+    update_role_permissions,
+    delete_role,
+    get_composite_roles,
+    add_composite_role,
+    remove_composite_role,
+))]
 pub struct RoleApiDoc;
 
 pub fn role_routes(state: AppState) -> Router<AppState> {
@@ -55,5 +67,26 @@ pub fn role_routes(state: AppState) -> Router<AppState> {
             ),
             patch(update_role_permissions),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/roles/{{role_id}}/composites",
+                state.args.server.root_path
+            ),
+            get(get_composite_roles),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/roles/{{role_id}}/composites/{{child_role_id}}",
+                state.args.server.root_path
+            ),
+            post(add_composite_role),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/roles/{{role_id}}/composites/{{child_role_id}}",
+                state.args.server.root_path
+            ),
+            delete(remove_composite_role),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
@@ -133,6 +133,12 @@ impl From<AuthenticationError> for ApiError {
             AuthenticationError::InvalidRequest => {
                 Self::Unauthorized("Invalid authorization request".to_string())
             }
+            AuthenticationError::AccountLocked => {
+                Self::Forbidden("Account is temporarily locked".to_string())
+            }
+            AuthenticationError::EmailNotVerified => {
+                Self::Forbidden("Email address is not verified".to_string())
+            }
         }
     }
 }
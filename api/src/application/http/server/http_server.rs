@@ -2,6 +2,9 @@ use std::sync::Arc;
 
 use crate::application::http::authentication::router::authentication_routes;
 use crate::application::http::client::router::client_routes;
+use crate::application::http::client_scope::router::client_scope_routes;
+use crate::application::http::event::router::event_routes;
+use crate::application::http::group::router::group_routes;
 use crate::application::http::realm::router::realm_routes;
 use crate::application::http::role::router::role_routes;
 use crate::application::http::server::app_state::AppState;
@@ -97,7 +100,10 @@ pub fn router(state: AppState) -> Result<Router, anyhow::Error> {
         .merge(user_routes(state.clone()))
         .merge(authentication_routes(&state.args.server.root_path))
         .merge(role_routes(state.clone()))
+        .merge(group_routes(state.clone()))
+        .merge(client_scope_routes(state.clone()))
         .merge(webhook_routes(state.clone()))
+        .merge(event_routes(state.clone()))
         .merge(trident_routes(state.clone()))
         .merge(health_routes(&state.args.server.root_path))
         .route(
@@ -1,7 +1,8 @@
 use crate::application::http::{
     authentication::router::AuthenticationApiDoc, client::router::ClientApiDoc,
-    realm::router::RealmApiDoc, role::router::RoleApiDoc, trident::router::TridentApiDoc,
-    user::router::UserApiDoc, webhook::router::WebhookApiDoc,
+    client_scope::router::ClientScopeApiDoc, event::router::EventApiDoc,
+    group::router::GroupApiDoc, realm::router::RealmApiDoc, role::router::RoleApiDoc,
+    trident::router::TridentApiDoc, user::router::UserApiDoc, webhook::router::WebhookApiDoc,
 };
 use utoipa::OpenApi;
 
@@ -16,7 +17,10 @@ use utoipa::OpenApi;
         (path = "/realms/{realm_name}/users", api = UserApiDoc),
         (path = "/realms/{realm_name}", api = AuthenticationApiDoc),
         (path = "/realms/{realm_name}/roles", api = RoleApiDoc),
+        (path = "/realms/{realm_name}/groups", api = GroupApiDoc),
+        (path = "/realms/{realm_name}/client-scopes", api = ClientScopeApiDoc),
         (path = "/realms/{realm_name}/webhooks", api = WebhookApiDoc),
+        (path = "/realms/{realm_name}/events", api = EventApiDoc),
         (path = "/realms/{realm_name}", api = TridentApiDoc),
     )
 )]
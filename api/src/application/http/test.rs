@@ -92,6 +92,7 @@ impl UserBuilder {
             roles: self.roles,
             realm: None,
             required_actions: Vec::new(),
+            attributes: Default::default(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
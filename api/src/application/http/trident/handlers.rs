@@ -1,6 +1,7 @@
 pub mod burn_recovery_code;
 pub mod challenge_otp;
 pub mod generate_recovery_codes;
+pub mod send_verify_email;
 pub mod setup_otp;
 pub mod update_password;
 pub mod verify_otp;
@@ -1,4 +1,7 @@
-use axum::{Extension, extract::State};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
 use axum_cookie::CookieManager;
 use ferriskey_core::domain::{
     authentication::value_objects::Identity,
@@ -15,6 +18,7 @@ use crate::application::http::server::{
     },
     app_state::AppState,
 };
+use crate::application::{client_ip::ClientIp, user_agent::UserAgent};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
 pub struct BurnRecoveryCodeRequest {
@@ -34,14 +38,20 @@ pub struct BurnRecoveryCodeResponse {
     summary = "Burn a recovery code to authenticate",
     description = "Using a recovery code allows a user to bypass a MFA challenge",
     request_body = BurnRecoveryCodeRequest,
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
     responses(
         (status = 200, body = BurnRecoveryCodeResponse),
         (status = 400, body = String)
     )
 )]
 pub async fn burn_recovery_code(
+    Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
     cookie: CookieManager,
     ValidateJson(payload): ValidateJson<BurnRecoveryCodeRequest>,
 ) -> Result<Response<BurnRecoveryCodeResponse>, ApiError> {
@@ -56,12 +66,19 @@ pub async fn burn_recovery_code(
                 session_code,
                 format: payload.recovery_code_format,
                 code: payload.recovery_code,
+                ip_address,
+                user_agent,
             },
         )
         .await
         .map_err(ApiError::from)?;
 
     Ok(Response::OK(BurnRecoveryCodeResponse {
-        login_url: result.login_url,
+        login_url: result.login_url.unwrap_or_else(|| {
+            format!(
+                "{}/realms/{}/authentication/consent",
+                state.args.webapp_url, realm_name
+            )
+        }),
     }))
 }
@@ -1,3 +1,4 @@
+use crate::application::client_ip::ClientIp;
 use crate::application::http::server::{
     api_entities::{
         api_error::{ApiError, ValidateJson},
@@ -5,7 +6,11 @@ use crate::application::http::server::{
     },
     app_state::AppState,
 };
-use axum::{Extension, extract::State};
+use crate::application::user_agent::UserAgent;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
 use axum_cookie::CookieManager;
 use ferriskey_core::domain::authentication::value_objects::Identity;
 use ferriskey_core::domain::trident::ports::{ChallengeOtpInput, TridentService};
@@ -31,13 +36,19 @@ pub struct ChallengeOtpResponse {
     tag = "auth",
     summary = "Challenge OTP for user authentication",
     description = "Challenges the user to provide a One-Time Password (OTP) for authentication. This is typically used in multi-factor authentication scenarios.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
     responses(
         (status = 200, body = ChallengeOtpResponse)
     )
 )]
 pub async fn challenge_otp(
+    Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
     cookie: CookieManager,
     ValidateJson(payload): ValidateJson<ChallengeOtpRequest>,
 ) -> Result<Response<ChallengeOtpResponse>, ApiError> {
@@ -51,13 +62,20 @@ pub async fn challenge_otp(
             ChallengeOtpInput {
                 code: payload.code,
                 session_code,
+                ip_address,
+                user_agent,
             },
         )
         .await
         .map_err(|e| ApiError::InternalServerError(e.to_string()))?;
 
     let response = ChallengeOtpResponse {
-        url: result.login_url,
+        url: result.login_url.unwrap_or_else(|| {
+            format!(
+                "{}/realms/{}/authentication/consent",
+                state.args.webapp_url, realm_name
+            )
+        }),
     };
 
     Ok(Response::OK(response))
@@ -0,0 +1,61 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    action_token::{entities::SendVerifyEmailInput, ports::ActionTokenService},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::{
+    http::server::{
+        api_entities::{api_error::ApiError, response::Response},
+        app_state::AppState,
+    },
+    url::FullUrl,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct SendVerifyEmailResponse {
+    pub message: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/login-actions/send-verify-email",
+    tag = "auth",
+    summary = "Send a verification email",
+    description = "Emails the authenticated user a link verifying their email address, completing the verify_email required action.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+    ),
+    responses(
+        (status = 200, body = SendVerifyEmailResponse, description = "Verification email sent"),
+        (status = 400, description = "The user has no email address or the realm has no SMTP server"),
+        (status = 403, description = "Forbidden - Only users can verify an email address"),
+    )
+)]
+pub async fn send_verify_email(
+    Path(realm_name): Path<String>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+) -> Result<Response<SendVerifyEmailResponse>, ApiError> {
+    state
+        .service
+        .send_verify_email(
+            identity,
+            SendVerifyEmailInput {
+                realm_name,
+                webapp_url: state.args.webapp_url.clone(),
+                base_url,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(SendVerifyEmailResponse {
+        message: "Verification email sent".to_string(),
+    }))
+}
@@ -17,6 +17,7 @@ use crate::application::http::server::{
     },
     app_state::AppState,
 };
+use crate::application::{client_ip::ClientIp, user_agent::UserAgent};
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdatePasswordRequest {
@@ -43,6 +44,8 @@ pub async fn update_password(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
     ValidateJson(payload): ValidateJson<UpdatePasswordRequest>,
 ) -> Result<Response<UpdatePasswordResponse>, ApiError> {
     state
@@ -52,6 +55,8 @@ pub async fn update_password(
             UpdatePasswordInput {
                 value: payload.value,
                 realm_name,
+                ip_address,
+                user_agent,
             },
         )
         .await
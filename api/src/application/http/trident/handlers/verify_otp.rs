@@ -1,3 +1,4 @@
+use crate::application::client_ip::ClientIp;
 use crate::application::http::{
     server::{
         api_entities::{
@@ -8,6 +9,7 @@ use crate::application::http::{
     },
     trident::validators::OtpVerifyRequest,
 };
+use crate::application::user_agent::UserAgent;
 use axum::{Extension, extract::State};
 use ferriskey_core::domain::{
     authentication::value_objects::Identity,
@@ -38,6 +40,8 @@ pub struct VerifyOtpResponse {
 pub async fn verify_otp(
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    ClientIp(ip_address): ClientIp,
+    UserAgent(user_agent): UserAgent,
     ValidateJson(payload): ValidateJson<OtpVerifyRequest>,
 ) -> Result<Response<VerifyOtpResponse>, ApiError> {
     let result = state
@@ -48,6 +52,8 @@ pub async fn verify_otp(
                 code: payload.code,
                 label: Some(payload.label),
                 secret: payload.secret,
+                ip_address,
+                user_agent,
             },
         )
         .await
@@ -12,6 +12,7 @@ use crate::application::{
             burn_recovery_code::{__path_burn_recovery_code, burn_recovery_code},
             challenge_otp::{__path_challenge_otp, challenge_otp},
             generate_recovery_codes::{__path_generate_recovery_codes, generate_recovery_codes},
+            send_verify_email::{__path_send_verify_email, send_verify_email},
             setup_otp::{__path_setup_otp, setup_otp},
             update_password::{__path_update_password, update_password},
             verify_otp::{__path_verify_otp, verify_otp},
@@ -26,7 +27,8 @@ use crate::application::{
     challenge_otp,
     update_password,
     burn_recovery_code,
-    generate_recovery_codes
+    generate_recovery_codes,
+    send_verify_email
 ))]
 pub struct TridentApiDoc;
 
@@ -74,5 +76,12 @@ pub fn trident_routes(state: AppState) -> Router<AppState> {
             ),
             post(burn_recovery_code),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/login-actions/send-verify-email",
+                state.args.server.root_path
+            ),
+            post(send_verify_email),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
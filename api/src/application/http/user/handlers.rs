@@ -1,12 +1,19 @@
 pub mod assign_role;
 pub mod bulk_delete_user;
+pub mod clear_brute_force_lockout;
 pub mod create_user;
 pub mod delete_credential;
 pub mod delete_user;
+pub mod execute_actions_email;
+pub mod get_brute_force_status;
 pub mod get_credentials;
 pub mod get_user;
+pub mod get_user_consents;
+pub mod get_user_groups;
 pub mod get_user_roles;
 pub mod get_users;
 pub mod reset_password;
+pub mod revoke_user_consent;
 pub mod unassign_role;
 pub mod update_user;
+pub mod update_user_attributes;
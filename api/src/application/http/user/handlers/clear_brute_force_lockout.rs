@@ -0,0 +1,60 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::brute_force::ports::{BruteForceService, ClearUserLockoutInput};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ClearBruteForceLockoutResponse {
+    pub message: String,
+    pub realm_name: String,
+    pub user_id: Uuid,
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{user_id}/brute-force",
+    tag = "user",
+    summary = "Clear the brute-force lockout of a user",
+    description = "Resets the failed login count of a user and lifts any temporary or permanent lockout. Lockouts of IP addresses are not affected.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, body = ClearBruteForceLockoutResponse, description = "Lockout cleared successfully"),
+        (status = 401, description = "User not found in this realm"),
+        (status = 403, description = "Forbidden: User does not have permission to update this user")
+    )
+)]
+pub async fn clear_brute_force_lockout(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<ClearBruteForceLockoutResponse>, ApiError> {
+    state
+        .service
+        .clear_user_lockout(
+            identity,
+            ClearUserLockoutInput {
+                realm_name: realm_name.clone(),
+                user_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(ClearBruteForceLockoutResponse {
+        message: format!("Lockout of user {user_id} in realm {realm_name} cleared successfully"),
+        realm_name,
+        user_id,
+    }))
+}
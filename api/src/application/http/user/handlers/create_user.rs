@@ -60,6 +60,7 @@ pub async fn create_user(
                 lastname: payload.lastname,
                 email: payload.email,
                 email_verified: payload.email_verified,
+                attributes: payload.attributes,
             },
         )
         .await?;
@@ -0,0 +1,76 @@
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::{
+    action_token::{entities::ExecuteActionsEmailInput, ports::ActionTokenService},
+    authentication::value_objects::Identity,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::application::{
+    http::{
+        server::{
+            api_entities::{
+                api_error::{ApiError, ValidateJson},
+                response::Response,
+            },
+            app_state::AppState,
+        },
+        user::validators::ExecuteActionsEmailValidator,
+    },
+    url::FullUrl,
+};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct ExecuteActionsEmailResponse {
+    pub message: String,
+    pub user_id: Uuid,
+}
+
+#[utoipa::path(
+    put,
+    path = "/{user_id}/execute-actions-email",
+    tag = "user",
+    summary = "Send an execute-actions email",
+    description = "Emails the user a link to verify their email address, choose a new password or set up an OTP. Actions that cannot be completed from the link are required at the next login.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    request_body = ExecuteActionsEmailValidator,
+    responses(
+        (status = 200, body = ExecuteActionsEmailResponse, description = "Email sent"),
+        (status = 400, description = "The user has no email address or the realm has no SMTP server"),
+        (status = 403, description = "Insufficient permissions"),
+    )
+)]
+pub async fn execute_actions_email(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    FullUrl(_, base_url): FullUrl,
+    ValidateJson(payload): ValidateJson<ExecuteActionsEmailValidator>,
+) -> Result<Response<ExecuteActionsEmailResponse>, ApiError> {
+    state
+        .service
+        .send_execute_actions_email(
+            identity,
+            ExecuteActionsEmailInput {
+                realm_name,
+                user_id,
+                actions: payload.actions,
+                lifespan: payload.lifespan,
+                webapp_url: state.args.webapp_url.clone(),
+                base_url,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(ExecuteActionsEmailResponse {
+        message: "Execute actions email sent".to_string(),
+        user_id,
+    }))
+}
@@ -0,0 +1,57 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::brute_force::{
+    entities::BruteForceStatus,
+    ports::{BruteForceService, GetUserBruteForceStatusInput},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct BruteForceStatusResponse {
+    pub data: BruteForceStatus,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{user_id}/brute-force",
+    tag = "user",
+    summary = "Get the brute-force lockout status of a user",
+    description = "Returns the failed login count of a user and whether it is currently locked out by the realm's brute-force protection.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, body = BruteForceStatusResponse, description = "Lockout status retrieved successfully"),
+        (status = 401, description = "User not found in this realm"),
+        (status = 403, description = "Forbidden: User does not have permission to access this user")
+    )
+)]
+pub async fn get_brute_force_status(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<BruteForceStatusResponse>, ApiError> {
+    let status = state
+        .service
+        .get_user_brute_force_status(
+            identity,
+            GetUserBruteForceStatusInput {
+                realm_name,
+                user_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(BruteForceStatusResponse { data: status }))
+}
@@ -0,0 +1,55 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::{GetUserConsentsInput, UserConsent},
+    ports::ClientScopeService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetUserConsentsResponse {
+    pub data: Vec<UserConsent>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get the consents of a user",
+    path = "/{user_id}/consents",
+    tag = "user",
+    description = "Retrieves the client scopes the user approved, per client.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, body = GetUserConsentsResponse),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn get_user_consents(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetUserConsentsResponse>, ApiError> {
+    let consents = state
+        .service
+        .get_user_consents(
+            identity,
+            GetUserConsentsInput {
+                realm_name,
+                user_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetUserConsentsResponse { data: consents }))
+}
@@ -0,0 +1,55 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::group::{
+    entities::{GetUserGroupsInput, Group},
+    ports::GroupService,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetUserGroupsResponse {
+    pub data: Vec<Group>,
+}
+
+#[utoipa::path(
+    get,
+    summary = "Get the groups a user belongs to",
+    path = "/{user_id}/groups",
+    tag = "user",
+    description = "Retrieves the groups the user is a direct member of.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    responses(
+        (status = 200, body = GetUserGroupsResponse),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn get_user_groups(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetUserGroupsResponse>, ApiError> {
+    let groups = state
+        .service
+        .get_user_groups(
+            identity,
+            GetUserGroupsInput {
+                realm_name,
+                user_id,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(GetUserGroupsResponse { data: groups }))
+}
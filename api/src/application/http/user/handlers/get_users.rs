@@ -1,45 +1,79 @@
-use crate::application::http::server::{
-    api_entities::{api_error::ApiError, response::Response},
-    app_state::AppState,
+use crate::application::http::{
+    server::{
+        api_entities::{api_error::ApiError, response::Response},
+        app_state::AppState,
+    },
+    user::validators::GetUsersValidator,
 };
 use axum::{
     Extension,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 use ferriskey_core::domain::authentication::value_objects::Identity;
-use ferriskey_core::domain::user::entities::User;
+use ferriskey_core::domain::user::entities::{GetUsersInput, User, UserQuery};
 use ferriskey_core::domain::user::ports::UserService;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
+use validator::Validate;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct UsersResponse {
     pub data: Vec<User>,
+    pub total: u64,
+    pub offset: u64,
+    pub limit: u64,
 }
 
 #[utoipa::path(
     get,
     path = "",
     tag = "user",
-    summary = "Get all users in a realm",
-    description = "Retrieves all users associated with a specific realm.",
+    summary = "Search users in a realm",
+    description = "Retrieves one page of the users of a realm matching the search and filters, along with the total number of matching users.",
     params(
         ("realm_name" = String, Path, description = "Realm name"),
+        GetUsersValidator,
     ),
     responses(
         (status = 200, description = "Users retrieved successfully", body = UsersResponse),
+        (status = 422, description = "Invalid query parameters"),
     )
 )]
 pub async fn get_users(
     Path(realm_name): Path<String>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
+    Query(params): Query<GetUsersValidator>,
 ) -> Result<Response<UsersResponse>, ApiError> {
-    let users = state
+    params.validate()?;
+
+    let defaults = UserQuery::default();
+    let query = UserQuery {
+        search: params.search,
+        enabled: params.enabled,
+        email_verified: params.email_verified,
+        role: params.role,
+        attribute: params.attribute.and_then(|attribute| {
+            attribute
+                .split_once(':')
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+        }),
+        sort: params.sort.unwrap_or(defaults.sort),
+        order: params.order.unwrap_or(defaults.order),
+        offset: params.offset.unwrap_or(defaults.offset),
+        limit: params.limit.unwrap_or(defaults.limit),
+    };
+
+    let page = state
         .service
-        .get_users(identity, realm_name)
+        .get_users(identity, GetUsersInput { realm_name, query })
         .await
         .map_err(ApiError::from)?;
 
-    Ok(Response::OK(UsersResponse { data: users }))
+    Ok(Response::OK(UsersResponse {
+        data: page.data,
+        total: page.total,
+        offset: page.offset,
+        limit: page.limit,
+    }))
 }
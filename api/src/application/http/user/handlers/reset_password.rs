@@ -62,7 +62,7 @@ pub async fn reset_password(
             },
         )
         .await
-        .map_err(|_| ApiError::InternalServerError("Internal server error".to_string()))?;
+        .map_err(ApiError::from)?;
 
     Ok(Response::OK(ResetPasswordResponse {
         message: "Password reset successfully".to_string(),
@@ -0,0 +1,49 @@
+use crate::application::http::server::{
+    api_entities::{api_error::ApiError, response::Response},
+    app_state::AppState,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::client_scope::{
+    entities::RevokeUserConsentInput, ports::ClientScopeService,
+};
+use uuid::Uuid;
+
+#[utoipa::path(
+    delete,
+    summary = "Revoke the consent of a user for a client",
+    path = "/{user_id}/consents/{client_id}",
+    tag = "user",
+    description = "Deletes the consent the user gave to a client. The user is asked again on the next login to the client.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+        ("client_id" = Uuid, Path, description = "Client ID"),
+    ),
+    responses(
+        (status = 200, description = "Consent revoked successfully"),
+        (status = 404, description = "Consent not found")
+    )
+)]
+pub async fn revoke_user_consent(
+    Path((realm_name, user_id, client_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<()>, ApiError> {
+    state
+        .service
+        .revoke_user_consent(
+            identity,
+            RevokeUserConsentInput {
+                realm_name,
+                user_id,
+                client_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)
+        .map(|_| Response::OK(()))
+}
@@ -66,6 +66,7 @@ pub async fn update_user(
                 email_verified: payload.email_verified,
                 enabled: payload.enabled.unwrap_or(true),
                 required_actions: payload.required_actions,
+                attributes: payload.attributes,
             },
         )
         .await?;
@@ -0,0 +1,67 @@
+use crate::application::http::{
+    server::{
+        api_entities::{
+            api_error::{ApiError, ValidateJson},
+            response::Response,
+        },
+        app_state::AppState,
+    },
+    user::validators::UpdateUserAttributesValidator,
+};
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::user::entities::User;
+use ferriskey_core::domain::user::{entities::UpdateUserAttributesInput, ports::UserService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct UpdateUserAttributesResponse {
+    pub data: User,
+}
+
+#[utoipa::path(
+    put,
+    path = "/{user_id}/attributes",
+    tag = "user",
+    summary = "Replace the custom attributes of a user",
+    description = "Replaces the custom attributes of a user after checking them against the user profile of the realm. Users may call it on themselves to change the attributes the profile opens to self-service.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("user_id" = Uuid, Path, description = "User ID"),
+    ),
+    request_body(
+        content = UpdateUserAttributesValidator,
+        description = "Attributes of the user",
+        content_type = "application/json",
+    ),
+    responses(
+        (status = 200, body = UpdateUserAttributesResponse, description = "Attributes updated successfully"),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Attributes do not satisfy the user profile"),
+    )
+)]
+pub async fn update_user_attributes(
+    Path((realm_name, user_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+    ValidateJson(payload): ValidateJson<UpdateUserAttributesValidator>,
+) -> Result<Response<UpdateUserAttributesResponse>, ApiError> {
+    let user = state
+        .service
+        .update_user_attributes(
+            identity,
+            UpdateUserAttributesInput {
+                realm_name,
+                user_id,
+                attributes: payload.attributes,
+            },
+        )
+        .await?;
+
+    Ok(Response::OK(UpdateUserAttributesResponse { data: user }))
+}
@@ -10,16 +10,23 @@ use crate::application::http::server::app_state::AppState;
 use super::handlers::{
     assign_role::{__path_assign_role, assign_role},
     bulk_delete_user::{__path_bulk_delete_user, bulk_delete_user},
+    clear_brute_force_lockout::{__path_clear_brute_force_lockout, clear_brute_force_lockout},
     create_user::{__path_create_user, create_user},
     delete_credential::{__path_delete_user_credential, delete_user_credential},
     delete_user::{__path_delete_user, delete_user},
+    execute_actions_email::{__path_execute_actions_email, execute_actions_email},
+    get_brute_force_status::{__path_get_brute_force_status, get_brute_force_status},
     get_credentials::{__path_get_user_credentials, get_user_credentials},
     get_user::{__path_get_user, get_user},
+    get_user_consents::{__path_get_user_consents, get_user_consents},
+    get_user_groups::{__path_get_user_groups, get_user_groups},
     get_user_roles::{__path_get_user_roles, get_user_roles},
     get_users::{__path_get_users, get_users},
     reset_password::{__path_reset_password, reset_password},
+    revoke_user_consent::{__path_revoke_user_consent, revoke_user_consent},
     unassign_role::{__path_unassign_role, unassign_role},
     update_user::{__path_update_user, update_user},
+    update_user_attributes::{__path_update_user_attributes, update_user_attributes},
 };
 
 #[derive(OpenApi)]
@@ -27,15 +34,22 @@ use super::handlers::{
     get_users,
     get_user,
     get_user_roles,
+    get_user_groups,
     assign_role,
     create_user,
     update_user,
+    update_user_attributes,
     bulk_delete_user,
     delete_user,
     reset_password,
+    execute_actions_email,
     get_user_credentials,
     delete_user_credential,
     unassign_role,
+    get_brute_force_status,
+    clear_brute_force_lockout,
+    get_user_consents,
+    revoke_user_consent,
 ))]
 pub struct UserApiDoc;
 
@@ -59,6 +73,20 @@ pub fn user_routes(state: AppState) -> Router<AppState> {
             "/realms/{realm_name}/users/{user_id}/roles",
             get(get_user_roles),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/groups",
+                state.args.server.root_path
+            ),
+            get(get_user_groups),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/attributes",
+                state.args.server.root_path
+            ),
+            put(update_user_attributes),
+        )
         .route(
             "/realms/{realm_name}/users/{user_id}/credentials",
             get(get_user_credentials),
@@ -84,6 +112,13 @@ pub fn user_routes(state: AppState) -> Router<AppState> {
             ),
             put(reset_password),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/execute-actions-email",
+                state.args.server.root_path
+            ),
+            put(execute_actions_email),
+        )
         .route(
             &format!(
                 "{}/realms/{{realm_name}}/users/bulk",
@@ -119,5 +154,33 @@ pub fn user_routes(state: AppState) -> Router<AppState> {
             ),
             delete(unassign_role),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/brute-force",
+                state.args.server.root_path
+            ),
+            get(get_brute_force_status),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/brute-force",
+                state.args.server.root_path
+            ),
+            delete(clear_brute_force_lockout),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/consents",
+                state.args.server.root_path
+            ),
+            get(get_user_consents),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/users/{{user_id}}/consents/{{client_id}}",
+                state.args.server.root_path
+            ),
+            delete(revoke_user_consent),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
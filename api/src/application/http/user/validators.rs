@@ -1,5 +1,11 @@
+use ferriskey_core::domain::{
+    action_token::entities::MAX_ADMIN_ACTION_TOKEN_LIFESPAN,
+    user::entities::{
+        MAX_USER_PAGE_SIZE, RequiredAction, SortOrder, UserAttributes, UserSortField,
+    },
+};
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -36,6 +42,9 @@ pub struct CreateUserValidator {
 
     #[serde(default)]
     pub email_verified: Option<bool>,
+
+    #[serde(default)]
+    pub attributes: Option<UserAttributes>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -66,4 +75,74 @@ pub struct UpdateUserValidator {
 
     #[serde(default)]
     pub required_actions: Option<Vec<String>>,
+
+    /// Replaces the custom attributes of the user when set.
+    #[serde(default)]
+    pub attributes: Option<UserAttributes>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateUserAttributesValidator {
+    #[serde(default)]
+    pub attributes: UserAttributes,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GetUsersValidator {
+    /// Terms matched against the username, email, first name and last name.
+    #[serde(default)]
+    pub search: Option<String>,
+
+    #[serde(default)]
+    pub enabled: Option<bool>,
+
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+
+    /// Name of a role assigned directly to the users.
+    #[serde(default)]
+    pub role: Option<String>,
+
+    /// Attribute the users hold, as `name:value`.
+    #[validate(custom(function = "validate_attribute_filter"))]
+    #[serde(default)]
+    pub attribute: Option<String>,
+
+    #[serde(default)]
+    pub sort: Option<UserSortField>,
+
+    #[serde(default)]
+    pub order: Option<SortOrder>,
+
+    #[serde(default)]
+    pub offset: Option<u64>,
+
+    #[validate(range(min = 1, max = MAX_USER_PAGE_SIZE, message = "limit must be between 1 and 100"))]
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ExecuteActionsEmailValidator {
+    #[validate(length(min = 1, message = "at least one action is required"))]
+    #[serde(default)]
+    pub actions: Vec<RequiredAction>,
+
+    /// Lifespan of the emailed link in seconds, 12 hours by default.
+    #[validate(range(
+        min = 60,
+        max = MAX_ADMIN_ACTION_TOKEN_LIFESPAN,
+        message = "lifespan must be between 60 seconds and 30 days"
+    ))]
+    #[serde(default)]
+    pub lifespan: Option<u32>,
+}
+
+fn validate_attribute_filter(attribute: &str) -> Result<(), validator::ValidationError> {
+    match attribute.split_once(':') {
+        Some((name, _)) if !name.is_empty() => Ok(()),
+        _ => Err(validator::ValidationError::new("attribute")
+            .with_message("attribute must be formatted as name:value".into())),
+    }
 }
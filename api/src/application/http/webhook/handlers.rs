@@ -2,4 +2,7 @@ pub mod create_webhook;
 pub mod delete_webhook;
 pub mod fetch_webhook;
 pub mod get_webhook;
+pub mod get_webhook_deliveries;
+pub mod redeliver_webhook;
+pub mod test_webhook;
 pub mod update_webhook;
@@ -15,6 +15,8 @@ use utoipa::ToSchema;
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 pub struct CreateWebhookResponse {
     pub data: Webhook,
+    /// Key the deliveries are signed with, only returned on creation.
+    pub secret: String,
 }
 
 #[utoipa::path(
@@ -22,7 +24,7 @@ pub struct CreateWebhookResponse {
     path = "",
     tag = "webhook",
     summary = "Create webhook",
-    description = "Creates a new webhook in the system related to the current realm.",
+    description = "Creates a new webhook in the system related to the current realm. The response carries the secret its deliveries are signed with, which is not returned afterwards.",
     responses(
         (status = 200, body = CreateWebhookResponse)
     ),
@@ -46,11 +48,19 @@ pub async fn create_webhook(
                 name: payload.name,
                 description: payload.description,
                 endpoint: payload.endpoint,
+                secret: payload.secret,
+                enabled: payload.enabled,
                 subscribers: payload.subscribers,
+                payload_fields: payload.payload_fields,
+                payload_template: payload.payload_template,
+                headers: payload.headers,
             },
         )
         .await
         .map_err(ApiError::from)?;
 
-    Ok(Response::OK(CreateWebhookResponse { data: webhook }))
+    Ok(Response::OK(CreateWebhookResponse {
+        secret: webhook.secret.clone(),
+        data: webhook,
+    }))
 }
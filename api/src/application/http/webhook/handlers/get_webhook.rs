@@ -27,8 +27,7 @@ use uuid::Uuid;
 )]
 
 pub async fn get_webhook(
-    Path(realm_name): Path<String>,
-    Path(webhook_id): Path<Uuid>,
+    Path((realm_name, webhook_id)): Path<(String, Uuid)>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
 ) -> Result<Response<Option<Webhook>>, ApiError> {
@@ -0,0 +1,55 @@
+use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::webhook::entities::webhook_delivery::WebhookDelivery;
+use ferriskey_core::domain::webhook::ports::{GetWebhookDeliveriesInput, WebhookService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct GetWebhookDeliveriesResponse {
+    pub data: Vec<WebhookDelivery>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/{webhook_id}/deliveries",
+    tag = "webhook",
+    summary = "Get webhook deliveries",
+    description = "Lists the latest deliveries of a webhook, newest first, with the status code, latency and error of their last attempt.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("webhook_id" = Uuid, Path, description = "Webhook ID"),
+    ),
+    responses(
+        (status = 200, body = GetWebhookDeliveriesResponse),
+        (status = 404, description = "Webhook not found"),
+    ),
+)]
+pub async fn get_webhook_deliveries(
+    Path((realm_name, webhook_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<GetWebhookDeliveriesResponse>, ApiError> {
+    let deliveries = state
+        .service
+        .get_webhook_deliveries(
+            identity,
+            GetWebhookDeliveriesInput {
+                realm_name,
+                webhook_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(GetWebhookDeliveriesResponse {
+        data: deliveries,
+    }))
+}
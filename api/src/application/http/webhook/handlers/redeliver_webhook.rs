@@ -0,0 +1,57 @@
+use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::webhook::entities::webhook_delivery::WebhookDelivery;
+use ferriskey_core::domain::webhook::ports::{RedeliverWebhookInput, WebhookService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RedeliverWebhookResponse {
+    pub data: WebhookDelivery,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{webhook_id}/deliveries/{delivery_id}/redeliver",
+    tag = "webhook",
+    summary = "Redeliver webhook event",
+    description = "Queues the event of a past delivery to be sent to the webhook again, as a new delivery.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("webhook_id" = Uuid, Path, description = "Webhook ID"),
+        ("delivery_id" = Uuid, Path, description = "Delivery ID"),
+    ),
+    responses(
+        (status = 202, body = RedeliverWebhookResponse),
+        (status = 404, description = "Webhook or delivery not found"),
+    ),
+)]
+pub async fn redeliver_webhook(
+    Path((realm_name, webhook_id, delivery_id)): Path<(String, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<RedeliverWebhookResponse>, ApiError> {
+    let delivery = state
+        .service
+        .redeliver_webhook(
+            identity,
+            RedeliverWebhookInput {
+                realm_name,
+                webhook_id,
+                delivery_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::Accepted(RedeliverWebhookResponse {
+        data: delivery,
+    }))
+}
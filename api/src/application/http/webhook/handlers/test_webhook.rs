@@ -0,0 +1,53 @@
+use crate::application::http::server::api_entities::api_error::ApiError;
+use crate::application::http::server::api_entities::response::Response;
+use crate::application::http::server::app_state::AppState;
+use axum::{
+    Extension,
+    extract::{Path, State},
+};
+use ferriskey_core::domain::authentication::value_objects::Identity;
+use ferriskey_core::domain::webhook::entities::webhook_delivery::WebhookDelivery;
+use ferriskey_core::domain::webhook::ports::{TestWebhookInput, WebhookService};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct TestWebhookResponse {
+    pub data: WebhookDelivery,
+}
+
+#[utoipa::path(
+    post,
+    path = "/{webhook_id}/test",
+    tag = "webhook",
+    summary = "Send test event",
+    description = "Sends a webhook.test event to the endpoint right away, even when the webhook is disabled, and returns the resulting delivery. The attempt counts towards the health of the webhook.",
+    params(
+        ("realm_name" = String, Path, description = "Realm name"),
+        ("webhook_id" = Uuid, Path, description = "Webhook ID"),
+    ),
+    responses(
+        (status = 200, body = TestWebhookResponse),
+        (status = 404, description = "Webhook not found"),
+    ),
+)]
+pub async fn test_webhook(
+    Path((realm_name, webhook_id)): Path<(String, Uuid)>,
+    State(state): State<AppState>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Response<TestWebhookResponse>, ApiError> {
+    let delivery = state
+        .service
+        .test_webhook(
+            identity,
+            TestWebhookInput {
+                realm_name,
+                webhook_id,
+            },
+        )
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(Response::OK(TestWebhookResponse { data: delivery }))
+}
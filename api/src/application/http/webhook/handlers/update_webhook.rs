@@ -30,8 +30,7 @@ pub struct UpdateWebhookResponse {
 )]
 
 pub async fn update_webhook(
-    Path(realm_name): Path<String>,
-    Path(webhook_id): Path<Uuid>,
+    Path((realm_name, webhook_id)): Path<(String, Uuid)>,
     State(state): State<AppState>,
     Extension(identity): Extension<Identity>,
     ValidateJson(payload): ValidateJson<UpdateWebhookValidator>,
@@ -46,7 +45,12 @@ pub async fn update_webhook(
                 name: payload.name,
                 description: payload.description,
                 endpoint: payload.endpoint,
+                secret: payload.secret,
+                enabled: payload.enabled,
                 subscribers: payload.subscribers,
+                payload_fields: payload.payload_fields,
+                payload_template: payload.payload_template,
+                headers: payload.headers,
             },
         )
         .await
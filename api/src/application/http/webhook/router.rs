@@ -2,6 +2,11 @@ use super::handlers::create_webhook::{__path_create_webhook, create_webhook};
 use super::handlers::delete_webhook::{__path_delete_webhook, delete_webhook};
 use super::handlers::fetch_webhook::{__path_fetch_webhooks, fetch_webhooks};
 use super::handlers::get_webhook::{__path_get_webhook, get_webhook};
+use super::handlers::get_webhook_deliveries::{
+    __path_get_webhook_deliveries, get_webhook_deliveries,
+};
+use super::handlers::redeliver_webhook::{__path_redeliver_webhook, redeliver_webhook};
+use super::handlers::test_webhook::{__path_test_webhook, test_webhook};
 use super::handlers::update_webhook::{__path_update_webhook, update_webhook};
 use crate::application::{auth::auth, http::server::app_state::AppState};
 
@@ -17,7 +22,10 @@ use utoipa::OpenApi;
     get_webhook,
     create_webhook,
     update_webhook,
-    delete_webhook
+    delete_webhook,
+    get_webhook_deliveries,
+    redeliver_webhook,
+    test_webhook
 ))]
 pub struct WebhookApiDoc;
 
@@ -58,5 +66,26 @@ pub fn webhook_routes(state: AppState) -> Router<AppState> {
             ),
             delete(delete_webhook),
         )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/webhooks/{{webhook_id}}/deliveries",
+                state.args.server.root_path
+            ),
+            get(get_webhook_deliveries),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/webhooks/{{webhook_id}}/deliveries/{{delivery_id}}/redeliver",
+                state.args.server.root_path
+            ),
+            post(redeliver_webhook),
+        )
+        .route(
+            &format!(
+                "{}/realms/{{realm_name}}/webhooks/{{webhook_id}}/test",
+                state.args.server.root_path
+            ),
+            post(test_webhook),
+        )
         .layer(middleware::from_fn_with_state(state.clone(), auth))
 }
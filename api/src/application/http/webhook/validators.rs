@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use ferriskey_core::domain::webhook::entities::webhook_trigger::WebhookTrigger;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -15,9 +17,34 @@ pub struct CreateWebhookValidator {
     #[serde(default)]
     pub endpoint: String,
 
+    /// Key the deliveries are signed with, generated on creation and kept on
+    /// update when unset.
+    #[validate(length(min = 16, message = "secret must be at least 16 characters"))]
+    #[serde(default)]
+    pub secret: Option<String>,
+
+    /// Whether events are delivered to the webhook, enabled on creation and
+    /// kept on update when unset.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+
     #[validate(length(min = 1, message = "subscribers is required"))]
     #[serde(default)]
     pub subscribers: Vec<WebhookTrigger>,
+
+    /// JSON pointers of the payload values sent to the endpoint, such as
+    /// `/data/id`. The whole payload is sent when empty.
+    #[serde(default)]
+    pub payload_fields: Vec<String>,
+
+    /// Handlebars template rendering the selected payload into the JSON body
+    /// to send, for receivers expecting a format of their own.
+    #[serde(default)]
+    pub payload_template: Option<String>,
+
+    /// Static headers added to every delivery, such as an API key.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
@@ -32,7 +59,33 @@ pub struct UpdateWebhookValidator {
     #[serde(default)]
     pub endpoint: String,
 
+    /// Key the deliveries are signed with, generated on creation and kept on
+    /// update when unset.
+    #[validate(length(min = 16, message = "secret must be at least 16 characters"))]
+    #[serde(default)]
+    pub secret: Option<String>,
+
+    /// Whether events are delivered to the webhook, enabled on creation and
+    /// kept on update when unset.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+
     #[validate(length(min = 1, message = "subscribers is required"))]
     #[serde(default)]
     pub subscribers: Vec<WebhookTrigger>,
+
+    /// JSON pointers of the payload values sent to the endpoint, such as
+    /// `/data/id`, kept when unset. The whole payload is sent when empty.
+    #[serde(default)]
+    pub payload_fields: Option<Vec<String>>,
+
+    /// Handlebars template rendering the selected payload into the JSON body
+    /// to send, kept when unset and removed when empty.
+    #[serde(default)]
+    pub payload_template: Option<String>,
+
+    /// Static headers added to every delivery, kept when unset. Headers sent
+    /// with their masked value keep their current value.
+    #[serde(default)]
+    pub headers: Option<BTreeMap<String, String>>,
 }
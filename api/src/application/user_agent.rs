@@ -0,0 +1,28 @@
+use std::convert::Infallible;
+
+use axum::{extract::FromRequestParts, http::header::USER_AGENT};
+
+/// `User-Agent` header of the request, if any.
+#[derive(Debug, Clone)]
+pub struct UserAgent(pub Option<String>);
+
+impl<S> FromRequestParts<S> for UserAgent
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(USER_AGENT)
+            .and_then(|h| h.to_str().ok())
+            .map(|h| h.trim().to_string())
+            .filter(|h| !h.is_empty());
+
+        Ok(UserAgent(user_agent))
+    }
+}
@@ -1,9 +1,10 @@
 #![allow(deprecated)]
 
-use std::{fmt::Display, path::PathBuf};
+use std::{fmt::Display, net::IpAddr, path::PathBuf};
 
 use clap::{Parser, ValueEnum};
-use ferriskey_core::domain::common::{DatabaseConfig, FerriskeyConfig};
+use ferriskey_core::domain::common::{DatabaseConfig, EmailConfig, FerriskeyConfig};
+use ipnet::IpNet;
 use url::Url;
 
 #[derive(Debug, Clone, ValueEnum, Default)]
@@ -40,6 +41,8 @@ pub struct Args {
     pub admin: AdminArgs,
     #[command(flatten)]
     pub db: DatabaseArgs,
+    #[command(flatten)]
+    pub email: EmailArgs,
     #[arg(
         short,
         long,
@@ -66,6 +69,7 @@ impl Default for Args {
         Self {
             admin: AdminArgs::default(),
             db: DatabaseArgs::default(),
+            email: EmailArgs::default(),
             env: Environment::Development,
             log: LogArgs::default(),
             server: ServerArgs::default(),
@@ -183,6 +187,32 @@ impl From<Url> for DatabaseArgs {
     }
 }
 
+#[derive(Debug, Clone, ValueEnum, Default)]
+pub enum EmailTransport {
+    #[default]
+    Smtp,
+    File,
+}
+
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct EmailArgs {
+    #[arg(
+        long = "email-transport",
+        env = "EMAIL_TRANSPORT",
+        name = "EMAIL_TRANSPORT",
+        default_value = "smtp",
+        long_help = "How emails are sent: through the SMTP server of each realm, or logged and written to files for development"
+    )]
+    pub transport: EmailTransport,
+    #[arg(
+        long = "email-file-directory",
+        env = "EMAIL_FILE_DIRECTORY",
+        name = "EMAIL_FILE_DIRECTORY",
+        long_help = "Directory the file transport writes emails to, as .eml files"
+    )]
+    pub file_directory: Option<PathBuf>,
+}
+
 #[derive(clap::Args, Debug, Clone)]
 pub struct LogArgs {
     #[arg(
@@ -251,6 +281,16 @@ pub struct ServerArgs {
     pub root_path: String,
     #[command(flatten)]
     pub tls: Option<ServerTlsArgs>,
+    #[arg(
+        long = "server-trusted-proxies",
+        env = "SERVER_TRUSTED_PROXIES",
+        name = "SERVER_TRUSTED_PROXIES",
+        num_args = 0..,
+        value_delimiter = ',',
+        long_help = "The addresses or CIDR ranges of the reverse proxies allowed to set the X-Forwarded-For and X-Real-IP headers",
+        value_parser = parse_trusted_proxy,
+    )]
+    pub trusted_proxies: Vec<IpNet>,
 }
 
 impl Default for ServerArgs {
@@ -261,6 +301,7 @@ impl Default for ServerArgs {
             port: 3333,
             root_path: String::new(),
             tls: None,
+            trusted_proxies: vec![],
         }
     }
 }
@@ -295,6 +336,14 @@ fn parse_root_path(value: &str) -> Result<String, String> {
     }
 }
 
+fn parse_trusted_proxy(value: &str) -> Result<IpNet, String> {
+    let value = value.trim();
+    value
+        .parse::<IpNet>()
+        .or_else(|_| value.parse::<IpAddr>().map(IpNet::from))
+        .map_err(|_| format!("invalid trusted proxy: {value}"))
+}
+
 impl From<Args> for FerriskeyConfig {
     fn from(value: Args) -> Self {
         FerriskeyConfig {
@@ -305,6 +354,12 @@ impl From<Args> for FerriskeyConfig {
                 port: value.db.port,
                 username: value.db.user,
             },
+            email: match value.email.transport {
+                EmailTransport::Smtp => EmailConfig::Smtp,
+                EmailTransport::File => EmailConfig::File {
+                    directory: value.email.file_directory,
+                },
+            },
         }
     }
 }
@@ -352,4 +407,26 @@ mod test {
             assert_eq!(path, "/api");
         }
     }
+
+    mod parse_trusted_proxy {
+        use super::*;
+
+        #[test]
+        fn address() {
+            let proxy = parse_trusted_proxy("10.0.0.1").unwrap();
+            assert!(proxy.contains(&"10.0.0.1".parse::<IpAddr>().unwrap()));
+            assert!(!proxy.contains(&"10.0.0.2".parse::<IpAddr>().unwrap()));
+        }
+
+        #[test]
+        fn cidr() {
+            let proxy = parse_trusted_proxy("10.0.0.0/8").unwrap();
+            assert!(proxy.contains(&"10.1.2.3".parse::<IpAddr>().unwrap()));
+        }
+
+        #[test]
+        fn invalid() {
+            assert!(parse_trusted_proxy("proxy").is_err());
+        }
+    }
 }
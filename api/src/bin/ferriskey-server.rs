@@ -13,6 +13,7 @@
 
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum_server::tls_rustls::RustlsConfig;
 use clap::Parser;
@@ -21,9 +22,23 @@ use ferriskey_api::application::http::server::http_server::{router, state};
 use ferriskey_api::args::{Args, LogArgs};
 use ferriskey_core::domain::common::entities::StartupConfig;
 use ferriskey_core::domain::common::ports::CoreService;
+use ferriskey_core::domain::event::ports::EventService;
+use ferriskey_core::domain::webhook::ports::WebhookService;
 use tracing::{debug, error, info};
 use tracing_subscriber::EnvFilter;
 
+/// How often realms are checked for signing keys due for rotation or retirement.
+const KEY_ROTATION_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+
+/// How often the webhook outbox is checked for deliveries due for an attempt.
+const WEBHOOK_DELIVERY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often events past the retention period of their realm are purged.
+const EVENT_PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// How often deliveries past the history of their webhook are pruned.
+const WEBHOOK_PRUNE_INTERVAL: Duration = Duration::from_secs(3600);
+
 fn init_logger(args: &LogArgs) {
     let filter = EnvFilter::try_new(&args.filter).unwrap_or_else(|err| {
         eprint!("invalid log filter: {err}");
@@ -60,6 +75,50 @@ async fn main() -> Result<(), anyhow::Error> {
         })
         .await?;
 
+    let service = app_state.service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(KEY_ROTATION_CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = service.apply_key_rotation_policy().await {
+                error!("failed to apply key rotation policy: {e}");
+            }
+        }
+    });
+
+    let service = app_state.service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(WEBHOOK_DELIVERY_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = service.deliver_pending_webhooks().await {
+                error!("failed to deliver webhooks: {e}");
+            }
+        }
+    });
+
+    let service = app_state.service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EVENT_PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = service.purge_expired_events().await {
+                error!("failed to purge expired events: {e}");
+            }
+        }
+    });
+
+    let service = app_state.service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(WEBHOOK_PRUNE_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = service.prune_webhook_deliveries().await {
+                error!("failed to prune webhook deliveries: {e}");
+            }
+        }
+    });
+
     let router = router(app_state)?;
 
     let addr = {
@@ -87,12 +146,12 @@ async fn main() -> Result<(), anyhow::Error> {
         let tls_cfg = RustlsConfig::from_pem_file(tls.cert.clone(), tls.key.clone()).await?;
         info!("listening on {addr}");
         axum_server::bind_rustls(addr, tls_cfg)
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .await?;
     } else {
         info!("listening on {addr}");
         axum_server::bind(addr)
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .await?;
     }
     Ok(())
@@ -0,0 +1,6 @@
+-- Add down migration script here
+
+DROP INDEX IF EXISTS idx_refresh_tokens_user_client;
+
+ALTER TABLE refresh_tokens
+    DROP COLUMN IF EXISTS client_id;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN IF NOT EXISTS client_id VARCHAR(255);
+
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_user_client ON refresh_tokens (user_id, client_id);
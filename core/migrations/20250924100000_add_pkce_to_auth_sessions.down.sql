@@ -0,0 +1,8 @@
+-- Add down migration script here
+
+ALTER TABLE clients
+    DROP COLUMN IF EXISTS pkce_required;
+
+ALTER TABLE auth_sessions
+    DROP COLUMN IF EXISTS code_challenge_method,
+    DROP COLUMN IF EXISTS code_challenge;
@@ -0,0 +1,8 @@
+-- Add up migration script here
+
+ALTER TABLE auth_sessions
+    ADD COLUMN IF NOT EXISTS code_challenge VARCHAR(255),
+    ADD COLUMN IF NOT EXISTS code_challenge_method VARCHAR(10);
+
+ALTER TABLE clients
+    ADD COLUMN IF NOT EXISTS pkce_required BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE auth_sessions
+    DROP COLUMN IF EXISTS amr,
+    DROP COLUMN IF EXISTS authenticated_at;
@@ -0,0 +1,5 @@
+-- Add up migration script here
+
+ALTER TABLE auth_sessions
+    ADD COLUMN IF NOT EXISTS authenticated_at TIMESTAMP,
+    ADD COLUMN IF NOT EXISTS amr VARCHAR(255);
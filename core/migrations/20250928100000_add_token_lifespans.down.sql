@@ -0,0 +1,12 @@
+-- Add down migration script here
+
+ALTER TABLE clients
+    DROP COLUMN IF EXISTS auth_code_lifespan,
+    DROP COLUMN IF EXISTS refresh_token_lifespan,
+    DROP COLUMN IF EXISTS access_token_lifespan;
+
+ALTER TABLE realm_settings
+    DROP COLUMN IF EXISTS auth_code_lifespan,
+    DROP COLUMN IF EXISTS session_lifespan,
+    DROP COLUMN IF EXISTS refresh_token_lifespan,
+    DROP COLUMN IF EXISTS access_token_lifespan;
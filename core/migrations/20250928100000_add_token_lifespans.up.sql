@@ -0,0 +1,12 @@
+-- Add up migration script here
+
+ALTER TABLE realm_settings
+    ADD COLUMN IF NOT EXISTS access_token_lifespan INTEGER NOT NULL DEFAULT 300,
+    ADD COLUMN IF NOT EXISTS refresh_token_lifespan INTEGER NOT NULL DEFAULT 86400,
+    ADD COLUMN IF NOT EXISTS session_lifespan INTEGER NOT NULL DEFAULT 86400,
+    ADD COLUMN IF NOT EXISTS auth_code_lifespan INTEGER NOT NULL DEFAULT 60;
+
+ALTER TABLE clients
+    ADD COLUMN IF NOT EXISTS access_token_lifespan INTEGER,
+    ADD COLUMN IF NOT EXISTS refresh_token_lifespan INTEGER,
+    ADD COLUMN IF NOT EXISTS auth_code_lifespan INTEGER;
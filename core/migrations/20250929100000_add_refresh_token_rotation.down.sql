@@ -0,0 +1,9 @@
+-- Add down migration script here
+
+ALTER TABLE realm_settings
+    DROP COLUMN IF EXISTS refresh_token_rotation;
+
+DROP INDEX IF EXISTS idx_refresh_tokens_family_id;
+
+ALTER TABLE refresh_tokens
+    DROP COLUMN IF EXISTS family_id;
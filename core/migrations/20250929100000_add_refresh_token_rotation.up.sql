@@ -0,0 +1,9 @@
+-- Add up migration script here
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN IF NOT EXISTS family_id UUID;
+
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_family_id ON refresh_tokens (family_id);
+
+ALTER TABLE realm_settings
+    ADD COLUMN IF NOT EXISTS refresh_token_rotation BOOLEAN NOT NULL DEFAULT false;
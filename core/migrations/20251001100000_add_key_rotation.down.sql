@@ -0,0 +1,12 @@
+-- Add down migration script here
+
+ALTER TABLE realm_settings
+    DROP COLUMN IF EXISTS key_retirement_delay,
+    DROP COLUMN IF EXISTS key_rotation_interval;
+
+DROP INDEX IF EXISTS idx_jwt_keys_realm_status;
+
+ALTER TABLE jwt_keys
+    DROP COLUMN IF EXISTS rotated_at,
+    DROP COLUMN IF EXISTS status,
+    DROP COLUMN IF EXISTS version;
@@ -0,0 +1,12 @@
+-- Add up migration script here
+
+ALTER TABLE jwt_keys
+    ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1,
+    ADD COLUMN IF NOT EXISTS status VARCHAR(32) NOT NULL DEFAULT 'active',
+    ADD COLUMN IF NOT EXISTS rotated_at TIMESTAMP;
+
+CREATE INDEX IF NOT EXISTS idx_jwt_keys_realm_status ON jwt_keys (realm_id, status);
+
+ALTER TABLE realm_settings
+    ADD COLUMN IF NOT EXISTS key_rotation_interval INTEGER,
+    ADD COLUMN IF NOT EXISTS key_retirement_delay INTEGER NOT NULL DEFAULT 604800;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+
+ALTER TABLE jwt_keys
+    DROP COLUMN IF EXISTS algorithm;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+ALTER TABLE jwt_keys
+    ADD COLUMN IF NOT EXISTS algorithm VARCHAR(16) NOT NULL DEFAULT 'RS256';
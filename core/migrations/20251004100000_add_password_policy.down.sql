@@ -0,0 +1,12 @@
+-- Add down migration script here
+
+DELETE FROM credentials WHERE credential_type = 'password-history';
+
+DROP INDEX IF EXISTS unique_credential_type_per_user_id_idx;
+
+CREATE UNIQUE INDEX unique_credential_type_per_user_id_idx
+ON credentials (user_id, credential_type)
+WHERE credential_type <> 'recovery-code';
+
+ALTER TABLE realm_settings
+    DROP COLUMN IF EXISTS password_policy;
@@ -0,0 +1,11 @@
+-- Add up migration script here
+
+ALTER TABLE realm_settings
+    ADD COLUMN IF NOT EXISTS password_policy JSONB NOT NULL DEFAULT '{}'::jsonb;
+
+-- Previous passwords are kept as credentials to enforce the password history
+DROP INDEX IF EXISTS unique_credential_type_per_user_id_idx;
+
+CREATE UNIQUE INDEX unique_credential_type_per_user_id_idx
+ON credentials (user_id, credential_type)
+WHERE credential_type NOT IN ('recovery-code', 'password-history');
@@ -0,0 +1,6 @@
+-- Add down migration script here
+
+DROP TABLE IF EXISTS login_failures;
+
+ALTER TABLE realm_settings
+    DROP COLUMN IF EXISTS brute_force_protection;
@@ -0,0 +1,26 @@
+-- Add up migration script here
+
+ALTER TABLE realm_settings
+    ADD COLUMN IF NOT EXISTS brute_force_protection JSONB NOT NULL DEFAULT '{}'::jsonb;
+
+-- Failed login attempts, tracked either per user or per source IP
+CREATE TABLE login_failures (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    ip_address VARCHAR(64),
+    num_failures INTEGER NOT NULL DEFAULT 0,
+    last_failure TIMESTAMP NOT NULL,
+    locked_until TIMESTAMP,
+    permanently_locked BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    CHECK ((user_id IS NULL) <> (ip_address IS NULL))
+);
+
+CREATE UNIQUE INDEX unique_login_failure_user_idx
+ON login_failures (realm_id, user_id)
+WHERE user_id IS NOT NULL;
+
+CREATE UNIQUE INDEX unique_login_failure_ip_idx
+ON login_failures (realm_id, ip_address)
+WHERE ip_address IS NOT NULL;
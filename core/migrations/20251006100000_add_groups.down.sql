@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+DROP TABLE IF EXISTS group_roles;
+DROP TABLE IF EXISTS group_members;
+DROP TABLE IF EXISTS groups;
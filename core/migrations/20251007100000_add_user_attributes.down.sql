@@ -0,0 +1,6 @@
+-- Add down migration script here
+ALTER TABLE realm_settings
+    DROP COLUMN IF EXISTS user_profile;
+
+ALTER TABLE users
+    DROP COLUMN IF EXISTS attributes;
@@ -0,0 +1,6 @@
+-- Add up migration script here
+ALTER TABLE users
+    ADD COLUMN attributes JSONB NOT NULL DEFAULT '{}';
+
+ALTER TABLE realm_settings
+    ADD COLUMN user_profile JSONB NOT NULL DEFAULT '{}';
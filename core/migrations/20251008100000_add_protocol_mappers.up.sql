@@ -0,0 +1,14 @@
+-- Add up migration script here
+CREATE TABLE protocol_mappers (
+    id UUID PRIMARY KEY,
+    client_id UUID NOT NULL REFERENCES clients(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    config JSONB NOT NULL,
+    access_token BOOLEAN NOT NULL DEFAULT TRUE,
+    id_token BOOLEAN NOT NULL DEFAULT TRUE,
+    userinfo BOOLEAN NOT NULL DEFAULT TRUE,
+    introspection BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE (client_id, name)
+);
@@ -0,0 +1,15 @@
+-- Add down migration script here
+
+DELETE FROM protocol_mappers WHERE client_scope_id IS NOT NULL;
+
+ALTER TABLE protocol_mappers
+    DROP CONSTRAINT protocol_mappers_client_scope_id_name_key,
+    DROP CONSTRAINT protocol_mappers_single_owner,
+    DROP COLUMN client_scope_id,
+    ALTER COLUMN client_id SET NOT NULL;
+
+ALTER TABLE clients DROP COLUMN consent_required;
+
+DROP TABLE IF EXISTS user_consents;
+DROP TABLE IF EXISTS client_scope_mappings;
+DROP TABLE IF EXISTS client_scopes;
@@ -0,0 +1,62 @@
+-- Add up migration script here
+
+CREATE TABLE client_scopes (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    include_in_token_scope BOOLEAN NOT NULL DEFAULT TRUE,
+    display_on_consent_screen BOOLEAN NOT NULL DEFAULT TRUE,
+    consent_text TEXT,
+    -- How the scope is linked to newly created clients: none, default or optional
+    default_type VARCHAR(16) NOT NULL DEFAULT 'none',
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE (realm_id, name)
+);
+
+CREATE TABLE client_scope_mappings (
+    client_id UUID NOT NULL REFERENCES clients(id) ON DELETE CASCADE,
+    client_scope_id UUID NOT NULL REFERENCES client_scopes(id) ON DELETE CASCADE,
+    default_scope BOOLEAN NOT NULL,
+    PRIMARY KEY (client_id, client_scope_id)
+);
+
+CREATE INDEX idx_client_scope_mappings_client_scope_id ON client_scope_mappings (client_scope_id);
+
+CREATE TABLE user_consents (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    client_id UUID NOT NULL REFERENCES clients(id) ON DELETE CASCADE,
+    scopes JSONB NOT NULL DEFAULT '[]'::jsonb,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, client_id)
+);
+
+ALTER TABLE clients ADD COLUMN consent_required BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Protocol mappers belong either to a client or to a client scope
+ALTER TABLE protocol_mappers
+    ALTER COLUMN client_id DROP NOT NULL,
+    ADD COLUMN client_scope_id UUID REFERENCES client_scopes(id) ON DELETE CASCADE,
+    ADD CONSTRAINT protocol_mappers_single_owner CHECK ((client_id IS NULL) <> (client_scope_id IS NULL)),
+    ADD CONSTRAINT protocol_mappers_client_scope_id_name_key UNIQUE (client_scope_id, name);
+
+-- Built-in scopes for existing realms, linked to their existing clients
+INSERT INTO client_scopes (id, realm_id, name, description, consent_text, default_type)
+SELECT gen_random_uuid(), realms.id, builtin.name, builtin.description, builtin.consent_text, builtin.default_type
+FROM realms
+CROSS JOIN (
+    VALUES
+        ('profile', 'OpenID Connect built-in scope: profile', 'User profile', 'default'),
+        ('email', 'OpenID Connect built-in scope: email', 'Email address', 'default'),
+        ('roles', 'Roles of the user', 'User roles', 'default'),
+        ('offline_access', 'OpenID Connect built-in scope: offline_access', 'Offline access', 'optional')
+) AS builtin (name, description, consent_text, default_type);
+
+INSERT INTO client_scope_mappings (client_id, client_scope_id, default_scope)
+SELECT clients.id, client_scopes.id, client_scopes.default_type = 'default'
+FROM clients
+JOIN client_scopes ON client_scopes.realm_id = clients.realm_id
+WHERE client_scopes.default_type <> 'none';
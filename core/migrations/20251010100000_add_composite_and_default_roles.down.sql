@@ -0,0 +1,12 @@
+-- Add down migration script here
+
+DELETE FROM protocol_mappers
+WHERE client_scope_id IN (SELECT id FROM client_scopes WHERE name = 'roles')
+  AND name IN ('realm roles', 'client roles');
+
+DELETE FROM roles
+WHERE id IN (SELECT default_role_id FROM realms WHERE default_role_id IS NOT NULL);
+
+ALTER TABLE realms DROP COLUMN default_role_id;
+
+DROP TABLE role_composites;
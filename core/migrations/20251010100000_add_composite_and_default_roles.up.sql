@@ -0,0 +1,45 @@
+-- Add up migration script here
+
+-- Roles including other roles, expanded transitively when resolving the roles of a user
+CREATE TABLE role_composites (
+    composite_role_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    child_role_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (composite_role_id, child_role_id),
+    CHECK (composite_role_id <> child_role_id)
+);
+
+CREATE INDEX idx_role_composites_child_role_id ON role_composites (child_role_id);
+
+-- Composite role assigned to every new user of the realm
+ALTER TABLE realms ADD COLUMN default_role_id UUID REFERENCES roles(id) ON DELETE SET NULL;
+
+INSERT INTO roles (id, name, description, permissions, realm_id)
+SELECT gen_random_uuid(), 'default-roles-' || realms.name, 'Roles assigned to every new user of the realm', 0, realms.id
+FROM realms
+ON CONFLICT (name, realm_id) DO NOTHING;
+
+UPDATE realms
+SET default_role_id = roles.id
+FROM roles
+WHERE roles.realm_id = realms.id
+  AND roles.client_id IS NULL
+  AND roles.name = 'default-roles-' || realms.name;
+
+INSERT INTO user_role (user_id, role_id)
+SELECT users.id, realms.default_role_id
+FROM users
+JOIN realms ON realms.id = users.realm_id
+WHERE realms.default_role_id IS NOT NULL;
+
+-- Role claims released by the built-in roles scope
+INSERT INTO protocol_mappers (id, client_scope_id, name, config, access_token, id_token, userinfo, introspection)
+SELECT gen_random_uuid(), client_scopes.id, builtin.name, builtin.config::jsonb, TRUE, FALSE, FALSE, TRUE
+FROM client_scopes
+CROSS JOIN (
+    VALUES
+        ('realm roles', '{"type": "realm_roles", "claim": "realm_access.roles"}'),
+        ('client roles', '{"type": "client_roles", "client_id": null, "claim": "resource_access.${client_id}.roles"}')
+) AS builtin (name, config)
+WHERE client_scopes.name = 'roles'
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE realm_settings DROP COLUMN IF EXISTS default_locale;
+
+DROP TABLE IF EXISTS smtp_servers;
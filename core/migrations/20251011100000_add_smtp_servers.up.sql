@@ -0,0 +1,18 @@
+-- Add up migration script here
+
+CREATE TABLE smtp_servers (
+    realm_id UUID PRIMARY KEY REFERENCES realms(id) ON DELETE CASCADE,
+    host VARCHAR(255) NOT NULL,
+    port INTEGER NOT NULL,
+    -- Transport security: none, starttls or tls
+    encryption VARCHAR(16) NOT NULL DEFAULT 'starttls',
+    from_address VARCHAR(255) NOT NULL,
+    from_display_name VARCHAR(255),
+    reply_to VARCHAR(255),
+    username VARCHAR(255),
+    password TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE realm_settings ADD COLUMN default_locale VARCHAR(16) NOT NULL DEFAULT 'en';
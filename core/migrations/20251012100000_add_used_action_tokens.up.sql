@@ -0,0 +1,12 @@
+-- Add up migration script here
+
+-- Action tokens are signed JWTs; this table only records the ones already
+-- consumed so that each of them can be used once.
+CREATE TABLE used_action_tokens (
+    jti UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    expires_at TIMESTAMP NOT NULL,
+    used_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_used_action_tokens_expires_at ON used_action_tokens(expires_at);
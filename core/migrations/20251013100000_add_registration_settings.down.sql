@@ -0,0 +1,4 @@
+-- Add down migration script here
+
+ALTER TABLE realm_settings DROP COLUMN IF EXISTS verify_email;
+ALTER TABLE realm_settings DROP COLUMN IF EXISTS registration_allowed;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+ALTER TABLE realm_settings ADD COLUMN registration_allowed BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE realm_settings ADD COLUMN verify_email BOOLEAN NOT NULL DEFAULT FALSE;
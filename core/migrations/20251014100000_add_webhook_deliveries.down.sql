@@ -0,0 +1,6 @@
+-- Add down migration script here
+
+DROP TABLE IF EXISTS webhook_deliveries;
+
+ALTER TABLE webhooks
+    DROP COLUMN IF EXISTS secret;
@@ -0,0 +1,35 @@
+-- Add up migration script here
+
+ALTER TABLE webhooks
+    ADD COLUMN secret VARCHAR(255) NOT NULL DEFAULT '';
+
+-- Existing webhooks get a random secret to sign their deliveries with
+UPDATE webhooks
+    SET secret = replace(gen_random_uuid()::text, '-', '') || replace(gen_random_uuid()::text, '-', '')
+    WHERE secret = '';
+
+ALTER TABLE webhooks
+    ALTER COLUMN secret DROP DEFAULT;
+
+-- Outbox of the events to deliver to each webhook; rows are kept once
+-- delivered or abandoned as the delivery history of the webhook.
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY,
+    webhook_id UUID NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    event VARCHAR(255) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMP NULL,
+    response_status INTEGER NULL,
+    latency_ms BIGINT NULL,
+    error TEXT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMP NULL
+);
+
+CREATE INDEX idx_webhook_deliveries_pending ON webhook_deliveries(next_attempt_at)
+    WHERE status = 'pending';
+CREATE INDEX idx_webhook_deliveries_webhook_id ON webhook_deliveries(webhook_id, created_at DESC);
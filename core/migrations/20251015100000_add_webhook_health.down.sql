@@ -0,0 +1,8 @@
+-- Add down migration script here
+
+ALTER TABLE realm_settings DROP COLUMN IF EXISTS webhook_failure_threshold;
+
+ALTER TABLE webhooks DROP COLUMN IF EXISTS consecutive_failures;
+ALTER TABLE webhooks DROP COLUMN IF EXISTS last_failure_at;
+ALTER TABLE webhooks DROP COLUMN IF EXISTS last_success_at;
+ALTER TABLE webhooks DROP COLUMN IF EXISTS enabled;
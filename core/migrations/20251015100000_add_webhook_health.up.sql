@@ -0,0 +1,8 @@
+-- Add up migration script here
+
+ALTER TABLE webhooks ADD COLUMN enabled BOOLEAN NOT NULL DEFAULT TRUE;
+ALTER TABLE webhooks ADD COLUMN last_success_at TIMESTAMP NULL;
+ALTER TABLE webhooks ADD COLUMN last_failure_at TIMESTAMP NULL;
+ALTER TABLE webhooks ADD COLUMN consecutive_failures INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE realm_settings ADD COLUMN webhook_failure_threshold INTEGER NOT NULL DEFAULT 20;
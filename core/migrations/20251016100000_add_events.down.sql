@@ -0,0 +1,7 @@
+-- Add down migration script here
+
+ALTER TABLE realm_settings DROP COLUMN IF EXISTS admin_event_retention;
+ALTER TABLE realm_settings DROP COLUMN IF EXISTS login_event_retention;
+
+DROP TABLE IF EXISTS admin_events;
+DROP TABLE IF EXISTS login_events;
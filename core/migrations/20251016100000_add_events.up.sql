@@ -0,0 +1,39 @@
+-- Add up migration script here
+
+CREATE TABLE login_events (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    event_type VARCHAR(64) NOT NULL,
+    user_id UUID NULL,
+    username VARCHAR(255) NULL,
+    client_id VARCHAR(255) NULL,
+    ip_address VARCHAR(64) NULL,
+    user_agent TEXT NULL,
+    error VARCHAR(64) NULL,
+    required_action VARCHAR(64) NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_login_events_realm_id ON login_events(realm_id, created_at DESC);
+CREATE INDEX idx_login_events_user_id ON login_events(user_id, created_at DESC);
+
+-- The actor is not a foreign key so that events outlive the users and
+-- clients that caused them.
+CREATE TABLE admin_events (
+    id UUID PRIMARY KEY,
+    realm_id UUID NOT NULL REFERENCES realms(id) ON DELETE CASCADE,
+    resource_type VARCHAR(64) NOT NULL,
+    resource_id UUID NULL,
+    operation VARCHAR(32) NOT NULL,
+    actor_type VARCHAR(32) NOT NULL,
+    actor_id UUID NOT NULL,
+    actor_realm_id UUID NOT NULL,
+    actor_name VARCHAR(255) NOT NULL,
+    representation JSONB NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_admin_events_realm_id ON admin_events(realm_id, created_at DESC);
+
+ALTER TABLE realm_settings ADD COLUMN login_event_retention INTEGER NOT NULL DEFAULT 2592000;
+ALTER TABLE realm_settings ADD COLUMN admin_event_retention INTEGER NOT NULL DEFAULT 7776000;
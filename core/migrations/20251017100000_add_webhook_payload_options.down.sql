@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE webhooks DROP COLUMN IF EXISTS headers;
+ALTER TABLE webhooks DROP COLUMN IF EXISTS payload_template;
+ALTER TABLE webhooks DROP COLUMN IF EXISTS payload_fields;
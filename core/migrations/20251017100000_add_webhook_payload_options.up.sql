@@ -0,0 +1,5 @@
+-- Add up migration script here
+
+ALTER TABLE webhooks ADD COLUMN payload_fields JSONB NOT NULL DEFAULT '[]';
+ALTER TABLE webhooks ADD COLUMN payload_template TEXT NULL;
+ALTER TABLE webhooks ADD COLUMN headers JSONB NOT NULL DEFAULT '{}';
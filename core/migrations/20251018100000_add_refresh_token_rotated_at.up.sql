@@ -0,0 +1,4 @@
+-- Add up migration script here
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN IF NOT EXISTS rotated_at TIMESTAMP NULL;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+
+ALTER TABLE refresh_tokens
+    DROP COLUMN IF EXISTS amr,
+    DROP COLUMN IF EXISTS auth_time;
@@ -0,0 +1,5 @@
+-- Add up migration script here
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN IF NOT EXISTS auth_time TIMESTAMP NULL,
+    ADD COLUMN IF NOT EXISTS amr VARCHAR(255) NULL;
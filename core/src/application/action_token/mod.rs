@@ -0,0 +1,591 @@
+use jsonwebtoken::{Validation, decode, errors::ErrorKind};
+use serde_json::json;
+use tracing::error;
+
+use crate::{
+    application::common::{FerriskeyService, policies::ensure_policy},
+    domain::{
+        action_token::{
+            entities::{
+                ActionTokenClaims, ActionTokenDetails, ActionTokenResult, ActionTokenType,
+                DEFAULT_ADMIN_ACTION_TOKEN_LIFESPAN, ExecuteActionTokenInput,
+                ExecuteActionsEmailInput, ForgotPasswordInput, GetActionTokenInput,
+                SendVerifyEmailInput, USER_ACTION_TOKEN_LIFESPAN,
+            },
+            ports::{ActionTokenService, UsedActionTokenRepository},
+        },
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        email::entities::EmailTemplate,
+        jwt::{
+            ports::{KeyStoreRepository, RefreshTokenRepository},
+            services::find_verification_key,
+        },
+        realm::{entities::Realm, ports::RealmRepository},
+        user::{
+            entities::{RequiredAction, User},
+            ports::{UserPolicy, UserRepository, UserRequiredActionRepository},
+            value_objects::UpdateUserRequest,
+        },
+    },
+};
+
+/// Where the web application the emailed links point to expects the token.
+fn action_token_link(webapp_url: &str, realm_name: &str, token: &str) -> String {
+    format!("{webapp_url}/realms/{realm_name}/authentication/action-token?key={token}")
+}
+
+impl FerriskeyService {
+    async fn sign_action_token(
+        &self,
+        claims: &ActionTokenClaims,
+        realm: &Realm,
+    ) -> Result<String, CoreError> {
+        let realm_setting = self.realm_setting(realm.id).await?;
+
+        let jwt_key_pair = self
+            .keystore_repository
+            .get_or_generate_key(realm.id, realm_setting.signing_algorithm())
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        jsonwebtoken::encode(&jwt_key_pair.header(), claims, &jwt_key_pair.encoding_key).map_err(
+            |e| {
+                error!("action token generation error: {}", e);
+
+                CoreError::TokenGenerationError(e.to_string())
+            },
+        )
+    }
+
+    /// Checks the signature, expiry and single use of `token`, and that the
+    /// user it was issued to can still use it.
+    async fn verify_action_token(
+        &self,
+        realm: &Realm,
+        token: &str,
+    ) -> Result<(ActionTokenClaims, User), CoreError> {
+        let jwt_key_pair = find_verification_key(&self.keystore_repository, token, realm.id)
+            .await
+            .map_err(|_| CoreError::InvalidToken)?;
+
+        let mut validation = Validation::new(jwt_key_pair.algorithm.into());
+        validation.validate_aud = false;
+
+        let claims = decode::<ActionTokenClaims>(token, &jwt_key_pair.decoding_key, &validation)
+            .map_err(|e| match e.kind() {
+                ErrorKind::ExpiredSignature => CoreError::ExpiredToken,
+                _ => CoreError::InvalidToken,
+            })?
+            .claims;
+
+        if self
+            .used_action_token_repository
+            .is_used(claims.jti)
+            .await?
+        {
+            return Err(CoreError::InvalidToken);
+        }
+
+        let user = self
+            .user_repository
+            .get_by_id(claims.sub)
+            .await
+            .map_err(|_| CoreError::InvalidToken)?;
+
+        if user.realm_id != realm.id
+            || !user.enabled
+            || !user.email.eq_ignore_ascii_case(&claims.email)
+        {
+            return Err(CoreError::InvalidToken);
+        }
+
+        Ok((claims, user))
+    }
+
+    /// Issues an action token for `user` and emails them the link to it.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) async fn send_action_token(
+        &self,
+        realm: &Realm,
+        user: &User,
+        typ: ActionTokenType,
+        actions: Vec<RequiredAction>,
+        lifespan: u32,
+        webapp_url: &str,
+        base_url: &str,
+    ) -> Result<(), CoreError> {
+        if user.email.is_empty() {
+            return Err(CoreError::Invalid);
+        }
+
+        let claims = ActionTokenClaims::new(
+            typ,
+            user.id,
+            user.email.clone(),
+            format!("{base_url}/realms/{}", realm.name),
+            lifespan,
+            actions,
+        );
+
+        let token = self.sign_action_token(&claims, realm).await?;
+        let actions = claims.completed_actions();
+
+        let template = match typ {
+            ActionTokenType::VerifyEmail => EmailTemplate::VerifyEmail,
+            ActionTokenType::ResetCredentials => EmailTemplate::ResetPassword,
+            ActionTokenType::ExecuteActions => EmailTemplate::ExecuteActions,
+        };
+
+        let data = json!({
+            "username": user.username,
+            "link": action_token_link(webapp_url, &realm.name, &token),
+            "expires_in": lifespan.div_ceil(60),
+            "verify_email": actions.contains(&RequiredAction::VerifyEmail),
+            "update_password": actions.contains(&RequiredAction::UpdatePassword),
+            "configure_otp": actions.contains(&RequiredAction::ConfigureOtp),
+        });
+
+        self.send_user_email(realm, user, template, data).await
+    }
+}
+
+impl ActionTokenService for FerriskeyService {
+    async fn forgot_password(&self, input: ForgotPasswordInput) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let user = match self
+            .user_repository
+            .get_by_username(input.username.clone(), realm.id)
+            .await
+        {
+            Ok(user) => Some(user),
+            Err(_) => self
+                .user_repository
+                .get_by_email(input.username, realm.id)
+                .await
+                .ok(),
+        };
+
+        let Some(user) = user.filter(|user| user.enabled && user.client_id.is_none()) else {
+            return Ok(());
+        };
+
+        // Sent in the background so that the response time does not tell
+        // whether the account exists
+        let service = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = service
+                .send_action_token(
+                    &realm,
+                    &user,
+                    ActionTokenType::ResetCredentials,
+                    Vec::new(),
+                    USER_ACTION_TOKEN_LIFESPAN,
+                    &input.webapp_url,
+                    &input.base_url,
+                )
+                .await
+            {
+                error!("failed to send reset password email: {:?}", e);
+            }
+        });
+
+        Ok(())
+    }
+
+    async fn send_verify_email(
+        &self,
+        identity: Identity,
+        input: SendVerifyEmailInput,
+    ) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let user = match identity {
+            Identity::User(user) => user,
+            _ => return Err(CoreError::Forbidden("is not user".to_string())),
+        };
+
+        let user = self
+            .user_repository
+            .get_by_id(user.id)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
+
+        if user.realm_id != realm.id {
+            return Err(CoreError::InvalidUser);
+        }
+
+        self.send_action_token(
+            &realm,
+            &user,
+            ActionTokenType::VerifyEmail,
+            Vec::new(),
+            USER_ACTION_TOKEN_LIFESPAN,
+            &input.webapp_url,
+            &input.base_url,
+        )
+        .await
+    }
+
+    async fn send_execute_actions_email(
+        &self,
+        identity: Identity,
+        input: ExecuteActionsEmailInput,
+    ) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy.can_update_user(identity, realm.clone()).await,
+            "insufficient permissions",
+        )?;
+
+        let user = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
+
+        if user.realm_id != realm.id {
+            return Err(CoreError::InvalidUser);
+        }
+
+        let mut actions = input.actions;
+        actions.sort();
+        actions.dedup();
+
+        if actions.is_empty() {
+            return Err(CoreError::Invalid);
+        }
+
+        self.send_action_token(
+            &realm,
+            &user,
+            ActionTokenType::ExecuteActions,
+            actions,
+            input
+                .lifespan
+                .unwrap_or(DEFAULT_ADMIN_ACTION_TOKEN_LIFESPAN),
+            &input.webapp_url,
+            &input.base_url,
+        )
+        .await
+    }
+
+    async fn get_action_token(
+        &self,
+        input: GetActionTokenInput,
+    ) -> Result<ActionTokenDetails, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let (claims, user) = self.verify_action_token(&realm, &input.token).await?;
+
+        Ok(ActionTokenDetails {
+            typ: claims.typ,
+            username: user.username,
+            email: user.email,
+            actions: claims.completed_actions(),
+            requires_password: claims.requires_password(),
+            expires_at: claims.expires_at(),
+        })
+    }
+
+    async fn execute_action_token(
+        &self,
+        input: ExecuteActionTokenInput,
+    ) -> Result<ActionTokenResult, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let (claims, user) = self.verify_action_token(&realm, &input.token).await?;
+        let actions = claims.completed_actions();
+
+        let password = match claims.requires_password() {
+            true => Some(
+                input
+                    .password
+                    .as_deref()
+                    .filter(|password| !password.is_empty())
+                    .ok_or(CoreError::Invalid)?,
+            ),
+            false => None,
+        };
+
+        // A password rejected by the policy leaves the token usable
+        if let Some(password) = password {
+            self.check_password(&user, password).await?;
+        }
+
+        // Claimed before any change is made, so that concurrent requests
+        // cannot both consume the token
+        if !self
+            .used_action_token_repository
+            .mark_used(claims.jti, realm.id, claims.expires_at())
+            .await?
+        {
+            return Err(CoreError::InvalidToken);
+        }
+
+        if let Some(password) = password {
+            self.store_password(&user, password, false).await?;
+
+            // Sessions opened with the previous password end with it
+            self.refresh_token_repository
+                .revoke_by_user_id(user.id)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+        }
+
+        if actions.contains(&RequiredAction::VerifyEmail) && !user.email_verified {
+            self.user_repository
+                .update_user(
+                    user.id,
+                    UpdateUserRequest {
+                        firstname: user.firstname.clone(),
+                        lastname: user.lastname.clone(),
+                        email: user.email.clone(),
+                        email_verified: true,
+                        enabled: user.enabled,
+                        required_actions: None,
+                        attributes: None,
+                    },
+                )
+                .await?;
+        }
+
+        let mut pending_actions = Vec::new();
+
+        for action in actions {
+            let is_required = user.required_actions.contains(&action);
+
+            match action {
+                RequiredAction::ConfigureOtp => {
+                    if !is_required {
+                        self.user_required_action_repository
+                            .add_required_action(user.id, action.clone())
+                            .await
+                            .map_err(|_| CoreError::InternalServerError)?;
+                    }
+
+                    pending_actions.push(action);
+                }
+                _ if is_required => {
+                    self.user_required_action_repository
+                        .remove_required_action(user.id, action)
+                        .await
+                        .map_err(|_| CoreError::InternalServerError)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(ActionTokenResult {
+            typ: claims.typ,
+            user_id: user.id,
+            pending_actions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        application::common::FerriskeyService,
+        domain::{
+            action_token::{
+                entities::{ActionTokenClaims, ActionTokenType, ExecuteActionTokenInput},
+                ports::ActionTokenService,
+            },
+            common::{
+                DatabaseConfig, EmailConfig, FerriskeyConfig, entities::app_errors::CoreError,
+            },
+            credential::entities::PasswordPolicy,
+            realm::{
+                entities::Realm, ports::RealmRepository, value_objects::UpdateRealmSettingRequest,
+            },
+            user::{
+                entities::{User, UserAttributes},
+                ports::UserRepository,
+                value_objects::CreateUserRequest,
+            },
+        },
+    };
+
+    async fn setup_test_service() -> FerriskeyService {
+        let database_host = std::env::var("DATABASE_HOST").expect("DATABASE_HOST no set");
+        let port = std::env::var("DATABASE_PORT").expect("DATABASE_PORT no set");
+        let port: u16 = port.parse().expect("DATABASE_PORT not a number");
+
+        let username = std::env::var("DATABASE_USERNAME").expect("DATABASE_USERNAME no set");
+        let password = std::env::var("DATABASE_PASSWORD").expect("DATABASE_PASSWORD no set");
+        let name = std::env::var("DATABASE_NAME").expect("DATABASE_NAME no set");
+
+        let config = FerriskeyConfig {
+            database: DatabaseConfig {
+                host: database_host,
+                port,
+                username,
+                password,
+                name,
+            },
+            email: EmailConfig::default(),
+        };
+
+        FerriskeyService::new(config)
+            .await
+            .expect("Failed to create FerriskeyService")
+    }
+
+    async fn create_test_user(service: &FerriskeyService) -> (Realm, User) {
+        let realm = service
+            .realm_repository
+            .create_realm(format!("test-realm-{}", Uuid::new_v4()))
+            .await
+            .expect("Failed to create test realm");
+        let user = service
+            .user_repository
+            .create_user(CreateUserRequest {
+                username: format!("testuser-{}", Uuid::new_v4()),
+                email: "test@example.com".to_string(),
+                email_verified: false,
+                enabled: true,
+                firstname: "Test".to_string(),
+                lastname: "User".to_string(),
+                realm_id: realm.id,
+                client_id: None,
+                attributes: UserAttributes::new(),
+            })
+            .await
+            .expect("Failed to create test user");
+
+        (realm, user)
+    }
+
+    async fn issue_test_token(
+        service: &FerriskeyService,
+        realm: &Realm,
+        user: &User,
+        typ: ActionTokenType,
+    ) -> String {
+        let claims = ActionTokenClaims::new(
+            typ,
+            user.id,
+            user.email.clone(),
+            format!("http://localhost/realms/{}", realm.name),
+            300,
+            Vec::new(),
+        );
+
+        service
+            .sign_action_token(&claims, realm)
+            .await
+            .expect("Failed to sign action token")
+    }
+
+    #[tokio::test]
+    async fn test_action_token_is_consumed_once() {
+        let service = setup_test_service().await;
+        let (realm, user) = create_test_user(&service).await;
+        let token = issue_test_token(&service, &realm, &user, ActionTokenType::VerifyEmail).await;
+
+        let execute = || {
+            service.execute_action_token(ExecuteActionTokenInput {
+                realm_name: realm.name.clone(),
+                token: token.clone(),
+                password: None,
+            })
+        };
+
+        let (first, second) = tokio::join!(execute(), execute());
+
+        assert_eq!(
+            [first.is_ok(), second.is_ok()]
+                .iter()
+                .filter(|ok| **ok)
+                .count(),
+            1
+        );
+        assert!(matches!(execute().await, Err(CoreError::InvalidToken)));
+
+        let user = service
+            .user_repository
+            .get_by_id(user.id)
+            .await
+            .expect("Failed to get test user");
+
+        assert!(user.email_verified);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_password_leaves_the_action_token_usable() {
+        let service = setup_test_service().await;
+        let (realm, user) = create_test_user(&service).await;
+
+        service
+            .realm_repository
+            .create_realm_settings(realm.id, "RS256".to_string())
+            .await
+            .expect("Failed to create realm settings");
+        service
+            .realm_repository
+            .update_realm_setting(
+                realm.id,
+                UpdateRealmSettingRequest {
+                    password_policy: Some(PasswordPolicy {
+                        min_length: Some(12),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("Failed to update realm settings");
+
+        let token =
+            issue_test_token(&service, &realm, &user, ActionTokenType::ResetCredentials).await;
+
+        let execute = |password: &str| {
+            service.execute_action_token(ExecuteActionTokenInput {
+                realm_name: realm.name.clone(),
+                token: token.clone(),
+                password: Some(password.to_string()),
+            })
+        };
+
+        assert!(matches!(
+            execute("short").await,
+            Err(CoreError::PasswordPolicyViolation(_))
+        ));
+        assert!(execute("a long enough password").await.is_ok());
+        assert!(matches!(
+            execute("a long enough password").await,
+            Err(CoreError::InvalidToken)
+        ));
+    }
+}
@@ -1,30 +1,169 @@
+use tracing::error;
+use url::Url;
 use uuid::Uuid;
 
 use crate::{
     application::common::FerriskeyService,
     domain::{
+        action_token::entities::{ActionTokenType, USER_ACTION_TOKEN_LIFESPAN},
         authentication::{
             entities::{
-                AuthInput, AuthOutput, AuthSession, AuthSessionParams, AuthenticateInput,
-                AuthenticateOutput, AuthenticationMethod, AuthorizeRequestInput,
-                AuthorizeRequestOutput, CredentialsAuthParams,
+                AuthEvent, AuthInput, AuthOutput, AuthSession, AuthSessionParams,
+                AuthenticateInput, AuthenticateOutput, AuthenticationError, AuthenticationMethod,
+                AuthorizeRequestInput, AuthorizeRequestOutput, CodeChallengeMethod, ConsentInput,
+                ConsentRequest, CredentialsAuthParams, GetConsentInput, GetUserInfoInput,
+                IntrospectTokenInput, LogoutInput, LogoutOutput, RegisterInput, RevokeTokenInput,
+                TokenIntrospection, UserInfo, is_valid_pkce_value,
             },
             ports::{AuthService, AuthSessionRepository, AuthenticatePort, GrantTypeService},
+            services::auth_events::notify_auth_event,
             value_objects::{GrantTypeParams, Identity},
         },
-        client::ports::{ClientRepository, RedirectUriRepository},
-        common::entities::app_errors::CoreError,
+        client::{
+            entities::{Client, protocol_mapper::TokenTarget, redirect_uri::RedirectUri},
+            ports::{ClientRepository, RedirectUriRepository},
+        },
+        common::{entities::app_errors::CoreError, generate_random_string},
         jwt::{
-            entities::{ClaimsTyp, JwkKey},
-            ports::KeyStoreRepository,
+            entities::{ClaimsTyp, JwkKey, KeyStatus, SigningAlgorithm},
+            ports::{KeyStoreRepository, RefreshTokenRepository},
         },
         realm::ports::RealmRepository,
-        user::ports::UserRepository,
+        user::{
+            entities::{AttributeEditor, RequiredAction, UserAttributes},
+            ports::{UserRepository, UserRequiredActionRepository},
+            value_objects::CreateUserRequest,
+        },
+        webhook::{
+            entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
+            ports::{WebhookNotifierRepository, WebhookRepository},
+        },
     },
 };
 
 pub mod services;
 
+fn is_redirect_uri_allowed(allowed: &[RedirectUri], redirect_uri: &str) -> bool {
+    allowed.iter().any(|uri| {
+        if uri.value == redirect_uri {
+            return true;
+        }
+
+        if let Ok(regex) = regex::Regex::new(&uri.value) {
+            return regex.is_match(redirect_uri);
+        }
+
+        false
+    })
+}
+
+/// Appends `params` to the query of `redirect_uri`, percent-encoded so that
+/// caller supplied values cannot inject parameters of their own.
+fn redirect_with_params(redirect_uri: &str, params: &[(&str, &str)]) -> Result<String, CoreError> {
+    let mut url = Url::parse(redirect_uri).map_err(|_| CoreError::InvalidRedirectUri)?;
+    url.query_pairs_mut().extend_pairs(params);
+
+    Ok(url.into())
+}
+
+impl FerriskeyService {
+    /// Authenticates a client calling a token endpoint.
+    ///
+    /// Public clients are identified by their client_id only, confidential
+    /// clients must also present their secret.
+    async fn authenticate_client(
+        &self,
+        realm_id: Uuid,
+        client_id: String,
+        client_secret: Option<String>,
+    ) -> Result<Client, CoreError> {
+        let client = self
+            .client_repository
+            .get_by_client_id(client_id, realm_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+
+        if !client.enabled {
+            return Err(CoreError::InvalidClient);
+        }
+
+        if !client.public_client && (client_secret.is_none() || client.secret != client_secret) {
+            return Err(CoreError::InvalidClientSecret);
+        }
+
+        Ok(client)
+    }
+
+    /// Loads a session of the realm whose user authenticated but has not
+    /// been issued a code yet, pending consent.
+    async fn consent_session(
+        &self,
+        realm_name: String,
+        session_code: Uuid,
+    ) -> Result<(AuthSession, Uuid), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let auth_session = self
+            .auth_session_repository
+            .get_by_session_code(session_code)
+            .await
+            .map_err(|_| CoreError::SessionNotFound)?;
+
+        match auth_session.user_id {
+            Some(user_id)
+                if auth_session.realm_id == realm.id
+                    && auth_session.authenticated
+                    && auth_session.code.is_none() =>
+            {
+                Ok((auth_session, user_id))
+            }
+            _ => Err(CoreError::SessionNotFound),
+        }
+    }
+
+    /// Completes the logout `event` with the user of the authentication
+    /// session of the browser. Returns `None` when the session is missing,
+    /// expired or not authenticated, leaving nobody to log out.
+    async fn session_logout_event(
+        &self,
+        realm_id: Uuid,
+        session_code: Uuid,
+        event: AuthEvent,
+    ) -> Result<Option<AuthEvent>, CoreError> {
+        let Ok(auth_session) = self
+            .auth_session_repository
+            .get_by_session_code(session_code)
+            .await
+        else {
+            return Ok(None);
+        };
+
+        let user_id = match auth_session.user_id {
+            Some(user_id) if auth_session.realm_id == realm_id && auth_session.authenticated => {
+                user_id
+            }
+            _ => return Ok(None),
+        };
+
+        let user = self
+            .user_repository
+            .get_by_id(user_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+        let client = self
+            .client_repository
+            .get_by_id(auth_session.client_id)
+            .await?;
+
+        Ok(Some(event.with_user(&user).with_client(client.client_id)))
+    }
+}
+
 impl AuthService for FerriskeyService {
     async fn auth(&self, input: AuthInput) -> Result<AuthOutput, CoreError> {
         let realm = self
@@ -48,17 +187,7 @@ impl AuthService for FerriskeyService {
             .await
             .map_err(|_| CoreError::RedirectUriNotFound)?;
 
-        if !client_redirect_uris.iter().any(|uri| {
-            if uri.value == redirect_uri {
-                return true;
-            }
-
-            if let Ok(regex) = regex::Regex::new(&uri.value) {
-                return regex.is_match(&redirect_uri);
-            }
-
-            false
-        }) {
+        if !is_redirect_uri_allowed(&client_redirect_uris, &redirect_uri) {
             return Err(CoreError::InvalidClient);
         }
 
@@ -66,6 +195,27 @@ impl AuthService for FerriskeyService {
             return Err(CoreError::InvalidClient);
         }
 
+        let code_challenge_method = match (&input.code_challenge, input.code_challenge_method) {
+            (Some(challenge), method) => {
+                if !is_valid_pkce_value(challenge) {
+                    return Err(CoreError::InvalidRequest);
+                }
+
+                match method {
+                    Some(method) => Some(
+                        CodeChallengeMethod::try_from(method)
+                            .map_err(|_| CoreError::InvalidRequest)?,
+                    ),
+                    None => Some(CodeChallengeMethod::Plain),
+                }
+            }
+            (None, Some(_)) => return Err(CoreError::InvalidRequest),
+            (None, None) if client.pkce_required => return Err(CoreError::InvalidRequest),
+            (None, None) => None,
+        };
+
+        let lifespans = self.realm_setting(realm.id).await?.lifespans_for(&client);
+
         let params = AuthSessionParams {
             realm_id: realm.id,
             client_id: client.id,
@@ -73,10 +223,13 @@ impl AuthService for FerriskeyService {
             response_type: input.response_type,
             scope: input.scope.unwrap_or_default(),
             state: input.state.clone(),
-            nonce: None,
+            nonce: input.nonce,
             user_id: None,
             code: None,
             authenticated: false,
+            code_challenge: input.code_challenge,
+            code_challenge_method,
+            lifespan: lifespans.session,
         };
         let session = self
             .auth_session_repository
@@ -91,7 +244,11 @@ impl AuthService for FerriskeyService {
             input.state.unwrap_or_default()
         );
 
-        Ok(AuthOutput { login_url, session })
+        Ok(AuthOutput {
+            login_url,
+            session,
+            session_lifespan: lifespans.session,
+        })
     }
 
     async fn get_certs(&self, realm_name: String) -> Result<Vec<JwkKey>, CoreError> {
@@ -102,17 +259,49 @@ impl AuthService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
-        let jwk_keypair = self
+        let realm_setting = self.realm_setting(realm.id).await?;
+
+        // Make sure the realm has an active key before publishing its keys.
+        self.keystore_repository
+            .get_or_generate_key(realm.id, realm_setting.signing_algorithm())
+            .await
+            .map_err(|_| CoreError::RealmKeyNotFound)?;
+
+        let keys = self
             .keystore_repository
-            .get_or_generate_key(realm.id)
+            .list_keys(realm.id)
             .await
             .map_err(|_| CoreError::RealmKeyNotFound)?;
 
-        let jwk_key = jwk_keypair
-            .to_jwk_key()
-            .map_err(|e| CoreError::InvalidKey(e.to_string()))?;
+        keys.iter()
+            .filter(|key| key.status != KeyStatus::Retired)
+            .map(|key| {
+                key.to_jwk_key()
+                    .map_err(|e| CoreError::InvalidKey(e.to_string()))
+            })
+            .collect()
+    }
+
+    async fn get_signing_algorithm(
+        &self,
+        realm_name: String,
+    ) -> Result<SigningAlgorithm, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_setting = self.realm_setting(realm.id).await?;
 
-        Ok(vec![jwk_key])
+        let key = self
+            .keystore_repository
+            .get_or_generate_key(realm.id, realm_setting.signing_algorithm())
+            .await
+            .map_err(|_| CoreError::RealmKeyNotFound)?;
+
+        Ok(key.algorithm)
     }
 
     async fn exchange_token(
@@ -142,12 +331,21 @@ impl AuthService for FerriskeyService {
             password: input.password,
             refresh_token: input.refresh_token,
             redirect_uri: None,
+            scope: input.scope,
+            code_verifier: input.code_verifier,
+            ip_address: input.ip_address,
+            user_agent: input.user_agent,
         };
 
         self.grant_type_strategies
             .authenticate_with_grant_type(input.grant_type, params)
             .await
-            .map_err(|_| CoreError::InternalServerError)
+            .map_err(|e| match e {
+                AuthenticationError::AccountLocked => CoreError::AccountLocked,
+                AuthenticationError::EmailNotVerified => CoreError::EmailNotVerified,
+                AuthenticationError::InvalidRefreshToken => CoreError::InvalidRefreshToken,
+                _ => CoreError::InternalServerError,
+            })
     }
 
     async fn authorize_request(
@@ -228,6 +426,8 @@ impl AuthService for FerriskeyService {
                     base_url: input.base_url,
                     username,
                     password,
+                    ip_address: input.ip_address,
+                    user_agent: input.user_agent,
                 };
 
                 self.authenticate_factory
@@ -236,4 +436,586 @@ impl AuthService for FerriskeyService {
             }
         }
     }
+
+    async fn get_userinfo(&self, input: GetUserInfoInput) -> Result<UserInfo, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let claims = self
+            .grant_type_strategies
+            .verify_token(input.token, realm.id)
+            .await?;
+
+        if claims.typ != ClaimsTyp::Bearer {
+            return Err(CoreError::InvalidToken);
+        }
+
+        let user = self
+            .user_repository
+            .get_by_id(claims.sub)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
+
+        if user.realm_id != realm.id || !user.enabled {
+            return Err(CoreError::InvalidToken);
+        }
+
+        let profile = claims.has_scope("profile");
+        let email = claims.has_scope("email");
+
+        let mapped = self
+            .grant_type_strategies
+            .mapped_claims(
+                realm.id,
+                &claims.azp,
+                claims.scope.as_deref(),
+                &user,
+                TokenTarget::Userinfo,
+            )
+            .await?;
+
+        Ok(UserInfo {
+            sub: user.id,
+            preferred_username: profile.then(|| user.username.clone()),
+            email: email.then(|| user.email.clone()),
+            email_verified: email.then_some(user.email_verified),
+            given_name: profile.then(|| user.firstname.clone()),
+            family_name: profile.then(|| user.lastname.clone()),
+            claims: mapped.claims,
+        })
+    }
+
+    async fn introspect_token(
+        &self,
+        input: IntrospectTokenInput,
+    ) -> Result<TokenIntrospection, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let client = self
+            .authenticate_client(realm.id, input.client_id, input.client_secret)
+            .await?;
+
+        if client.public_client {
+            return Err(CoreError::InvalidClient);
+        }
+
+        let claims = match self
+            .grant_type_strategies
+            .verify_token(input.token.clone(), realm.id)
+            .await
+        {
+            Ok(claims) => claims,
+            Err(_) => return Ok(TokenIntrospection::inactive()),
+        };
+
+        if claims.typ == ClaimsTyp::Refresh
+            && self
+                .grant_type_strategies
+                .verify_refresh_token(input.token, realm.id)
+                .await
+                .is_err()
+        {
+            return Ok(TokenIntrospection::inactive());
+        }
+
+        let user = match self.user_repository.get_by_id(claims.sub).await {
+            Ok(user) if user.enabled && user.realm_id == realm.id => user,
+            _ => return Ok(TokenIntrospection::inactive()),
+        };
+
+        let token_type = match claims.typ {
+            ClaimsTyp::Bearer => "Bearer",
+            ClaimsTyp::Refresh => "Refresh",
+        };
+
+        let mapped = self
+            .grant_type_strategies
+            .mapped_claims(
+                realm.id,
+                &claims.azp,
+                claims.scope.as_deref(),
+                &user,
+                TokenTarget::Introspection,
+            )
+            .await?;
+
+        let mut aud = claims.aud;
+        for audience in mapped.audiences {
+            if !aud.contains(&audience) {
+                aud.push(audience);
+            }
+        }
+
+        Ok(TokenIntrospection {
+            active: true,
+            scope: claims.scope,
+            client_id: Some(claims.azp),
+            username: Some(user.username),
+            exp: claims.exp,
+            iat: Some(claims.iat),
+            sub: Some(claims.sub),
+            aud: Some(aud),
+            iss: Some(claims.iss),
+            token_type: Some(token_type.to_string()),
+            claims: mapped.claims,
+        })
+    }
+
+    async fn revoke_token(&self, input: RevokeTokenInput) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let client = self
+            .authenticate_client(realm.id, input.client_id, input.client_secret)
+            .await?;
+
+        // RFC 7009: invalid or already expired tokens are not an error
+        let claims = match self
+            .grant_type_strategies
+            .verify_token(input.token, realm.id)
+            .await
+        {
+            Ok(claims) => claims,
+            Err(_) => return Ok(()),
+        };
+
+        if claims.azp != client.client_id {
+            return Err(CoreError::InvalidClient);
+        }
+
+        self.refresh_token_repository
+            .revoke_by_user_and_client(claims.sub, claims.azp)
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
+
+    async fn logout(&self, input: LogoutInput) -> Result<LogoutOutput, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let issuer = format!("{}/realms/{}", input.base_url, realm.name);
+        let claims = match input.id_token_hint {
+            Some(hint) => Some(
+                self.grant_type_strategies
+                    .verify_id_token(hint, realm.id, &issuer)
+                    .await
+                    .map_err(|_| CoreError::InvalidToken)?,
+            ),
+            None => None,
+        };
+
+        let client_id = match (input.client_id, &claims) {
+            (Some(client_id), Some(claims)) if client_id != claims.azp => {
+                return Err(CoreError::InvalidRequest);
+            }
+            (Some(client_id), _) => Some(client_id),
+            (None, Some(claims)) => Some(claims.azp.clone()),
+            (None, None) => None,
+        };
+
+        let redirect_url = match input.post_logout_redirect_uri {
+            Some(redirect_uri) => {
+                let client_id = client_id.ok_or(CoreError::InvalidRequest)?;
+                let client = self
+                    .client_repository
+                    .get_by_client_id(client_id, realm.id)
+                    .await
+                    .map_err(|_| CoreError::InvalidClient)?;
+
+                let client_redirect_uris = self
+                    .redirect_uri_repository
+                    .get_enabled_by_client_id(client.id)
+                    .await
+                    .map_err(|_| CoreError::RedirectUriNotFound)?;
+
+                if !is_redirect_uri_allowed(&client_redirect_uris, &redirect_uri) {
+                    return Err(CoreError::InvalidRedirectUri);
+                }
+
+                match input.state {
+                    Some(state) => Some(redirect_with_params(
+                        &redirect_uri,
+                        &[("state", state.as_str())],
+                    )?),
+                    None => Some(redirect_uri),
+                }
+            }
+            None => None,
+        };
+
+        let event = AuthEvent::new(input.ip_address, input.user_agent);
+        let event = match (claims, input.session_code) {
+            (Some(claims), _) => Some(
+                AuthEvent {
+                    user_id: Some(claims.sub),
+                    username: claims.preferred_username,
+                    ..event
+                }
+                .with_client(claims.azp),
+            ),
+            (None, Some(session_code)) => {
+                self.session_logout_event(realm.id, session_code, event)
+                    .await?
+            }
+            (None, None) => None,
+        };
+
+        if let Some(event) = event
+            && let Some(user_id) = event.user_id
+        {
+            self.refresh_token_repository
+                .revoke_by_user_id(user_id)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+
+            self.auth_session_repository
+                .delete_by_user_id(user_id)
+                .await
+                .map_err(|_| CoreError::SessionDeleteError)?;
+
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                realm.id,
+                WebhookTrigger::AuthLogout,
+                event,
+            )
+            .await;
+        }
+
+        Ok(LogoutOutput { redirect_url })
+    }
+
+    async fn get_consent(&self, input: GetConsentInput) -> Result<ConsentRequest, CoreError> {
+        let (auth_session, user_id) = self
+            .consent_session(input.realm_name, input.session_code)
+            .await?;
+
+        let client = self
+            .client_repository
+            .get_by_id(auth_session.client_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+
+        let scopes = self
+            .authenticate_factory
+            .missing_consent(&auth_session, user_id)
+            .await?;
+
+        Ok(ConsentRequest {
+            client_id: client.client_id,
+            client_name: client.name,
+            scopes,
+        })
+    }
+
+    async fn consent(&self, input: ConsentInput) -> Result<AuthenticateOutput, CoreError> {
+        let (auth_session, user_id) = self
+            .consent_session(input.realm_name, input.session_code)
+            .await?;
+
+        if !input.approve {
+            let mut params = vec![("error", "access_denied")];
+            if let Some(state) = &auth_session.state {
+                params.push(("state", state.as_str()));
+            }
+
+            return Ok(AuthenticateOutput::denied(
+                user_id,
+                redirect_with_params(&auth_session.redirect_uri, &params)?,
+            ));
+        }
+
+        let missing = self
+            .authenticate_factory
+            .missing_consent(&auth_session, user_id)
+            .await?;
+
+        self.client_scope_resolver
+            .grant_consent(user_id, auth_session.client_id, &missing)
+            .await?;
+
+        let authorization_code = generate_random_string();
+
+        self.auth_session_repository
+            .update_code_and_user_id(
+                input.session_code,
+                authorization_code.clone(),
+                user_id,
+                auth_session.amr.clone(),
+            )
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        let redirect_url = self
+            .authenticate_factory
+            .build_redirect_url(&auth_session, &authorization_code)?;
+
+        Ok(AuthenticateOutput::complete_with_redirect(
+            user_id,
+            authorization_code,
+            redirect_url,
+        ))
+    }
+
+    async fn register(&self, input: RegisterInput) -> Result<AuthenticateOutput, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let auth_session = self
+            .auth_session_repository
+            .get_by_session_code(input.session_code)
+            .await
+            .map_err(|_| CoreError::SessionNotFound)?;
+
+        if auth_session.realm_id != realm.id || auth_session.user_id.is_some() {
+            return Err(CoreError::SessionNotFound);
+        }
+
+        let realm_setting = self.realm_setting(realm.id).await?;
+
+        if !realm_setting.registration_allowed {
+            return Err(CoreError::Forbidden("registration is disabled".to_string()));
+        }
+
+        let username_taken = self
+            .user_repository
+            .get_by_username(input.username.clone(), realm.id)
+            .await
+            .is_ok();
+        let email_taken = self
+            .user_repository
+            .get_by_email(input.email.clone(), realm.id)
+            .await
+            .is_ok();
+
+        if username_taken || email_taken {
+            return Err(CoreError::AlreadyExists);
+        }
+
+        let violations =
+            realm_setting
+                .password_policy
+                .validate(&input.password, &input.username, &input.email);
+
+        if !violations.is_empty() {
+            return Err(CoreError::PasswordPolicyViolation(violations));
+        }
+
+        self.ensure_user_profile(
+            realm.id,
+            &input.attributes,
+            &UserAttributes::new(),
+            AttributeEditor::User,
+        )
+        .await?;
+
+        let mut user = self
+            .user_repository
+            .create_user(CreateUserRequest {
+                client_id: None,
+                realm_id: realm.id,
+                username: input.username,
+                firstname: input.firstname,
+                lastname: input.lastname,
+                email: input.email,
+                email_verified: false,
+                enabled: true,
+                attributes: input.attributes,
+            })
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        self.assign_default_roles(&realm, user.id).await?;
+        self.set_password(&user, &input.password, false).await?;
+
+        if realm_setting.verify_email {
+            self.user_required_action_repository
+                .add_required_action(user.id, RequiredAction::VerifyEmail)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+
+            user.required_actions.push(RequiredAction::VerifyEmail);
+
+            // The user can ask for another link if this one does not arrive
+            if let Err(e) = self
+                .send_action_token(
+                    &realm,
+                    &user,
+                    ActionTokenType::VerifyEmail,
+                    Vec::new(),
+                    USER_ACTION_TOKEN_LIFESPAN,
+                    &input.webapp_url,
+                    &input.base_url,
+                )
+                .await
+            {
+                error!("failed to send verification email: {:?}", e);
+            }
+        }
+
+        let webhooks = self
+            .webhook_repository
+            .fetch_webhooks_by_subscriber(realm.id, WebhookTrigger::UserRegistered)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        self.webhook_notifier_repository
+            .notify(
+                webhooks,
+                WebhookPayload::new(WebhookTrigger::UserRegistered, user.id, Some(user.clone())),
+            )
+            .await?;
+
+        let client = self
+            .client_repository
+            .get_by_id(auth_session.client_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+
+        self.authenticate_factory
+            .complete_registration(
+                &user,
+                &realm,
+                client.client_id,
+                input.session_code,
+                auth_session,
+                input.base_url,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        client::value_objects::CreateClientRequest,
+        common::{DatabaseConfig, EmailConfig, FerriskeyConfig},
+    };
+
+    #[test]
+    fn test_redirect_params_are_percent_encoded() {
+        assert_eq!(
+            redirect_with_params(
+                "https://app.example.com/callback?tab=1",
+                &[("error", "access_denied"), ("state", "a&code=evil #x")],
+            )
+            .unwrap(),
+            "https://app.example.com/callback?tab=1&error=access_denied&state=a%26code%3Devil+%23x"
+        );
+        assert!(redirect_with_params("not a url", &[]).is_err());
+    }
+
+    async fn setup_test_service() -> FerriskeyService {
+        let database_host = std::env::var("DATABASE_HOST").expect("DATABASE_HOST no set");
+        let port = std::env::var("DATABASE_PORT").expect("DATABASE_PORT no set");
+        let port: u16 = port.parse().expect("DATABASE_PORT not a number");
+
+        let username = std::env::var("DATABASE_USERNAME").expect("DATABASE_USERNAME no set");
+        let password = std::env::var("DATABASE_PASSWORD").expect("DATABASE_PASSWORD no set");
+        let name = std::env::var("DATABASE_NAME").expect("DATABASE_NAME no set");
+
+        let config = FerriskeyConfig {
+            database: DatabaseConfig {
+                host: database_host,
+                port,
+                username,
+                password,
+                name,
+            },
+            email: EmailConfig::default(),
+        };
+
+        FerriskeyService::new(config)
+            .await
+            .expect("Failed to create FerriskeyService")
+    }
+
+    #[tokio::test]
+    async fn test_expired_auth_sessions_are_not_found() {
+        let service = setup_test_service().await;
+        let realm = service
+            .realm_repository
+            .create_realm(format!("test-realm-{}", Uuid::new_v4()))
+            .await
+            .expect("Failed to create test realm");
+        let client = service
+            .client_repository
+            .create_client(CreateClientRequest {
+                realm_id: realm.id,
+                name: "test".to_string(),
+                client_id: format!("test-client-{}", Uuid::new_v4()),
+                secret: None,
+                enabled: true,
+                protocol: "openid-connect".to_string(),
+                public_client: true,
+                service_account_enabled: false,
+                direct_access_grants_enabled: false,
+                client_type: "public".to_string(),
+            })
+            .await
+            .expect("Failed to create test client");
+
+        let session = |lifespan: i64| {
+            AuthSession::new(AuthSessionParams {
+                realm_id: realm.id,
+                client_id: client.id,
+                redirect_uri: "http://localhost/callback".to_string(),
+                response_type: "code".to_string(),
+                scope: "openid".to_string(),
+                state: None,
+                nonce: None,
+                user_id: None,
+                code: Some(generate_random_string()),
+                authenticated: false,
+                code_challenge: None,
+                code_challenge_method: None,
+                lifespan,
+            })
+        };
+
+        for (session, expired) in [(session(600), false), (session(-1), true)] {
+            service
+                .auth_session_repository
+                .create(&session)
+                .await
+                .expect("Failed to create auth session");
+
+            let by_session_code = service
+                .auth_session_repository
+                .get_by_session_code(session.id)
+                .await;
+            let by_code = service
+                .auth_session_repository
+                .get_by_code(session.code.clone().unwrap_or_default())
+                .await
+                .expect("Failed to get auth session");
+
+            assert_eq!(by_session_code.is_err(), expired);
+            assert_eq!(by_code.is_none(), expired);
+        }
+    }
 }
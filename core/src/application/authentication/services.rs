@@ -7,12 +7,16 @@ use crate::{
     domain::{
         authentication::{
             entities::{
-                AuthSession, AuthenticateOutput, AuthenticationStepStatus, CredentialsAuthParams,
+                AuthEvent, AuthFailureReason, AuthSession, AuthenticateOutput,
+                AuthenticationStepStatus, CredentialsAuthParams,
             },
             ports::{AuthSessionRepository, AuthenticatePort},
+            services::auth_events::notify_auth_event,
             value_objects::AuthenticationResult,
         },
+        brute_force::services::BruteForceDetector,
         client::ports::ClientRepository,
+        client_scope::{entities::ClientScope, services::ClientScopeResolver},
         common::{entities::app_errors::CoreError, generate_random_string},
         credential::ports::CredentialRepository,
         crypto::ports::HasherRepository,
@@ -20,13 +24,29 @@ use crate::{
             entities::{ClaimsTyp, JwtClaim},
             ports::JwtService,
         },
-        realm::ports::RealmRepository,
-        user::{entities::RequiredAction, ports::UserRepository},
+        realm::{
+            entities::{Realm, RealmSetting},
+            ports::RealmRepository,
+        },
+        user::{
+            entities::{RequiredAction, User},
+            ports::{UserRepository, UserRequiredActionRepository},
+        },
+        webhook::entities::webhook_trigger::WebhookTrigger,
     },
     infrastructure::{
-        auth_session::AuthSessionRepoAny, client::repositories::ClientRepoAny,
-        credential::CredentialRepoAny, hasher::HasherRepoAny, realm::repositories::RealmRepoAny,
-        user::UserRepoAny,
+        auth_session::AuthSessionRepoAny,
+        client::repositories::ClientRepoAny,
+        credential::CredentialRepoAny,
+        event::repositories::EventRepoAny,
+        hasher::HasherRepoAny,
+        realm::repositories::RealmRepoAny,
+        user::{
+            UserRepoAny, repositories::user_required_action_repository::UserRequiredActionRepoAny,
+        },
+        webhook::repositories::{
+            webhook_notifier_repository::WebhookNotifierRepoAny, webhook_repository::WebhookRepoAny,
+        },
     },
 };
 
@@ -38,10 +58,17 @@ pub struct AuthenticateFactory {
     client_repository: ClientRepoAny,
     credential_repository: CredentialRepoAny,
     hasher_repository: HasherRepoAny,
+    user_required_action_repository: UserRequiredActionRepoAny,
+    webhook_repository: WebhookRepoAny,
+    webhook_notifier_repository: WebhookNotifierRepoAny,
+    event_repository: EventRepoAny,
     jwt_service: DefaultJwtService,
+    brute_force_detector: BruteForceDetector,
+    client_scope_resolver: ClientScopeResolver,
 }
 
 impl AuthenticateFactory {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         auth_session_repository: AuthSessionRepoAny,
         user_repository: UserRepoAny,
@@ -49,7 +76,13 @@ impl AuthenticateFactory {
         client_repository: ClientRepoAny,
         credential_repository: CredentialRepoAny,
         hasher_repository: HasherRepoAny,
+        user_required_action_repository: UserRequiredActionRepoAny,
+        webhook_repository: WebhookRepoAny,
+        webhook_notifier_repository: WebhookNotifierRepoAny,
+        event_repository: EventRepoAny,
         jwt_service: DefaultJwtService,
+        brute_force_detector: BruteForceDetector,
+        client_scope_resolver: ClientScopeResolver,
     ) -> Self {
         Self {
             auth_session_repository,
@@ -58,9 +91,104 @@ impl AuthenticateFactory {
             client_repository,
             credential_repository,
             hasher_repository,
+            user_required_action_repository,
+            webhook_repository,
+            webhook_notifier_repository,
+            event_repository,
             jwt_service,
+            brute_force_detector,
+            client_scope_resolver,
         }
     }
+
+    /// Scopes requested by the session the user still has to consent to.
+    pub async fn missing_consent(
+        &self,
+        auth_session: &AuthSession,
+        user_id: Uuid,
+    ) -> Result<Vec<ClientScope>, CoreError> {
+        let client = self
+            .client_repository
+            .get_by_id(auth_session.client_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+
+        let granted = self
+            .client_scope_resolver
+            .granted_scopes(client.id, Some(&auth_session.scope))
+            .await?;
+
+        self.client_scope_resolver
+            .missing_consent(&client, user_id, &granted)
+            .await
+    }
+
+    /// Continues the login of a user who has just registered from the
+    /// session.
+    pub async fn complete_registration(
+        &self,
+        user: &User,
+        realm: &Realm,
+        client_id: String,
+        session_code: Uuid,
+        auth_session: AuthSession,
+        base_url: String,
+    ) -> Result<AuthenticateOutput, CoreError> {
+        let token = match user.required_actions.is_empty() {
+            true => None,
+            false => Some(
+                self.jwt_service
+                    .generate_token(temporary_claim(user, realm, client_id, &base_url), realm.id)
+                    .await
+                    .map_err(|e| CoreError::TokenGenerationError(e.to_string()))?
+                    .token,
+            ),
+        };
+
+        let auth_result = AuthenticationResult {
+            code: None,
+            required_actions: user.required_actions.clone(),
+            user_id: user.id,
+            token,
+            credentials: vec!["password".to_string()],
+        };
+
+        self.determine_next_step(auth_result, session_code, auth_session)
+            .await
+    }
+
+    async fn require_action(
+        &self,
+        user: &mut User,
+        action: RequiredAction,
+    ) -> Result<(), CoreError> {
+        if user.required_actions.contains(&action) {
+            return Ok(());
+        }
+
+        self.user_required_action_repository
+            .add_required_action(user.id, action.clone())
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        user.required_actions.push(action);
+
+        Ok(())
+    }
+}
+
+/// Claims of the short-lived token handed to a user who still has to go
+/// through required actions or an OTP challenge.
+fn temporary_claim(user: &User, realm: &Realm, client_id: String, base_url: &str) -> JwtClaim {
+    JwtClaim::new(
+        user.id,
+        user.username.clone(),
+        format!("{}/realms/{}", base_url, realm.name),
+        vec![format!("{}-realm", realm.name), "account".to_string()],
+        ClaimsTyp::Bearer,
+        client_id,
+        Some(user.email.clone()),
+    )
 }
 
 impl AuthenticatePort for AuthenticateFactory {
@@ -91,8 +219,13 @@ impl AuthenticatePort for AuthenticateFactory {
             ));
         }
 
-        self.finalize_authentication(auth_result.user_id, session_code, auth_session)
-            .await
+        self.finalize_authentication(
+            auth_result.user_id,
+            session_code,
+            auth_session,
+            vec!["pwd".to_string()],
+        )
+        .await
     }
 
     async fn finalize_authentication(
@@ -100,11 +233,25 @@ impl AuthenticatePort for AuthenticateFactory {
         user_id: Uuid,
         session_code: Uuid,
         auth_session: AuthSession,
+        amr: Vec<String>,
     ) -> Result<AuthenticateOutput, CoreError> {
+        if !self
+            .missing_consent(&auth_session, user_id)
+            .await?
+            .is_empty()
+        {
+            self.auth_session_repository
+                .mark_authenticated(session_code, user_id, amr)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+
+            return Ok(AuthenticateOutput::requires_consent(user_id));
+        }
+
         let authorization_code = generate_random_string();
 
         self.auth_session_repository
-            .update_code_and_user_id(session_code, authorization_code.clone(), user_id)
+            .update_code_and_user_id(session_code, authorization_code.clone(), user_id, amr)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
@@ -154,7 +301,8 @@ impl AuthenticatePort for AuthenticateFactory {
             });
         }
 
-        self.finalize_authentication(claims.sub, session_code, auth_session)
+        // The user is already signed in, no credential was presented for this session
+        self.finalize_authentication(claims.sub, session_code, auth_session, Vec::new())
             .await
     }
 
@@ -163,7 +311,52 @@ impl AuthenticatePort for AuthenticateFactory {
         params: CredentialsAuthParams,
         auth_session: AuthSession,
     ) -> Result<AuthenticateOutput, CoreError> {
-        let auth_result = self
+        let realm = self
+            .realm_repository
+            .get_by_name(params.realm_name.clone())
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let user = self
+            .user_repository
+            .get_by_username(params.username.clone(), realm.id)
+            .await
+            .ok();
+        let user_id = user.as_ref().map(|user| user.id);
+
+        let event = AuthEvent::new(params.ip_address.clone(), params.user_agent.clone())
+            .with_client(params.client_id.clone());
+        let event = match &user {
+            Some(user) => event.with_user(user),
+            None => AuthEvent {
+                username: Some(params.username.clone()),
+                ..event
+            },
+        };
+
+        let ip_address = params.ip_address.as_deref();
+
+        let locked = self
+            .brute_force_detector
+            .ensure_not_locked(realm.id, user_id, ip_address)
+            .await;
+
+        if let Err(CoreError::AccountLocked) = locked {
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                realm.id,
+                WebhookTrigger::AuthLoginFailed,
+                event.clone().with_reason(AuthFailureReason::AccountLocked),
+            )
+            .await;
+        }
+
+        locked?;
+
+        let auth_result = match self
             .using_session_code(
                 params.realm_name,
                 params.client_id,
@@ -172,10 +365,51 @@ impl AuthenticatePort for AuthenticateFactory {
                 params.password,
                 params.base_url,
             )
+            .await
+        {
+            Ok(auth_result) => auth_result,
+            Err(e @ (CoreError::InvalidUser | CoreError::InvalidPassword)) => {
+                self.brute_force_detector
+                    .record_failure(realm.id, user_id, ip_address)
+                    .await?;
+
+                notify_auth_event(
+                    &self.event_repository,
+                    &self.webhook_repository,
+                    &self.webhook_notifier_repository,
+                    realm.id,
+                    WebhookTrigger::AuthLoginFailed,
+                    event.with_reason(AuthFailureReason::InvalidCredentials),
+                )
+                .await;
+
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.brute_force_detector
+            .record_success(realm.id, auth_result.user_id)
             .await?;
 
-        self.determine_next_step(auth_result, params.session_code, auth_session)
-            .await
+        let output = self
+            .determine_next_step(auth_result, params.session_code, auth_session)
+            .await?;
+
+        // Logins behind an OTP challenge succeed once it is passed
+        if output.status != AuthenticationStepStatus::RequiresOtpChallenge {
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                realm.id,
+                WebhookTrigger::AuthLoginSucceeded,
+                event,
+            )
+            .await;
+        }
+
+        Ok(output)
     }
 
     async fn using_session_code(
@@ -199,7 +433,7 @@ impl AuthenticatePort for AuthenticateFactory {
             .await
             .map_err(|_| CoreError::InvalidClient)?;
 
-        let user = self
+        let mut user = self
             .user_repository
             .get_by_username(username, realm.id)
             .await
@@ -240,17 +474,27 @@ impl AuthenticatePort for AuthenticateFactory {
         if !has_valid_password {
             return Err(CoreError::InvalidPassword);
         }
-        let iss = format!("{}/realms/{}", base_url, realm.name);
-
-        let jwt_claim = JwtClaim::new(
-            user.id,
-            user.username.clone(),
-            iss,
-            vec![format!("{}-realm", realm.name), "account".to_string()],
-            ClaimsTyp::Bearer,
-            client_id.clone(),
-            Some(user.email.clone()),
-        );
+
+        let realm_setting = match self.realm_repository.get_realm_settings(realm.id).await {
+            Ok(realm_setting) => realm_setting,
+            Err(CoreError::NotFound) => RealmSetting::new(realm.id, None),
+            Err(_) => return Err(CoreError::InternalServerError),
+        };
+
+        if realm_setting
+            .password_policy
+            .is_expired(credential.created_at)
+        {
+            self.require_action(&mut user, RequiredAction::UpdatePassword)
+                .await?;
+        }
+
+        if realm_setting.verify_email && !user.email_verified {
+            self.require_action(&mut user, RequiredAction::VerifyEmail)
+                .await?;
+        }
+
+        let jwt_claim = temporary_claim(&user, &realm, client_id.clone(), &base_url);
 
         if !user.required_actions.is_empty() || has_temporary_password {
             let jwt_token = self
@@ -0,0 +1,91 @@
+use chrono::Utc;
+
+use crate::{
+    application::common::{FerriskeyService, policies::ensure_policy},
+    domain::{
+        authentication::value_objects::Identity,
+        brute_force::{
+            entities::{BruteForceStatus, LoginFailureSubject},
+            ports::{
+                BruteForceService, ClearUserLockoutInput, GetUserBruteForceStatusInput,
+                LoginFailureRepository,
+            },
+        },
+        common::entities::app_errors::CoreError,
+        realm::ports::RealmRepository,
+        user::ports::{UserPolicy, UserRepository},
+    },
+};
+
+impl BruteForceService for FerriskeyService {
+    async fn get_user_brute_force_status(
+        &self,
+        identity: Identity,
+        input: GetUserBruteForceStatusInput,
+    ) -> Result<BruteForceStatus, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_user(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let user = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
+
+        if user.realm_id != realm_id {
+            return Err(CoreError::InvalidUser);
+        }
+
+        let failure = self
+            .login_failure_repository
+            .get_login_failure(realm_id, &LoginFailureSubject::User(user.id))
+            .await?;
+
+        Ok(BruteForceStatus::new(user.id, failure, Utc::now()))
+    }
+
+    async fn clear_user_lockout(
+        &self,
+        identity: Identity,
+        input: ClearUserLockoutInput,
+    ) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_user(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let user = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
+
+        if user.realm_id != realm_id {
+            return Err(CoreError::InvalidUser);
+        }
+
+        self.login_failure_repository
+            .delete_login_failure(realm_id, &LoginFailureSubject::User(user.id))
+            .await
+    }
+}
@@ -1,18 +1,28 @@
+use chrono::Utc;
+use uuid::Uuid;
+
 use crate::{
     application::common::{FerriskeyService, policies::ensure_policy},
     domain::{
         authentication::value_objects::Identity,
         client::{
             entities::{
-                Client, CreateClientInput, CreateRedirectUriInput, CreateRoleInput,
-                DeleteClientInput, DeleteRedirectUriInput, GetClientInput, GetClientRolesInput,
-                GetClientsInput, GetRedirectUrisInput, UpdateClientInput, UpdateRedirectUriInput,
+                Client, CreateClientInput, CreateProtocolMapperInput, CreateRedirectUriInput,
+                CreateRoleInput, DeleteClientInput, DeleteProtocolMapperInput,
+                DeleteRedirectUriInput, GetClientInput, GetClientRolesInput, GetClientsInput,
+                GetProtocolMappersInput, GetRedirectUrisInput, UpdateClientInput,
+                UpdateProtocolMapperInput, UpdateRedirectUriInput,
+                protocol_mapper::{ProtocolMapper, ProtocolMapperOwner},
                 redirect_uri::RedirectUri,
             },
-            ports::{ClientPolicy, ClientRepository, ClientService, RedirectUriRepository},
+            ports::{
+                ClientPolicy, ClientRepository, ClientService, ProtocolMapperRepository,
+                RedirectUriRepository,
+            },
             value_objects::CreateClientRequest,
         },
         common::{entities::app_errors::CoreError, generate_random_string},
+        event::entities::{AdminEvent, OperationType, ResourceType},
         realm::ports::RealmRepository,
         role::{
             entities::Role,
@@ -24,6 +34,35 @@ use crate::{
 
 mod policies;
 
+impl FerriskeyService {
+    pub(crate) async fn realm_client(
+        &self,
+        realm_id: Uuid,
+        client_id: Uuid,
+    ) -> Result<Client, CoreError> {
+        self.client_repository
+            .get_by_id(client_id)
+            .await
+            .ok()
+            .filter(|client| client.realm_id == realm_id)
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn client_protocol_mapper(
+        &self,
+        client_id: Uuid,
+        mapper_id: Uuid,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let mapper = self.protocol_mapper_repository.get_by_id(mapper_id).await?;
+
+        if mapper.client_id != Some(client_id) {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(mapper)
+    }
+}
+
 impl ClientService for FerriskeyService {
     async fn create_client(
         &self,
@@ -40,7 +79,7 @@ impl ClientService for FerriskeyService {
         let realm_id = realm.id;
 
         ensure_policy(
-            self.policy.can_create_client(identity, realm).await,
+            self.policy.can_create_client(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -63,6 +102,20 @@ impl ClientService for FerriskeyService {
             .await
             .map_err(|_| CoreError::CreateClientError)?;
 
+        self.link_default_client_scopes(realm_id, client.id).await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Client,
+                OperationType::Create,
+            )
+            .with_resource(client.id)
+            .with_created(&client),
+        )
+        .await;
+
         // @TODO: Implement webhook notifier call
 
         Ok(client)
@@ -80,8 +133,10 @@ impl ClientService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_create_client(identity, realm).await,
+            self.policy.can_create_client(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -91,6 +146,18 @@ impl ClientService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InvalidRedirectUri)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RedirectUri,
+                OperationType::Create,
+            )
+            .with_resource(redirect_uri.id)
+            .with_created(&redirect_uri),
+        )
+        .await;
+
         // @TODO: Implement webhook notifier call
 
         Ok(redirect_uri)
@@ -110,7 +177,7 @@ impl ClientService for FerriskeyService {
 
         let realm_id = realm.id;
         ensure_policy(
-            self.policy.can_create_role(identity, realm).await,
+            self.policy.can_create_role(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -126,6 +193,18 @@ impl ClientService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Role,
+                OperationType::Create,
+            )
+            .with_resource(role.id)
+            .with_created(&role),
+        )
+        .await;
+
         Ok(role)
     }
 
@@ -141,16 +220,32 @@ impl ClientService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_delete_client(identity, realm).await,
+            self.policy.can_delete_client(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
+        let client = self.realm_client(realm_id, input.client_id).await?;
+
         self.client_repository
-            .delete_by_id(input.client_id)
+            .delete_by_id(client.id)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Client,
+                OperationType::Delete,
+            )
+            .with_resource(client.id)
+            .with_deleted(&client),
+        )
+        .await;
+
         // @TODO: Implement webhook notifier
         Ok(())
     }
@@ -167,8 +262,10 @@ impl ClientService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_client(identity, realm).await,
+            self.policy.can_update_client(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -177,6 +274,17 @@ impl ClientService for FerriskeyService {
             .await
             .map_err(|_| CoreError::RedirectUriNotFound)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RedirectUri,
+                OperationType::Delete,
+            )
+            .with_resource(input.uri_id),
+        )
+        .await;
+
         // @TODO: Implement webhook notifier
         Ok(())
     }
@@ -286,17 +394,33 @@ impl ClientService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_client(identity, realm).await,
+            self.policy.can_update_client(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
+        let previous = self.realm_client(realm_id, input.client_id).await?;
+
         let client = self
             .client_repository
-            .update_client(input.client_id, input.payload)
+            .update_client(previous.id, input.payload)
             .await
             .map_err(|_| CoreError::NotFound)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Client,
+                OperationType::Update,
+            )
+            .with_resource(client.id)
+            .with_changes(&previous, &client),
+        )
+        .await;
+
         Ok(client)
     }
 
@@ -312,8 +436,10 @@ impl ClientService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_client(identity, realm).await,
+            self.policy.can_update_client(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -323,6 +449,198 @@ impl ClientService for FerriskeyService {
             .await
             .map_err(|_| CoreError::NotFound)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RedirectUri,
+                OperationType::Update,
+            )
+            .with_resource(redirect_uri.id),
+        )
+        .await;
+
         Ok(redirect_uri)
     }
+
+    async fn get_protocol_mappers(
+        &self,
+        identity: Identity,
+        input: GetProtocolMappersInput,
+    ) -> Result<Vec<ProtocolMapper>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_view_client(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self.realm_client(realm_id, input.client_id).await?;
+
+        self.protocol_mapper_repository
+            .get_by_client_id(client.id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
+
+    async fn create_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: CreateProtocolMapperInput,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_update_client(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        if !input.config.is_valid() {
+            return Err(CoreError::Invalid);
+        }
+
+        let client = self.realm_client(realm_id, input.client_id).await?;
+
+        let mapper = self
+            .protocol_mapper_repository
+            .create_protocol_mapper(ProtocolMapper::new(
+                ProtocolMapperOwner::Client(client.id),
+                input.name,
+                input.config,
+                input.targets,
+            ))
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::ProtocolMapper,
+                OperationType::Create,
+            )
+            .with_resource(mapper.id)
+            .with_created(&mapper),
+        )
+        .await;
+
+        Ok(mapper)
+    }
+
+    async fn update_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: UpdateProtocolMapperInput,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_update_client(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self.realm_client(realm_id, input.client_id).await?;
+        let mut mapper = self
+            .client_protocol_mapper(client.id, input.mapper_id)
+            .await?;
+
+        let previous = mapper.clone();
+
+        if let Some(config) = input.config {
+            if !config.is_valid() {
+                return Err(CoreError::Invalid);
+            }
+
+            mapper.config = config;
+        }
+
+        if let Some(name) = input.name {
+            mapper.name = name;
+        }
+
+        mapper.access_token = input.access_token.unwrap_or(mapper.access_token);
+        mapper.id_token = input.id_token.unwrap_or(mapper.id_token);
+        mapper.userinfo = input.userinfo.unwrap_or(mapper.userinfo);
+        mapper.introspection = input.introspection.unwrap_or(mapper.introspection);
+        mapper.updated_at = Utc::now();
+
+        let mapper = self
+            .protocol_mapper_repository
+            .update_protocol_mapper(mapper)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::ProtocolMapper,
+                OperationType::Update,
+            )
+            .with_resource(mapper.id)
+            .with_changes(&previous, &mapper),
+        )
+        .await;
+
+        Ok(mapper)
+    }
+
+    async fn delete_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: DeleteProtocolMapperInput,
+    ) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_update_client(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self.realm_client(realm_id, input.client_id).await?;
+        let mapper = self
+            .client_protocol_mapper(client.id, input.mapper_id)
+            .await?;
+
+        self.protocol_mapper_repository
+            .delete(mapper.id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::ProtocolMapper,
+                OperationType::Delete,
+            )
+            .with_resource(mapper.id)
+            .with_deleted(&mapper),
+        )
+        .await;
+
+        Ok(())
+    }
 }
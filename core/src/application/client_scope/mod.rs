@@ -0,0 +1,627 @@
+use chrono::Utc;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::common::{FerriskeyService, policies::ensure_policy},
+    domain::{
+        authentication::value_objects::Identity,
+        client::{
+            entities::protocol_mapper::{ProtocolMapper, ProtocolMapperOwner},
+            ports::ProtocolMapperRepository,
+        },
+        client_scope::{
+            entities::{
+                AssignClientScopeInput, ClientScope, ClientScopeDefaultType, ClientScopeMapping,
+                CreateClientScopeInput, CreateClientScopeProtocolMapperInput,
+                DeleteClientScopeInput, DeleteClientScopeProtocolMapperInput, GetClientScopeInput,
+                GetClientScopeMappingsInput, GetUserConsentsInput, OPENID_SCOPE,
+                RemoveClientScopeInput, RevokeUserConsentInput, UpdateClientScopeInput,
+                UpdateClientScopeProtocolMapperInput, UserConsent,
+            },
+            ports::{
+                ClientScopePolicy, ClientScopeRepository, ClientScopeService, UserConsentRepository,
+            },
+            value_objects::{CreateClientScopeRequest, UpdateClientScopeRequest},
+        },
+        common::entities::app_errors::CoreError,
+        event::entities::{AdminEvent, OperationType, ResourceType},
+        realm::{entities::Realm, ports::RealmRepository},
+        user::ports::{UserPolicy, UserRepository},
+    },
+};
+
+pub mod policies;
+
+impl FerriskeyService {
+    async fn client_scope_realm(&self, realm_name: String) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    /// Loads a client scope, treating scopes of other realms as missing.
+    async fn realm_client_scope(
+        &self,
+        realm: &Realm,
+        scope_id: Uuid,
+    ) -> Result<ClientScope, CoreError> {
+        self.client_scope_repository
+            .get_by_id(scope_id)
+            .await?
+            .filter(|client_scope| client_scope.realm_id == realm.id)
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn client_scope_protocol_mapper(
+        &self,
+        scope_id: Uuid,
+        mapper_id: Uuid,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let mapper = self.protocol_mapper_repository.get_by_id(mapper_id).await?;
+
+        if mapper.client_scope_id != Some(scope_id) {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(mapper)
+    }
+
+    /// Creates the built-in client scopes of a realm that has none yet.
+    pub(crate) async fn provision_client_scopes(&self, realm_id: Uuid) -> Result<(), CoreError> {
+        let client_scopes = self
+            .client_scope_repository
+            .find_by_realm_id(realm_id)
+            .await?;
+
+        if !client_scopes.is_empty() {
+            return Ok(());
+        }
+
+        for payload in ClientScope::builtin(realm_id) {
+            let client_scope = self
+                .client_scope_repository
+                .create_client_scope(payload)
+                .await?;
+
+            for mapper in client_scope.builtin_protocol_mappers() {
+                self.protocol_mapper_repository
+                    .create_protocol_mapper(mapper)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Links the default and optional client scopes of the realm to a newly
+    /// created client.
+    pub(crate) async fn link_default_client_scopes(
+        &self,
+        realm_id: Uuid,
+        client_id: Uuid,
+    ) -> Result<(), CoreError> {
+        let client_scopes = self
+            .client_scope_repository
+            .find_by_realm_id(realm_id)
+            .await?;
+
+        for client_scope in client_scopes {
+            let default_scope = match client_scope.default_type {
+                ClientScopeDefaultType::None => continue,
+                ClientScopeDefaultType::Default => true,
+                ClientScopeDefaultType::Optional => false,
+            };
+
+            self.client_scope_repository
+                .assign_to_client(client_id, client_scope.id, default_scope)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ClientScopeService for FerriskeyService {
+    async fn get_client_scopes(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<ClientScope>, CoreError> {
+        let realm = self.client_scope_realm(realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_client_scope(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.client_scope_repository
+            .find_by_realm_id(realm_id)
+            .await
+    }
+
+    async fn get_client_scope(
+        &self,
+        identity: Identity,
+        input: GetClientScopeInput,
+    ) -> Result<ClientScope, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_view_client_scope(identity, realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        self.realm_client_scope(&realm, input.scope_id).await
+    }
+
+    async fn create_client_scope(
+        &self,
+        identity: Identity,
+        input: CreateClientScopeInput,
+    ) -> Result<ClientScope, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm)
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client_scope = self
+            .client_scope_repository
+            .create_client_scope(CreateClientScopeRequest {
+                realm_id,
+                name: input.name,
+                description: input.description,
+                include_in_token_scope: input.include_in_token_scope,
+                display_on_consent_screen: input.display_on_consent_screen,
+                consent_text: input.consent_text,
+                default_type: input.default_type,
+            })
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::ClientScope,
+                OperationType::Create,
+            )
+            .with_resource(client_scope.id)
+            .with_created(&client_scope),
+        )
+        .await;
+
+        Ok(client_scope)
+    }
+
+    async fn update_client_scope(
+        &self,
+        identity: Identity,
+        input: UpdateClientScopeInput,
+    ) -> Result<ClientScope, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+
+        let updated = self
+            .client_scope_repository
+            .update_client_scope(
+                client_scope.id,
+                UpdateClientScopeRequest {
+                    name: input.name,
+                    description: input.description,
+                    include_in_token_scope: input.include_in_token_scope,
+                    display_on_consent_screen: input.display_on_consent_screen,
+                    consent_text: input.consent_text,
+                    default_type: input.default_type,
+                },
+            )
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::ClientScope,
+                OperationType::Update,
+            )
+            .with_resource(client_scope.id)
+            .with_changes(&client_scope, &updated),
+        )
+        .await;
+
+        Ok(updated)
+    }
+
+    async fn delete_client_scope(
+        &self,
+        identity: Identity,
+        input: DeleteClientScopeInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+
+        self.client_scope_repository
+            .delete_client_scope(client_scope.id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::ClientScope,
+                OperationType::Delete,
+            )
+            .with_resource(client_scope.id)
+            .with_deleted(&client_scope),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn get_client_scope_mappings(
+        &self,
+        identity: Identity,
+        input: GetClientScopeMappingsInput,
+    ) -> Result<Vec<ClientScopeMapping>, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_client_scope(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let client = self.realm_client(realm_id, input.client_id).await?;
+
+        self.client_scope_repository
+            .get_client_mappings(client.id)
+            .await
+    }
+
+    async fn assign_client_scope(
+        &self,
+        identity: Identity,
+        input: AssignClientScopeInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client = self.realm_client(realm.id, input.client_id).await?;
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+
+        self.client_scope_repository
+            .assign_to_client(client.id, client_scope.id, input.default_scope)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::ClientScopeMapping,
+                OperationType::Create,
+            )
+            .with_resource(client.id)
+            .with_created(&json!({
+                "client_scope_id": client_scope.id,
+                "default_scope": input.default_scope,
+            })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn remove_client_scope(
+        &self,
+        identity: Identity,
+        input: RemoveClientScopeInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client = self.realm_client(realm.id, input.client_id).await?;
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+
+        self.client_scope_repository
+            .remove_from_client(client.id, client_scope.id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::ClientScopeMapping,
+                OperationType::Delete,
+            )
+            .with_resource(client.id)
+            .with_deleted(&json!({ "client_scope_id": client_scope.id })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn get_client_scope_protocol_mappers(
+        &self,
+        identity: Identity,
+        input: GetClientScopeInput,
+    ) -> Result<Vec<ProtocolMapper>, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_view_client_scope(identity, realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+
+        self.protocol_mapper_repository
+            .get_by_client_scope_ids(&[client_scope.id])
+            .await
+    }
+
+    async fn create_client_scope_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: CreateClientScopeProtocolMapperInput,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        if !input.config.is_valid() {
+            return Err(CoreError::Invalid);
+        }
+
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+
+        let mapper = self
+            .protocol_mapper_repository
+            .create_protocol_mapper(ProtocolMapper::new(
+                ProtocolMapperOwner::ClientScope(client_scope.id),
+                input.name,
+                input.config,
+                input.targets,
+            ))
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::ProtocolMapper,
+                OperationType::Create,
+            )
+            .with_resource(mapper.id)
+            .with_created(&mapper),
+        )
+        .await;
+
+        Ok(mapper)
+    }
+
+    async fn update_client_scope_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: UpdateClientScopeProtocolMapperInput,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+        let mut mapper = self
+            .client_scope_protocol_mapper(client_scope.id, input.mapper_id)
+            .await?;
+
+        let previous = mapper.clone();
+
+        if let Some(config) = input.config {
+            if !config.is_valid() {
+                return Err(CoreError::Invalid);
+            }
+
+            mapper.config = config;
+        }
+
+        if let Some(name) = input.name {
+            mapper.name = name;
+        }
+
+        mapper.access_token = input.access_token.unwrap_or(mapper.access_token);
+        mapper.id_token = input.id_token.unwrap_or(mapper.id_token);
+        mapper.userinfo = input.userinfo.unwrap_or(mapper.userinfo);
+        mapper.introspection = input.introspection.unwrap_or(mapper.introspection);
+        mapper.updated_at = Utc::now();
+
+        let mapper = self
+            .protocol_mapper_repository
+            .update_protocol_mapper(mapper)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::ProtocolMapper,
+                OperationType::Update,
+            )
+            .with_resource(mapper.id)
+            .with_changes(&previous, &mapper),
+        )
+        .await;
+
+        Ok(mapper)
+    }
+
+    async fn delete_client_scope_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: DeleteClientScopeProtocolMapperInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_client_scope(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let client_scope = self.realm_client_scope(&realm, input.scope_id).await?;
+        let mapper = self
+            .client_scope_protocol_mapper(client_scope.id, input.mapper_id)
+            .await?;
+
+        self.protocol_mapper_repository.delete(mapper.id).await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::ProtocolMapper,
+                OperationType::Delete,
+            )
+            .with_resource(mapper.id)
+            .with_deleted(&mapper),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn get_user_consents(
+        &self,
+        identity: Identity,
+        input: GetUserConsentsInput,
+    ) -> Result<Vec<UserConsent>, CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_user(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let user = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::NotFound)?;
+
+        if user.realm_id != realm_id {
+            return Err(CoreError::NotFound);
+        }
+
+        self.user_consent_repository.get_by_user_id(user.id).await
+    }
+
+    async fn revoke_user_consent(
+        &self,
+        identity: Identity,
+        input: RevokeUserConsentInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.client_scope_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_user(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        let user = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::NotFound)?;
+
+        if user.realm_id != realm_id {
+            return Err(CoreError::NotFound);
+        }
+
+        self.user_consent_repository
+            .delete(user.id, input.client_id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::UserConsent,
+                OperationType::Delete,
+            )
+            .with_resource(user.id)
+            .with_deleted(&json!({ "client_id": input.client_id })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn get_supported_scopes(&self, realm_name: String) -> Result<Vec<String>, CoreError> {
+        let realm = self.client_scope_realm(realm_name).await?;
+
+        let client_scopes = self
+            .client_scope_repository
+            .find_by_realm_id(realm.id)
+            .await?;
+
+        Ok(std::iter::once(OPENID_SCOPE.to_string())
+            .chain(
+                client_scopes
+                    .into_iter()
+                    .map(|client_scope| client_scope.name),
+            )
+            .collect())
+    }
+}
@@ -1,13 +1,18 @@
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
 use crate::{
     application::{
         authentication::services::AuthenticateFactory, common::permissions::FerriskeyPolicy,
     },
     domain::{
         authentication::services::grant_type_service::GrantTypeStrategies,
+        brute_force::services::BruteForceDetector,
         client::{
             ports::{ClientRepository, RedirectUriRepository},
             value_objects::CreateClientRequest,
         },
+        client_scope::services::ClientScopeResolver,
         common::{
             AppConfig, FerriskeyConfig,
             entities::{InitializationResult, StartupConfig, app_errors::CoreError},
@@ -16,21 +21,32 @@ use crate::{
         },
         credential::ports::CredentialRepository,
         crypto::ports::HasherRepository,
+        email::services::EmailTemplates,
         jwt::{ports::KeyStoreRepository, services::JwtServiceImpl},
-        realm::ports::RealmRepository,
+        realm::{
+            entities::{Realm, RealmSetting},
+            ports::RealmRepository,
+        },
         role::{
             entities::permission::Permissions, ports::RoleRepository,
             value_objects::CreateRoleRequest,
         },
         user::{
+            entities::UserAttributes,
             ports::{UserRepository, UserRoleRepository},
             value_objects::CreateUserRequest,
         },
     },
     infrastructure::{
+        action_token::repositories::UsedActionTokenRepoAny,
         auth_session::AuthSessionRepoAny,
-        client::repositories::{ClientRepoAny, RedirectUriRepoAny},
+        brute_force::LoginFailureRepoAny,
+        client::repositories::{ClientRepoAny, ProtocolMapperRepoAny, RedirectUriRepoAny},
+        client_scope::repositories::{ClientScopeRepoAny, UserConsentRepoAny},
         credential::CredentialRepoAny,
+        email::{repositories::SmtpServerRepoAny, senders::EmailSenderAny},
+        event::repositories::EventRepoAny,
+        group::repositories::GroupRepoAny,
         hasher::HasherRepoAny,
         health::HealthCheckRepoAny,
         jwt::KeyStoreRepoAny,
@@ -47,7 +63,9 @@ use crate::{
             },
         },
         webhook::repositories::{
-            webhook_notifier_repository::WebhookNotifierRepoAny, webhook_repository::WebhookRepoAny,
+            webhook_delivery_repository::WebhookDeliveryRepoAny,
+            webhook_notifier_repository::WebhookNotifierRepoAny,
+            webhook_repository::WebhookRepoAny,
         },
     },
 };
@@ -74,9 +92,22 @@ pub struct FerriskeyService {
     pub(crate) webhook_repository: WebhookRepoAny,
     pub(crate) policy: FerriskeyPolicy,
     pub(crate) webhook_notifier_repository: WebhookNotifierRepoAny,
+    pub(crate) webhook_delivery_repository: WebhookDeliveryRepoAny,
     pub(crate) grant_type_strategies: GrantTypeStrategies,
     pub(crate) authenticate_factory: AuthenticateFactory,
     pub(crate) recovery_code_repo: RecoveryCodeRepoAny,
+    pub(crate) refresh_token_repository: RefreshTokenRepoAny,
+    pub(crate) login_failure_repository: LoginFailureRepoAny,
+    pub(crate) group_repository: GroupRepoAny,
+    pub(crate) protocol_mapper_repository: ProtocolMapperRepoAny,
+    pub(crate) client_scope_repository: ClientScopeRepoAny,
+    pub(crate) user_consent_repository: UserConsentRepoAny,
+    pub(crate) client_scope_resolver: ClientScopeResolver,
+    pub(crate) smtp_server_repository: SmtpServerRepoAny,
+    pub(crate) email_sender: EmailSenderAny,
+    pub(crate) email_templates: EmailTemplates,
+    pub(crate) used_action_token_repository: UsedActionTokenRepoAny,
+    pub(crate) event_repository: EventRepoAny,
 }
 
 impl FerriskeyService {
@@ -90,12 +121,30 @@ impl FerriskeyService {
             config.database.name
         );
 
-        let repos = build_repos_from_env(AppConfig { database_url }).await?;
+        let repos = build_repos_from_env(AppConfig {
+            database_url,
+            email: config.email,
+        })
+        .await?;
 
         let policy = FerriskeyPolicy::new(
             repos.user_repository.clone(),
             repos.client_repository.clone(),
             repos.user_role_repository.clone(),
+            repos.group_repository.clone(),
+            repos.role_repository.clone(),
+        );
+
+        let brute_force_detector = BruteForceDetector::new(
+            repos.login_failure_repository.clone(),
+            repos.realm_repository.clone(),
+            repos.webhook_repository.clone(),
+            repos.webhook_notifier_repository.clone(),
+        );
+
+        let client_scope_resolver = ClientScopeResolver::new(
+            repos.client_scope_repository.clone(),
+            repos.user_consent_repository.clone(),
         );
 
         let grant_type_strategies = GrantTypeStrategies::new(
@@ -106,6 +155,16 @@ impl FerriskeyService {
             repos.keystore_repository.clone(),
             repos.refresh_token_repository.clone(),
             repos.client_repository.clone(),
+            repos.realm_repository.clone(),
+            repos.webhook_repository.clone(),
+            repos.webhook_notifier_repository.clone(),
+            repos.event_repository.clone(),
+            repos.protocol_mapper_repository.clone(),
+            repos.user_role_repository.clone(),
+            repos.group_repository.clone(),
+            repos.role_repository.clone(),
+            brute_force_detector.clone(),
+            client_scope_resolver.clone(),
         );
 
         let jwt_service = DefaultJwtService::new(
@@ -121,7 +180,13 @@ impl FerriskeyService {
             repos.client_repository.clone(),
             repos.credential_repository.clone(),
             repos.hasher_repository.clone(),
+            repos.user_required_action_repository.clone(),
+            repos.webhook_repository.clone(),
+            repos.webhook_notifier_repository.clone(),
+            repos.event_repository.clone(),
             jwt_service,
+            brute_force_detector,
+            client_scope_resolver.clone(),
         );
 
         Ok(FerriskeyService {
@@ -140,12 +205,75 @@ impl FerriskeyService {
             health_check_repository: repos.health_check_repository,
             webhook_repository: repos.webhook_repository,
             webhook_notifier_repository: repos.webhook_notifier_repository,
+            webhook_delivery_repository: repos.webhook_delivery_repository,
+            refresh_token_repository: repos.refresh_token_repository,
+            login_failure_repository: repos.login_failure_repository,
+            group_repository: repos.group_repository,
+            protocol_mapper_repository: repos.protocol_mapper_repository,
+            client_scope_repository: repos.client_scope_repository,
+            user_consent_repository: repos.user_consent_repository,
+            client_scope_resolver,
+            smtp_server_repository: repos.smtp_server_repository,
+            email_sender: repos.email_sender,
+            email_templates: EmailTemplates::new(),
+            used_action_token_repository: repos.used_action_token_repository,
+            event_repository: repos.event_repository,
 
             policy,
             grant_type_strategies,
             authenticate_factory,
         })
     }
+
+    /// Settings of the realm, falling back to the defaults for realms that
+    /// have none stored.
+    pub(crate) async fn realm_setting(&self, realm_id: Uuid) -> Result<RealmSetting, CoreError> {
+        match self.realm_repository.get_realm_settings(realm_id).await {
+            Ok(realm_setting) => Ok(realm_setting),
+            Err(CoreError::NotFound) => Ok(RealmSetting::new(realm_id, None)),
+            Err(_) => Err(CoreError::InternalServerError),
+        }
+    }
+
+    /// Rotates the signing key of `realm` once it is older than the rotation
+    /// interval, and retires the passive keys past the retirement delay.
+    async fn apply_realm_key_rotation_policy(&self, realm: &Realm) -> Result<(), CoreError> {
+        let realm_setting = self.realm_setting(realm.id).await?;
+        let algorithm = realm_setting.signing_algorithm();
+
+        let now = Utc::now();
+
+        if let Some(interval) = realm_setting.key_rotation_interval {
+            let active_key = self
+                .keystore_repository
+                .get_or_generate_key(realm.id, algorithm)
+                .await
+                .map_err(|_| CoreError::RealmKeyNotFound)?;
+
+            let created_before = now - Duration::seconds(interval.into());
+
+            // Checked again under the lock, as every instance runs this
+            if active_key.created_at <= created_before {
+                let rotated = self
+                    .keystore_repository
+                    .rotate_key_created_before(realm.id, algorithm, created_before)
+                    .await
+                    .map_err(|_| CoreError::InternalServerError)?;
+
+                if rotated.is_some() {
+                    tracing::info!("rotated signing key of realm {}", realm.name);
+                }
+            }
+        }
+
+        self.keystore_repository
+            .retire_passive_keys(
+                realm.id,
+                now - Duration::seconds(realm_setting.key_retirement_delay.into()),
+            )
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
 }
 
 impl CoreService for FerriskeyService {
@@ -186,8 +314,21 @@ impl CoreService for FerriskeyService {
             }
         };
 
+        if let Err(CoreError::NotFound) = self.realm_repository.get_realm_settings(realm.id).await {
+            self.realm_repository
+                .create_realm_settings(realm.id, "RS256".to_string())
+                .await?;
+
+            tracing::info!("{} realm settings created", config.master_realm_name);
+        }
+
+        self.provision_client_scopes(realm.id).await?;
+        self.provision_default_role(&realm).await?;
+
+        let realm_setting = self.realm_setting(realm.id).await?;
+
         self.keystore_repository
-            .get_or_generate_key(realm.id)
+            .get_or_generate_key(realm.id, realm_setting.signing_algorithm())
             .await
             .map_err(|_| CoreError::RealmKeyNotFound)?;
 
@@ -223,6 +364,8 @@ impl CoreService for FerriskeyService {
                     .await
                     .map_err(|_| CoreError::CreateClientError)?;
 
+                self.link_default_client_scopes(realm.id, client.id).await?;
+
                 tracing::info!("client {:} created", config.default_client_id.clone());
 
                 client
@@ -260,6 +403,8 @@ impl CoreService for FerriskeyService {
                     .await
                     .map_err(|_| CoreError::CreateClientError)?;
 
+                self.link_default_client_scopes(realm.id, client.id).await?;
+
                 tracing::info!("client {:} created", master_realm_client_id.clone());
 
                 client
@@ -290,10 +435,13 @@ impl CoreService for FerriskeyService {
                         realm_id: realm.id,
                         client_id: None,
                         username: config.admin_username.clone(),
+                        attributes: UserAttributes::new(),
                     })
                     .await
                     .map_err(|_| CoreError::InternalServerError)?;
 
+                self.assign_default_roles(&realm, user.id).await?;
+
                 tracing::info!("user {:} created", user.username);
                 user
             }
@@ -412,4 +560,24 @@ impl CoreService for FerriskeyService {
             default_client_id: client.id,
         })
     }
+
+    async fn apply_key_rotation_policy(&self) -> Result<(), CoreError> {
+        let realms = self
+            .realm_repository
+            .fetch_realm()
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        // One realm failing must not hold back the rotation of the others
+        for realm in realms {
+            if let Err(e) = self.apply_realm_key_rotation_policy(&realm).await {
+                tracing::error!(
+                    "failed to apply key rotation policy of realm {}: {e}",
+                    realm.name
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,19 +1,21 @@
 use std::collections::HashSet;
 
+use uuid::Uuid;
+
 use crate::{
     domain::{
         authentication::value_objects::Identity,
         client::{entities::Client, ports::ClientRepository},
         common::{entities::app_errors::CoreError, policies::Policy},
+        group::services::effective_roles,
         realm::entities::Realm,
         role::entities::{Role, permission::Permissions},
-        user::{
-            entities::User,
-            ports::{UserRepository, UserRoleRepository},
-        },
+        user::{entities::User, ports::UserRepository},
     },
     infrastructure::{
         client::repositories::ClientRepoAny,
+        group::repositories::GroupRepoAny,
+        role::repositories::RoleRepoAny,
         user::{UserRepoAny, repositories::user_role_repository::UserRoleRepoAny},
     },
 };
@@ -23,6 +25,8 @@ pub struct FerriskeyPolicy {
     user_repository: UserRepoAny,
     client_repository: ClientRepoAny,
     user_role_repository: UserRoleRepoAny,
+    group_repository: GroupRepoAny,
+    role_repository: RoleRepoAny,
 }
 
 impl FerriskeyPolicy {
@@ -30,14 +34,30 @@ impl FerriskeyPolicy {
         user_repository: UserRepoAny,
         client_repository: ClientRepoAny,
         user_role_repository: UserRoleRepoAny,
+        group_repository: GroupRepoAny,
+        role_repository: RoleRepoAny,
     ) -> Self {
         FerriskeyPolicy {
             user_repository,
             client_repository,
             user_role_repository,
+            group_repository,
+            role_repository,
         }
     }
 
+    /// Roles assigned directly to the user plus the ones inherited from its
+    /// groups and their ancestors, expanded through composite roles.
+    pub(crate) async fn effective_roles(&self, user_id: Uuid) -> Result<Vec<Role>, CoreError> {
+        effective_roles(
+            &self.user_role_repository,
+            &self.group_repository,
+            &self.role_repository,
+            user_id,
+        )
+        .await
+    }
+
     /// Check if the user can manage users in the target realm
     ///
     /// # Arguments
@@ -77,8 +97,7 @@ impl Policy for FerriskeyPolicy {
         client: &Client,
     ) -> Result<HashSet<Permissions>, CoreError> {
         let roles = self
-            .user_role_repository
-            .get_user_roles(user.id)
+            .effective_roles(user.id)
             .await
             .map_err(|_| CoreError::Forbidden("user not found".to_string()))?;
 
@@ -146,8 +165,7 @@ impl Policy for FerriskeyPolicy {
 
     async fn get_user_permissions(&self, user: &User) -> Result<HashSet<Permissions>, CoreError> {
         let roles = self
-            .user_role_repository
-            .get_user_roles(user.id)
+            .effective_roles(user.id)
             .await
             .map_err(|_| CoreError::Forbidden("user not found".to_string()))?;
 
@@ -0,0 +1,244 @@
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::{
+    application::common::{FerriskeyService, policies::ensure_policy},
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        email::{
+            entities::{
+                DEFAULT_FROM_ADDRESS, DeleteSmtpServerInput, EmailMessage, EmailTemplate,
+                GetSmtpServerInput, SendTestEmailInput, SmtpServer, UpdateSmtpServerInput,
+            },
+            ports::{EmailSender, EmailService, SmtpServerRepository},
+            services::EmailTemplates,
+        },
+        event::entities::{AdminEvent, OperationType, ResourceType},
+        realm::{
+            entities::Realm,
+            ports::{RealmPolicy, RealmRepository},
+        },
+        user::{entities::User, ports::UserRepository},
+    },
+};
+
+impl FerriskeyService {
+    /// Renders `template` in the preferred locale, or the realm default, and
+    /// sends it through the email transport. `realm_name` is added to `data`.
+    pub(crate) async fn send_email(
+        &self,
+        realm: &Realm,
+        to: &str,
+        preferred_locale: Option<&str>,
+        template: EmailTemplate,
+        mut data: Value,
+    ) -> Result<(), CoreError> {
+        let realm_setting = self.realm_setting(realm.id).await?;
+        let locale =
+            EmailTemplates::resolve_locale(preferred_locale, &realm_setting.default_locale);
+
+        if let Value::Object(fields) = &mut data {
+            fields.insert("realm_name".to_string(), Value::String(realm.name.clone()));
+        }
+
+        let rendered = self.email_templates.render(template, locale, &data)?;
+        let server = self
+            .smtp_server_repository
+            .get_by_realm_id(realm.id)
+            .await?;
+
+        let message = EmailMessage {
+            from: server
+                .as_ref()
+                .map(SmtpServer::from_mailbox)
+                .unwrap_or_else(|| DEFAULT_FROM_ADDRESS.to_string()),
+            reply_to: server.as_ref().and_then(|server| server.reply_to.clone()),
+            to: to.to_string(),
+            subject: rendered.subject,
+            text_body: rendered.text_body,
+            html_body: rendered.html_body,
+        };
+
+        self.email_sender.send(server, message).await
+    }
+
+    /// Sends `template` to the user, in the locale of their `locale`
+    /// attribute when the templates support it.
+    pub(crate) async fn send_user_email(
+        &self,
+        realm: &Realm,
+        user: &User,
+        template: EmailTemplate,
+        data: Value,
+    ) -> Result<(), CoreError> {
+        self.send_email(realm, &user.email, user.locale(), template, data)
+            .await
+    }
+}
+
+impl EmailService for FerriskeyService {
+    async fn get_smtp_server(
+        &self,
+        identity: Identity,
+        input: GetSmtpServerInput,
+    ) -> Result<SmtpServer, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_realm(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.smtp_server_repository
+            .get_by_realm_id(realm_id)
+            .await?
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn update_smtp_server(
+        &self,
+        identity: Identity,
+        input: UpdateSmtpServerInput,
+    ) -> Result<SmtpServer, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_realm(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        let existing = self
+            .smtp_server_repository
+            .get_by_realm_id(realm_id)
+            .await?;
+        let previous = existing.clone();
+        let now = Utc::now();
+
+        let server = SmtpServer {
+            realm_id,
+            host: input.host,
+            port: input.port,
+            encryption: input.encryption,
+            from_address: input.from_address,
+            from_display_name: input.from_display_name,
+            reply_to: input.reply_to,
+            password: input.username.as_ref().and_then(|_| {
+                input
+                    .password
+                    .or_else(|| existing.as_ref().and_then(|server| server.password.clone()))
+            }),
+            username: input.username,
+            created_at: existing.map_or(now, |server| server.created_at),
+            updated_at: now,
+        };
+
+        let server = self
+            .smtp_server_repository
+            .save_smtp_server(&server)
+            .await?;
+
+        let event = AdminEvent::new(
+            realm_id,
+            &identity,
+            ResourceType::SmtpServer,
+            OperationType::Update,
+        );
+        self.record_admin_event(match &previous {
+            Some(previous) => event.with_changes(previous, &server),
+            None => event.with_created(&server),
+        })
+        .await;
+
+        Ok(server)
+    }
+
+    async fn delete_smtp_server(
+        &self,
+        identity: Identity,
+        input: DeleteSmtpServerInput,
+    ) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_realm(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.smtp_server_repository
+            .delete_by_realm_id(realm_id)
+            .await?;
+
+        self.record_admin_event(AdminEvent::new(
+            realm_id,
+            &identity,
+            ResourceType::SmtpServer,
+            OperationType::Delete,
+        ))
+        .await;
+
+        Ok(())
+    }
+
+    async fn send_test_email(
+        &self,
+        identity: Identity,
+        input: SendTestEmailInput,
+    ) -> Result<(), CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        ensure_policy(
+            self.policy
+                .can_update_realm(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let caller = match identity {
+            Identity::User(user) => Some(user),
+            Identity::Client(client) => self.user_repository.get_by_client_id(client.id).await.ok(),
+        };
+
+        let data = Value::Object(Default::default());
+
+        match (input.to, caller) {
+            (Some(to), caller) => {
+                let locale = caller.as_ref().and_then(User::locale);
+
+                self.send_email(&realm, &to, locale, EmailTemplate::Test, data)
+                    .await
+            }
+            (None, Some(caller)) if !caller.email.is_empty() => {
+                self.send_user_email(&realm, &caller, EmailTemplate::Test, data)
+                    .await
+            }
+            _ => Err(CoreError::Invalid),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use chrono::{Duration, Utc};
+
+use crate::{
+    application::common::{FerriskeyService, policies::ensure_policy},
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        event::{
+            entities::{
+                AdminEvent, AdminEventPage, AdminEventQuery, ClearEventsInput, GetAdminEventsInput,
+                GetLoginEventsInput, LoginEventPage, LoginEventQuery, MAX_EVENT_PAGE_SIZE,
+            },
+            ports::{EventPolicy, EventRepository, EventService},
+        },
+        realm::{entities::Realm, ports::RealmRepository},
+    },
+};
+
+pub mod policies;
+
+impl FerriskeyService {
+    async fn event_realm(&self, realm_name: String) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    /// Records an operation made through the admin API. The operation has
+    /// already been applied, so a failure to record it is only logged.
+    pub(crate) async fn record_admin_event(&self, event: AdminEvent) {
+        if let Err(e) = self.event_repository.create_admin_event(event).await {
+            tracing::error!("failed to record admin event: {e}");
+        }
+    }
+}
+
+impl EventService for FerriskeyService {
+    async fn get_login_events(
+        &self,
+        identity: Identity,
+        input: GetLoginEventsInput,
+    ) -> Result<LoginEventPage, CoreError> {
+        let realm = self.event_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_events(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let query = LoginEventQuery {
+            limit: input.query.limit.clamp(1, MAX_EVENT_PAGE_SIZE),
+            ..input.query
+        };
+
+        self.event_repository
+            .search_login_events(realm_id, query)
+            .await
+    }
+
+    async fn get_admin_events(
+        &self,
+        identity: Identity,
+        input: GetAdminEventsInput,
+    ) -> Result<AdminEventPage, CoreError> {
+        let realm = self.event_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_events(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let query = AdminEventQuery {
+            limit: input.query.limit.clamp(1, MAX_EVENT_PAGE_SIZE),
+            ..input.query
+        };
+
+        self.event_repository
+            .search_admin_events(realm_id, query)
+            .await
+    }
+
+    async fn clear_login_events(
+        &self,
+        identity: Identity,
+        input: ClearEventsInput,
+    ) -> Result<u64, CoreError> {
+        let realm = self.event_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_manage_events(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.event_repository
+            .delete_login_events(realm_id, None)
+            .await
+    }
+
+    async fn clear_admin_events(
+        &self,
+        identity: Identity,
+        input: ClearEventsInput,
+    ) -> Result<u64, CoreError> {
+        let realm = self.event_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_manage_events(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.event_repository
+            .delete_admin_events(realm_id, None)
+            .await
+    }
+
+    async fn purge_expired_events(&self) -> Result<(), CoreError> {
+        let realms = self
+            .realm_repository
+            .fetch_realm()
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        let now = Utc::now();
+
+        for realm in realms {
+            let realm_setting = self.realm_setting(realm.id).await?;
+
+            if realm_setting.login_event_retention > 0 {
+                let before = now - Duration::seconds(realm_setting.login_event_retention.into());
+
+                self.event_repository
+                    .delete_login_events(realm.id, Some(before))
+                    .await?;
+            }
+
+            if realm_setting.admin_event_retention > 0 {
+                let before = now - Duration::seconds(realm_setting.admin_event_retention.into());
+
+                self.event_repository
+                    .delete_admin_events(realm.id, Some(before))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
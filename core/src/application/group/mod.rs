@@ -0,0 +1,562 @@
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    application::common::{FerriskeyService, policies::ensure_policy},
+    domain::{
+        authentication::value_objects::Identity,
+        common::entities::app_errors::CoreError,
+        event::entities::{AdminEvent, OperationType, ResourceType},
+        group::{
+            entities::{
+                CreateGroupInput, DeleteGroupInput, GetGroupInput, GetUserGroupsInput, Group,
+                GroupMemberInput, GroupRoleInput, UpdateGroupInput,
+            },
+            ports::{GroupPolicy, GroupRepository, GroupService},
+            value_objects::{CreateGroupRequest, UpdateGroupRequest},
+        },
+        realm::{entities::Realm, ports::RealmRepository},
+        role::{entities::Role, ports::RoleRepository},
+        user::{entities::User, ports::UserRepository},
+    },
+};
+
+pub mod policies;
+
+impl FerriskeyService {
+    async fn group_realm(&self, realm_name: String) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    /// Loads a group, treating groups of other realms as missing.
+    async fn realm_group(&self, realm: &Realm, group_id: Uuid) -> Result<Group, CoreError> {
+        self.group_repository
+            .get_by_id(group_id)
+            .await?
+            .filter(|group| group.realm_id == realm.id)
+            .ok_or(CoreError::NotFound)
+    }
+
+    async fn realm_user(&self, realm: &Realm, user_id: Uuid) -> Result<User, CoreError> {
+        let user = self
+            .user_repository
+            .get_by_id(user_id)
+            .await
+            .map_err(|_| CoreError::NotFound)?;
+
+        if user.realm_id != realm.id {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(user)
+    }
+}
+
+impl GroupService for FerriskeyService {
+    async fn get_groups(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<Group>, CoreError> {
+        let realm = self.group_realm(realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_group(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.group_repository.find_by_realm_id(realm_id).await
+    }
+
+    async fn get_group(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> Result<Group, CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_group(identity, realm.clone()).await,
+            "insufficient permissions",
+        )?;
+
+        self.realm_group(&realm, input.group_id).await
+    }
+
+    async fn get_group_children(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> Result<Vec<Group>, CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_group(identity, realm.clone()).await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+
+        self.group_repository.find_children(group.id).await
+    }
+
+    async fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateGroupInput,
+    ) -> Result<Group, CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_group(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        if let Some(parent_id) = input.parent_id {
+            self.realm_group(&realm, parent_id).await?;
+        }
+
+        let group = self
+            .group_repository
+            .create_group(CreateGroupRequest {
+                realm_id: realm.id,
+                parent_id: input.parent_id,
+                name: input.name,
+                description: input.description,
+                attributes: input.attributes,
+            })
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::Group,
+                OperationType::Create,
+            )
+            .with_resource(group.id)
+            .with_created(&group),
+        )
+        .await;
+
+        Ok(group)
+    }
+
+    async fn update_group(
+        &self,
+        identity: Identity,
+        input: UpdateGroupInput,
+    ) -> Result<Group, CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_group(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+
+        let updated = self
+            .group_repository
+            .update_group(
+                group.id,
+                UpdateGroupRequest {
+                    name: input.name,
+                    description: input.description,
+                    attributes: input.attributes,
+                },
+            )
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::Group,
+                OperationType::Update,
+            )
+            .with_resource(group.id)
+            .with_changes(&group, &updated),
+        )
+        .await;
+
+        Ok(updated)
+    }
+
+    async fn delete_group(
+        &self,
+        identity: Identity,
+        input: DeleteGroupInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_group(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+
+        self.group_repository.delete_group(group.id).await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::Group,
+                OperationType::Delete,
+            )
+            .with_resource(group.id)
+            .with_deleted(&group),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn get_group_members(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> Result<Vec<User>, CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_group(identity, realm.clone()).await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+
+        self.group_repository.get_members(group.id).await
+    }
+
+    async fn add_group_member(
+        &self,
+        identity: Identity,
+        input: GroupMemberInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_group(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+        let user = self.realm_user(&realm, input.user_id).await?;
+
+        self.group_repository.add_member(group.id, user.id).await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::GroupMembership,
+                OperationType::Create,
+            )
+            .with_resource(group.id)
+            .with_created(&json!({ "user_id": user.id })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn remove_group_member(
+        &self,
+        identity: Identity,
+        input: GroupMemberInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_group(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+
+        self.group_repository
+            .remove_member(group.id, input.user_id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::GroupMembership,
+                OperationType::Delete,
+            )
+            .with_resource(group.id)
+            .with_deleted(&json!({ "user_id": input.user_id })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn get_user_groups(
+        &self,
+        identity: Identity,
+        input: GetUserGroupsInput,
+    ) -> Result<Vec<Group>, CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_group(identity, realm.clone()).await,
+            "insufficient permissions",
+        )?;
+
+        let user = self.realm_user(&realm, input.user_id).await?;
+
+        self.group_repository.get_user_groups(user.id).await
+    }
+
+    async fn get_group_roles(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> Result<Vec<Role>, CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_group(identity, realm.clone()).await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+
+        self.group_repository.get_group_roles(group.id).await
+    }
+
+    async fn assign_group_role(
+        &self,
+        identity: Identity,
+        input: GroupRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_group(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+        let role = self
+            .role_repository
+            .get_by_id(input.role_id)
+            .await?
+            .filter(|role| role.realm_id == realm.id)
+            .ok_or(CoreError::NotFound)?;
+
+        self.group_repository.assign_role(group.id, role.id).await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::GroupRoleMapping,
+                OperationType::Create,
+            )
+            .with_resource(group.id)
+            .with_created(&json!({ "role_id": role.id })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn unassign_group_role(
+        &self,
+        identity: Identity,
+        input: GroupRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.group_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_manage_group(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let group = self.realm_group(&realm, input.group_id).await?;
+
+        self.group_repository
+            .unassign_role(group.id, input.role_id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::GroupRoleMapping,
+                OperationType::Delete,
+            )
+            .with_resource(group.id)
+            .with_deleted(&json!({ "role_id": input.role_id })),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        application::common::FerriskeyService,
+        domain::{
+            common::{DatabaseConfig, EmailConfig, FerriskeyConfig},
+            group::{
+                entities::{Group, GroupAttributes},
+                ports::GroupRepository,
+                value_objects::CreateGroupRequest,
+            },
+            realm::ports::RealmRepository,
+            role::{ports::RoleRepository, value_objects::CreateRoleRequest},
+            user::{
+                entities::UserAttributes, ports::UserRepository, value_objects::CreateUserRequest,
+            },
+        },
+    };
+
+    async fn setup_test_service() -> FerriskeyService {
+        let database_host = std::env::var("DATABASE_HOST").expect("DATABASE_HOST no set");
+        let port = std::env::var("DATABASE_PORT").expect("DATABASE_PORT no set");
+        let port: u16 = port.parse().expect("DATABASE_PORT not a number");
+
+        let username = std::env::var("DATABASE_USERNAME").expect("DATABASE_USERNAME no set");
+        let password = std::env::var("DATABASE_PASSWORD").expect("DATABASE_PASSWORD no set");
+        let name = std::env::var("DATABASE_NAME").expect("DATABASE_NAME no set");
+
+        let config = FerriskeyConfig {
+            database: DatabaseConfig {
+                host: database_host,
+                port,
+                username,
+                password,
+                name,
+            },
+            email: EmailConfig::default(),
+        };
+
+        FerriskeyService::new(config)
+            .await
+            .expect("Failed to create FerriskeyService")
+    }
+
+    async fn create_test_group(
+        service: &FerriskeyService,
+        realm_id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Group {
+        let group = service
+            .group_repository
+            .create_group(CreateGroupRequest {
+                realm_id,
+                parent_id,
+                name: format!("group-{}", Uuid::new_v4()),
+                description: None,
+                attributes: GroupAttributes::new(),
+            })
+            .await
+            .expect("Failed to create test group");
+
+        let role = service
+            .role_repository
+            .create(CreateRoleRequest {
+                name: format!("role-of-{}", group.name),
+                description: None,
+                permissions: Vec::new(),
+                realm_id,
+                client_id: None,
+            })
+            .await
+            .expect("Failed to create test role");
+
+        service
+            .group_repository
+            .assign_role(group.id, role.id)
+            .await
+            .expect("Failed to assign test role");
+
+        group
+    }
+
+    #[tokio::test]
+    async fn test_members_inherit_the_roles_of_ancestor_groups() {
+        let service = setup_test_service().await;
+
+        let realm = service
+            .realm_repository
+            .create_realm(format!("test-realm-{}", Uuid::new_v4()))
+            .await
+            .expect("Failed to create test realm");
+        let user = service
+            .user_repository
+            .create_user(CreateUserRequest {
+                username: format!("testuser-{}", Uuid::new_v4()),
+                email: "test@example.com".to_string(),
+                email_verified: true,
+                enabled: true,
+                firstname: "Test".to_string(),
+                lastname: "User".to_string(),
+                realm_id: realm.id,
+                client_id: None,
+                attributes: UserAttributes::new(),
+            })
+            .await
+            .expect("Failed to create test user");
+
+        let root = create_test_group(&service, realm.id, None).await;
+        let parent = create_test_group(&service, realm.id, Some(root.id)).await;
+        let group = create_test_group(&service, realm.id, Some(parent.id)).await;
+        let sibling = create_test_group(&service, realm.id, Some(root.id)).await;
+
+        service
+            .group_repository
+            .add_member(group.id, user.id)
+            .await
+            .expect("Failed to add test member");
+
+        let mut roles: Vec<String> = service
+            .group_repository
+            .get_inherited_roles(user.id)
+            .await
+            .expect("Failed to get inherited roles")
+            .into_iter()
+            .map(|role| role.name)
+            .collect();
+        roles.sort();
+
+        let mut expected: Vec<String> = [&root, &parent, &group]
+            .iter()
+            .map(|group| format!("role-of-{}", group.name))
+            .collect();
+        expected.sort();
+
+        assert_eq!(roles, expected);
+        assert!(!roles.contains(&format!("role-of-{}", sibling.name)));
+    }
+}
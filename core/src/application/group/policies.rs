@@ -0,0 +1,55 @@
+use crate::{
+    application::common::permissions::FerriskeyPolicy,
+    domain::{
+        authentication::value_objects::Identity,
+        common::{entities::app_errors::CoreError, policies::Policy},
+        group::ports::GroupPolicy,
+        realm::entities::Realm,
+        role::entities::permission::Permissions,
+    },
+};
+
+impl GroupPolicy for FerriskeyPolicy {
+    async fn can_view_group(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(&identity).await?;
+
+        let permissions = self
+            .get_permission_for_target_realm(&user, &target_realm)
+            .await?;
+
+        let has_permission = Permissions::has_one_of_permissions(
+            &permissions.iter().cloned().collect::<Vec<Permissions>>(),
+            &[
+                Permissions::ManageRealm,
+                Permissions::ManageUsers,
+                Permissions::ViewUsers,
+                Permissions::QueryGroups,
+            ],
+        );
+
+        Ok(has_permission)
+    }
+
+    async fn can_manage_group(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> Result<bool, CoreError> {
+        let user = self.get_user_from_identity(&identity).await?;
+
+        let permissions = self
+            .get_permission_for_target_realm(&user, &target_realm)
+            .await?;
+
+        let has_permission = Permissions::has_one_of_permissions(
+            &permissions.iter().cloned().collect::<Vec<Permissions>>(),
+            &[Permissions::ManageRealm, Permissions::ManageUsers],
+        );
+
+        Ok(has_permission)
+    }
+}
@@ -1,6 +1,12 @@
+pub mod action_token;
 pub mod authentication;
+pub mod brute_force;
 pub mod client;
+pub mod client_scope;
 pub mod common;
+pub mod email;
+pub mod event;
+pub mod group;
 pub mod health;
 pub mod realm;
 pub mod role;
@@ -6,13 +6,21 @@ use crate::{
         authentication::value_objects::Identity,
         client::{ports::ClientRepository, value_objects::CreateClientRequest},
         common::{entities::app_errors::CoreError, generate_random_string},
+        email::services::EmailTemplates,
+        event::entities::{AdminEvent, OperationType, ResourceType},
+        jwt::{
+            entities::{KeyStatus, RealmKey, SigningAlgorithm},
+            ports::KeyStoreRepository,
+        },
         realm::{
             entities::{Realm, RealmSetting},
             ports::{
                 CreateRealmInput, CreateRealmWithUserInput, DeleteRealmInput, GetRealmInput,
-                GetRealmSettingInput, RealmPolicy, RealmRepository, RealmService, UpdateRealmInput,
+                GetRealmKeysInput, GetRealmSettingInput, RealmPolicy, RealmRepository,
+                RealmService, RetireRealmKeyInput, RotateRealmKeyInput, UpdateRealmInput,
                 UpdateRealmSettingInput,
             },
+            value_objects::UpdateRealmSettingRequest,
         },
         role::{
             entities::permission::Permissions, ports::RoleRepository,
@@ -41,8 +49,8 @@ impl RealmService for FerriskeyService {
             .ok_or(CoreError::InternalServerError)?;
 
         let user_roles = self
-            .user_role_repository
-            .get_user_roles(user.id)
+            .policy
+            .effective_roles(user.id)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
@@ -151,7 +159,9 @@ impl RealmService for FerriskeyService {
             .ok_or(CoreError::InvalidRealm)?;
 
         ensure_policy(
-            self.policy.can_create_realm(identity, realm_master).await,
+            self.policy
+                .can_create_realm(identity.clone(), realm_master)
+                .await,
             "insufficient permissions",
         )?;
 
@@ -159,6 +169,22 @@ impl RealmService for FerriskeyService {
         self.realm_repository
             .create_realm_settings(realm.id, "RS256".to_string())
             .await?;
+        self.provision_client_scopes(realm.id).await?;
+        self.provision_default_role(&realm).await?;
+
+        // Realms are created and deleted from the realm of the administrator,
+        // which also keeps the event once the realm and its events are gone.
+        self.record_admin_event(
+            AdminEvent::new(
+                identity.realm_id(),
+                &identity,
+                ResourceType::Realm,
+                OperationType::Create,
+            )
+            .with_resource(realm.id)
+            .with_created(&realm),
+        )
+        .await;
 
         Ok(realm)
     }
@@ -210,6 +236,9 @@ impl RealmService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.link_default_client_scopes(realm_master.id, client.id)
+            .await?;
+
         // Create role for client
         let permissions = Permissions::to_names(&[
             Permissions::ManageRealm,
@@ -251,17 +280,31 @@ impl RealmService for FerriskeyService {
             .ok_or(CoreError::InvalidRealm)?;
 
         ensure_policy(
-            self.policy.can_update_realm(identity, realm).await,
+            self.policy
+                .can_update_realm(identity.clone(), realm.clone())
+                .await,
             "insufficient permissions",
         )?;
 
-        let realm = self
+        let updated = self
             .realm_repository
             .update_realm(input.realm_name, input.name)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        Ok(realm)
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::Realm,
+                OperationType::Update,
+            )
+            .with_resource(realm.id)
+            .with_changes(&realm, &updated),
+        )
+        .await;
+
+        Ok(updated)
     }
 
     async fn update_realm_setting(
@@ -279,16 +322,76 @@ impl RealmService for FerriskeyService {
         let realm_id = realm.id;
 
         ensure_policy(
-            self.policy.can_update_realm(identity, realm).await,
+            self.policy.can_update_realm(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
+        if let Some(algorithm) = &input.algorithm {
+            SigningAlgorithm::try_from(algorithm.clone()).map_err(|_| CoreError::Invalid)?;
+        }
+
+        if let Some(default_locale) = &input.default_locale
+            && !EmailTemplates::is_supported(default_locale)
+        {
+            return Err(CoreError::Invalid);
+        }
+
+        if let Some(user_profile) = &input.user_profile
+            && !user_profile.is_valid()
+        {
+            return Err(CoreError::Invalid);
+        }
+
+        let previous = self.realm_setting(realm_id).await?;
+
         let realm_setting = self
             .realm_repository
-            .update_realm_setting(realm_id, input.algorithm)
+            .update_realm_setting(
+                realm_id,
+                UpdateRealmSettingRequest {
+                    default_signing_algorithm: input.algorithm,
+                    access_token_lifespan: input.access_token_lifespan,
+                    refresh_token_lifespan: input.refresh_token_lifespan,
+                    session_lifespan: input.session_lifespan,
+                    auth_code_lifespan: input.auth_code_lifespan,
+                    refresh_token_rotation: input.refresh_token_rotation,
+                    key_rotation_interval: input.key_rotation_interval,
+                    key_retirement_delay: input.key_retirement_delay,
+                    password_policy: input.password_policy,
+                    brute_force_protection: input.brute_force_protection,
+                    user_profile: input.user_profile,
+                    default_locale: input.default_locale,
+                    registration_allowed: input.registration_allowed,
+                    verify_email: input.verify_email,
+                    webhook_failure_threshold: input.webhook_failure_threshold,
+                    login_event_retention: input.login_event_retention,
+                    admin_event_retention: input.admin_event_retention,
+                },
+            )
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        // The previous key keeps verifying the tokens it issued as a passive
+        // key.
+        if realm_setting.signing_algorithm() != previous.signing_algorithm() {
+            self.keystore_repository
+                .rotate_key(realm_id, realm_setting.signing_algorithm())
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+        }
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RealmSettings,
+                OperationType::Update,
+            )
+            .with_resource(realm_setting.id)
+            .with_changes(&previous, &realm_setting),
+        )
+        .await;
+
         Ok(realm_setting)
     }
 
@@ -305,7 +408,9 @@ impl RealmService for FerriskeyService {
             .ok_or(CoreError::InvalidRealm)?;
 
         ensure_policy(
-            self.policy.can_delete_realm(identity, realm).await,
+            self.policy
+                .can_delete_realm(identity.clone(), realm.clone())
+                .await,
             "insufficient permissions",
         )?;
 
@@ -314,6 +419,147 @@ impl RealmService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                identity.realm_id(),
+                &identity,
+                ResourceType::Realm,
+                OperationType::Delete,
+            )
+            .with_resource(realm.id)
+            .with_deleted(&realm),
+        )
+        .await;
+
         Ok(())
     }
+
+    async fn get_realm_keys(
+        &self,
+        identity: Identity,
+        input: GetRealmKeysInput,
+    ) -> Result<Vec<RealmKey>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_realm(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let keys = self
+            .keystore_repository
+            .list_keys(realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(keys.into_iter().map(RealmKey::from).collect())
+    }
+
+    async fn rotate_realm_key(
+        &self,
+        identity: Identity,
+        input: RotateRealmKeyInput,
+    ) -> Result<RealmKey, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_realm(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        let algorithm = self.realm_setting(realm_id).await?.signing_algorithm();
+
+        let key: RealmKey = self
+            .keystore_repository
+            .rotate_key(realm_id, algorithm)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into();
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RealmKey,
+                OperationType::Action,
+            )
+            .with_resource(key.id)
+            .with_created(&key),
+        )
+        .await;
+
+        Ok(key)
+    }
+
+    async fn retire_realm_key(
+        &self,
+        identity: Identity,
+        input: RetireRealmKeyInput,
+    ) -> Result<RealmKey, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_realm(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        let key = self
+            .keystore_repository
+            .get_key(realm_id, input.key_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::NotFound)?;
+
+        // A new key must take over signing before the active one is retired.
+        if key.status == KeyStatus::Active {
+            let algorithm = self.realm_setting(realm_id).await?.signing_algorithm();
+
+            self.keystore_repository
+                .rotate_key(realm_id, algorithm)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+        }
+
+        let retired: RealmKey = self
+            .keystore_repository
+            .retire_key(realm_id, input.key_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into();
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RealmKey,
+                OperationType::Action,
+            )
+            .with_resource(retired.id)
+            .with_changes(&RealmKey::from(key), &retired),
+        )
+        .await;
+
+        Ok(retired)
+    }
 }
@@ -1,18 +1,115 @@
+use serde_json::json;
+use uuid::Uuid;
+
 use crate::{
     application::common::{FerriskeyService, policies::ensure_policy},
     domain::{
         authentication::value_objects::Identity,
         common::entities::app_errors::CoreError,
-        realm::ports::RealmRepository,
+        event::entities::{AdminEvent, OperationType, ResourceType},
+        realm::{entities::Realm, ports::RealmRepository},
         role::{
-            entities::{GetUserRolesInput, Role, UpdateRoleInput},
+            entities::{
+                CompositeRoleInput, DefaultRoleInput, GetCompositeRolesInput, GetUserRolesInput,
+                Role, UpdateRoleInput,
+            },
             ports::{RolePolicy, RoleRepository, RoleService},
-            value_objects::{UpdateRolePermissionsRequest, UpdateRoleRequest},
+            services::expand_composites,
+            value_objects::{CreateRoleRequest, UpdateRolePermissionsRequest, UpdateRoleRequest},
         },
         user::ports::UserRoleRepository,
     },
 };
 
+impl FerriskeyService {
+    async fn role_realm(&self, realm_name: String) -> Result<Realm, CoreError> {
+        self.realm_repository
+            .get_by_name(realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)
+    }
+
+    async fn realm_role(&self, realm_id: Uuid, role_id: Uuid) -> Result<Role, CoreError> {
+        self.role_repository
+            .get_by_id(role_id)
+            .await?
+            .filter(|role| role.realm_id == realm_id)
+            .ok_or(CoreError::NotFound)
+    }
+
+    /// Includes `child_role_id` in `role_id`, refusing links that would make
+    /// a role include itself.
+    async fn link_composite(
+        &self,
+        realm_id: Uuid,
+        role_id: Uuid,
+        child_role_id: Uuid,
+    ) -> Result<(), CoreError> {
+        self.realm_role(realm_id, role_id).await?;
+        let child = self.realm_role(realm_id, child_role_id).await?;
+
+        let reachable = expand_composites(&self.role_repository, vec![child]).await?;
+        if reachable.iter().any(|role| role.id == role_id) {
+            return Err(CoreError::Invalid);
+        }
+
+        self.role_repository
+            .add_composite(role_id, child_role_id)
+            .await
+    }
+
+    /// Returns the default role of the realm, creating it when missing.
+    pub(crate) async fn provision_default_role(&self, realm: &Realm) -> Result<Role, CoreError> {
+        if let Some(role_id) = realm.default_role_id
+            && let Some(role) = self.role_repository.get_by_id(role_id).await?
+        {
+            return Ok(role);
+        }
+
+        let name = Role::default_role_name(&realm.name);
+        let role = match self
+            .role_repository
+            .find_by_name(name.clone(), realm.id)
+            .await?
+        {
+            Some(role) => role,
+            None => {
+                self.role_repository
+                    .create(CreateRoleRequest {
+                        name,
+                        description: Some(
+                            "Roles assigned to every new user of the realm".to_string(),
+                        ),
+                        permissions: Vec::new(),
+                        realm_id: realm.id,
+                        client_id: None,
+                    })
+                    .await?
+            }
+        };
+
+        self.realm_repository
+            .set_default_role(realm.id, role.id)
+            .await?;
+
+        Ok(role)
+    }
+
+    /// Assigns the default role of the realm to a newly created user.
+    pub(crate) async fn assign_default_roles(
+        &self,
+        realm: &Realm,
+        user_id: Uuid,
+    ) -> Result<(), CoreError> {
+        let role = self.provision_default_role(realm).await?;
+
+        self.user_role_repository
+            .assign_role(user_id, role.id)
+            .await
+    }
+}
+
 impl RoleService for FerriskeyService {
     async fn delete_role(
         &self,
@@ -27,16 +124,37 @@ impl RoleService for FerriskeyService {
             .map_err(|_| CoreError::InternalServerError)?
             .ok_or(CoreError::InternalServerError)?;
 
+        let realm_id = realm.id;
+        let default_role_id = realm.default_role_id;
+
         ensure_policy(
-            self.policy.can_delete_role(identity, realm).await,
+            self.policy.can_delete_role(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
+        if default_role_id == Some(role_id) {
+            return Err(CoreError::Invalid);
+        }
+
+        let role = self.realm_role(realm_id, role_id).await?;
+
         self.role_repository
-            .delete_by_id(role_id)
+            .delete_by_id(role.id)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Role,
+                OperationType::Delete,
+            )
+            .with_resource(role.id)
+            .with_deleted(&role),
+        )
+        .await;
+
         Ok(())
     }
 
@@ -101,15 +219,19 @@ impl RoleService for FerriskeyService {
             .map_err(|_| CoreError::InternalServerError)?
             .ok_or(CoreError::InternalServerError)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_role(identity, realm).await,
+            self.policy.can_update_role(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
+        let previous = self.realm_role(realm_id, input.role_id).await?;
+
         let role = self
             .role_repository
             .update_by_id(
-                input.role_id,
+                previous.id,
                 UpdateRoleRequest {
                     description: input.description,
                     name: input.name,
@@ -118,6 +240,18 @@ impl RoleService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Role,
+                OperationType::Update,
+            )
+            .with_resource(role.id)
+            .with_changes(&previous, &role),
+        )
+        .await;
+
         Ok(role)
     }
 
@@ -135,17 +269,33 @@ impl RoleService for FerriskeyService {
             .map_err(|_| CoreError::InternalServerError)?
             .ok_or(CoreError::InternalServerError)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_role(identity, realm).await,
+            self.policy.can_update_role(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
+        let previous = self.realm_role(realm_id, role_id).await?;
+
         let role = self
             .role_repository
-            .update_permissions_by_id(role_id, UpdateRolePermissionsRequest { permissions })
+            .update_permissions_by_id(previous.id, UpdateRolePermissionsRequest { permissions })
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Role,
+                OperationType::Update,
+            )
+            .with_resource(role.id)
+            .with_changes(&previous, &role),
+        )
+        .await;
+
         Ok(role)
     }
 
@@ -171,6 +321,175 @@ impl RoleService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)
     }
+
+    async fn get_composite_roles(
+        &self,
+        identity: Identity,
+        input: GetCompositeRolesInput,
+    ) -> Result<Vec<Role>, CoreError> {
+        let realm = self.role_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_view_role(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.realm_role(realm_id, input.role_id).await?;
+
+        self.role_repository.get_composites(&[input.role_id]).await
+    }
+
+    async fn add_composite_role(
+        &self,
+        identity: Identity,
+        input: CompositeRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.role_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_role(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.link_composite(realm_id, input.role_id, input.child_role_id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RoleComposite,
+                OperationType::Create,
+            )
+            .with_resource(input.role_id)
+            .with_created(&json!({ "child_role_id": input.child_role_id })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn remove_composite_role(
+        &self,
+        identity: Identity,
+        input: CompositeRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.role_realm(input.realm_name).await?;
+        let realm_id = realm.id;
+
+        ensure_policy(
+            self.policy.can_update_role(identity.clone(), realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.realm_role(realm_id, input.role_id).await?;
+
+        self.role_repository
+            .remove_composite(input.role_id, input.child_role_id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::RoleComposite,
+                OperationType::Delete,
+            )
+            .with_resource(input.role_id)
+            .with_deleted(&json!({ "child_role_id": input.child_role_id })),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn get_default_roles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> Result<Vec<Role>, CoreError> {
+        let realm = self.role_realm(realm_name).await?;
+
+        ensure_policy(
+            self.policy.can_view_role(identity, realm.clone()).await,
+            "insufficient permissions",
+        )?;
+
+        let default_role = self.provision_default_role(&realm).await?;
+
+        self.role_repository
+            .get_composites(&[default_role.id])
+            .await
+    }
+
+    async fn add_default_role(
+        &self,
+        identity: Identity,
+        input: DefaultRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.role_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_update_role(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let default_role = self.provision_default_role(&realm).await?;
+
+        self.link_composite(realm.id, default_role.id, input.role_id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::DefaultRole,
+                OperationType::Create,
+            )
+            .with_resource(input.role_id),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn remove_default_role(
+        &self,
+        identity: Identity,
+        input: DefaultRoleInput,
+    ) -> Result<(), CoreError> {
+        let realm = self.role_realm(input.realm_name).await?;
+
+        ensure_policy(
+            self.policy
+                .can_update_role(identity.clone(), realm.clone())
+                .await,
+            "insufficient permissions",
+        )?;
+
+        let default_role = self.provision_default_role(&realm).await?;
+
+        self.role_repository
+            .remove_composite(default_role.id, input.role_id)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm.id,
+                &identity,
+                ResourceType::DefaultRole,
+                OperationType::Delete,
+            )
+            .with_resource(input.role_id),
+        )
+        .await;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -181,14 +500,21 @@ mod tests {
         application::common::FerriskeyService,
         domain::{
             authentication::value_objects::Identity,
-            common::{DatabaseConfig, FerriskeyConfig, entities::app_errors::CoreError},
+            common::{
+                DatabaseConfig, EmailConfig, FerriskeyConfig, entities::app_errors::CoreError,
+            },
             realm::{entities::Realm, ports::RealmRepository},
             role::{
                 entities::Role,
                 ports::{RoleRepository, RoleService},
+                services::expand_composites,
                 value_objects::CreateRoleRequest,
             },
-            user::{entities::User, ports::UserRepository, value_objects::CreateUserRequest},
+            user::{
+                entities::{User, UserAttributes},
+                ports::UserRepository,
+                value_objects::CreateUserRequest,
+            },
         },
     };
 
@@ -209,6 +535,7 @@ mod tests {
                 password,
                 name,
             },
+            email: EmailConfig::default(),
         };
 
         FerriskeyService::new(config)
@@ -238,6 +565,7 @@ mod tests {
                 lastname: "User".to_string(),
                 realm_id,
                 client_id: None,
+                attributes: UserAttributes::new(),
             })
             .await
             .expect("Failed to create test user")
@@ -289,4 +617,35 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_composite_roles_expand_transitively_without_cycles() {
+        let service = setup_test_service().await;
+        let realm = create_test_realm(&service).await;
+        let parent = create_test_role(&service, realm.id).await;
+        let child = create_test_role(&service, realm.id).await;
+        let grandchild = create_test_role(&service, realm.id).await;
+
+        service
+            .link_composite(realm.id, parent.id, child.id)
+            .await
+            .expect("Failed to link child role");
+        service
+            .link_composite(realm.id, child.id, grandchild.id)
+            .await
+            .expect("Failed to link grandchild role");
+
+        let roles = expand_composites(&service.role_repository, vec![parent.clone()])
+            .await
+            .expect("Failed to expand composite roles");
+
+        assert_eq!(roles.len(), 3);
+        assert!(roles.iter().any(|role| role.id == grandchild.id));
+
+        let cycle = service
+            .link_composite(realm.id, grandchild.id, parent.id)
+            .await;
+
+        assert!(matches!(cycle, Err(CoreError::Invalid)));
+    }
 }
@@ -9,10 +9,15 @@ use uuid::Uuid;
 use crate::{
     application::common::FerriskeyService,
     domain::{
-        authentication::{ports::AuthSessionRepository, value_objects::Identity},
+        authentication::{
+            entities::{AuthEvent, AuthFailureReason},
+            ports::AuthSessionRepository,
+            services::auth_events::notify_auth_event,
+            value_objects::Identity,
+        },
+        client::ports::ClientRepository,
         common::{entities::app_errors::CoreError, generate_random_string},
         credential::{entities::Credential, ports::CredentialRepository},
-        crypto::ports::HasherRepository,
         trident::{
             entities::TotpSecret,
             ports::{
@@ -22,7 +27,11 @@ use crate::{
                 UpdatePasswordInput, VerifyOtpInput, VerifyOtpOutput,
             },
         },
-        user::{entities::RequiredAction, ports::UserRequiredActionRepository},
+        user::{
+            entities::{RequiredAction, User},
+            ports::UserRequiredActionRepository,
+        },
+        webhook::entities::webhook_trigger::WebhookTrigger,
     },
     infrastructure::recovery_code::formatters::RecoveryCodeFormat,
 };
@@ -100,6 +109,30 @@ fn verify(secret: &TotpSecret, code: &str) -> Result<bool, CoreError> {
     Ok(false)
 }
 
+impl FerriskeyService {
+    /// Event about `user`, authenticating to the client of the session
+    /// `client_id` refers to.
+    async fn session_auth_event(
+        &self,
+        user: &User,
+        client_id: Uuid,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> AuthEvent {
+        let client_id = self
+            .client_repository
+            .get_by_id(client_id)
+            .await
+            .ok()
+            .map(|client| client.client_id);
+
+        AuthEvent {
+            client_id,
+            ..AuthEvent::new(ip_address, user_agent).with_user(user)
+        }
+    }
+}
+
 impl TridentService for FerriskeyService {
     async fn generate_recovery_code(
         &self,
@@ -231,10 +264,54 @@ impl TridentService for FerriskeyService {
                 CoreError::InternalServerError
             })?;
 
+        let event = self
+            .session_auth_event(
+                &user,
+                auth_session.client_id,
+                input.ip_address,
+                input.user_agent,
+            )
+            .await;
+
+        notify_auth_event(
+            &self.event_repository,
+            &self.webhook_repository,
+            &self.webhook_notifier_repository,
+            user.realm_id,
+            WebhookTrigger::AuthRecoveryCodeBurned,
+            event.clone(),
+        )
+        .await;
+        notify_auth_event(
+            &self.event_repository,
+            &self.webhook_repository,
+            &self.webhook_notifier_repository,
+            user.realm_id,
+            WebhookTrigger::AuthLoginSucceeded,
+            event,
+        )
+        .await;
+
+        let amr = vec!["pwd".to_string(), "otp".to_string()];
+
+        if !self
+            .authenticate_factory
+            .missing_consent(&auth_session, user.id)
+            .await?
+            .is_empty()
+        {
+            self.auth_session_repository
+                .mark_authenticated(session_code, user.id, amr)
+                .await
+                .map_err(|e| CoreError::RecoveryCodeBurnError(e.to_string()))?;
+
+            return Ok(BurnRecoveryCodeOutput { login_url: None });
+        }
+
         let authorization_code = generate_random_string();
 
         self.auth_session_repository
-            .update_code_and_user_id(session_code, authorization_code.clone(), user.id)
+            .update_code_and_user_id(session_code, authorization_code.clone(), user.id, amr)
             .await
             .map_err(|e| CoreError::TotpVerificationFailed(e.to_string()))?;
 
@@ -247,7 +324,9 @@ impl TridentService for FerriskeyService {
             auth_session.redirect_uri, authorization_code, current_state
         );
 
-        Ok(BurnRecoveryCodeOutput { login_url })
+        Ok(BurnRecoveryCodeOutput {
+            login_url: Some(login_url),
+        })
     }
 
     async fn challenge_otp(
@@ -286,17 +365,63 @@ impl TridentService for FerriskeyService {
 
         let is_valid = verify(&secret, &input.code)?;
 
+        let event = self
+            .session_auth_event(
+                &user,
+                auth_session.client_id,
+                input.ip_address,
+                input.user_agent,
+            )
+            .await;
+
         if !is_valid {
             tracing::error!("invalid OTP code for user: {}", user.email);
+
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                user.realm_id,
+                WebhookTrigger::AuthOtpFailed,
+                event.with_reason(AuthFailureReason::InvalidOtp),
+            )
+            .await;
+
             return Err(CoreError::TotpVerificationFailed(
                 "failed to verify OTP".to_string(),
             ));
         }
 
+        notify_auth_event(
+            &self.event_repository,
+            &self.webhook_repository,
+            &self.webhook_notifier_repository,
+            user.realm_id,
+            WebhookTrigger::AuthLoginSucceeded,
+            event,
+        )
+        .await;
+
+        let amr = vec!["pwd".to_string(), "otp".to_string()];
+
+        if !self
+            .authenticate_factory
+            .missing_consent(&auth_session, user.id)
+            .await?
+            .is_empty()
+        {
+            self.auth_session_repository
+                .mark_authenticated(session_code, user.id, amr)
+                .await
+                .map_err(|e| CoreError::TotpVerificationFailed(e.to_string()))?;
+
+            return Ok(ChallengeOtpOutput { login_url: None });
+        }
+
         let authorization_code = generate_random_string();
 
         self.auth_session_repository
-            .update_code_and_user_id(session_code, authorization_code.clone(), user.id)
+            .update_code_and_user_id(session_code, authorization_code.clone(), user.id, amr)
             .await
             .map_err(|e| CoreError::TotpVerificationFailed(e.to_string()))?;
 
@@ -309,7 +434,9 @@ impl TridentService for FerriskeyService {
             auth_session.redirect_uri, authorization_code, current_state
         );
 
-        Ok(ChallengeOtpOutput { login_url })
+        Ok(ChallengeOtpOutput {
+            login_url: Some(login_url),
+        })
     }
 
     async fn setup_otp(
@@ -341,34 +468,40 @@ impl TridentService for FerriskeyService {
             _ => return Err(CoreError::Forbidden("is not user".to_string())),
         };
 
-        let password_credential = self
-            .credential_repository
-            .get_password_credential(user.id)
-            .await;
-
-        if password_credential.is_ok() {
-            self.credential_repository
-                .delete_password_credential(user.id)
-                .await
-                .map_err(|_| CoreError::DeleteCredentialError)?;
-        }
-
-        let hash_result = self
-            .hasher_repository
-            .hash_password(&input.value)
-            .await
-            .map_err(|e| CoreError::HashPasswordError(e.to_string()))?;
-
-        self.credential_repository
-            .create_credential(user.id, "password".into(), hash_result, "".into(), false)
-            .await
-            .map_err(|_| CoreError::CreateCredentialError)?;
+        self.set_password(&user, &input.value, false).await?;
 
         self.user_required_action_repository
             .remove_required_action(user.id, RequiredAction::UpdatePassword)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        let event = AuthEvent::new(input.ip_address, input.user_agent).with_user(&user);
+
+        notify_auth_event(
+            &self.event_repository,
+            &self.webhook_repository,
+            &self.webhook_notifier_repository,
+            user.realm_id,
+            WebhookTrigger::AuthPasswordUpdated,
+            event.clone(),
+        )
+        .await;
+
+        if user
+            .required_actions
+            .contains(&RequiredAction::UpdatePassword)
+        {
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                user.realm_id,
+                WebhookTrigger::AuthRequiredActionCompleted,
+                event.with_required_action(RequiredAction::UpdatePassword),
+            )
+            .await;
+        }
+
         Ok(())
     }
 
@@ -422,6 +555,33 @@ impl TridentService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        let event = AuthEvent::new(input.ip_address, input.user_agent).with_user(&user);
+
+        notify_auth_event(
+            &self.event_repository,
+            &self.webhook_repository,
+            &self.webhook_notifier_repository,
+            user.realm_id,
+            WebhookTrigger::AuthOtpConfigured,
+            event.clone(),
+        )
+        .await;
+
+        if user
+            .required_actions
+            .contains(&RequiredAction::ConfigureOtp)
+        {
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                user.realm_id,
+                WebhookTrigger::AuthRequiredActionCompleted,
+                event.with_required_action(RequiredAction::ConfigureOtp),
+            )
+            .await;
+        }
+
         Ok(VerifyOtpOutput {
             message: "OTP verified successfully".to_string(),
             user_id: user.id,
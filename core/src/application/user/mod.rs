@@ -1,3 +1,4 @@
+use serde_json::json;
 use uuid::Uuid;
 
 use crate::{
@@ -7,14 +8,15 @@ use crate::{
     domain::{
         authentication::value_objects::Identity,
         common::{entities::app_errors::CoreError, policies::Policy},
-        credential::ports::CredentialRepository,
-        crypto::ports::HasherRepository,
+        event::entities::{AdminEvent, OperationType, ResourceType},
         realm::{entities::Realm, ports::RealmRepository},
         role::entities::permission::Permissions,
         user::{
             entities::{
-                AssignRoleInput, CreateUserInput, GetUserInput, RequiredAction, ResetPasswordInput,
-                UnassignRoleInput, UpdateUserInput, User,
+                AssignRoleInput, AttributeEditor, CreateUserInput, GetUserInput, GetUsersInput,
+                MAX_USER_PAGE_SIZE, RequiredAction, ResetPasswordInput, UnassignRoleInput,
+                UpdateUserAttributesInput, UpdateUserInput, User, UserAttributes, UserPage,
+                UserQuery,
             },
             ports::{
                 UserPolicy, UserRepository, UserRequiredActionRepository, UserRoleRepository,
@@ -41,8 +43,10 @@ impl UserService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_user(identity, realm).await,
+            self.policy.can_update_user(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -52,6 +56,17 @@ impl UserService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::User,
+                OperationType::Delete,
+            )
+            .with_resource(user_id),
+        )
+        .await;
+
         Ok(count)
     }
 
@@ -67,39 +82,36 @@ impl UserService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_user(identity, realm).await,
+            self.policy.can_update_user(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
-        let password_credential = self
-            .credential_repository
-            .get_password_credential(input.user_id)
-            .await;
+        let user = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
 
-        if password_credential.is_ok() {
-            self.credential_repository
-                .delete_password_credential(input.user_id)
-                .await
-                .map_err(|_| CoreError::DeletePasswordCredentialError)?;
+        if user.realm_id != realm_id {
+            return Err(CoreError::InvalidUser);
         }
 
-        let hash_result = self
-            .hasher_repository
-            .hash_password(&input.password)
-            .await
-            .map_err(|e| CoreError::HashPasswordError(e.to_string()))?;
-
-        self.credential_repository
-            .create_credential(
-                input.user_id,
-                "password".into(),
-                hash_result,
-                "".into(),
-                input.temporary,
+        self.set_password(&user, &input.password, input.temporary)
+            .await?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::UserCredential,
+                OperationType::Update,
             )
-            .await
-            .map_err(|_| CoreError::CreateCredentialError)?;
+            .with_resource(user.id),
+        )
+        .await;
 
         // @TODO: webhook call action
 
@@ -117,15 +129,37 @@ impl UserService for FerriskeyService {
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_user(identity, realm).await,
+            self.policy.can_update_user(identity.clone(), realm).await,
             "You are not allowed to view users in this realm.",
         )?;
 
+        let previous = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
+
+        if previous.realm_id != realm_id {
+            return Err(CoreError::InvalidUser);
+        }
+
+        if let Some(attributes) = &input.attributes {
+            self.ensure_user_profile(
+                realm_id,
+                attributes,
+                &previous.attributes,
+                AttributeEditor::Admin,
+            )
+            .await?;
+        }
+
         let user = self
             .user_repository
             .update_user(
-                input.user_id,
+                previous.id,
                 UpdateUserRequest {
                     email: input.email,
                     email_verified: input.email_verified.unwrap_or(false),
@@ -133,6 +167,7 @@ impl UserService for FerriskeyService {
                     firstname: input.firstname,
                     lastname: input.lastname,
                     required_actions: None,
+                    attributes: input.attributes,
                 },
             )
             .await
@@ -154,17 +189,29 @@ impl UserService for FerriskeyService {
             }
         }
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::User,
+                OperationType::Update,
+            )
+            .with_resource(user.id)
+            .with_changes(&previous, &user),
+        )
+        .await;
+
         Ok(user)
     }
 
     async fn get_users(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> Result<Vec<User>, CoreError> {
+        input: GetUsersInput,
+    ) -> Result<UserPage, CoreError> {
         let realm = self
             .realm_repository
-            .get_by_name(realm_name)
+            .get_by_name(input.realm_name)
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
@@ -175,10 +222,12 @@ impl UserService for FerriskeyService {
             "You are not allowed to view users in this realm.",
         )?;
 
-        self.user_repository
-            .find_by_realm_id(realm_id)
-            .await
-            .map_err(|_| CoreError::InternalServerError)
+        let query = UserQuery {
+            limit: input.query.limit.clamp(1, MAX_USER_PAGE_SIZE),
+            ..input.query
+        };
+
+        self.user_repository.search_users(realm_id, query).await
     }
 
     async fn assign_role(
@@ -192,8 +241,10 @@ impl UserService for FerriskeyService {
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_user(identity, realm).await,
+            self.policy.can_update_user(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -202,6 +253,18 @@ impl UserService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::UserRoleMapping,
+                OperationType::Create,
+            )
+            .with_resource(input.user_id)
+            .with_created(&json!({ "role_id": input.role_id })),
+        )
+        .await;
+
         Ok(())
     }
 
@@ -216,17 +279,32 @@ impl UserService for FerriskeyService {
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_delete_user(identity, realm).await,
+            self.policy.can_delete_user(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
         let count = self
             .user_repository
-            .bulk_delete_user(input.ids)
+            .bulk_delete_user(input.ids.clone())
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        for user_id in input.ids {
+            self.record_admin_event(
+                AdminEvent::new(
+                    realm_id,
+                    &identity,
+                    ResourceType::User,
+                    OperationType::Delete,
+                )
+                .with_resource(user_id),
+            )
+            .await;
+        }
+
         Ok(count)
     }
 
@@ -243,10 +321,21 @@ impl UserService for FerriskeyService {
 
         let realm_id = realm.id;
         ensure_policy(
-            self.policy.can_create_user(identity, realm.clone()).await,
+            self.policy
+                .can_create_user(identity.clone(), realm.clone())
+                .await,
             "insufficient permissions",
         )?;
 
+        let attributes = input.attributes.unwrap_or_default();
+        self.ensure_user_profile(
+            realm_id,
+            &attributes,
+            &UserAttributes::new(),
+            AttributeEditor::Admin,
+        )
+        .await?;
+
         let mut user = self
             .user_repository
             .create_user(CreateUserRequest {
@@ -258,12 +347,27 @@ impl UserService for FerriskeyService {
                 email: input.email,
                 email_verified: input.email_verified.unwrap_or(false),
                 enabled: true,
+                attributes,
             })
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.assign_default_roles(&realm, user.id).await?;
+
         user.realm = Some(realm);
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::User,
+                OperationType::Create,
+            )
+            .with_resource(user.id)
+            .with_created(&user),
+        )
+        .await;
+
         Ok(user)
     }
 
@@ -296,8 +400,10 @@ impl UserService for FerriskeyService {
             .await?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_update_user(identity, realm).await,
+            self.policy.can_update_user(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -306,8 +412,93 @@ impl UserService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::UserRoleMapping,
+                OperationType::Delete,
+            )
+            .with_resource(input.user_id)
+            .with_deleted(&json!({ "role_id": input.role_id })),
+        )
+        .await;
+
         Ok(())
     }
+
+    async fn update_user_attributes(
+        &self,
+        identity: Identity,
+        input: UpdateUserAttributesInput,
+    ) -> Result<User, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+
+        let user = self
+            .user_repository
+            .get_by_id(input.user_id)
+            .await
+            .map_err(|_| CoreError::InvalidUser)?;
+
+        if user.realm_id != realm_id {
+            return Err(CoreError::InvalidUser);
+        }
+
+        let is_self = matches!(&identity, Identity::User(current) if current.id == user.id);
+
+        let editor = if self
+            .policy
+            .can_update_user(identity.clone(), realm)
+            .await
+            .unwrap_or(false)
+        {
+            AttributeEditor::Admin
+        } else if is_self {
+            AttributeEditor::User
+        } else {
+            return Err(CoreError::Forbidden("insufficient permissions".to_string()));
+        };
+
+        self.ensure_user_profile(realm_id, &input.attributes, &user.attributes, editor)
+            .await?;
+
+        let updated = self
+            .user_repository
+            .update_user(
+                user.id,
+                UpdateUserRequest {
+                    firstname: user.firstname.clone(),
+                    lastname: user.lastname.clone(),
+                    email: user.email.clone(),
+                    email_verified: user.email_verified,
+                    enabled: user.enabled,
+                    required_actions: None,
+                    attributes: Some(input.attributes),
+                },
+            )
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::User,
+                OperationType::Update,
+            )
+            .with_resource(user.id)
+            .with_changes(&user, &updated),
+        )
+        .await;
+
+        Ok(updated)
+    }
 }
 
 impl UserPolicy for FerriskeyPolicy {
@@ -4,13 +4,170 @@ use crate::{
         authentication::value_objects::Identity,
         common::entities::app_errors::CoreError,
         credential::{
-            entities::{CredentialOverview, GetCredentialsInput},
+            entities::{
+                Credential, CredentialOverview, GetCredentialsInput, PasswordPolicyViolation,
+            },
             ports::{CredentialRepository, CredentialService},
         },
+        crypto::ports::HasherRepository,
+        event::entities::{AdminEvent, OperationType, ResourceType},
         realm::ports::RealmRepository,
-        user::ports::UserPolicy,
+        user::{
+            entities::{AttributeEditor, User, UserAttributes},
+            ports::UserPolicy,
+        },
     },
 };
+use uuid::Uuid;
+
+impl FerriskeyService {
+    /// Checks the attributes `editor` wants to replace `previous` with
+    /// against the user profile of the realm.
+    pub(crate) async fn ensure_user_profile(
+        &self,
+        realm_id: Uuid,
+        attributes: &UserAttributes,
+        previous: &UserAttributes,
+        editor: AttributeEditor,
+    ) -> Result<(), CoreError> {
+        let profile = self.realm_setting(realm_id).await?.user_profile;
+
+        let violations = profile.validate(attributes, previous, editor);
+
+        if !violations.is_empty() {
+            return Err(CoreError::UserAttributeViolation(violations));
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the password of `user`, once it satisfies the password policy
+    /// of the user's realm.
+    pub(crate) async fn set_password(
+        &self,
+        user: &User,
+        password: &str,
+        temporary: bool,
+    ) -> Result<(), CoreError> {
+        self.check_password(user, password).await?;
+        self.store_password(user, password, temporary).await
+    }
+
+    /// Checks `password` against the password policy of the user's realm,
+    /// including the passwords the user recently used.
+    pub(crate) async fn check_password(
+        &self,
+        user: &User,
+        password: &str,
+    ) -> Result<(), CoreError> {
+        let policy = self.realm_setting(user.realm_id).await?.password_policy;
+
+        let mut violations = policy.validate(password, &user.username, &user.email);
+
+        let current = self
+            .credential_repository
+            .get_password_credential(user.id)
+            .await
+            .ok();
+
+        if policy.history > 0 {
+            let history = self
+                .credential_repository
+                .get_password_history(user.id)
+                .await
+                .map_err(|_| CoreError::GetUserCredentialsError)?;
+
+            for credential in current.iter().chain(&history).take(policy.history as usize) {
+                if self.password_matches(password, credential).await? {
+                    violations.push(PasswordPolicyViolation::RecentlyUsed(policy.history));
+                    break;
+                }
+            }
+        }
+
+        if !violations.is_empty() {
+            return Err(CoreError::PasswordPolicyViolation(violations));
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the password of `user` without checking it, for callers that
+    /// already went through `check_password`.
+    pub(crate) async fn store_password(
+        &self,
+        user: &User,
+        password: &str,
+        temporary: bool,
+    ) -> Result<(), CoreError> {
+        let policy = self.realm_setting(user.realm_id).await?.password_policy;
+
+        let current = self
+            .credential_repository
+            .get_password_credential(user.id)
+            .await
+            .ok();
+
+        if current.is_some() {
+            // The current password counts towards the history
+            if policy.history > 1 {
+                self.credential_repository
+                    .archive_password_credential(user.id)
+                    .await
+                    .map_err(|_| CoreError::DeletePasswordCredentialError)?;
+            } else {
+                self.credential_repository
+                    .delete_password_credential(user.id)
+                    .await
+                    .map_err(|_| CoreError::DeletePasswordCredentialError)?;
+            }
+        }
+
+        let hash_result = self
+            .hasher_repository
+            .hash_password(password)
+            .await
+            .map_err(|e| CoreError::HashPasswordError(e.to_string()))?;
+
+        self.credential_repository
+            .create_credential(
+                user.id,
+                "password".into(),
+                hash_result,
+                "".into(),
+                temporary,
+            )
+            .await
+            .map_err(|_| CoreError::CreateCredentialError)?;
+
+        self.credential_repository
+            .prune_password_history(user.id, policy.history.saturating_sub(1) as usize)
+            .await
+            .map_err(|_| CoreError::DeleteCredentialError)?;
+
+        Ok(())
+    }
+
+    async fn password_matches(
+        &self,
+        password: &str,
+        credential: &Credential,
+    ) -> Result<bool, CoreError> {
+        let Some(salt) = &credential.salt else {
+            return Ok(false);
+        };
+
+        self.hasher_repository
+            .verify_password(
+                password,
+                &credential.secret_data,
+                &credential.credential_data,
+                salt,
+            )
+            .await
+            .map_err(|e| CoreError::VerifyPasswordError(e.to_string()))
+    }
+}
 
 impl CredentialService for FerriskeyService {
     async fn get_credentials(
@@ -54,8 +211,10 @@ impl CredentialService for FerriskeyService {
             .map_err(|_| CoreError::InvalidRealm)?
             .ok_or(CoreError::InvalidRealm)?;
 
+        let realm_id = realm.id;
+
         ensure_policy(
-            self.policy.can_delete_user(identity, realm).await,
+            self.policy.can_delete_user(identity.clone(), realm).await,
             "insufficient permissions",
         )?;
 
@@ -64,6 +223,17 @@ impl CredentialService for FerriskeyService {
             .await
             .map_err(|_| CoreError::DeleteCredentialError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::UserCredential,
+                OperationType::Delete,
+            )
+            .with_resource(input.credential_id),
+        )
+        .await;
+
         // @TODO: implement webhook notifier
 
         Ok(())
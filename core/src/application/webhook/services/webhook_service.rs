@@ -1,3 +1,6 @@
+use chrono::{DateTime, Duration, Utc};
+use futures::future::join_all;
+use tracing::{error, warn};
 use uuid::Uuid;
 
 use crate::{
@@ -5,20 +8,143 @@ use crate::{
     domain::{
         authentication::value_objects::Identity,
         common::entities::app_errors::CoreError,
+        event::entities::{AdminEvent, OperationType, ResourceType},
         realm::ports::RealmRepository,
         webhook::{
             entities::{
-                webhook::Webhook, webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger,
+                webhook::{MASKED_HEADER_VALUE, Webhook, validate_webhook_options},
+                webhook_delivery::{
+                    WEBHOOK_DELIVERY_HISTORY_LIMIT, WebhookDelivery, WebhookDeliveryStatus,
+                },
+                webhook_payload::WebhookPayload,
+                webhook_trigger::WebhookTrigger,
             },
             ports::{
-                CreateWebhookInput, DeleteWebhookInput, GetWebhookInput,
-                GetWebhookSubscribersInput, GetWebhooksInput, UpdateWebhookInput,
+                CreateWebhookInput, DeleteWebhookInput, GetWebhookDeliveriesInput, GetWebhookInput,
+                GetWebhookSubscribersInput, GetWebhooksInput, RedeliverWebhookInput,
+                TestWebhookInput, UpdateWebhookInput, WebhookDeliveryRepository,
                 WebhookNotifierRepository, WebhookPolicy, WebhookRepository, WebhookService,
             },
         },
     },
 };
 
+/// Deliveries attempted at once by each run of the delivery worker.
+const DELIVERY_BATCH_SIZE: u64 = 20;
+
+/// How long a claimed delivery is hidden from other workers; longer than the
+/// timeout of an attempt.
+const DELIVERY_LEASE: Duration = Duration::minutes(2);
+
+impl FerriskeyService {
+    /// Makes one attempt at a queued `delivery` and records its outcome.
+    async fn attempt_delivery(&self, mut delivery: WebhookDelivery) -> Result<(), CoreError> {
+        let webhook = self
+            .webhook_repository
+            .get_webhook_by_id(delivery.webhook_id, delivery.realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        // Deliveries go away with their webhook
+        let Some(webhook) = webhook else {
+            return Ok(());
+        };
+
+        if webhook.enabled {
+            self.post_delivery(&webhook, &mut delivery).await;
+        } else {
+            delivery.abandon("webhook is disabled", Utc::now());
+        }
+
+        self.webhook_delivery_repository
+            .update_delivery(&delivery)
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
+
+    /// Posts `delivery` to `webhook` and updates the health of the webhook.
+    /// The attempt is recorded on `delivery` even when the health update
+    /// fails, so that it is stored and not posted again.
+    async fn post_delivery(&self, webhook: &Webhook, delivery: &mut WebhookDelivery) {
+        let attempt = self
+            .webhook_notifier_repository
+            .deliver(webhook, delivery)
+            .await;
+        let success = attempt.is_success();
+        let now = Utc::now();
+
+        delivery.record_attempt(attempt, now);
+
+        if let Err(e) = self.record_webhook_health(webhook, success, now).await {
+            error!("failed to update the health of webhook {}: {e}", webhook.id);
+        }
+    }
+
+    /// Records the outcome of an attempt on `webhook`, disabling it once it
+    /// failed as many times in a row as the realm allows.
+    async fn record_webhook_health(
+        &self,
+        webhook: &Webhook,
+        success: bool,
+        now: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        let webhook = self
+            .webhook_repository
+            .record_delivery_outcome(webhook.id, success, now)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if success || !webhook.enabled {
+            return Ok(());
+        }
+
+        let threshold = self
+            .realm_setting(webhook.realm_id)
+            .await?
+            .webhook_failure_threshold;
+
+        if threshold == 0 || webhook.consecutive_failures < threshold {
+            return Ok(());
+        }
+
+        let disabled = self
+            .webhook_repository
+            .disable_webhook(webhook.id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        // Another attempt may have disabled it meanwhile
+        if !disabled {
+            return Ok(());
+        }
+
+        warn!(
+            "webhook {} disabled after {} consecutive failures",
+            webhook.id, webhook.consecutive_failures
+        );
+
+        let webhooks = self
+            .webhook_repository
+            .fetch_webhooks_by_subscriber(webhook.realm_id, WebhookTrigger::WebhookDisabled)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        self.webhook_notifier_repository
+            .notify(
+                webhooks,
+                WebhookPayload::new(
+                    WebhookTrigger::WebhookDisabled,
+                    webhook.id,
+                    Some(Webhook {
+                        enabled: false,
+                        ..webhook
+                    }),
+                ),
+            )
+            .await
+    }
+}
+
 impl WebhookService for FerriskeyService {
     async fn get_webhooks_by_realm(
         &self,
@@ -116,10 +242,18 @@ impl WebhookService for FerriskeyService {
         let realm_id = realm.id;
 
         ensure_policy(
-            self.policy.can_create_webhook(identity, realm).await,
+            self.policy
+                .can_create_webhook(identity.clone(), realm)
+                .await,
             "insufficient permissions",
         )?;
 
+        validate_webhook_options(
+            &input.payload_fields,
+            input.payload_template.as_deref(),
+            &input.headers,
+        )?;
+
         let webhook = self
             .webhook_repository
             .create_webhook(
@@ -127,11 +261,28 @@ impl WebhookService for FerriskeyService {
                 input.name,
                 input.description,
                 input.endpoint,
+                input.secret.unwrap_or_else(Webhook::generate_secret),
+                input.enabled.unwrap_or(true),
                 input.subscribers,
+                input.payload_fields,
+                input.payload_template,
+                input.headers,
             )
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Webhook,
+                OperationType::Create,
+            )
+            .with_resource(webhook.id)
+            .with_created(&webhook),
+        )
+        .await;
+
         let webhooks = self
             .webhook_repository
             .fetch_webhooks_by_subscriber(realm_id, WebhookTrigger::WebhookCreated)
@@ -167,22 +318,78 @@ impl WebhookService for FerriskeyService {
         let realm_id = realm.id;
 
         ensure_policy(
-            self.policy.can_update_webhook(identity, realm).await,
+            self.policy
+                .can_update_webhook(identity.clone(), realm)
+                .await,
             "insufficient permissions",
         )?;
 
+        let previous = self
+            .webhook_repository
+            .get_webhook_by_id(input.webhook_id, realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::WebhookNotFound)?;
+
+        // Masked values are the ones the headers were listed with
+        let headers = input.headers.map(|headers| {
+            headers
+                .into_iter()
+                .filter_map(|(name, value)| match value.as_str() {
+                    MASKED_HEADER_VALUE => previous
+                        .headers
+                        .get(&name)
+                        .map(|previous| (name, previous.clone())),
+                    _ => Some((name, value)),
+                })
+                .collect()
+        });
+
+        let payload_template = input
+            .payload_template
+            .map(|template| Some(template).filter(|template| !template.is_empty()));
+
+        validate_webhook_options(
+            input
+                .payload_fields
+                .as_deref()
+                .unwrap_or(&previous.payload_fields),
+            payload_template
+                .as_ref()
+                .unwrap_or(&previous.payload_template)
+                .as_deref(),
+            headers.as_ref().unwrap_or(&previous.headers),
+        )?;
+
         let webhook = self
             .webhook_repository
             .update_webhook(
-                input.webhook_id,
+                previous.id,
                 input.name,
                 input.description,
                 input.endpoint,
+                input.secret,
+                input.enabled,
                 input.subscribers,
+                input.payload_fields,
+                payload_template,
+                headers,
             )
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Webhook,
+                OperationType::Update,
+            )
+            .with_resource(webhook.id)
+            .with_changes(&previous, &webhook),
+        )
+        .await;
+
         let webhooks = self
             .webhook_repository
             .fetch_webhooks_by_subscriber(realm_id, WebhookTrigger::WebhookCreated)
@@ -218,7 +425,9 @@ impl WebhookService for FerriskeyService {
         let realm_id = realm.id;
 
         ensure_policy(
-            self.policy.can_delete_webhook(identity, realm).await,
+            self.policy
+                .can_delete_webhook(identity.clone(), realm)
+                .await,
             "insufficient permissions",
         )?;
 
@@ -227,6 +436,17 @@ impl WebhookService for FerriskeyService {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
+        self.record_admin_event(
+            AdminEvent::new(
+                realm_id,
+                &identity,
+                ResourceType::Webhook,
+                OperationType::Delete,
+            )
+            .with_resource(input.webhook_id),
+        )
+        .await;
+
         let webhooks = self
             .webhook_repository
             .fetch_webhooks_by_subscriber(realm_id, WebhookTrigger::WebhookCreated)
@@ -242,4 +462,160 @@ impl WebhookService for FerriskeyService {
 
         Ok(())
     }
+    async fn get_webhook_deliveries(
+        &self,
+        identity: Identity,
+        input: GetWebhookDeliveriesInput,
+    ) -> Result<Vec<WebhookDelivery>, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_view_webhook(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.webhook_repository
+            .get_webhook_by_id(input.webhook_id, realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::WebhookNotFound)?;
+
+        self.webhook_delivery_repository
+            .fetch_deliveries_by_webhook(input.webhook_id, WEBHOOK_DELIVERY_HISTORY_LIMIT)
+            .await
+            .map_err(|_| CoreError::InternalServerError)
+    }
+
+    async fn redeliver_webhook(
+        &self,
+        identity: Identity,
+        input: RedeliverWebhookInput,
+    ) -> Result<WebhookDelivery, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_update_webhook(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        self.webhook_repository
+            .get_webhook_by_id(input.webhook_id, realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::WebhookNotFound)?;
+
+        let delivery = self
+            .webhook_delivery_repository
+            .get_delivery(input.delivery_id, input.webhook_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::NotFound)?
+            .redeliver();
+
+        self.webhook_delivery_repository
+            .create_deliveries(vec![delivery.clone()])
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(delivery)
+    }
+
+    async fn test_webhook(
+        &self,
+        identity: Identity,
+        input: TestWebhookInput,
+    ) -> Result<WebhookDelivery, CoreError> {
+        let realm = self
+            .realm_repository
+            .get_by_name(input.realm_name)
+            .await
+            .map_err(|_| CoreError::InvalidRealm)?
+            .ok_or(CoreError::InvalidRealm)?;
+
+        let realm_id = realm.id;
+        ensure_policy(
+            self.policy.can_update_webhook(identity, realm).await,
+            "insufficient permissions",
+        )?;
+
+        let webhook = self
+            .webhook_repository
+            .get_webhook_by_id(input.webhook_id, realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::WebhookNotFound)?;
+
+        let payload = serde_json::to_value(WebhookPayload::new(
+            WebhookTrigger::WebhookTest,
+            webhook.id,
+            Some(webhook.clone()),
+        ))
+        .map_err(|_| CoreError::InternalServerError)?;
+
+        let mut delivery =
+            WebhookDelivery::for_webhook(&webhook, WebhookTrigger::WebhookTest, &payload);
+
+        // Not attempted when the template of the webhook failed to render
+        if delivery.status == WebhookDeliveryStatus::Pending {
+            self.post_delivery(&webhook, &mut delivery).await;
+        }
+
+        // Stored once attempted, so that the delivery worker never picks it up
+        self.webhook_delivery_repository
+            .create_deliveries(vec![delivery.clone()])
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(delivery)
+    }
+
+    async fn deliver_pending_webhooks(&self) -> Result<(), CoreError> {
+        loop {
+            let deliveries = self
+                .webhook_delivery_repository
+                .claim_due_deliveries(DELIVERY_BATCH_SIZE, DELIVERY_LEASE)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+
+            let claimed = deliveries.len() as u64;
+
+            for result in join_all(
+                deliveries
+                    .into_iter()
+                    .map(|delivery| self.attempt_delivery(delivery)),
+            )
+            .await
+            {
+                if let Err(e) = result {
+                    error!("failed to record webhook delivery: {}", e);
+                }
+            }
+
+            if claimed < DELIVERY_BATCH_SIZE {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn prune_webhook_deliveries(&self) -> Result<(), CoreError> {
+        // Deliveries past the history of their webhook are never shown again
+        self.webhook_delivery_repository
+            .prune_deliveries(WEBHOOK_DELIVERY_HISTORY_LIMIT)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(())
+    }
 }
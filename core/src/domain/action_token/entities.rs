@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::user::entities::RequiredAction;
+
+/// Lifespan, in seconds, of the tokens users request for themselves.
+pub const USER_ACTION_TOKEN_LIFESPAN: u32 = 900;
+
+/// Default lifespan, in seconds, of the tokens sent by an administrator.
+pub const DEFAULT_ADMIN_ACTION_TOKEN_LIFESPAN: u32 = 43_200;
+
+/// Longest lifespan, in seconds, an administrator can give a token.
+pub const MAX_ADMIN_ACTION_TOKEN_LIFESPAN: u32 = 2_592_000;
+
+/// What the holder of an action token is allowed to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ActionTokenType {
+    /// Confirms that the user owns the email address the token was sent to.
+    VerifyEmail,
+    /// Lets the user choose a new password without knowing the current one.
+    ResetCredentials,
+    /// Completes the required actions an administrator asked for.
+    ExecuteActions,
+}
+
+impl Display for ActionTokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActionTokenType::VerifyEmail => write!(f, "verify-email"),
+            ActionTokenType::ResetCredentials => write!(f, "reset-credentials"),
+            ActionTokenType::ExecuteActions => write!(f, "execute-actions"),
+        }
+    }
+}
+
+/// Claims of an action token, signed with the active key of the realm.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionTokenClaims {
+    pub sub: Uuid,
+    pub jti: Uuid,
+    pub iss: String,
+    pub iat: i64,
+    pub exp: i64,
+    pub typ: ActionTokenType,
+    /// Address the token was sent to; verifying it fails once the user's
+    /// address has changed.
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<RequiredAction>,
+}
+
+impl ActionTokenClaims {
+    pub fn new(
+        typ: ActionTokenType,
+        user_id: Uuid,
+        email: String,
+        iss: String,
+        lifespan: u32,
+        actions: Vec<RequiredAction>,
+    ) -> Self {
+        let now = Utc::now().timestamp();
+
+        Self {
+            sub: user_id,
+            jti: Uuid::new_v4(),
+            iss,
+            iat: now,
+            exp: now + i64::from(lifespan),
+            typ,
+            email,
+            actions,
+        }
+    }
+
+    /// Required actions completed by consuming the token.
+    pub fn completed_actions(&self) -> Vec<RequiredAction> {
+        match self.typ {
+            ActionTokenType::VerifyEmail => vec![RequiredAction::VerifyEmail],
+            ActionTokenType::ResetCredentials => vec![RequiredAction::UpdatePassword],
+            ActionTokenType::ExecuteActions => self.actions.clone(),
+        }
+    }
+
+    /// Whether consuming the token needs a new password.
+    pub fn requires_password(&self) -> bool {
+        self.completed_actions()
+            .contains(&RequiredAction::UpdatePassword)
+    }
+
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp(self.exp, 0).unwrap_or_default()
+    }
+}
+
+/// What an action token lets its holder do, shown before it is consumed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ActionTokenDetails {
+    pub typ: ActionTokenType,
+    pub username: String,
+    pub email: String,
+    pub actions: Vec<RequiredAction>,
+    pub requires_password: bool,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Outcome of consuming an action token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ActionTokenResult {
+    pub typ: ActionTokenType,
+    pub user_id: Uuid,
+    /// Actions that cannot be completed from a link, such as configuring an
+    /// OTP, left for the next login.
+    pub pending_actions: Vec<RequiredAction>,
+}
+
+pub struct ForgotPasswordInput {
+    pub realm_name: String,
+    /// Username or email address of the user.
+    pub username: String,
+    /// Base URL of the web application the emailed link points to.
+    pub webapp_url: String,
+    pub base_url: String,
+}
+
+pub struct SendVerifyEmailInput {
+    pub realm_name: String,
+    pub webapp_url: String,
+    pub base_url: String,
+}
+
+pub struct ExecuteActionsEmailInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+    pub actions: Vec<RequiredAction>,
+    /// Lifespan of the token in seconds, [`DEFAULT_ADMIN_ACTION_TOKEN_LIFESPAN`]
+    /// when unset.
+    pub lifespan: Option<u32>,
+    pub webapp_url: String,
+    pub base_url: String,
+}
+
+pub struct GetActionTokenInput {
+    pub realm_name: String,
+    pub token: String,
+}
+
+pub struct ExecuteActionTokenInput {
+    pub realm_name: String,
+    pub token: String,
+    /// Required by tokens that reset the password of the user.
+    pub password: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completed_actions_follow_the_token_type() {
+        let user_id = Uuid::new_v4();
+        let token = |typ, actions| {
+            ActionTokenClaims::new(
+                typ,
+                user_id,
+                "jane@acme.test".to_string(),
+                "http://localhost/realms/acme".to_string(),
+                USER_ACTION_TOKEN_LIFESPAN,
+                actions,
+            )
+        };
+
+        let verify = token(ActionTokenType::VerifyEmail, vec![]);
+        assert_eq!(
+            verify.completed_actions(),
+            vec![RequiredAction::VerifyEmail]
+        );
+        assert!(!verify.requires_password());
+
+        assert!(token(ActionTokenType::ResetCredentials, vec![]).requires_password());
+
+        let execute = token(
+            ActionTokenType::ExecuteActions,
+            vec![RequiredAction::ConfigureOtp, RequiredAction::UpdatePassword],
+        );
+        assert!(execute.requires_password());
+        assert_eq!(
+            execute.exp - execute.iat,
+            i64::from(USER_ACTION_TOKEN_LIFESPAN)
+        );
+    }
+
+    #[test]
+    fn test_claims_round_trip_through_json() {
+        let claims = ActionTokenClaims::new(
+            ActionTokenType::ExecuteActions,
+            Uuid::new_v4(),
+            "jane@acme.test".to_string(),
+            "http://localhost/realms/acme".to_string(),
+            DEFAULT_ADMIN_ACTION_TOKEN_LIFESPAN,
+            vec![RequiredAction::VerifyEmail],
+        );
+
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["typ"], "execute-actions");
+        assert_eq!(json["actions"][0], "verify_email");
+
+        let decoded: ActionTokenClaims = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, claims);
+    }
+}
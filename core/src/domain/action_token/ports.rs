@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    action_token::entities::{
+        ActionTokenDetails, ActionTokenResult, ExecuteActionTokenInput, ExecuteActionsEmailInput,
+        ForgotPasswordInput, GetActionTokenInput, SendVerifyEmailInput,
+    },
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+};
+
+pub trait ActionTokenService: Clone + Send + Sync + 'static {
+    /// Emails a reset-credentials link to the user. Succeeds whether or not
+    /// the user exists, so that it cannot be used to find accounts.
+    fn forgot_password(
+        &self,
+        input: ForgotPasswordInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Emails a verify-email link to the authenticated user.
+    fn send_verify_email(
+        &self,
+        identity: Identity,
+        input: SendVerifyEmailInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Emails the user a link completing the given required actions.
+    fn send_execute_actions_email(
+        &self,
+        identity: Identity,
+        input: ExecuteActionsEmailInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_action_token(
+        &self,
+        input: GetActionTokenInput,
+    ) -> impl Future<Output = Result<ActionTokenDetails, CoreError>> + Send;
+
+    /// Performs the actions of the token, which cannot be used again.
+    fn execute_action_token(
+        &self,
+        input: ExecuteActionTokenInput,
+    ) -> impl Future<Output = Result<ActionTokenResult, CoreError>> + Send;
+}
+
+pub trait UsedActionTokenRepository: Clone + Send + Sync + 'static {
+    fn is_used(&self, jti: Uuid) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    /// Records the token as used, returning `false` if it already was. Tokens
+    /// past their expiry are forgotten along the way.
+    fn mark_used(
+        &self,
+        jti: Uuid,
+        realm_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
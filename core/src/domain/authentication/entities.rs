@@ -1,14 +1,20 @@
 use std::fmt::Display;
 
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use thiserror::Error;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::domain::{
-    authentication::value_objects::Identity, common::generate_timestamp, jwt::entities::JwtClaim,
-    user::entities::RequiredAction,
+    authentication::value_objects::Identity,
+    client::entities::protocol_mapper::MappedClaims,
+    client_scope::entities::ClientScope,
+    common::generate_timestamp,
+    jwt::entities::JwtClaim,
+    user::entities::{RequiredAction, User, UserAttributes},
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
@@ -17,7 +23,8 @@ pub struct JwtToken {
     token_type: String,
     refresh_token: String,
     expires_in: u32,
-    id_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_token: Option<String>,
 }
 
 impl JwtToken {
@@ -26,7 +33,7 @@ impl JwtToken {
         token_type: String,
         refresh_token: String,
         expires_in: u32,
-        id_token: String,
+        id_token: Option<String>,
     ) -> Self {
         Self {
             access_token,
@@ -88,6 +95,12 @@ pub enum AuthenticationError {
 
     #[error("Invalid authorization request")]
     InvalidRequest,
+
+    #[error("Account is temporarily locked")]
+    AccountLocked,
+
+    #[error("Email address is not verified")]
+    EmailNotVerified,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +118,11 @@ pub struct AuthSession {
     pub authenticated: bool,
     pub created_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<CodeChallengeMethod>,
+    pub authenticated_at: Option<DateTime<Utc>>,
+    /// Authentication methods references (RFC 8176) used to authenticate the user.
+    pub amr: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -119,6 +137,10 @@ pub struct AuthSessionParams {
     pub user_id: Option<Uuid>,
     pub code: Option<String>,
     pub authenticated: bool,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<CodeChallengeMethod>,
+    /// Seconds the session lasts, the session lifespan of the realm.
+    pub lifespan: i64,
 }
 
 impl AuthSession {
@@ -139,11 +161,94 @@ impl AuthSession {
             code: params.code,
             authenticated: params.authenticated,
             created_at: now,
-            expires_at: now + Duration::minutes(10),
+            expires_at: now + Duration::seconds(params.lifespan),
+            code_challenge: params.code_challenge,
+            code_challenge_method: params.code_challenge_method,
+            authenticated_at: None,
+            amr: Vec::new(),
+        }
+    }
+
+    /// Whether the authorization code is older than `lifespan` seconds. The
+    /// code is issued when the user finishes authenticating.
+    pub fn is_code_expired(&self, lifespan: i64) -> bool {
+        let issued_at = self.authenticated_at.unwrap_or(self.created_at);
+
+        issued_at + Duration::seconds(lifespan) < Utc::now()
+    }
+
+    /// Checks the PKCE `code_verifier` sent to the token endpoint against the
+    /// challenge stored when the authorization request was made.
+    pub fn verify_code_verifier(&self, code_verifier: Option<&str>) -> bool {
+        match (&self.code_challenge, code_verifier) {
+            (Some(challenge), Some(verifier)) => self
+                .code_challenge_method
+                .clone()
+                .unwrap_or_default()
+                .verify(verifier, challenge),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+/// PKCE code challenge methods, as defined by RFC 7636.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum CodeChallengeMethod {
+    #[default]
+    #[serde(rename = "plain")]
+    Plain,
+
+    #[serde(rename = "S256")]
+    S256,
+}
+
+impl CodeChallengeMethod {
+    pub fn verify(&self, code_verifier: &str, code_challenge: &str) -> bool {
+        if !is_valid_pkce_value(code_verifier) {
+            return false;
+        }
+
+        match self {
+            CodeChallengeMethod::Plain => code_verifier == code_challenge,
+            CodeChallengeMethod::S256 => {
+                let digest = Sha256::digest(code_verifier.as_bytes());
+                BASE64_URL_SAFE_NO_PAD.encode(digest) == code_challenge
+            }
+        }
+    }
+}
+
+impl Display for CodeChallengeMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CodeChallengeMethod::Plain => write!(f, "plain"),
+            CodeChallengeMethod::S256 => write!(f, "S256"),
+        }
+    }
+}
+
+impl TryFrom<String> for CodeChallengeMethod {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "plain" => Ok(CodeChallengeMethod::Plain),
+            "S256" => Ok(CodeChallengeMethod::S256),
+            _ => Err(format!("Unsupported code challenge method: {value}")),
         }
     }
 }
 
+/// Code verifiers and challenges are 43 to 128 characters from the
+/// unreserved URI character set.
+pub fn is_valid_pkce_value(value: &str) -> bool {
+    (43..=128).contains(&value.len())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~'))
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
 pub enum GrantType {
     #[default]
@@ -178,11 +283,16 @@ pub struct AuthInput {
     pub response_type: String,
     pub scope: Option<String>,
     pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
 }
 
 pub struct AuthOutput {
     pub login_url: String,
     pub session: AuthSession,
+    /// Seconds the session cookies are kept by the browser.
+    pub session_lifespan: i64,
 }
 
 pub struct ExchangeTokenInput {
@@ -195,6 +305,10 @@ pub struct ExchangeTokenInput {
     pub refresh_token: Option<String>,
     pub base_url: String,
     pub grant_type: GrantType,
+    pub scope: Option<String>,
+    pub code_verifier: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 pub struct AuthorizeRequestInput {
@@ -206,15 +320,114 @@ pub struct AuthorizeRequestOutput {
     pub identity: Identity,
 }
 
+pub struct GetUserInfoInput {
+    pub realm_name: String,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UserInfo {
+    pub sub: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+
+    /// Claims added by the protocol mappers of the client.
+    #[serde(flatten)]
+    #[schema(value_type = Object)]
+    pub claims: MappedClaims,
+}
+
+pub struct IntrospectTokenInput {
+    pub realm_name: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct TokenIntrospection {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+
+    /// Claims added by the protocol mappers of the client.
+    #[serde(flatten)]
+    #[schema(value_type = Object)]
+    pub claims: MappedClaims,
+}
+
+impl TokenIntrospection {
+    /// Response for tokens that are expired, revoked, malformed or unknown.
+    ///
+    /// RFC 7662 forbids disclosing anything else about such tokens.
+    pub fn inactive() -> Self {
+        Self::default()
+    }
+}
+
+pub struct RevokeTokenInput {
+    pub realm_name: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub token: String,
+}
+
+pub struct LogoutInput {
+    pub realm_name: String,
+    pub client_id: Option<String>,
+    pub id_token_hint: Option<String>,
+    /// Code of the browser's authentication session, which identifies the
+    /// user when no `id_token_hint` is given.
+    pub session_code: Option<Uuid>,
+    pub post_logout_redirect_uri: Option<String>,
+    pub state: Option<String>,
+    /// Scheme and host the realm is served from, which the hint must have
+    /// been issued by.
+    pub base_url: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+pub struct LogoutOutput {
+    pub redirect_url: Option<String>,
+}
+
 pub struct AuthenticateInput {
     pub realm_name: String,
     pub client_id: String,
     pub session_code: Uuid,
     pub base_url: String,
     pub auth_method: AuthenticationMethod,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 impl AuthenticateInput {
+    #[allow(clippy::too_many_arguments)]
     pub fn with_user_credentials(
         realm_name: String,
         client_id: String,
@@ -222,6 +435,8 @@ impl AuthenticateInput {
         base_url: String,
         username: String,
         password: String,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
     ) -> Self {
         Self {
             realm_name,
@@ -229,6 +444,8 @@ impl AuthenticateInput {
             session_code,
             base_url,
             auth_method: AuthenticationMethod::UserCredentials { username, password },
+            ip_address,
+            user_agent,
         }
     }
 
@@ -245,6 +462,8 @@ impl AuthenticateInput {
             session_code,
             base_url,
             auth_method: AuthenticationMethod::ExistingToken { token },
+            ip_address: None,
+            user_agent: None,
         }
     }
 
@@ -303,6 +522,31 @@ impl AuthenticateOutput {
         }
     }
 
+    pub fn requires_consent(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            status: AuthenticationStepStatus::RequiresConsent,
+            authorization_code: None,
+            temporary_token: None,
+            required_actions: Vec::new(),
+            redirect_url: None,
+            session_state: None,
+        }
+    }
+
+    /// The user refused the request, the client is sent back an error.
+    pub fn denied(user_id: Uuid, redirect_url: String) -> Self {
+        Self {
+            user_id,
+            status: AuthenticationStepStatus::Failed,
+            authorization_code: None,
+            temporary_token: None,
+            required_actions: Vec::new(),
+            redirect_url: Some(redirect_url),
+            session_state: None,
+        }
+    }
+
     pub fn requires_otp_challenge(user_id: Uuid, temporary_token: String) -> Self {
         Self {
             user_id,
@@ -324,6 +568,8 @@ pub struct CredentialsAuthParams {
     pub base_url: String,
     pub username: String,
     pub password: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -331,11 +577,184 @@ pub enum AuthenticationStepStatus {
     Success,
     RequiresActions,
     RequiresOtpChallenge,
+    RequiresConsent,
     Failed,
 }
 
+pub struct GetConsentInput {
+    pub realm_name: String,
+    pub session_code: Uuid,
+}
+
+/// What the consent screen shows for a session waiting on consent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ConsentRequest {
+    pub client_id: String,
+    pub client_name: String,
+    pub scopes: Vec<ClientScope>,
+}
+
+pub struct ConsentInput {
+    pub realm_name: String,
+    pub session_code: Uuid,
+    pub approve: bool,
+}
+
+/// Account created by a user from the login session.
+pub struct RegisterInput {
+    pub realm_name: String,
+    pub session_code: Uuid,
+    pub base_url: String,
+    /// Base URL of the web application the verification link points to.
+    pub webapp_url: String,
+    pub username: String,
+    pub email: String,
+    pub firstname: String,
+    pub lastname: String,
+    pub password: String,
+    pub attributes: UserAttributes,
+}
+
 #[derive(Debug, Clone)]
 pub enum AuthenticationMethod {
     UserCredentials { username: String, password: String },
     ExistingToken { token: String },
 }
+
+/// Why an authentication attempt was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthFailureReason {
+    InvalidCredentials,
+    AccountLocked,
+    EmailNotVerified,
+    InvalidOtp,
+}
+
+impl Display for AuthFailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthFailureReason::InvalidCredentials => write!(f, "invalid_credentials"),
+            AuthFailureReason::AccountLocked => write!(f, "account_locked"),
+            AuthFailureReason::EmailNotVerified => write!(f, "email_not_verified"),
+            AuthFailureReason::InvalidOtp => write!(f, "invalid_otp"),
+        }
+    }
+}
+
+/// Payload of the webhooks sent for authentication and security events.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AuthEvent {
+    pub user_id: Option<Uuid>,
+    pub username: Option<String>,
+    /// `client_id` of the client the user authenticated to, when known.
+    pub client_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<AuthFailureReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_action: Option<RequiredAction>,
+}
+
+impl AuthEvent {
+    pub fn new(ip_address: Option<String>, user_agent: Option<String>) -> Self {
+        Self {
+            ip_address,
+            user_agent,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_user(mut self, user: &User) -> Self {
+        self.user_id = Some(user.id);
+        self.username = Some(user.username.clone());
+        self
+    }
+
+    pub fn with_client(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn with_reason(mut self, reason: AuthFailureReason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    pub fn with_required_action(mut self, action: RequiredAction) -> Self {
+        self.required_action = Some(action);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example from RFC 7636, Appendix B
+    const VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+    const CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+    #[test]
+    fn test_s256_code_challenge() {
+        assert!(CodeChallengeMethod::S256.verify(VERIFIER, CHALLENGE));
+        assert!(!CodeChallengeMethod::S256.verify(VERIFIER, VERIFIER));
+    }
+
+    #[test]
+    fn test_plain_code_challenge() {
+        assert!(CodeChallengeMethod::Plain.verify(VERIFIER, VERIFIER));
+        assert!(!CodeChallengeMethod::Plain.verify(VERIFIER, CHALLENGE));
+    }
+
+    #[test]
+    fn test_auth_session_expires_after_its_lifespan() {
+        let session = AuthSession::new(AuthSessionParams {
+            realm_id: Uuid::new_v4(),
+            client_id: Uuid::new_v4(),
+            redirect_uri: "http://localhost/callback".to_string(),
+            response_type: "code".to_string(),
+            scope: "openid".to_string(),
+            state: None,
+            nonce: None,
+            user_id: None,
+            code: None,
+            authenticated: false,
+            code_challenge: None,
+            code_challenge_method: None,
+            lifespan: 1_800,
+        });
+
+        assert_eq!(
+            session.expires_at - session.created_at,
+            Duration::seconds(1_800)
+        );
+    }
+
+    #[test]
+    fn test_auth_event_only_carries_relevant_details() {
+        let event = AuthEvent::new(Some("203.0.113.7".to_string()), None)
+            .with_client("account")
+            .with_reason(AuthFailureReason::InvalidCredentials);
+
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "user_id": null,
+                "username": null,
+                "client_id": "account",
+                "ip_address": "203.0.113.7",
+                "user_agent": null,
+                "reason": "invalid_credentials",
+            })
+        );
+    }
+
+    #[test]
+    fn test_code_verifier_must_be_well_formed() {
+        assert!(!CodeChallengeMethod::Plain.verify("short", "short"));
+        assert!(!is_valid_pkce_value(&"a".repeat(129)));
+        assert!(!is_valid_pkce_value(&format!("{}!", &VERIFIER[..43])));
+    }
+}
@@ -4,13 +4,15 @@ use crate::domain::{
     authentication::{
         entities::{
             AuthInput, AuthOutput, AuthSession, AuthenticateInput, AuthenticateOutput,
-            AuthenticationError, AuthorizeRequestInput, AuthorizeRequestOutput,
-            CredentialsAuthParams, ExchangeTokenInput, GrantType, JwtToken,
+            AuthenticationError, AuthorizeRequestInput, AuthorizeRequestOutput, ConsentInput,
+            ConsentRequest, CredentialsAuthParams, ExchangeTokenInput, GetConsentInput,
+            GetUserInfoInput, GrantType, IntrospectTokenInput, JwtToken, LogoutInput, LogoutOutput,
+            RegisterInput, RevokeTokenInput, TokenIntrospection, UserInfo,
         },
         value_objects::{AuthenticationResult, CreateAuthSessionRequest, GrantTypeParams},
     },
     common::entities::app_errors::CoreError,
-    jwt::entities::JwkKey,
+    jwt::entities::{JwkKey, SigningAlgorithm},
 };
 
 /// A strategy for handling different OAuth2 grant types during authentication.
@@ -55,10 +57,12 @@ pub trait AuthSessionRepository: Clone + Send + Sync + 'static {
         &self,
         session: &AuthSession,
     ) -> impl Future<Output = Result<AuthSession, AuthenticationError>> + Send;
+    /// Expired sessions are not found.
     fn get_by_session_code(
         &self,
         session_code: Uuid,
     ) -> impl Future<Output = Result<AuthSession, AuthenticationError>> + Send;
+    /// Expired sessions are not found.
     fn get_by_code(
         &self,
         code: String,
@@ -68,7 +72,20 @@ pub trait AuthSessionRepository: Clone + Send + Sync + 'static {
         session_code: Uuid,
         code: String,
         user_id: Uuid,
+        amr: Vec<String>,
     ) -> impl Future<Output = Result<AuthSession, AuthenticationError>> + Send;
+    /// Records the authenticated user without issuing an authorization code,
+    /// for sessions waiting on a further step such as consent.
+    fn mark_authenticated(
+        &self,
+        session_code: Uuid,
+        user_id: Uuid,
+        amr: Vec<String>,
+    ) -> impl Future<Output = Result<AuthSession, AuthenticationError>> + Send;
+    fn delete_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<(), AuthenticationError>> + Send;
 }
 
 pub trait AuthService: Clone + Send + Sync + 'static {
@@ -77,6 +94,11 @@ pub trait AuthService: Clone + Send + Sync + 'static {
         &self,
         realm_name: String,
     ) -> impl Future<Output = Result<Vec<JwkKey>, CoreError>> + Send;
+    /// Returns the algorithm the realm currently signs its tokens with.
+    fn get_signing_algorithm(
+        &self,
+        realm_name: String,
+    ) -> impl Future<Output = Result<SigningAlgorithm, CoreError>> + Send;
     fn exchange_token(
         &self,
         input: ExchangeTokenInput,
@@ -89,6 +111,35 @@ pub trait AuthService: Clone + Send + Sync + 'static {
         &self,
         input: AuthenticateInput,
     ) -> impl Future<Output = Result<AuthenticateOutput, CoreError>> + Send;
+    fn get_userinfo(
+        &self,
+        input: GetUserInfoInput,
+    ) -> impl Future<Output = Result<UserInfo, CoreError>> + Send;
+    fn introspect_token(
+        &self,
+        input: IntrospectTokenInput,
+    ) -> impl Future<Output = Result<TokenIntrospection, CoreError>> + Send;
+    fn revoke_token(
+        &self,
+        input: RevokeTokenInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn logout(
+        &self,
+        input: LogoutInput,
+    ) -> impl Future<Output = Result<LogoutOutput, CoreError>> + Send;
+    fn get_consent(
+        &self,
+        input: GetConsentInput,
+    ) -> impl Future<Output = Result<ConsentRequest, CoreError>> + Send;
+    fn consent(
+        &self,
+        input: ConsentInput,
+    ) -> impl Future<Output = Result<AuthenticateOutput, CoreError>> + Send;
+    /// Creates an account from the login session and signs the new user in.
+    fn register(
+        &self,
+        input: RegisterInput,
+    ) -> impl Future<Output = Result<AuthenticateOutput, CoreError>> + Send;
 }
 
 /// A strategy for handling different OAuth2 grant types during authentication.
@@ -139,6 +190,7 @@ pub trait AuthenticatePort: Clone + Send + Sync + 'static {
         user_id: Uuid,
         session_code: Uuid,
         auth_session: AuthSession,
+        amr: Vec<String>,
     ) -> impl Future<Output = Result<AuthenticateOutput, CoreError>> + Send;
 
     fn build_redirect_url(
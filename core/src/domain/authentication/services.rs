@@ -1 +1,2 @@
+pub mod auth_events;
 pub mod grant_type_service;
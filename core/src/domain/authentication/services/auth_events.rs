@@ -0,0 +1,62 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::entities::AuthEvent,
+    event::{
+        entities::{LoginEvent, LoginEventType},
+        ports::EventRepository,
+    },
+    webhook::{
+        entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
+        ports::{WebhookNotifierRepository, WebhookRepository},
+    },
+};
+
+/// Records `event` among the login events of the realm and queues it for
+/// the webhooks subscribed to `trigger`. The payload refers to the user, or
+/// to the realm when the user is unknown. Failures are only logged, so that
+/// they never fail the authentication, and a login event that cannot be
+/// recorded does not keep the webhooks from being notified.
+pub async fn notify_auth_event<E, W, N>(
+    event_repository: &E,
+    webhook_repository: &W,
+    webhook_notifier_repository: &N,
+    realm_id: Uuid,
+    trigger: WebhookTrigger,
+    event: AuthEvent,
+) where
+    E: EventRepository,
+    W: WebhookRepository,
+    N: WebhookNotifierRepository,
+{
+    if let Some(event_type) = LoginEventType::from_trigger(&trigger)
+        && let Err(e) = event_repository
+            .create_login_event(LoginEvent::new(realm_id, event_type, event.clone()))
+            .await
+    {
+        tracing::error!("failed to record login event: {e}");
+    }
+
+    let webhooks = match webhook_repository
+        .fetch_webhooks_by_subscriber(realm_id, trigger.clone())
+        .await
+    {
+        Ok(webhooks) => webhooks,
+        Err(e) => {
+            tracing::error!("failed to notify auth event: {e}");
+            return;
+        }
+    };
+
+    let resource_id = event.user_id.unwrap_or(realm_id);
+
+    if let Err(e) = webhook_notifier_repository
+        .notify(
+            webhooks,
+            WebhookPayload::new(trigger, resource_id, Some(event)),
+        )
+        .await
+    {
+        tracing::error!("failed to notify auth event: {e}");
+    }
+}
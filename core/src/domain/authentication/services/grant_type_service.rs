@@ -1,31 +1,66 @@
 use chrono::{TimeZone, Utc};
-use jsonwebtoken::{Algorithm, Header, Validation};
+use jsonwebtoken::Validation;
+use serde::Serialize;
 use uuid::Uuid;
 
 use crate::{
     domain::{
         authentication::{
-            entities::{AuthenticationError, GrantType, JwtToken},
+            entities::{AuthEvent, AuthFailureReason, AuthenticationError, GrantType, JwtToken},
             ports::{AuthSessionRepository, GrantTypeService, GrantTypeStrategy},
+            services::auth_events::notify_auth_event,
             value_objects::GrantTypeParams,
         },
-        client::ports::ClientRepository,
+        brute_force::services::BruteForceDetector,
+        client::{
+            entities::{
+                Client,
+                protocol_mapper::{MapperContext, MapperOutput, ProtocolMapper, TokenTarget},
+            },
+            ports::{ClientRepository, ProtocolMapperRepository},
+        },
+        client_scope::{entities::ClientScope, services::ClientScopeResolver},
         common::entities::app_errors::CoreError,
         credential::ports::CredentialRepository,
         crypto::ports::HasherRepository,
+        group::{ports::GroupRepository, services::effective_roles},
         jwt::{
-            entities::{ClaimsTyp, Jwt, JwtClaim},
+            entities::{ClaimsTyp, IdTokenClaim, Jwt, JwtClaim, RefreshToken, RefreshTokenReuse},
             ports::{KeyStoreRepository, RefreshTokenRepository},
+            services::find_verification_key,
+        },
+        realm::{
+            entities::{RealmSetting, TokenLifespans},
+            ports::RealmRepository,
+        },
+        user::{entities::User, ports::UserRepository},
+        webhook::{
+            entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
+            ports::{WebhookNotifierRepository, WebhookRepository},
         },
-        user::ports::UserRepository,
     },
     infrastructure::{
-        auth_session::AuthSessionRepoAny, client::repositories::ClientRepoAny,
-        credential::CredentialRepoAny, hasher::HasherRepoAny, jwt::KeyStoreRepoAny,
-        refresh_token::RefreshTokenRepoAny, user::UserRepoAny,
+        auth_session::AuthSessionRepoAny,
+        client::repositories::{ClientRepoAny, ProtocolMapperRepoAny},
+        credential::CredentialRepoAny,
+        event::repositories::EventRepoAny,
+        group::repositories::GroupRepoAny,
+        hasher::HasherRepoAny,
+        jwt::KeyStoreRepoAny,
+        realm::repositories::RealmRepoAny,
+        refresh_token::RefreshTokenRepoAny,
+        role::repositories::RoleRepoAny,
+        user::{UserRepoAny, repositories::user_role_repository::UserRoleRepoAny},
+        webhook::repositories::{
+            webhook_notifier_repository::WebhookNotifierRepoAny, webhook_repository::WebhookRepoAny,
+        },
     },
 };
 
+fn requests_openid(scope: Option<&str>) -> bool {
+    scope.is_some_and(|scope| scope.split_whitespace().any(|s| s == "openid"))
+}
+
 #[derive(Clone)]
 pub struct GrantTypeStrategies {
     credential_repository: CredentialRepoAny,
@@ -35,19 +70,41 @@ pub struct GrantTypeStrategies {
     keystore_repository: KeyStoreRepoAny,
     refresh_token_repository: RefreshTokenRepoAny,
     client_repository: ClientRepoAny,
+    realm_repository: RealmRepoAny,
+    webhook_repository: WebhookRepoAny,
+    webhook_notifier_repository: WebhookNotifierRepoAny,
+    event_repository: EventRepoAny,
+    protocol_mapper_repository: ProtocolMapperRepoAny,
+    user_role_repository: UserRoleRepoAny,
+    group_repository: GroupRepoAny,
+    role_repository: RoleRepoAny,
+    brute_force_detector: BruteForceDetector,
+    client_scope_resolver: ClientScopeResolver,
 }
 
 struct GenerateTokenInput {
     base_url: String,
     realm_name: String,
-    user_id: Uuid,
-    username: String,
-    client_id: String,
-    email: String,
     realm_id: Uuid,
+    client_id: String,
+    scope: Option<String>,
+    user: User,
+    context: AuthenticationContext,
+    lifespans: TokenLifespans,
+    /// Family of the refresh token being rotated, if any.
+    refresh_token_family: Option<Uuid>,
+}
+
+/// How the user authenticated, carried into the ID token.
+#[derive(Default)]
+struct AuthenticationContext {
+    nonce: Option<String>,
+    auth_time: Option<i64>,
+    amr: Vec<String>,
 }
 
 impl GrantTypeStrategies {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         credential_repository: CredentialRepoAny,
         hasher_repository: HasherRepoAny,
@@ -56,6 +113,16 @@ impl GrantTypeStrategies {
         keystore_repository: KeyStoreRepoAny,
         refresh_token_repository: RefreshTokenRepoAny,
         client_repository: ClientRepoAny,
+        realm_repository: RealmRepoAny,
+        webhook_repository: WebhookRepoAny,
+        webhook_notifier_repository: WebhookNotifierRepoAny,
+        event_repository: EventRepoAny,
+        protocol_mapper_repository: ProtocolMapperRepoAny,
+        user_role_repository: UserRoleRepoAny,
+        group_repository: GroupRepoAny,
+        role_repository: RoleRepoAny,
+        brute_force_detector: BruteForceDetector,
+        client_scope_resolver: ClientScopeResolver,
     ) -> Self {
         Self {
             credential_repository,
@@ -65,9 +132,165 @@ impl GrantTypeStrategies {
             keystore_repository,
             refresh_token_repository,
             client_repository,
+            realm_repository,
+            webhook_repository,
+            webhook_notifier_repository,
+            event_repository,
+            protocol_mapper_repository,
+            user_role_repository,
+            group_repository,
+            role_repository,
+            brute_force_detector,
+            client_scope_resolver,
+        }
+    }
+
+    /// Loads the protocol mappers of the granted client scopes followed by
+    /// the ones of the client, together with what the user brings to them.
+    /// Roles and groups are only fetched when a mapper needs them.
+    async fn protocol_mappers(
+        &self,
+        realm_id: Uuid,
+        client: &Client,
+        granted: &[ClientScope],
+        user: &User,
+    ) -> Result<(Vec<ProtocolMapper>, MapperContext), CoreError> {
+        let client_scope_ids: Vec<Uuid> = granted.iter().map(|scope| scope.id).collect();
+
+        let mut mappers = self
+            .protocol_mapper_repository
+            .get_by_client_scope_ids(&client_scope_ids)
+            .await?;
+
+        mappers.extend(
+            self.protocol_mapper_repository
+                .get_by_client_id(client.id)
+                .await?,
+        );
+
+        let roles = match mappers.iter().any(|mapper| mapper.config.needs_roles()) {
+            true => {
+                effective_roles(
+                    &self.user_role_repository,
+                    &self.group_repository,
+                    &self.role_repository,
+                    user.id,
+                )
+                .await?
+            }
+            false => Vec::new(),
+        };
+
+        let (groups, realm_groups) = match mappers.iter().any(|mapper| mapper.config.needs_groups())
+        {
+            true => (
+                self.group_repository.get_user_groups(user.id).await?,
+                self.group_repository.find_by_realm_id(realm_id).await?,
+            ),
+            false => (Vec::new(), Vec::new()),
+        };
+
+        let context = MapperContext::new(user, &roles, &groups, &realm_groups);
+
+        Ok((mappers, context))
+    }
+
+    /// Claims the protocol mappers of `client_id` and of the client scopes
+    /// granted for `scope` add to `target` for `user`.
+    pub async fn mapped_claims(
+        &self,
+        realm_id: Uuid,
+        client_id: &str,
+        scope: Option<&str>,
+        user: &User,
+        target: TokenTarget,
+    ) -> Result<MapperOutput, CoreError> {
+        let client = match self
+            .client_repository
+            .get_by_client_id(client_id.to_string(), realm_id)
+            .await
+        {
+            Ok(client) => client,
+            Err(CoreError::NotFound) => return Ok(MapperOutput::default()),
+            Err(_) => return Err(CoreError::InternalServerError),
+        };
+
+        let granted = self
+            .client_scope_resolver
+            .granted_scopes(client.id, scope)
+            .await?;
+
+        let (mappers, context) = self
+            .protocol_mappers(realm_id, &client, &granted, user)
+            .await?;
+
+        Ok(MapperOutput::evaluate(&mappers, target, &context))
+    }
+
+    async fn realm_setting(&self, realm_id: Uuid) -> Result<RealmSetting, CoreError> {
+        match self.realm_repository.get_realm_settings(realm_id).await {
+            Ok(realm_setting) => Ok(realm_setting),
+            Err(CoreError::NotFound) => Ok(RealmSetting::new(realm_id, None)),
+            Err(_) => Err(CoreError::InternalServerError),
         }
     }
 
+    async fn token_lifespans(
+        &self,
+        realm_id: Uuid,
+        client: &Client,
+    ) -> Result<TokenLifespans, CoreError> {
+        let realm_setting = self.realm_setting(realm_id).await?;
+
+        Ok(realm_setting.lifespans_for(client))
+    }
+
+    /// Handles a rotated refresh token being presented again: the whole
+    /// family is revoked, as either the legitimate client or an attacker
+    /// holds a stolen token.
+    async fn revoke_reused_refresh_token(
+        &self,
+        realm_id: Uuid,
+        refresh_token: RefreshToken,
+    ) -> Result<(), CoreError> {
+        let Some(family_id) = refresh_token.family_id else {
+            return Ok(());
+        };
+
+        tracing::warn!(
+            "refresh token {} reused, revoking family {}",
+            refresh_token.jti,
+            family_id
+        );
+
+        self.refresh_token_repository
+            .revoke_family(family_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        let webhooks = self
+            .webhook_repository
+            .fetch_webhooks_by_subscriber(realm_id, WebhookTrigger::AuthRefreshTokenReused)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        self.webhook_notifier_repository
+            .notify(
+                webhooks,
+                WebhookPayload::new(
+                    WebhookTrigger::AuthRefreshTokenReused,
+                    refresh_token.user_id,
+                    Some(RefreshTokenReuse {
+                        user_id: refresh_token.user_id,
+                        client_id: refresh_token.client_id,
+                        family_id,
+                        jti: refresh_token.jti,
+                    }),
+                ),
+            )
+            .await
+    }
+
     async fn verify_password(&self, user_id: Uuid, password: String) -> Result<bool, CoreError> {
         let credential = self
             .credential_repository
@@ -91,21 +314,26 @@ impl GrantTypeStrategies {
         Ok(is_valid)
     }
 
-    async fn generate_token(&self, claims: JwtClaim, realm_id: Uuid) -> Result<Jwt, CoreError> {
+    async fn sign<T: Serialize>(&self, claims: &T, realm_id: Uuid) -> Result<String, CoreError> {
+        let realm_setting = self.realm_setting(realm_id).await?;
+
         let jwt_key_pair = self
             .keystore_repository
-            .get_or_generate_key(realm_id)
+            .get_or_generate_key(realm_id, realm_setting.signing_algorithm())
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let header = Header::new(jsonwebtoken::Algorithm::RS256);
-        let token =
-            jsonwebtoken::encode(&header, &claims, &jwt_key_pair.encoding_key).map_err(|e| {
+        jsonwebtoken::encode(&jwt_key_pair.header(), claims, &jwt_key_pair.encoding_key).map_err(
+            |e| {
                 tracing::error!("JWT generation error: {}", e);
 
                 CoreError::TokenGenerationError(e.to_string())
-            })?;
+            },
+        )
+    }
 
+    async fn generate_token(&self, claims: JwtClaim, realm_id: Uuid) -> Result<Jwt, CoreError> {
+        let token = self.sign(&claims, realm_id).await?;
         let exp = claims.exp.unwrap_or(0);
 
         Ok(Jwt {
@@ -114,24 +342,51 @@ impl GrantTypeStrategies {
         })
     }
 
-    async fn create_jwt(&self, input: GenerateTokenInput) -> Result<(Jwt, Jwt), CoreError> {
+    async fn create_jwt(&self, input: GenerateTokenInput) -> Result<JwtToken, CoreError> {
         let iss = format!("{}/realms/{}", input.base_url, input.realm_name);
         let realm_audit = format!("{}-realm", input.realm_name);
 
+        let client = self
+            .client_repository
+            .get_by_client_id(input.client_id.clone(), input.realm_id)
+            .await
+            .map_err(|_| CoreError::InvalidClient)?;
+
+        let granted = self
+            .client_scope_resolver
+            .granted_scopes(client.id, input.scope.as_deref())
+            .await?;
+        let scope = ClientScope::scope_claim(input.scope.as_deref(), &granted);
+
+        let (mappers, mapper_context) = self
+            .protocol_mappers(input.realm_id, &client, &granted, &input.user)
+            .await?;
+        let access_mapped =
+            MapperOutput::evaluate(&mappers, TokenTarget::AccessToken, &mapper_context);
+
         let claims = JwtClaim::new(
-            input.user_id,
-            input.username,
+            input.user.id,
+            input.user.username.clone(),
             iss,
             vec![realm_audit, "account".to_string()],
             ClaimsTyp::Bearer,
-            input.client_id,
-            Some(input.email),
-        );
+            input.client_id.clone(),
+            Some(input.user.email.clone()),
+        )
+        .with_lifespan(input.lifespans.access_token)
+        .with_scope(Some(scope))
+        .with_mapped_claims(access_mapped);
 
         let jwt = self.generate_token(claims.clone(), input.realm_id).await?;
 
-        let refresh_claims =
-            JwtClaim::new_refresh_token(claims.sub, claims.iss, claims.aud, claims.azp);
+        let refresh_claims = JwtClaim::new_refresh_token(
+            claims.sub,
+            claims.iss.clone(),
+            claims.aud.clone(),
+            claims.azp.clone(),
+        )
+        .with_lifespan(input.lifespans.refresh_token)
+        .with_scope(claims.scope.clone());
 
         let refresh_token = self
             .generate_token(refresh_claims.clone(), input.realm_id)
@@ -140,24 +395,89 @@ impl GrantTypeStrategies {
         self.refresh_token_repository
             .create(
                 refresh_claims.jti,
-                input.user_id,
+                input.user.id,
+                Some(refresh_claims.azp.clone()),
+                Some(input.refresh_token_family.unwrap_or(refresh_claims.jti)),
+                input
+                    .context
+                    .auth_time
+                    .and_then(|auth_time| Utc.timestamp_opt(auth_time, 0).single()),
+                input.context.amr.clone(),
                 Some(Utc.timestamp_opt(refresh_token.expires_at, 0).unwrap()),
             )
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        Ok((jwt, refresh_token))
+        let id_token = match requests_openid(input.scope.as_deref()) {
+            true => {
+                let id_mapped =
+                    MapperOutput::evaluate(&mappers, TokenTarget::IdToken, &mapper_context);
+
+                Some(
+                    self.create_id_token(&input, &claims, &jwt, id_mapped)
+                        .await?,
+                )
+            }
+            false => None,
+        };
+
+        Ok(JwtToken::new(
+            jwt.token,
+            "Bearer".to_string(),
+            refresh_token.token,
+            u32::try_from(input.lifespans.access_token).unwrap_or_default(),
+            id_token.map(|id_token| id_token.token),
+        ))
     }
 
-    pub async fn verify_token(&self, token: String, realm_id: Uuid) -> Result<JwtClaim, CoreError> {
-        let mut validation = Validation::new(Algorithm::RS256);
+    async fn create_id_token(
+        &self,
+        input: &GenerateTokenInput,
+        access_claims: &JwtClaim,
+        access_token: &Jwt,
+        mapped: MapperOutput,
+    ) -> Result<Jwt, CoreError> {
+        let user = &input.user;
+        let profile = access_claims.has_scope("profile");
+        let email = access_claims.has_scope("email");
+
+        let claims = IdTokenClaim {
+            iss: access_claims.iss.clone(),
+            sub: user.id,
+            aud: input.client_id.clone(),
+            exp: access_token.expires_at,
+            iat: access_claims.iat,
+            azp: input.client_id.clone(),
+            at_hash: IdTokenClaim::hash_access_token(&access_token.token),
+            auth_time: input.context.auth_time,
+            nonce: input.context.nonce.clone(),
+            acr: input
+                .context
+                .auth_time
+                .map(|_| IdTokenClaim::acr_from_amr(&input.context.amr)),
+            amr: input.context.amr.clone(),
+            preferred_username: profile.then(|| user.username.clone()),
+            given_name: profile.then(|| user.firstname.clone()),
+            family_name: profile.then(|| user.lastname.clone()),
+            email: email.then(|| user.email.clone()),
+            email_verified: email.then_some(user.email_verified),
+            claims: mapped.claims,
+        };
 
-        let jwt_key_pair = self
-            .keystore_repository
-            .get_or_generate_key(realm_id)
+        let token = self.sign(&claims, input.realm_id).await?;
+
+        Ok(Jwt {
+            token,
+            expires_at: claims.exp,
+        })
+    }
+
+    pub async fn verify_token(&self, token: String, realm_id: Uuid) -> Result<JwtClaim, CoreError> {
+        let jwt_key_pair = find_verification_key(&self.keystore_repository, &token, realm_id)
             .await
-            .map_err(|_| CoreError::InternalServerError)?;
+            .map_err(|e| CoreError::TokenValidationError(e.to_string()))?;
 
+        let mut validation = Validation::new(jwt_key_pair.algorithm.into());
         validation.validate_aud = false;
         let token_data =
             jsonwebtoken::decode::<JwtClaim>(&token, &jwt_key_pair.decoding_key, &validation)
@@ -174,6 +494,22 @@ impl GrantTypeStrategies {
         Ok(token_data.claims)
     }
 
+    /// Verifies an ID token issued by the realm to `issuer` and presented
+    /// back as a hint, whether or not it expired.
+    pub async fn verify_id_token(
+        &self,
+        token: String,
+        realm_id: Uuid,
+        issuer: &str,
+    ) -> Result<IdTokenClaim, CoreError> {
+        let jwt_key_pair = find_verification_key(&self.keystore_repository, &token, realm_id)
+            .await
+            .map_err(|e| CoreError::TokenValidationError(e.to_string()))?;
+
+        IdTokenClaim::decode_hint(&token, &jwt_key_pair, issuer)
+            .map_err(|e| CoreError::TokenValidationError(e.to_string()))
+    }
+
     pub async fn verify_refresh_token(
         &self,
         token: String,
@@ -212,18 +548,22 @@ impl GrantTypeService for GrantTypeStrategies {
                 .authorization_code(params)
                 .await
                 .map_err(|_| AuthenticationError::InternalServerError),
-            GrantType::Password => self
-                .password(params)
-                .await
-                .map_err(|_| AuthenticationError::InternalServerError),
+            GrantType::Password => self.password(params).await.map_err(|e| match e {
+                CoreError::AccountLocked => AuthenticationError::AccountLocked,
+                CoreError::EmailNotVerified => AuthenticationError::EmailNotVerified,
+                _ => AuthenticationError::InternalServerError,
+            }),
             GrantType::Credentials => self
                 .client_credential(params)
                 .await
                 .map_err(|_| AuthenticationError::InternalServerError),
-            GrantType::RefreshToken => self
-                .refresh_token(params)
-                .await
-                .map_err(|_| AuthenticationError::InternalServerError),
+            GrantType::RefreshToken => self.refresh_token(params).await.map_err(|e| match e {
+                CoreError::InvalidRefreshToken
+                | CoreError::ExpiredToken
+                | CoreError::InvalidToken
+                | CoreError::TokenValidationError(_) => AuthenticationError::InvalidRefreshToken,
+                _ => AuthenticationError::InternalServerError,
+            }),
         }
     }
 }
@@ -239,6 +579,24 @@ impl GrantTypeStrategy for GrantTypeStrategies {
             .map_err(|_| CoreError::InternalServerError)?
             .ok_or(CoreError::NotFound)?;
 
+        let client = self
+            .client_repository
+            .get_by_client_id(params.client_id.clone(), params.realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        let lifespans = self.token_lifespans(params.realm_id, &client).await?;
+
+        if auth_session.is_code_expired(lifespans.auth_code) {
+            tracing::warn!("expired authorization code for session {}", auth_session.id);
+            return Err(CoreError::ExpiredToken);
+        }
+
+        if !auth_session.verify_code_verifier(params.code_verifier.as_deref()) {
+            tracing::warn!("invalid PKCE code verifier for session {}", auth_session.id);
+            return Err(CoreError::InvalidRequest);
+        }
+
         let user_id = auth_session.user_id.ok_or(CoreError::NotFound)?;
 
         let user = self
@@ -247,25 +605,22 @@ impl GrantTypeStrategy for GrantTypeStrategies {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let (jwt, refresh_token) = self
-            .create_jwt(GenerateTokenInput {
-                base_url: params.base_url,
-                client_id: params.client_id,
-                email: user.email,
-                realm_id: params.realm_id,
-                realm_name: params.realm_name,
-                user_id: user.id,
-                username: user.username,
-            })
-            .await?;
-
-        Ok(JwtToken::new(
-            jwt.token,
-            "Bearer".to_string(),
-            refresh_token.token,
-            3600,
-            "id_token".to_string(),
-        ))
+        self.create_jwt(GenerateTokenInput {
+            base_url: params.base_url,
+            client_id: params.client_id,
+            realm_id: params.realm_id,
+            realm_name: params.realm_name,
+            scope: Some(auth_session.scope),
+            user,
+            context: AuthenticationContext {
+                nonce: auth_session.nonce,
+                auth_time: auth_session.authenticated_at.map(|dt| dt.timestamp()),
+                amr: auth_session.amr,
+            },
+            lifespans,
+            refresh_token_family: None,
+        })
+        .await
     }
 
     async fn client_credential(&self, params: GrantTypeParams) -> Result<JwtToken, CoreError> {
@@ -285,24 +640,20 @@ impl GrantTypeStrategy for GrantTypeStrategies {
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let (jwt, refresh_token) = self
-            .create_jwt(GenerateTokenInput {
-                base_url: params.base_url,
-                client_id: params.client_id,
-                email: user.email,
-                realm_id: params.realm_id,
-                realm_name: params.realm_name,
-                user_id: user.id,
-                username: user.username,
-            })
-            .await?;
-        Ok(JwtToken::new(
-            jwt.token,
-            "Bearer".to_string(),
-            refresh_token.token,
-            3600,
-            "id_token".to_string(),
-        ))
+        let lifespans = self.token_lifespans(params.realm_id, &client).await?;
+
+        self.create_jwt(GenerateTokenInput {
+            base_url: params.base_url,
+            client_id: params.client_id,
+            realm_id: params.realm_id,
+            realm_name: params.realm_name,
+            scope: params.scope,
+            user,
+            context: AuthenticationContext::default(),
+            lifespans,
+            refresh_token_family: None,
+        })
+        .await
     }
 
     async fn password(&self, params: GrantTypeParams) -> Result<JwtToken, CoreError> {
@@ -327,48 +678,138 @@ impl GrantTypeStrategy for GrantTypeStrategies {
 
         let user = self
             .user_repository
-            .get_by_username(username, params.realm_id)
+            .get_by_username(username.clone(), params.realm_id)
             .await
-            .map_err(|_| CoreError::InternalServerError)?;
+            .ok();
+
+        let event = AuthEvent::new(params.ip_address.clone(), params.user_agent.clone())
+            .with_client(params.client_id.clone());
+        let event = match &user {
+            Some(user) => event.with_user(user),
+            None => AuthEvent {
+                username: Some(username),
+                ..event
+            },
+        };
+
+        let ip_address = params.ip_address.as_deref();
+
+        let locked = self
+            .brute_force_detector
+            .ensure_not_locked(params.realm_id, user.as_ref().map(|u| u.id), ip_address)
+            .await;
+
+        if let Err(CoreError::AccountLocked) = locked {
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                params.realm_id,
+                WebhookTrigger::AuthLoginFailed,
+                event.clone().with_reason(AuthFailureReason::AccountLocked),
+            )
+            .await;
+        }
+
+        locked?;
 
-        let credential = self.verify_password(user.id, password).await;
+        let Some(user) = user else {
+            self.brute_force_detector
+                .record_failure(params.realm_id, None, ip_address)
+                .await?;
 
-        let is_valid = match credential {
-            Ok(is_valid) => is_valid,
-            Err(_) => return Err(CoreError::Invalid),
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                params.realm_id,
+                WebhookTrigger::AuthLoginFailed,
+                event.with_reason(AuthFailureReason::InvalidCredentials),
+            )
+            .await;
+
+            return Err(CoreError::InternalServerError);
         };
 
+        let is_valid = self
+            .verify_password(user.id, password)
+            .await
+            .unwrap_or(false);
+
         if !is_valid {
+            self.brute_force_detector
+                .record_failure(params.realm_id, Some(user.id), ip_address)
+                .await?;
+
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                params.realm_id,
+                WebhookTrigger::AuthLoginFailed,
+                event.with_reason(AuthFailureReason::InvalidCredentials),
+            )
+            .await;
+
             return Err(CoreError::Invalid);
         }
 
-        let (jwt, refresh_token) = self
+        self.brute_force_detector
+            .record_success(params.realm_id, user.id)
+            .await?;
+
+        if !user.email_verified && self.realm_setting(params.realm_id).await?.verify_email {
+            notify_auth_event(
+                &self.event_repository,
+                &self.webhook_repository,
+                &self.webhook_notifier_repository,
+                params.realm_id,
+                WebhookTrigger::AuthLoginFailed,
+                event.with_reason(AuthFailureReason::EmailNotVerified),
+            )
+            .await;
+
+            return Err(CoreError::EmailNotVerified);
+        }
+
+        let lifespans = self.token_lifespans(params.realm_id, &client).await?;
+        let realm_id = params.realm_id;
+
+        let token = self
             .create_jwt(GenerateTokenInput {
                 base_url: params.base_url,
                 client_id: params.client_id,
-                email: user.email,
                 realm_id: params.realm_id,
                 realm_name: params.realm_name,
-                user_id: user.id,
-                username: user.username,
+                scope: params.scope,
+                user,
+                context: AuthenticationContext {
+                    nonce: None,
+                    auth_time: Some(Utc::now().timestamp()),
+                    amr: vec!["pwd".to_string()],
+                },
+                lifespans,
+                refresh_token_family: None,
             })
             .await?;
 
-        Ok(JwtToken::new(
-            jwt.token,
-            "Bearer".to_string(),
-            refresh_token.token,
-            3600,
-            "id_token".to_string(),
-        ))
+        notify_auth_event(
+            &self.event_repository,
+            &self.webhook_repository,
+            &self.webhook_notifier_repository,
+            realm_id,
+            WebhookTrigger::AuthLoginSucceeded,
+            event,
+        )
+        .await;
+
+        Ok(token)
     }
 
     async fn refresh_token(&self, params: GrantTypeParams) -> Result<JwtToken, CoreError> {
         let refresh_token = params.refresh_token.ok_or(CoreError::InvalidRefreshToken)?;
 
-        let claims = self
-            .verify_refresh_token(refresh_token, params.realm_id)
-            .await?;
+        let claims = self.verify_token(refresh_token, params.realm_id).await?;
 
         if claims.typ != ClaimsTyp::Refresh {
             return Err(CoreError::InvalidToken);
@@ -379,35 +820,102 @@ impl GrantTypeStrategy for GrantTypeStrategies {
             return Err(CoreError::InvalidToken);
         }
 
+        let client = self
+            .client_repository
+            .get_by_client_id(params.client_id.clone(), params.realm_id)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        let stored_token = self
+            .refresh_token_repository
+            .get_by_jti(claims.jti)
+            .await
+            .map_err(|_| CoreError::InvalidRefreshToken)?;
+
+        if let Some(expires_at) = stored_token.expires_at
+            && expires_at < Utc::now()
+        {
+            return Err(CoreError::ExpiredToken);
+        }
+
+        let realm_setting = self.realm_setting(params.realm_id).await?;
+
+        if realm_setting.refresh_token_rotation {
+            let rotated = self
+                .refresh_token_repository
+                .rotate(claims.jti)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+
+            if !rotated {
+                // Read again, as the token may have been rotated concurrently
+                let stored_token = self
+                    .refresh_token_repository
+                    .get_by_jti(claims.jti)
+                    .await
+                    .map_err(|_| CoreError::InvalidRefreshToken)?;
+
+                // Tokens revoked on logout or through the revocation endpoint
+                // are merely no longer valid
+                if stored_token.rotated_at.is_some() {
+                    self.revoke_reused_refresh_token(params.realm_id, stored_token)
+                        .await?;
+                }
+
+                return Err(CoreError::InvalidRefreshToken);
+            }
+        } else if stored_token.revoked {
+            return Err(CoreError::ExpiredToken);
+        }
+
         let user = self
             .user_repository
             .get_by_id(claims.sub)
             .await
             .map_err(|_| CoreError::InternalServerError)?;
 
-        let (jwt, refresh_token) = self
+        let event = AuthEvent::new(params.ip_address, params.user_agent)
+            .with_user(&user)
+            .with_client(params.client_id.clone());
+        let realm_id = params.realm_id;
+
+        let token = self
             .create_jwt(GenerateTokenInput {
                 base_url: params.base_url,
                 client_id: params.client_id,
-                email: user.email,
                 realm_id: params.realm_id,
                 realm_name: params.realm_name,
-                user_id: user.id,
-                username: user.username,
+                scope: claims.scope,
+                user,
+                // The ID token still describes the original authentication
+                context: AuthenticationContext {
+                    nonce: None,
+                    auth_time: stored_token.auth_time.map(|dt| dt.timestamp()),
+                    amr: stored_token.amr,
+                },
+                lifespans: realm_setting.lifespans_for(&client),
+                refresh_token_family: stored_token.family_id,
             })
             .await?;
 
-        self.refresh_token_repository
-            .delete(claims.jti)
-            .await
-            .map_err(|_| CoreError::InternalServerError)?;
-        Ok(JwtToken::new(
-            jwt.token,
-            "Bearer".to_string(),
-            refresh_token.token,
-            3600,
-            "id_token".to_string(),
-        ))
+        if !realm_setting.refresh_token_rotation {
+            self.refresh_token_repository
+                .delete(claims.jti)
+                .await
+                .map_err(|_| CoreError::InternalServerError)?;
+        }
+
+        notify_auth_event(
+            &self.event_repository,
+            &self.webhook_repository,
+            &self.webhook_notifier_repository,
+            realm_id,
+            WebhookTrigger::AuthTokenRefreshed,
+            event,
+        )
+        .await;
+
+        Ok(token)
     }
 }
 
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::{
-    authentication::entities::GrantType,
+    authentication::entities::{CodeChallengeMethod, GrantType},
     client::entities::Client,
     user::entities::{RequiredAction, User},
 };
@@ -28,6 +28,8 @@ pub struct CreateAuthSessionRequest {
     pub state: Option<String>,
     pub nonce: Option<String>,
     pub user_id: Option<Uuid>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<CodeChallengeMethod>,
 }
 
 pub struct GrantTypeParams {
@@ -41,6 +43,10 @@ pub struct GrantTypeParams {
     pub password: Option<String>,
     pub refresh_token: Option<String>,
     pub redirect_uri: Option<String>,
+    pub scope: Option<String>,
+    pub code_verifier: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -63,6 +69,8 @@ impl CreateAuthSessionRequest {
             state: None,
             nonce: None,
             user_id: None,
+            code_challenge: None,
+            code_challenge_method: None,
         }
     }
 
@@ -80,6 +88,16 @@ impl CreateAuthSessionRequest {
         self
     }
 
+    pub fn with_pkce(
+        mut self,
+        code_challenge: Option<String>,
+        code_challenge_method: Option<CodeChallengeMethod>,
+    ) -> Self {
+        self.code_challenge = code_challenge;
+        self.code_challenge_method = code_challenge_method;
+        self
+    }
+
     pub fn with_auth_info(mut self, user_id: Option<Uuid>) -> Self {
         self.user_id = user_id;
         self
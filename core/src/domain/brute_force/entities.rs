@@ -0,0 +1,264 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::common::generate_uuid_v7;
+
+/// Brute-force protection settings of a realm. Protection is disabled by
+/// default.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, ToSchema)]
+#[serde(default)]
+pub struct BruteForcePolicy {
+    pub enabled: bool,
+    /// Consecutive failed logins of a user before it is locked out.
+    pub max_failures: u32,
+    /// Failed logins from a single IP address before it is locked out.
+    pub max_ip_failures: u32,
+    /// Lockout applied once the threshold is reached, in seconds. It doubles
+    /// with every further failure.
+    pub wait_increment_seconds: u32,
+    /// Upper bound of a temporary lockout, in seconds.
+    pub max_wait_seconds: u32,
+    /// Time without failures after which the count is reset, in seconds.
+    pub failure_reset_seconds: u32,
+    /// Lock users out until an administrator clears the lockout instead of
+    /// temporarily. IP addresses are always locked out temporarily.
+    pub permanent_lockout: bool,
+}
+
+impl Default for BruteForcePolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_failures: 5,
+            max_ip_failures: 20,
+            wait_increment_seconds: 60,
+            max_wait_seconds: 900,
+            failure_reset_seconds: 43200,
+            permanent_lockout: false,
+        }
+    }
+}
+
+impl BruteForcePolicy {
+    pub fn max_failures_for(&self, subject: &LoginFailureSubject) -> u32 {
+        match subject {
+            LoginFailureSubject::User(_) => self.max_failures,
+            LoginFailureSubject::Ip(_) => self.max_ip_failures,
+        }
+    }
+
+    /// Time without failures after which the count is reset.
+    pub fn failure_reset_after(&self) -> Duration {
+        Duration::seconds(self.failure_reset_seconds.into())
+    }
+
+    /// Temporary lockout applied after `num_failures` failed attempts, given
+    /// a threshold of `max_failures`.
+    pub fn wait_for(&self, max_failures: u32, num_failures: u32) -> Option<Duration> {
+        if max_failures == 0 || num_failures < max_failures {
+            return None;
+        }
+
+        let exponent = (num_failures - max_failures).min(31);
+        let wait = u64::from(self.wait_increment_seconds)
+            .saturating_mul(1 << exponent)
+            .min(u64::from(self.max_wait_seconds));
+
+        Some(Duration::seconds(wait as i64))
+    }
+}
+
+/// What failed login attempts are counted against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoginFailureSubject {
+    User(Uuid),
+    Ip(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LoginFailure {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub ip_address: Option<String>,
+    pub num_failures: u32,
+    pub last_failure: DateTime<Utc>,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub permanently_locked: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoginFailure {
+    pub fn new(realm_id: Uuid, subject: LoginFailureSubject) -> Self {
+        let now = Utc::now();
+        let (user_id, ip_address) = match subject {
+            LoginFailureSubject::User(user_id) => (Some(user_id), None),
+            LoginFailureSubject::Ip(ip_address) => (None, Some(ip_address)),
+        };
+
+        Self {
+            id: generate_uuid_v7(),
+            realm_id,
+            user_id,
+            ip_address,
+            num_failures: 0,
+            last_failure: now,
+            locked_until: None,
+            permanently_locked: false,
+            created_at: now,
+        }
+    }
+
+    pub fn subject(&self) -> Option<LoginFailureSubject> {
+        match (self.user_id, &self.ip_address) {
+            (Some(user_id), _) => Some(LoginFailureSubject::User(user_id)),
+            (None, Some(ip_address)) => Some(LoginFailureSubject::Ip(ip_address.clone())),
+            (None, None) => None,
+        }
+    }
+
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.permanently_locked || self.locked_until.is_some_and(|until| until > now)
+    }
+
+    /// Applies the lockout the policy calls for given the current failure
+    /// count. Returns whether the count caused a new lockout.
+    pub fn apply_lockout(&mut self, policy: &BruteForcePolicy, now: DateTime<Utc>) -> bool {
+        let Some(subject) = self.subject() else {
+            return false;
+        };
+
+        let max_failures = policy.max_failures_for(&subject);
+
+        if policy.permanent_lockout
+            && matches!(subject, LoginFailureSubject::User(_))
+            && max_failures > 0
+            && self.num_failures >= max_failures
+        {
+            let newly_locked = !self.permanently_locked;
+            self.permanently_locked = true;
+
+            return newly_locked;
+        }
+
+        match policy.wait_for(max_failures, self.num_failures) {
+            Some(wait) => {
+                self.locked_until = Some(now + wait);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Lockout state of a user, as seen by administrators.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BruteForceStatus {
+    pub user_id: Uuid,
+    pub locked: bool,
+    pub num_failures: u32,
+    pub last_failure: Option<DateTime<Utc>>,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub permanently_locked: bool,
+}
+
+impl BruteForceStatus {
+    pub fn new(user_id: Uuid, failure: Option<LoginFailure>, now: DateTime<Utc>) -> Self {
+        match failure {
+            Some(failure) => Self {
+                user_id,
+                locked: failure.is_locked(now),
+                num_failures: failure.num_failures,
+                last_failure: Some(failure.last_failure),
+                locked_until: failure.locked_until,
+                permanently_locked: failure.permanently_locked,
+            },
+            None => Self {
+                user_id,
+                locked: false,
+                num_failures: 0,
+                last_failure: None,
+                locked_until: None,
+                permanently_locked: false,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled_policy() -> BruteForcePolicy {
+        BruteForcePolicy {
+            enabled: true,
+            max_failures: 3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_wait_grows_exponentially_up_to_max() {
+        let policy = enabled_policy();
+
+        assert_eq!(policy.wait_for(3, 2), None);
+        assert_eq!(policy.wait_for(3, 3), Some(Duration::seconds(60)));
+        assert_eq!(policy.wait_for(3, 4), Some(Duration::seconds(120)));
+        assert_eq!(policy.wait_for(3, 5), Some(Duration::seconds(240)));
+        assert_eq!(policy.wait_for(3, 40), Some(Duration::seconds(900)));
+        assert_eq!(policy.wait_for(0, 40), None);
+    }
+
+    fn failure_with(subject: LoginFailureSubject, num_failures: u32) -> LoginFailure {
+        LoginFailure {
+            num_failures,
+            ..LoginFailure::new(Uuid::new_v4(), subject)
+        }
+    }
+
+    #[test]
+    fn test_user_is_locked_after_max_failures() {
+        let policy = enabled_policy();
+        let now = Utc::now();
+        let user = LoginFailureSubject::User(Uuid::new_v4());
+
+        assert!(!failure_with(user.clone(), 2).apply_lockout(&policy, now));
+
+        let mut failure = failure_with(user.clone(), 3);
+
+        assert!(failure.apply_lockout(&policy, now));
+        assert!(failure.is_locked(now));
+        assert!(!failure.is_locked(now + Duration::seconds(61)));
+
+        let permanent = BruteForcePolicy {
+            permanent_lockout: true,
+            ..policy
+        };
+        let mut failure = failure_with(user, 3);
+
+        assert!(failure.apply_lockout(&permanent, now));
+        assert!(failure.permanently_locked);
+        assert!(failure.is_locked(now + Duration::days(365)));
+        assert!(!failure.apply_lockout(&permanent, now));
+    }
+
+    #[test]
+    fn test_ip_is_only_locked_temporarily() {
+        let policy = BruteForcePolicy {
+            permanent_lockout: true,
+            ..enabled_policy()
+        };
+        let now = Utc::now();
+        let ip = LoginFailureSubject::Ip("10.0.0.1".into());
+
+        assert!(!failure_with(ip.clone(), 19).apply_lockout(&policy, now));
+
+        let mut failure = failure_with(ip, 20);
+
+        assert!(failure.apply_lockout(&policy, now));
+        assert!(!failure.permanently_locked);
+        assert!(!failure.is_locked(now + Duration::seconds(61)));
+    }
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    brute_force::entities::{BruteForceStatus, LoginFailure, LoginFailureSubject},
+    common::entities::app_errors::CoreError,
+};
+
+pub trait BruteForceService: Clone + Send + Sync + 'static {
+    fn get_user_brute_force_status(
+        &self,
+        identity: Identity,
+        input: GetUserBruteForceStatusInput,
+    ) -> impl Future<Output = Result<BruteForceStatus, CoreError>> + Send;
+
+    fn clear_user_lockout(
+        &self,
+        identity: Identity,
+        input: ClearUserLockoutInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait LoginFailureRepository: Clone + Send + Sync + 'static {
+    fn get_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+    ) -> impl Future<Output = Result<Option<LoginFailure>, CoreError>> + Send;
+
+    /// Atomically counts a failed attempt made at `now` against the
+    /// subject, creating its record if needed. The count restarts from one
+    /// when the subject is not locked and its last failure is older than
+    /// `reset_after`.
+    fn increment_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+        now: DateTime<Utc>,
+        reset_after: Duration,
+    ) -> impl Future<Output = Result<LoginFailure, CoreError>> + Send;
+
+    /// Stores the lockout of the failure record, never shortening a lockout
+    /// already in place.
+    fn lock_login_failure(
+        &self,
+        failure: &LoginFailure,
+    ) -> impl Future<Output = Result<LoginFailure, CoreError>> + Send;
+
+    fn delete_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub struct GetUserBruteForceStatusInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+}
+
+pub struct ClearUserLockoutInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+}
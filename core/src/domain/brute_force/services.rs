@@ -0,0 +1,168 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        brute_force::{
+            entities::{BruteForcePolicy, LoginFailure, LoginFailureSubject},
+            ports::LoginFailureRepository,
+        },
+        common::entities::app_errors::CoreError,
+        realm::ports::RealmRepository,
+        webhook::{
+            entities::{webhook_payload::WebhookPayload, webhook_trigger::WebhookTrigger},
+            ports::{WebhookNotifierRepository, WebhookRepository},
+        },
+    },
+    infrastructure::{
+        brute_force::LoginFailureRepoAny,
+        realm::repositories::RealmRepoAny,
+        webhook::repositories::{
+            webhook_notifier_repository::WebhookNotifierRepoAny, webhook_repository::WebhookRepoAny,
+        },
+    },
+};
+
+/// Tracks failed logins per user and per source IP address and rejects
+/// attempts from subjects that are locked out.
+#[derive(Clone)]
+pub struct BruteForceDetector {
+    login_failure_repository: LoginFailureRepoAny,
+    realm_repository: RealmRepoAny,
+    webhook_repository: WebhookRepoAny,
+    webhook_notifier_repository: WebhookNotifierRepoAny,
+}
+
+fn subjects(user_id: Option<Uuid>, ip_address: Option<&str>) -> Vec<LoginFailureSubject> {
+    user_id
+        .map(LoginFailureSubject::User)
+        .into_iter()
+        .chain(ip_address.map(|ip| LoginFailureSubject::Ip(ip.to_string())))
+        .collect()
+}
+
+impl BruteForceDetector {
+    pub fn new(
+        login_failure_repository: LoginFailureRepoAny,
+        realm_repository: RealmRepoAny,
+        webhook_repository: WebhookRepoAny,
+        webhook_notifier_repository: WebhookNotifierRepoAny,
+    ) -> Self {
+        Self {
+            login_failure_repository,
+            realm_repository,
+            webhook_repository,
+            webhook_notifier_repository,
+        }
+    }
+
+    async fn policy(&self, realm_id: Uuid) -> Result<BruteForcePolicy, CoreError> {
+        match self.realm_repository.get_realm_settings(realm_id).await {
+            Ok(realm_setting) => Ok(realm_setting.brute_force_protection),
+            Err(CoreError::NotFound) => Ok(BruteForcePolicy::default()),
+            Err(_) => Err(CoreError::InternalServerError),
+        }
+    }
+
+    /// Rejects a login attempt for a locked out user or from a locked out
+    /// IP address.
+    pub async fn ensure_not_locked(
+        &self,
+        realm_id: Uuid,
+        user_id: Option<Uuid>,
+        ip_address: Option<&str>,
+    ) -> Result<(), CoreError> {
+        if !self.policy(realm_id).await?.enabled {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+
+        for subject in subjects(user_id, ip_address) {
+            let failure = self
+                .login_failure_repository
+                .get_login_failure(realm_id, &subject)
+                .await?;
+
+            if failure.is_some_and(|failure| failure.is_locked(now)) {
+                tracing::warn!("rejected login attempt for locked out {:?}", subject);
+                return Err(CoreError::AccountLocked);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Counts a failed login against the user and the source IP address,
+    /// locking them out once the realm's threshold is reached.
+    pub async fn record_failure(
+        &self,
+        realm_id: Uuid,
+        user_id: Option<Uuid>,
+        ip_address: Option<&str>,
+    ) -> Result<(), CoreError> {
+        let policy = self.policy(realm_id).await?;
+
+        if !policy.enabled {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+
+        for subject in subjects(user_id, ip_address) {
+            let mut failure = self
+                .login_failure_repository
+                .increment_login_failure(realm_id, &subject, now, policy.failure_reset_after())
+                .await?;
+
+            if failure.apply_lockout(&policy, now) {
+                let failure = self
+                    .login_failure_repository
+                    .lock_login_failure(&failure)
+                    .await?;
+
+                tracing::warn!(
+                    "{:?} locked out after {} failed logins",
+                    subject,
+                    failure.num_failures
+                );
+
+                self.notify_lockout(failure).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resets the failure count of a user after a successful login. Counts
+    /// of IP addresses are left to expire, so that logging into one account
+    /// does not hide failures against others from the same address.
+    pub async fn record_success(&self, realm_id: Uuid, user_id: Uuid) -> Result<(), CoreError> {
+        if !self.policy(realm_id).await?.enabled {
+            return Ok(());
+        }
+
+        self.login_failure_repository
+            .delete_login_failure(realm_id, &LoginFailureSubject::User(user_id))
+            .await
+    }
+
+    async fn notify_lockout(&self, failure: LoginFailure) -> Result<(), CoreError> {
+        let webhooks = self
+            .webhook_repository
+            .fetch_webhooks_by_subscriber(failure.realm_id, WebhookTrigger::AuthLockout)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        self.webhook_notifier_repository
+            .notify(
+                webhooks,
+                WebhookPayload::new(
+                    WebhookTrigger::AuthLockout,
+                    failure.user_id.unwrap_or(failure.id),
+                    Some(failure),
+                ),
+            )
+            .await
+    }
+}
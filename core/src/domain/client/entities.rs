@@ -4,10 +4,14 @@ use utoipa::ToSchema;
 use uuid::{NoContext, Timestamp, Uuid};
 
 use crate::domain::client::{
-    entities::redirect_uri::RedirectUri,
+    entities::{
+        protocol_mapper::{ProtocolMapperConfig, ProtocolMapperTargets},
+        redirect_uri::RedirectUri,
+    },
     value_objects::{CreateRedirectUriRequest, UpdateClientRequest},
 };
 
+pub mod protocol_mapper;
 pub mod redirect_uri;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, ToSchema)]
@@ -21,6 +25,15 @@ pub struct Client {
     pub public_client: bool,
     pub service_account_enabled: bool,
     pub direct_access_grants_enabled: bool,
+    pub pkce_required: bool,
+    /// Overrides the realm's access token lifespan, in seconds.
+    pub access_token_lifespan: Option<i32>,
+    /// Overrides the realm's refresh token lifespan, in seconds.
+    pub refresh_token_lifespan: Option<i32>,
+    /// Overrides the realm's authorization code lifespan, in seconds.
+    pub auth_code_lifespan: Option<i32>,
+    /// Asks users to approve the requested scopes before issuing a code.
+    pub consent_required: bool,
     pub client_type: String,
     pub name: String,
     pub redirect_uris: Option<Vec<RedirectUri>>,
@@ -57,6 +70,11 @@ impl Client {
             public_client: config.public_client,
             service_account_enabled: config.service_account_enabled,
             direct_access_grants_enabled: config.direct_access_grants_enabled.unwrap_or_default(),
+            pkce_required: false,
+            access_token_lifespan: None,
+            refresh_token_lifespan: None,
+            auth_code_lifespan: None,
+            consent_required: false,
             client_type: config.client_type,
             name: config.name,
             redirect_uris: None,
@@ -84,6 +102,14 @@ pub struct CreateRedirectUriInput {
     pub payload: CreateRedirectUriRequest,
 }
 
+pub struct CreateProtocolMapperInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+    pub name: String,
+    pub config: ProtocolMapperConfig,
+    pub targets: ProtocolMapperTargets,
+}
+
 pub struct CreateRoleInput {
     pub realm_name: String,
     pub client_id: Uuid,
@@ -97,6 +123,12 @@ pub struct DeleteClientInput {
     pub client_id: Uuid,
 }
 
+pub struct DeleteProtocolMapperInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+    pub mapper_id: Uuid,
+}
+
 pub struct DeleteRedirectUriInput {
     pub realm_name: String,
     pub client_id: Uuid,
@@ -113,6 +145,11 @@ pub struct GetClientRolesInput {
     pub realm_name: String,
 }
 
+pub struct GetProtocolMappersInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+}
+
 pub struct GetRedirectUrisInput {
     pub realm_name: String,
     pub client_id: Uuid,
@@ -128,6 +165,18 @@ pub struct UpdateClientInput {
     pub payload: UpdateClientRequest,
 }
 
+pub struct UpdateProtocolMapperInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+    pub mapper_id: Uuid,
+    pub name: Option<String>,
+    pub config: Option<ProtocolMapperConfig>,
+    pub access_token: Option<bool>,
+    pub id_token: Option<bool>,
+    pub userinfo: Option<bool>,
+    pub introspection: Option<bool>,
+}
+
 pub struct UpdateRedirectUriInput {
     pub realm_name: String,
     pub client_id: Uuid,
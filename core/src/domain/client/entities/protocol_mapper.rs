@@ -0,0 +1,486 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use utoipa::ToSchema;
+use uuid::{NoContext, Timestamp, Uuid};
+
+use crate::domain::{group::entities::Group, role::entities::Role, user::entities::User};
+
+/// Top-level claims set by the issuer itself, which mappers cannot override:
+/// the named fields of the access, refresh and ID token claims, of the
+/// userinfo response and of the introspection response.
+const RESERVED_CLAIMS: &[&str] = &[
+    "iss",
+    "sub",
+    "aud",
+    "exp",
+    "iat",
+    "nbf",
+    "jti",
+    "typ",
+    "azp",
+    "scope",
+    "client_id",
+    "nonce",
+    "auth_time",
+    "at_hash",
+    "acr",
+    "amr",
+    "preferred_username",
+    "email",
+    "email_verified",
+    "given_name",
+    "family_name",
+    "active",
+    "token_type",
+    "username",
+];
+
+/// Placeholder replaced by the client id in the claim of a client roles mapper.
+const CLIENT_ID_PLACEHOLDER: &str = "${client_id}";
+
+/// Claims added to a token by protocol mappers, keyed by top-level claim name.
+pub type MappedClaims = BTreeMap<String, Value>;
+
+/// The token or response a protocol mapper can contribute claims to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenTarget {
+    AccessToken,
+    IdToken,
+    Userinfo,
+    Introspection,
+}
+
+/// Adds claims to the tokens issued to a client, or to every client a client
+/// scope is granted to. Each mapper chooses the token types it applies to.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct ProtocolMapper {
+    pub id: Uuid,
+    pub client_id: Option<Uuid>,
+    pub client_scope_id: Option<Uuid>,
+    pub name: String,
+    pub config: ProtocolMapperConfig,
+    pub access_token: bool,
+    pub id_token: bool,
+    pub userinfo: bool,
+    pub introspection: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The client or client scope a protocol mapper belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtocolMapperOwner {
+    Client(Uuid),
+    ClientScope(Uuid),
+}
+
+/// What a protocol mapper adds to a token. Claims may be dotted paths, such
+/// as `realm_access.roles`, which are written as nested objects.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProtocolMapperConfig {
+    /// Copies a custom attribute of the user.
+    UserAttribute {
+        attribute: String,
+        claim: String,
+        #[serde(default)]
+        multivalued: bool,
+    },
+    /// Adds a claim with a fixed value.
+    HardcodedClaim {
+        claim: String,
+        #[schema(value_type = Object)]
+        value: Value,
+    },
+    /// Lists the names of the realm roles of the user.
+    RealmRoles {
+        #[serde(default = "default_realm_roles_claim")]
+        claim: String,
+    },
+    /// Lists the names of the client roles of the user, either for a single
+    /// client or for every client. `${client_id}` in the claim is replaced by
+    /// the id of the client owning the roles.
+    ClientRoles {
+        #[serde(default)]
+        client_id: Option<String>,
+        #[serde(default = "default_client_roles_claim")]
+        claim: String,
+    },
+    /// Lists the groups of the user, by name or by full path.
+    GroupMembership {
+        #[serde(default = "default_groups_claim")]
+        claim: String,
+        #[serde(default)]
+        full_path: bool,
+    },
+    /// Adds an audience to the `aud` claim.
+    Audience { audience: String },
+}
+
+fn default_realm_roles_claim() -> String {
+    "realm_access.roles".to_string()
+}
+
+fn default_client_roles_claim() -> String {
+    format!("resource_access.{CLIENT_ID_PLACEHOLDER}.roles")
+}
+
+fn default_groups_claim() -> String {
+    "groups".to_string()
+}
+
+/// What the user brings to the evaluation of protocol mappers.
+#[derive(Debug, Clone, Default)]
+pub struct MapperContext {
+    pub attributes: BTreeMap<String, Vec<String>>,
+    pub realm_roles: Vec<String>,
+    /// Client role names, keyed by the `client_id` of their client.
+    pub client_roles: BTreeMap<String, Vec<String>>,
+    pub groups: Vec<String>,
+    pub group_paths: Vec<String>,
+}
+
+/// Output of a set of protocol mappers for a token.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MapperOutput {
+    pub claims: MappedClaims,
+    pub audiences: Vec<String>,
+}
+
+impl ProtocolMapper {
+    pub fn new(
+        owner: ProtocolMapperOwner,
+        name: String,
+        config: ProtocolMapperConfig,
+        targets: ProtocolMapperTargets,
+    ) -> Self {
+        let now = Utc::now();
+        let seconds = now.timestamp().try_into().unwrap_or(0);
+
+        let timestamp = Timestamp::from_unix(NoContext, seconds, 0);
+
+        let (client_id, client_scope_id) = match owner {
+            ProtocolMapperOwner::Client(client_id) => (Some(client_id), None),
+            ProtocolMapperOwner::ClientScope(client_scope_id) => (None, Some(client_scope_id)),
+        };
+
+        Self {
+            id: Uuid::new_v7(timestamp),
+            client_id,
+            client_scope_id,
+            name,
+            config,
+            access_token: targets.access_token,
+            id_token: targets.id_token,
+            userinfo: targets.userinfo,
+            introspection: targets.introspection,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn applies_to(&self, target: TokenTarget) -> bool {
+        match target {
+            TokenTarget::AccessToken => self.access_token,
+            TokenTarget::IdToken => self.id_token,
+            TokenTarget::Userinfo => self.userinfo,
+            TokenTarget::Introspection => self.introspection,
+        }
+    }
+}
+
+/// Token types a protocol mapper applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProtocolMapperTargets {
+    pub access_token: bool,
+    pub id_token: bool,
+    pub userinfo: bool,
+    pub introspection: bool,
+}
+
+impl ProtocolMapperConfig {
+    /// Checks that the mapper writes a well-formed claim which the issuer
+    /// does not already own.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::UserAttribute {
+                attribute, claim, ..
+            } => !attribute.is_empty() && is_valid_claim(claim),
+            Self::HardcodedClaim { claim, .. }
+            | Self::RealmRoles { claim }
+            | Self::ClientRoles { claim, .. }
+            | Self::GroupMembership { claim, .. } => is_valid_claim(claim),
+            Self::Audience { audience } => !audience.trim().is_empty(),
+        }
+    }
+
+    pub fn needs_roles(&self) -> bool {
+        matches!(self, Self::RealmRoles { .. } | Self::ClientRoles { .. })
+    }
+
+    pub fn needs_groups(&self) -> bool {
+        matches!(self, Self::GroupMembership { .. })
+    }
+
+    fn apply(&self, context: &MapperContext, output: &mut MapperOutput) {
+        match self {
+            Self::UserAttribute {
+                attribute,
+                claim,
+                multivalued,
+            } => {
+                let Some(values) = context.attributes.get(attribute) else {
+                    return;
+                };
+
+                let value = match multivalued {
+                    true => Value::from(values.clone()),
+                    false => match values.first() {
+                        Some(value) => Value::from(value.clone()),
+                        None => return,
+                    },
+                };
+
+                insert_claim(&mut output.claims, claim, value);
+            }
+            Self::HardcodedClaim { claim, value } => {
+                insert_claim(&mut output.claims, claim, value.clone());
+            }
+            Self::RealmRoles { claim } => {
+                insert_claim(
+                    &mut output.claims,
+                    claim,
+                    Value::from(context.realm_roles.clone()),
+                );
+            }
+            Self::ClientRoles { client_id, claim } => {
+                for (role_client_id, roles) in &context.client_roles {
+                    if client_id.as_ref().is_some_and(|id| id != role_client_id) {
+                        continue;
+                    }
+
+                    let path = claim
+                        .split('.')
+                        .map(|segment| segment.replace(CLIENT_ID_PLACEHOLDER, role_client_id))
+                        .collect::<Vec<String>>();
+
+                    insert_claim_path(&mut output.claims, &path, Value::from(roles.clone()));
+                }
+            }
+            Self::GroupMembership { claim, full_path } => {
+                let groups = match full_path {
+                    true => &context.group_paths,
+                    false => &context.groups,
+                };
+
+                insert_claim(&mut output.claims, claim, Value::from(groups.clone()));
+            }
+            Self::Audience { audience } => {
+                if !output.audiences.contains(audience) {
+                    output.audiences.push(audience.clone());
+                }
+            }
+        }
+    }
+}
+
+fn is_valid_claim(claim: &str) -> bool {
+    let mut segments = claim.split('.');
+
+    let Some(first) = segments.next() else {
+        return false;
+    };
+
+    !first.is_empty() && !RESERVED_CLAIMS.contains(&first) && segments.all(|s| !s.is_empty())
+}
+
+fn insert_claim(claims: &mut MappedClaims, claim: &str, value: Value) {
+    let path = claim
+        .split('.')
+        .map(str::to_string)
+        .collect::<Vec<String>>();
+
+    insert_claim_path(claims, &path, value);
+}
+
+/// Writes `value` at `path`, creating the intermediate objects and replacing
+/// any non-object value found on the way.
+fn insert_claim_path(claims: &mut MappedClaims, path: &[String], value: Value) {
+    let Some((first, rest)) = path.split_first() else {
+        return;
+    };
+
+    if rest.is_empty() {
+        claims.insert(first.clone(), value);
+        return;
+    }
+
+    let mut current = claims
+        .entry(first.clone())
+        .or_insert_with(|| Value::Object(Map::new()));
+
+    for segment in rest {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+
+        current = current
+            .as_object_mut()
+            .expect("value was just made an object")
+            .entry(segment.clone())
+            .or_insert(Value::Null);
+    }
+
+    *current = value;
+}
+
+impl MapperContext {
+    /// Builds the context of `user` from its effective roles, the groups it
+    /// belongs to and every group of the realm, used to resolve full paths.
+    pub fn new(user: &User, roles: &[Role], groups: &[Group], realm_groups: &[Group]) -> Self {
+        let mut realm_roles = Vec::new();
+        let mut client_roles: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for role in roles {
+            match &role.client {
+                Some(client) => client_roles
+                    .entry(client.client_id.clone())
+                    .or_default()
+                    .push(role.name.clone()),
+                None => realm_roles.push(role.name.clone()),
+            }
+        }
+
+        Self {
+            attributes: user.attributes.clone(),
+            realm_roles,
+            client_roles,
+            groups: groups.iter().map(|group| group.name.clone()).collect(),
+            group_paths: groups
+                .iter()
+                .map(|group| group.path(realm_groups))
+                .collect(),
+        }
+    }
+}
+
+impl MapperOutput {
+    /// Evaluates the mappers that apply to `target`, in order. A later
+    /// mapper writing the same claim wins.
+    pub fn evaluate(
+        mappers: &[ProtocolMapper],
+        target: TokenTarget,
+        context: &MapperContext,
+    ) -> Self {
+        let mut output = Self::default();
+
+        // Mappers stored before a claim became reserved are skipped.
+        for mapper in mappers
+            .iter()
+            .filter(|mapper| mapper.applies_to(target) && mapper.config.is_valid())
+        {
+            mapper.config.apply(context, &mut output);
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mapper(config: ProtocolMapperConfig, access_token: bool) -> ProtocolMapper {
+        ProtocolMapper::new(
+            ProtocolMapperOwner::Client(Uuid::nil()),
+            "mapper".to_string(),
+            config,
+            ProtocolMapperTargets {
+                access_token,
+                id_token: true,
+                userinfo: true,
+                introspection: true,
+            },
+        )
+    }
+
+    #[test]
+    fn evaluate_writes_nested_claims_for_the_target() {
+        let context = MapperContext {
+            attributes: BTreeMap::from([("department".to_string(), vec!["sales".to_string()])]),
+            realm_roles: vec!["admin".to_string()],
+            client_roles: BTreeMap::from([
+                ("api".to_string(), vec!["read".to_string()]),
+                ("web".to_string(), vec!["write".to_string()]),
+            ]),
+            ..Default::default()
+        };
+
+        let mappers = vec![
+            mapper(
+                ProtocolMapperConfig::RealmRoles {
+                    claim: default_realm_roles_claim(),
+                },
+                true,
+            ),
+            mapper(
+                ProtocolMapperConfig::ClientRoles {
+                    client_id: Some("api".to_string()),
+                    claim: default_client_roles_claim(),
+                },
+                true,
+            ),
+            mapper(
+                ProtocolMapperConfig::UserAttribute {
+                    attribute: "department".to_string(),
+                    claim: "org.department".to_string(),
+                    multivalued: false,
+                },
+                false,
+            ),
+            mapper(
+                ProtocolMapperConfig::Audience {
+                    audience: "api".to_string(),
+                },
+                true,
+            ),
+        ];
+
+        let output = MapperOutput::evaluate(&mappers, TokenTarget::AccessToken, &context);
+
+        assert_eq!(
+            Value::Object(output.claims.into_iter().collect()),
+            json!({
+                "realm_access": { "roles": ["admin"] },
+                "resource_access": { "api": { "roles": ["read"] } },
+            })
+        );
+        assert_eq!(output.audiences, vec!["api".to_string()]);
+
+        let output = MapperOutput::evaluate(&mappers, TokenTarget::IdToken, &context);
+
+        assert_eq!(
+            output.claims.get("org"),
+            Some(&json!({ "department": "sales" }))
+        );
+    }
+
+    #[test]
+    fn reserved_claims_are_rejected() {
+        let config = |claim: &str| ProtocolMapperConfig::HardcodedClaim {
+            claim: claim.to_string(),
+            value: json!(true),
+        };
+
+        assert!(!config("sub").is_valid());
+        assert!(!config("email").is_valid());
+        assert!(!config("preferred_username").is_valid());
+        assert!(!config("aud.extra").is_valid());
+        assert!(!config("custom..claim").is_valid());
+        assert!(config("custom.claim").is_valid());
+    }
+}
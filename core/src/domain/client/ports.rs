@@ -4,10 +4,11 @@ use crate::domain::{
     authentication::value_objects::Identity,
     client::{
         entities::{
-            Client, CreateClientInput, CreateRedirectUriInput, CreateRoleInput, DeleteClientInput,
-            DeleteRedirectUriInput, GetClientInput, GetClientRolesInput, GetClientsInput,
-            GetRedirectUrisInput, UpdateClientInput, UpdateRedirectUriInput,
-            redirect_uri::RedirectUri,
+            Client, CreateClientInput, CreateProtocolMapperInput, CreateRedirectUriInput,
+            CreateRoleInput, DeleteClientInput, DeleteProtocolMapperInput, DeleteRedirectUriInput,
+            GetClientInput, GetClientRolesInput, GetClientsInput, GetProtocolMappersInput,
+            GetRedirectUrisInput, UpdateClientInput, UpdateProtocolMapperInput,
+            UpdateRedirectUriInput, protocol_mapper::ProtocolMapper, redirect_uri::RedirectUri,
         },
         value_objects::{CreateClientRequest, CreateRedirectUriRequest, UpdateClientRequest},
     },
@@ -73,6 +74,27 @@ pub trait ClientService: Clone + Send + Sync + 'static {
         identity: Identity,
         input: UpdateRedirectUriInput,
     ) -> impl Future<Output = Result<RedirectUri, CoreError>> + Send;
+
+    fn get_protocol_mappers(
+        &self,
+        identity: Identity,
+        input: GetProtocolMappersInput,
+    ) -> impl Future<Output = Result<Vec<ProtocolMapper>, CoreError>> + Send;
+    fn create_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: CreateProtocolMapperInput,
+    ) -> impl Future<Output = Result<ProtocolMapper, CoreError>> + Send;
+    fn update_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: UpdateProtocolMapperInput,
+    ) -> impl Future<Output = Result<ProtocolMapper, CoreError>> + Send;
+    fn delete_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: DeleteProtocolMapperInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
 pub trait ClientPolicy: Clone + Send + Sync + 'static {
@@ -178,3 +200,30 @@ pub trait RedirectUriRepository: Clone + Send + Sync + 'static {
 
     fn delete(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
+
+pub trait ProtocolMapperRepository: Clone + Send + Sync + 'static {
+    fn create_protocol_mapper(
+        &self,
+        mapper: ProtocolMapper,
+    ) -> impl Future<Output = Result<ProtocolMapper, CoreError>> + Send;
+
+    fn get_by_client_id(
+        &self,
+        client_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<ProtocolMapper>, CoreError>> + Send;
+
+    fn get_by_client_scope_ids(
+        &self,
+        client_scope_ids: &[Uuid],
+    ) -> impl Future<Output = Result<Vec<ProtocolMapper>, CoreError>> + Send;
+
+    fn get_by_id(&self, id: Uuid)
+    -> impl Future<Output = Result<ProtocolMapper, CoreError>> + Send;
+
+    fn update_protocol_mapper(
+        &self,
+        mapper: ProtocolMapper,
+    ) -> impl Future<Output = Result<ProtocolMapper, CoreError>> + Send;
+
+    fn delete(&self, id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
@@ -21,6 +21,12 @@ pub struct UpdateClientRequest {
     pub client_id: Option<String>,
     pub enabled: Option<bool>,
     pub direct_access_grants_enabled: Option<bool>,
+    pub pkce_required: Option<bool>,
+    /// A value of `0` removes the override and falls back to the realm setting.
+    pub access_token_lifespan: Option<i32>,
+    pub refresh_token_lifespan: Option<i32>,
+    pub auth_code_lifespan: Option<i32>,
+    pub consent_required: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
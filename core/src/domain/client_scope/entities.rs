@@ -0,0 +1,376 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    client::entities::protocol_mapper::{
+        ProtocolMapper, ProtocolMapperConfig, ProtocolMapperOwner, ProtocolMapperTargets,
+    },
+    client_scope::value_objects::CreateClientScopeRequest,
+};
+
+/// Scope value asking for an ID token, which is not a client scope itself.
+pub const OPENID_SCOPE: &str = "openid";
+
+/// How a client scope is linked to the clients created in its realm.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientScopeDefaultType {
+    /// Only linked explicitly.
+    #[default]
+    None,
+    /// Always granted to the clients it is linked to.
+    Default,
+    /// Granted when requested in the `scope` parameter.
+    Optional,
+}
+
+impl Display for ClientScopeDefaultType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientScopeDefaultType::None => write!(f, "none"),
+            ClientScopeDefaultType::Default => write!(f, "default"),
+            ClientScopeDefaultType::Optional => write!(f, "optional"),
+        }
+    }
+}
+
+impl TryFrom<String> for ClientScopeDefaultType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "none" => Ok(ClientScopeDefaultType::None),
+            "default" => Ok(ClientScopeDefaultType::Default),
+            "optional" => Ok(ClientScopeDefaultType::Optional),
+            _ => Err(format!("Unsupported client scope type: {value}")),
+        }
+    }
+}
+
+/// A named set of claims and protocol mappers of a realm, which clients are
+/// granted as default or optional scopes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ClientScope {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    /// Whether the name of the scope is listed in the `scope` claim.
+    pub include_in_token_scope: bool,
+    pub display_on_consent_screen: bool,
+    /// Text shown on the consent screen instead of the name.
+    pub consent_text: Option<String>,
+    pub default_type: ClientScopeDefaultType,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A client scope linked to a client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ClientScopeMapping {
+    pub client_scope: ClientScope,
+    /// Default scopes are always granted, optional ones only when requested.
+    pub default_scope: bool,
+}
+
+/// Scopes a user approved for a client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UserConsent {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub client_id: Uuid,
+    pub scopes: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct CreateClientScopeInput {
+    pub realm_name: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub include_in_token_scope: bool,
+    pub display_on_consent_screen: bool,
+    pub consent_text: Option<String>,
+    pub default_type: ClientScopeDefaultType,
+}
+
+pub struct UpdateClientScopeInput {
+    pub realm_name: String,
+    pub scope_id: Uuid,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub include_in_token_scope: Option<bool>,
+    pub display_on_consent_screen: Option<bool>,
+    pub consent_text: Option<String>,
+    pub default_type: Option<ClientScopeDefaultType>,
+}
+
+pub struct GetClientScopeInput {
+    pub realm_name: String,
+    pub scope_id: Uuid,
+}
+
+pub struct DeleteClientScopeInput {
+    pub realm_name: String,
+    pub scope_id: Uuid,
+}
+
+pub struct GetClientScopeMappingsInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+}
+
+pub struct AssignClientScopeInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+    pub scope_id: Uuid,
+    pub default_scope: bool,
+}
+
+pub struct RemoveClientScopeInput {
+    pub realm_name: String,
+    pub client_id: Uuid,
+    pub scope_id: Uuid,
+}
+
+pub struct CreateClientScopeProtocolMapperInput {
+    pub realm_name: String,
+    pub scope_id: Uuid,
+    pub name: String,
+    pub config: ProtocolMapperConfig,
+    pub targets: ProtocolMapperTargets,
+}
+
+pub struct UpdateClientScopeProtocolMapperInput {
+    pub realm_name: String,
+    pub scope_id: Uuid,
+    pub mapper_id: Uuid,
+    pub name: Option<String>,
+    pub config: Option<ProtocolMapperConfig>,
+    pub access_token: Option<bool>,
+    pub id_token: Option<bool>,
+    pub userinfo: Option<bool>,
+    pub introspection: Option<bool>,
+}
+
+pub struct DeleteClientScopeProtocolMapperInput {
+    pub realm_name: String,
+    pub scope_id: Uuid,
+    pub mapper_id: Uuid,
+}
+
+pub struct GetUserConsentsInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+}
+
+pub struct RevokeUserConsentInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+    pub client_id: Uuid,
+}
+
+fn requested_scopes(scope: Option<&str>) -> impl Iterator<Item = &str> {
+    scope.unwrap_or_default().split_whitespace()
+}
+
+impl ClientScope {
+    /// Scopes every realm starts with.
+    pub fn builtin(realm_id: Uuid) -> Vec<CreateClientScopeRequest> {
+        [
+            (
+                "profile",
+                "OpenID Connect built-in scope: profile",
+                "User profile",
+                ClientScopeDefaultType::Default,
+            ),
+            (
+                "email",
+                "OpenID Connect built-in scope: email",
+                "Email address",
+                ClientScopeDefaultType::Default,
+            ),
+            (
+                "roles",
+                "Roles of the user",
+                "User roles",
+                ClientScopeDefaultType::Default,
+            ),
+            (
+                "offline_access",
+                "OpenID Connect built-in scope: offline_access",
+                "Offline access",
+                ClientScopeDefaultType::Optional,
+            ),
+        ]
+        .into_iter()
+        .map(
+            |(name, description, consent_text, default_type)| CreateClientScopeRequest {
+                realm_id,
+                name: name.to_string(),
+                description: Some(description.to_string()),
+                include_in_token_scope: true,
+                display_on_consent_screen: true,
+                consent_text: Some(consent_text.to_string()),
+                default_type,
+            },
+        )
+        .collect()
+    }
+
+    /// Protocol mappers a built-in scope starts with. The `roles` scope
+    /// releases the `realm_access` and `resource_access` claims.
+    pub fn builtin_protocol_mappers(&self) -> Vec<ProtocolMapper> {
+        if self.name != "roles" {
+            return Vec::new();
+        }
+
+        let targets = ProtocolMapperTargets {
+            access_token: true,
+            id_token: false,
+            userinfo: false,
+            introspection: true,
+        };
+
+        [
+            (
+                "realm roles",
+                ProtocolMapperConfig::RealmRoles {
+                    claim: "realm_access.roles".to_string(),
+                },
+            ),
+            (
+                "client roles",
+                ProtocolMapperConfig::ClientRoles {
+                    client_id: None,
+                    claim: "resource_access.${client_id}.roles".to_string(),
+                },
+            ),
+        ]
+        .into_iter()
+        .map(|(name, config)| {
+            ProtocolMapper::new(
+                ProtocolMapperOwner::ClientScope(self.id),
+                name.to_string(),
+                config,
+                targets,
+            )
+        })
+        .collect()
+    }
+
+    /// Scopes granted to a client for a request: every default scope plus
+    /// the optional ones named in `scope`. Unknown names are ignored.
+    pub fn grant(mappings: Vec<ClientScopeMapping>, scope: Option<&str>) -> Vec<ClientScope> {
+        let requested: Vec<&str> = requested_scopes(scope).collect();
+
+        mappings
+            .into_iter()
+            .filter(|mapping| {
+                mapping.default_scope || requested.contains(&mapping.client_scope.name.as_str())
+            })
+            .map(|mapping| mapping.client_scope)
+            .collect()
+    }
+
+    /// Value of the `scope` claim: `openid` when it was requested, followed
+    /// by the granted scopes that are listed in tokens.
+    pub fn scope_claim(scope: Option<&str>, granted: &[ClientScope]) -> String {
+        let openid = requested_scopes(scope)
+            .any(|s| s == OPENID_SCOPE)
+            .then_some(OPENID_SCOPE);
+
+        openid
+            .into_iter()
+            .chain(
+                granted
+                    .iter()
+                    .filter(|scope| scope.include_in_token_scope)
+                    .map(|scope| scope.name.as_str()),
+            )
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+}
+
+impl UserConsent {
+    /// Granted scopes shown on the consent screen that the user has not
+    /// approved yet.
+    pub fn missing(consent: Option<&UserConsent>, granted: &[ClientScope]) -> Vec<ClientScope> {
+        granted
+            .iter()
+            .filter(|scope| scope.display_on_consent_screen)
+            .filter(|scope| consent.is_none_or(|consent| !consent.scopes.contains(&scope.name)))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping(name: &str, default_scope: bool) -> ClientScopeMapping {
+        ClientScopeMapping {
+            client_scope: ClientScope {
+                id: Uuid::new_v4(),
+                realm_id: Uuid::nil(),
+                name: name.to_string(),
+                description: None,
+                include_in_token_scope: name != "hidden",
+                display_on_consent_screen: true,
+                consent_text: None,
+                default_type: ClientScopeDefaultType::None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            default_scope,
+        }
+    }
+
+    #[test]
+    fn grant_adds_requested_optional_scopes_to_defaults() {
+        let mappings = vec![
+            mapping("profile", true),
+            mapping("hidden", true),
+            mapping("offline_access", false),
+            mapping("billing", false),
+        ];
+
+        let granted = ClientScope::grant(mappings, Some("openid offline_access unknown"));
+
+        assert_eq!(
+            ClientScope::scope_claim(Some("openid offline_access unknown"), &granted),
+            "openid profile offline_access"
+        );
+        assert_eq!(
+            ClientScope::scope_claim(None, &granted),
+            "profile offline_access"
+        );
+    }
+
+    #[test]
+    fn missing_skips_approved_scopes() {
+        let granted =
+            ClientScope::grant(vec![mapping("profile", true), mapping("email", true)], None);
+
+        let consent = UserConsent {
+            id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            client_id: Uuid::nil(),
+            scopes: vec!["profile".to_string()],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let missing = UserConsent::missing(Some(&consent), &granted);
+
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].name, "email");
+        assert_eq!(UserConsent::missing(None, &granted).len(), 2);
+    }
+}
@@ -0,0 +1,200 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    client::entities::protocol_mapper::ProtocolMapper,
+    client_scope::{
+        entities::{
+            AssignClientScopeInput, ClientScope, ClientScopeMapping, CreateClientScopeInput,
+            CreateClientScopeProtocolMapperInput, DeleteClientScopeInput,
+            DeleteClientScopeProtocolMapperInput, GetClientScopeInput, GetClientScopeMappingsInput,
+            GetUserConsentsInput, RemoveClientScopeInput, RevokeUserConsentInput,
+            UpdateClientScopeInput, UpdateClientScopeProtocolMapperInput, UserConsent,
+        },
+        value_objects::{CreateClientScopeRequest, UpdateClientScopeRequest},
+    },
+    common::entities::app_errors::CoreError,
+    realm::entities::Realm,
+};
+
+pub trait ClientScopeService: Clone + Send + Sync + 'static {
+    fn get_client_scopes(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<ClientScope>, CoreError>> + Send;
+
+    fn get_client_scope(
+        &self,
+        identity: Identity,
+        input: GetClientScopeInput,
+    ) -> impl Future<Output = Result<ClientScope, CoreError>> + Send;
+
+    fn create_client_scope(
+        &self,
+        identity: Identity,
+        input: CreateClientScopeInput,
+    ) -> impl Future<Output = Result<ClientScope, CoreError>> + Send;
+
+    fn update_client_scope(
+        &self,
+        identity: Identity,
+        input: UpdateClientScopeInput,
+    ) -> impl Future<Output = Result<ClientScope, CoreError>> + Send;
+
+    fn delete_client_scope(
+        &self,
+        identity: Identity,
+        input: DeleteClientScopeInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_client_scope_mappings(
+        &self,
+        identity: Identity,
+        input: GetClientScopeMappingsInput,
+    ) -> impl Future<Output = Result<Vec<ClientScopeMapping>, CoreError>> + Send;
+
+    fn assign_client_scope(
+        &self,
+        identity: Identity,
+        input: AssignClientScopeInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn remove_client_scope(
+        &self,
+        identity: Identity,
+        input: RemoveClientScopeInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_client_scope_protocol_mappers(
+        &self,
+        identity: Identity,
+        input: GetClientScopeInput,
+    ) -> impl Future<Output = Result<Vec<ProtocolMapper>, CoreError>> + Send;
+
+    fn create_client_scope_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: CreateClientScopeProtocolMapperInput,
+    ) -> impl Future<Output = Result<ProtocolMapper, CoreError>> + Send;
+
+    fn update_client_scope_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: UpdateClientScopeProtocolMapperInput,
+    ) -> impl Future<Output = Result<ProtocolMapper, CoreError>> + Send;
+
+    fn delete_client_scope_protocol_mapper(
+        &self,
+        identity: Identity,
+        input: DeleteClientScopeProtocolMapperInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_user_consents(
+        &self,
+        identity: Identity,
+        input: GetUserConsentsInput,
+    ) -> impl Future<Output = Result<Vec<UserConsent>, CoreError>> + Send;
+
+    fn revoke_user_consent(
+        &self,
+        identity: Identity,
+        input: RevokeUserConsentInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Names of the client scopes of the realm, as advertised by discovery.
+    fn get_supported_scopes(
+        &self,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<String>, CoreError>> + Send;
+}
+
+pub trait ClientScopePolicy: Send + Sync + Clone {
+    fn can_view_client_scope(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_client_scope(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait ClientScopeRepository: Clone + Send + Sync + 'static {
+    fn create_client_scope(
+        &self,
+        payload: CreateClientScopeRequest,
+    ) -> impl Future<Output = Result<ClientScope, CoreError>> + Send;
+
+    fn get_by_id(
+        &self,
+        scope_id: Uuid,
+    ) -> impl Future<Output = Result<Option<ClientScope>, CoreError>> + Send;
+
+    fn find_by_realm_id(
+        &self,
+        realm_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<ClientScope>, CoreError>> + Send;
+
+    fn update_client_scope(
+        &self,
+        scope_id: Uuid,
+        payload: UpdateClientScopeRequest,
+    ) -> impl Future<Output = Result<ClientScope, CoreError>> + Send;
+
+    fn delete_client_scope(
+        &self,
+        scope_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_client_mappings(
+        &self,
+        client_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<ClientScopeMapping>, CoreError>> + Send;
+
+    /// Links the scope to the client, or changes whether it is a default
+    /// scope when it is already linked.
+    fn assign_to_client(
+        &self,
+        client_id: Uuid,
+        scope_id: Uuid,
+        default_scope: bool,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn remove_from_client(
+        &self,
+        client_id: Uuid,
+        scope_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait UserConsentRepository: Clone + Send + Sync + 'static {
+    fn get_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<UserConsent>, CoreError>> + Send;
+
+    fn get(
+        &self,
+        user_id: Uuid,
+        client_id: Uuid,
+    ) -> impl Future<Output = Result<Option<UserConsent>, CoreError>> + Send;
+
+    /// Stores the scopes approved by the user, replacing any previous
+    /// consent for the client.
+    fn upsert(
+        &self,
+        user_id: Uuid,
+        client_id: Uuid,
+        scopes: Vec<String>,
+    ) -> impl Future<Output = Result<UserConsent, CoreError>> + Send;
+
+    fn delete(
+        &self,
+        user_id: Uuid,
+        client_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
@@ -0,0 +1,89 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        client::entities::Client,
+        client_scope::{
+            entities::{ClientScope, UserConsent},
+            ports::{ClientScopeRepository, UserConsentRepository},
+        },
+        common::entities::app_errors::CoreError,
+    },
+    infrastructure::client_scope::repositories::{ClientScopeRepoAny, UserConsentRepoAny},
+};
+
+/// Resolves the client scopes granted to a client for a request and the
+/// ones the user still has to consent to.
+#[derive(Clone)]
+pub struct ClientScopeResolver {
+    client_scope_repository: ClientScopeRepoAny,
+    user_consent_repository: UserConsentRepoAny,
+}
+
+impl ClientScopeResolver {
+    pub fn new(
+        client_scope_repository: ClientScopeRepoAny,
+        user_consent_repository: UserConsentRepoAny,
+    ) -> Self {
+        Self {
+            client_scope_repository,
+            user_consent_repository,
+        }
+    }
+
+    /// Default scopes of the client plus the optional ones named in `scope`.
+    pub async fn granted_scopes(
+        &self,
+        client_id: Uuid,
+        scope: Option<&str>,
+    ) -> Result<Vec<ClientScope>, CoreError> {
+        let mappings = self
+            .client_scope_repository
+            .get_client_mappings(client_id)
+            .await?;
+
+        Ok(ClientScope::grant(mappings, scope))
+    }
+
+    /// Scopes of `granted` the user has not approved yet. Always empty for
+    /// clients that do not require consent.
+    pub async fn missing_consent(
+        &self,
+        client: &Client,
+        user_id: Uuid,
+        granted: &[ClientScope],
+    ) -> Result<Vec<ClientScope>, CoreError> {
+        if !client.consent_required {
+            return Ok(Vec::new());
+        }
+
+        let consent = self.user_consent_repository.get(user_id, client.id).await?;
+
+        Ok(UserConsent::missing(consent.as_ref(), granted))
+    }
+
+    /// Adds `approved` to the scopes the user consented to for the client.
+    pub async fn grant_consent(
+        &self,
+        user_id: Uuid,
+        client_id: Uuid,
+        approved: &[ClientScope],
+    ) -> Result<UserConsent, CoreError> {
+        let mut scopes = self
+            .user_consent_repository
+            .get(user_id, client_id)
+            .await?
+            .map(|consent| consent.scopes)
+            .unwrap_or_default();
+
+        for scope in approved {
+            if !scopes.contains(&scope.name) {
+                scopes.push(scope.name.clone());
+            }
+        }
+
+        self.user_consent_repository
+            .upsert(user_id, client_id, scopes)
+            .await
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::client_scope::entities::ClientScopeDefaultType;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateClientScopeRequest {
+    pub realm_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub include_in_token_scope: bool,
+    pub display_on_consent_screen: bool,
+    pub consent_text: Option<String>,
+    pub default_type: ClientScopeDefaultType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateClientScopeRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub include_in_token_scope: Option<bool>,
+    pub display_on_consent_screen: Option<bool>,
+    pub consent_text: Option<String>,
+    pub default_type: Option<ClientScopeDefaultType>,
+}
@@ -1,5 +1,9 @@
 use thiserror::Error;
 
+use crate::domain::{
+    credential::entities::PasswordPolicyViolation, user::entities::UserAttributeViolation,
+};
+
 #[derive(Error, Debug, Clone)]
 pub enum CoreError {
     #[error("Not found")]
@@ -140,9 +144,30 @@ pub enum CoreError {
     #[error("Realm not found for webhook")]
     WebhookRealmNotFound,
 
+    #[error("Invalid webhook: {0}")]
+    InvalidWebhook(String),
+
     #[error("Failed to create client")]
     CreateClientError,
 
     #[error("Service unavailable: {0}")]
     ServiceUnavailable(String),
+
+    #[error("Account is temporarily locked")]
+    AccountLocked,
+
+    #[error("Email address is not verified")]
+    EmailNotVerified,
+
+    #[error("Password does not satisfy the realm password policy")]
+    PasswordPolicyViolation(Vec<PasswordPolicyViolation>),
+
+    #[error("User attributes do not satisfy the realm user profile")]
+    UserAttributeViolation(Vec<UserAttributeViolation>),
+
+    #[error("No SMTP server is configured for the realm")]
+    EmailNotConfigured,
+
+    #[error("Failed to deliver email: {0}")]
+    EmailDeliveryFailed(String),
 }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use chrono::{DateTime, Utc};
 use rand::{Rng, distributions::Alphanumeric};
 use uuid::{NoContext, Timestamp, Uuid};
@@ -8,11 +10,24 @@ pub mod ports;
 
 pub struct AppConfig {
     pub database_url: String,
+    pub email: EmailConfig,
 }
 
 #[derive(Clone)]
 pub struct FerriskeyConfig {
     pub database: DatabaseConfig,
+    pub email: EmailConfig,
+}
+
+/// How emails leave FerrisKey.
+#[derive(Clone, Debug, Default)]
+pub enum EmailConfig {
+    /// Through the SMTP server configured on each realm.
+    #[default]
+    Smtp,
+    /// Written as `.eml` files to `directory` when set, and logged, for
+    /// development and tests.
+    File { directory: Option<PathBuf> },
 }
 
 #[derive(Clone)]
@@ -5,4 +5,8 @@ pub trait CoreService: Clone + Send + Sync {
         &self,
         config: StartupConfig,
     ) -> impl Future<Output = Result<InitializationResult, CoreError>> + Send;
+
+    /// Rotates the signing keys that outlived their realm's rotation
+    /// interval and retires the passive keys past their retirement delay.
+    fn apply_key_rotation_policy(&self) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
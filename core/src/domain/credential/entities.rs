@@ -1,4 +1,6 @@
-use chrono::{DateTime, Utc};
+use std::fmt::Display;
+
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use utoipa::ToSchema;
@@ -88,6 +90,137 @@ pub struct CredentialConfig {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Credential type of the previous passwords kept to enforce the password
+/// history.
+pub const PASSWORD_HISTORY_CREDENTIAL_TYPE: &str = "password-history";
+
+/// Rules a realm enforces on new passwords. Every rule is disabled by
+/// default.
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, ToSchema,
+)]
+#[serde(default)]
+pub struct PasswordPolicy {
+    pub min_length: Option<u32>,
+    pub min_lowercase: u32,
+    pub min_uppercase: u32,
+    pub min_digits: u32,
+    /// Minimum number of characters that are neither letters nor digits.
+    pub min_special: u32,
+    /// Reject passwords containing the username.
+    pub not_username: bool,
+    /// Reject passwords containing the email address.
+    pub not_email: bool,
+    /// Number of most recent passwords, the current one included, that
+    /// cannot be reused.
+    pub history: u32,
+    /// Days after which the user must change their password.
+    pub max_age_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "code", content = "value", rename_all = "snake_case")]
+pub enum PasswordPolicyViolation {
+    MinLength(u32),
+    MinLowercase(u32),
+    MinUppercase(u32),
+    MinDigits(u32),
+    MinSpecial(u32),
+    ContainsUsername,
+    ContainsEmail,
+    RecentlyUsed(u32),
+}
+
+impl Display for PasswordPolicyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordPolicyViolation::MinLength(n) => {
+                write!(f, "password must be at least {n} characters long")
+            }
+            PasswordPolicyViolation::MinLowercase(n) => {
+                write!(f, "password must contain at least {n} lowercase characters")
+            }
+            PasswordPolicyViolation::MinUppercase(n) => {
+                write!(f, "password must contain at least {n} uppercase characters")
+            }
+            PasswordPolicyViolation::MinDigits(n) => {
+                write!(f, "password must contain at least {n} digits")
+            }
+            PasswordPolicyViolation::MinSpecial(n) => {
+                write!(f, "password must contain at least {n} special characters")
+            }
+            PasswordPolicyViolation::ContainsUsername => {
+                write!(f, "password must not contain the username")
+            }
+            PasswordPolicyViolation::ContainsEmail => {
+                write!(f, "password must not contain the email address")
+            }
+            PasswordPolicyViolation::RecentlyUsed(n) => {
+                write!(f, "password must differ from the last {n} passwords")
+            }
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Checks `password` against every rule that does not require the
+    /// previous passwords of the user.
+    pub fn validate(
+        &self,
+        password: &str,
+        username: &str,
+        email: &str,
+    ) -> Vec<PasswordPolicyViolation> {
+        let mut violations = Vec::new();
+
+        let count = |predicate: fn(&char) -> bool| password.chars().filter(predicate).count();
+
+        if let Some(min_length) = self.min_length
+            && password.chars().count() < min_length as usize
+        {
+            violations.push(PasswordPolicyViolation::MinLength(min_length));
+        }
+
+        if count(char::is_ascii_lowercase) < self.min_lowercase as usize {
+            violations.push(PasswordPolicyViolation::MinLowercase(self.min_lowercase));
+        }
+
+        if count(char::is_ascii_uppercase) < self.min_uppercase as usize {
+            violations.push(PasswordPolicyViolation::MinUppercase(self.min_uppercase));
+        }
+
+        if count(char::is_ascii_digit) < self.min_digits as usize {
+            violations.push(PasswordPolicyViolation::MinDigits(self.min_digits));
+        }
+
+        if count(|c| !c.is_alphanumeric()) < self.min_special as usize {
+            violations.push(PasswordPolicyViolation::MinSpecial(self.min_special));
+        }
+
+        let lowercase_password = password.to_lowercase();
+
+        if self.not_username
+            && !username.is_empty()
+            && lowercase_password.contains(&username.to_lowercase())
+        {
+            violations.push(PasswordPolicyViolation::ContainsUsername);
+        }
+
+        if self.not_email && !email.is_empty() && lowercase_password.contains(&email.to_lowercase())
+        {
+            violations.push(PasswordPolicyViolation::ContainsEmail);
+        }
+
+        violations
+    }
+
+    /// Whether a password set at `created_at` has outlived the maximum age.
+    pub fn is_expired(&self, created_at: DateTime<Utc>) -> bool {
+        self.max_age_days
+            .is_some_and(|days| created_at + Duration::days(days.into()) <= Utc::now())
+    }
+}
+
 #[derive(Debug, Clone, Error)]
 pub enum CredentialError {
     #[error("Hash password error: {0}")]
@@ -121,3 +254,55 @@ pub struct DeleteCredentialInput {
     pub realm_name: String,
     pub credential_id: Uuid,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_password_policy_accepts_anything() {
+        assert!(PasswordPolicy::default().validate("a", "a", "a").is_empty());
+        assert!(!PasswordPolicy::default().is_expired(Utc::now() - Duration::days(3650)));
+    }
+
+    #[test]
+    fn test_password_policy_reports_every_violation() {
+        let policy = PasswordPolicy {
+            min_length: Some(12),
+            min_lowercase: 1,
+            min_uppercase: 1,
+            min_digits: 2,
+            min_special: 1,
+            not_username: true,
+            not_email: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            policy.validate("JOHN1", "john", "john@ferriskey.rs"),
+            vec![
+                PasswordPolicyViolation::MinLength(12),
+                PasswordPolicyViolation::MinLowercase(1),
+                PasswordPolicyViolation::MinDigits(2),
+                PasswordPolicyViolation::MinSpecial(1),
+                PasswordPolicyViolation::ContainsUsername,
+            ]
+        );
+        assert!(
+            policy
+                .validate("Correct-horse-42", "john", "john@ferriskey.rs")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_password_max_age() {
+        let policy = PasswordPolicy {
+            max_age_days: Some(90),
+            ..Default::default()
+        };
+
+        assert!(policy.is_expired(Utc::now() - Duration::days(91)));
+        assert!(!policy.is_expired(Utc::now() - Duration::days(89)));
+    }
+}
@@ -42,6 +42,26 @@ pub trait CredentialRepository: Clone + Send + Sync + 'static {
         user_id: Uuid,
     ) -> impl Future<Output = Result<(), CredentialError>> + Send;
 
+    /// Keeps the current password as a password history entry.
+    fn archive_password_credential(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<(), CredentialError>> + Send;
+
+    /// Lists the previous passwords of the user, most recent first.
+    fn get_password_history(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Credential>, CredentialError>> + Send;
+
+    /// Deletes the password history entries beyond the `keep` most recent.
+    fn prune_password_history(
+        &self,
+        user_id: Uuid,
+        keep: usize,
+    ) -> impl Future<Output = Result<(), CredentialError>> + Send;
+
+    /// Lists the credentials of the user, password history excluded.
     fn get_credentials_by_user_id(
         &self,
         user_id: Uuid,
@@ -0,0 +1,147 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Sender used when the realm has no SMTP server configured, which only the
+/// file transport accepts.
+pub const DEFAULT_FROM_ADDRESS: &str = "noreply@ferriskey.local";
+
+/// Transport security of the connection to an SMTP server.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpEncryption {
+    /// Plain connection, only suitable for local relays.
+    None,
+    /// Plain connection upgraded with `STARTTLS`, usually on port 587.
+    #[default]
+    StartTls,
+    /// TLS from the start of the connection, usually on port 465.
+    Tls,
+}
+
+impl Display for SmtpEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SmtpEncryption::None => write!(f, "none"),
+            SmtpEncryption::StartTls => write!(f, "starttls"),
+            SmtpEncryption::Tls => write!(f, "tls"),
+        }
+    }
+}
+
+impl TryFrom<String> for SmtpEncryption {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "none" => Ok(SmtpEncryption::None),
+            "starttls" => Ok(SmtpEncryption::StartTls),
+            "tls" => Ok(SmtpEncryption::Tls),
+            _ => Err(format!("unknown SMTP encryption: {value}")),
+        }
+    }
+}
+
+/// SMTP server a realm sends its emails through.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SmtpServer {
+    pub realm_id: Uuid,
+    pub host: String,
+    pub port: u16,
+    pub encryption: SmtpEncryption,
+    pub from_address: String,
+    pub from_display_name: Option<String>,
+    pub reply_to: Option<String>,
+    pub username: Option<String>,
+    /// Never returned by the API.
+    #[serde(skip_serializing, default)]
+    pub password: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SmtpServer {
+    /// Mailbox the emails of the realm are sent from.
+    pub fn from_mailbox(&self) -> String {
+        match &self.from_display_name {
+            Some(name) => format!("{name} <{}>", self.from_address),
+            None => self.from_address.clone(),
+        }
+    }
+}
+
+/// Email ready to be handed to an [`EmailSender`](super::ports::EmailSender).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub from: String,
+    pub reply_to: Option<String>,
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+/// Messages FerrisKey sends, each rendered from localized templates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmailTemplate {
+    Test,
+    VerifyEmail,
+    ResetPassword,
+    ExecuteActions,
+}
+
+impl EmailTemplate {
+    pub const ALL: [EmailTemplate; 4] = [
+        EmailTemplate::Test,
+        EmailTemplate::VerifyEmail,
+        EmailTemplate::ResetPassword,
+        EmailTemplate::ExecuteActions,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EmailTemplate::Test => "test",
+            EmailTemplate::VerifyEmail => "verify_email",
+            EmailTemplate::ResetPassword => "reset_password",
+            EmailTemplate::ExecuteActions => "execute_actions",
+        }
+    }
+}
+
+/// Subject and bodies of a rendered template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: String,
+}
+
+pub struct GetSmtpServerInput {
+    pub realm_name: String,
+}
+
+pub struct UpdateSmtpServerInput {
+    pub realm_name: String,
+    pub host: String,
+    pub port: u16,
+    pub encryption: SmtpEncryption,
+    pub from_address: String,
+    pub from_display_name: Option<String>,
+    pub reply_to: Option<String>,
+    pub username: Option<String>,
+    /// Keeps the stored password when unset.
+    pub password: Option<String>,
+}
+
+pub struct DeleteSmtpServerInput {
+    pub realm_name: String,
+}
+
+pub struct SendTestEmailInput {
+    pub realm_name: String,
+    /// Defaults to the email address of the caller.
+    pub to: Option<String>,
+}
@@ -0,0 +1,65 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    email::entities::{
+        DeleteSmtpServerInput, EmailMessage, GetSmtpServerInput, SendTestEmailInput, SmtpServer,
+        UpdateSmtpServerInput,
+    },
+};
+
+pub trait EmailService: Clone + Send + Sync + 'static {
+    fn get_smtp_server(
+        &self,
+        identity: Identity,
+        input: GetSmtpServerInput,
+    ) -> impl Future<Output = Result<SmtpServer, CoreError>> + Send;
+
+    fn update_smtp_server(
+        &self,
+        identity: Identity,
+        input: UpdateSmtpServerInput,
+    ) -> impl Future<Output = Result<SmtpServer, CoreError>> + Send;
+
+    fn delete_smtp_server(
+        &self,
+        identity: Identity,
+        input: DeleteSmtpServerInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Sends the test template to check the email settings of the realm.
+    fn send_test_email(
+        &self,
+        identity: Identity,
+        input: SendTestEmailInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait EmailSender: Clone + Send + Sync + 'static {
+    /// Delivers `message` through the SMTP server of the realm. Transports
+    /// that do not talk to a server accept `None`.
+    fn send(
+        &self,
+        server: Option<SmtpServer>,
+        message: EmailMessage,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait SmtpServerRepository: Clone + Send + Sync + 'static {
+    fn get_by_realm_id(
+        &self,
+        realm_id: Uuid,
+    ) -> impl Future<Output = Result<Option<SmtpServer>, CoreError>> + Send;
+
+    /// Inserts the SMTP server of the realm, or replaces it if it exists.
+    fn save_smtp_server(
+        &self,
+        server: &SmtpServer,
+    ) -> impl Future<Output = Result<SmtpServer, CoreError>> + Send;
+
+    fn delete_by_realm_id(
+        &self,
+        realm_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use handlebars::{Handlebars, no_escape};
+use serde::Serialize;
+use tracing::error;
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    email::entities::{EmailTemplate, RenderedEmail},
+    realm::entities::DEFAULT_LOCALE,
+};
+
+/// Locales the built-in templates are translated to.
+pub const SUPPORTED_LOCALES: [&str; 2] = ["en", "fr"];
+
+macro_rules! template {
+    ($locale:literal, $name:literal) => {
+        (
+            $locale,
+            $name,
+            include_str!(concat!("templates/", $locale, "/", $name, ".subject.hbs")),
+            include_str!(concat!("templates/", $locale, "/", $name, ".txt.hbs")),
+            include_str!(concat!("templates/", $locale, "/", $name, ".html.hbs")),
+        )
+    };
+}
+
+/// Locale, name, subject, text body and HTML body of each template.
+const TEMPLATES: [(&str, &str, &str, &str, &str); 8] = [
+    template!("en", "test"),
+    template!("en", "verify_email"),
+    template!("en", "reset_password"),
+    template!("en", "execute_actions"),
+    template!("fr", "test"),
+    template!("fr", "verify_email"),
+    template!("fr", "reset_password"),
+    template!("fr", "execute_actions"),
+];
+
+/// Renders the localized email templates. The HTML bodies escape the values
+/// they are given; subjects and text bodies do not.
+#[derive(Clone)]
+pub struct EmailTemplates {
+    html: Arc<Handlebars<'static>>,
+    text: Arc<Handlebars<'static>>,
+}
+
+impl Default for EmailTemplates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmailTemplates {
+    pub fn new() -> Self {
+        let mut html = Handlebars::new();
+        let mut text = Handlebars::new();
+        text.register_escape_fn(no_escape);
+
+        for (locale, name, subject, text_body, html_body) in TEMPLATES {
+            text.register_template_string(&format!("{locale}/{name}.subject"), subject)
+                .expect("invalid email subject template");
+            text.register_template_string(&format!("{locale}/{name}.txt"), text_body)
+                .expect("invalid email text template");
+            html.register_template_string(&format!("{locale}/{name}.html"), html_body)
+                .expect("invalid email HTML template");
+        }
+
+        Self {
+            html: Arc::new(html),
+            text: Arc::new(text),
+        }
+    }
+
+    pub fn is_supported(locale: &str) -> bool {
+        SUPPORTED_LOCALES.contains(&locale)
+    }
+
+    /// Picks the first supported locale among the preferred one and the
+    /// realm default, falling back to English.
+    pub fn resolve_locale<'a>(preferred: Option<&'a str>, realm_default: &'a str) -> &'a str {
+        preferred
+            .into_iter()
+            .chain([realm_default])
+            .find(|locale| Self::is_supported(locale))
+            .unwrap_or(DEFAULT_LOCALE)
+    }
+
+    pub fn render<T: Serialize>(
+        &self,
+        template: EmailTemplate,
+        locale: &str,
+        data: &T,
+    ) -> Result<RenderedEmail, CoreError> {
+        let locale = Self::resolve_locale(Some(locale), DEFAULT_LOCALE);
+        let name = format!("{locale}/{}", template.name());
+
+        let render = |registry: &Handlebars<'static>, suffix: &str| {
+            registry
+                .render(&format!("{name}.{suffix}"), data)
+                .map_err(|e| {
+                    error!("failed to render email template {name}.{suffix}: {:?}", e);
+                    CoreError::InternalServerError
+                })
+        };
+
+        Ok(RenderedEmail {
+            subject: render(&self.text, "subject")?.trim().to_string(),
+            text_body: render(&self.text, "txt")?,
+            html_body: render(&self.html, "html")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_every_template_renders_in_every_locale() {
+        let templates = EmailTemplates::new();
+
+        for template in EmailTemplate::ALL {
+            for locale in SUPPORTED_LOCALES {
+                let rendered = templates
+                    .render(template, locale, &json!({ "realm_name": "acme" }))
+                    .expect("template should render");
+
+                assert!(!rendered.subject.is_empty());
+                assert!(rendered.text_body.contains("acme"));
+                assert!(rendered.html_body.contains("acme"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_render_escapes_html_and_falls_back_to_english() {
+        let templates = EmailTemplates::new();
+
+        let rendered = templates
+            .render(
+                EmailTemplate::Test,
+                "de",
+                &json!({ "realm_name": "<b>acme</b>" }),
+            )
+            .expect("template should render");
+
+        assert_eq!(
+            rendered,
+            templates
+                .render(
+                    EmailTemplate::Test,
+                    "en",
+                    &json!({ "realm_name": "<b>acme</b>" })
+                )
+                .unwrap()
+        );
+        assert!(rendered.text_body.contains("<b>acme</b>"));
+        assert!(rendered.html_body.contains("&lt;b&gt;acme&lt;/b&gt;"));
+        assert_eq!(EmailTemplates::resolve_locale(Some("fr"), "en"), "fr");
+        assert_eq!(EmailTemplates::resolve_locale(Some("de"), "fr"), "fr");
+    }
+}
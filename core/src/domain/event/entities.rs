@@ -0,0 +1,561 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::{entities::AuthEvent, value_objects::Identity},
+    common::generate_timestamp,
+    user::entities::RequiredAction,
+    webhook::entities::webhook_trigger::WebhookTrigger,
+};
+
+pub const DEFAULT_EVENT_PAGE_SIZE: u64 = 20;
+pub const MAX_EVENT_PAGE_SIZE: u64 = 100;
+
+/// Fields whose values never appear in the representation of an admin event.
+const REDACTED_FIELDS: [&str; 4] = ["secret", "password", "secret_data", "credential_data"];
+
+const REDACTED_VALUE: &str = "**********";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LoginEventType {
+    Login,
+    LoginError,
+    RefreshToken,
+    Logout,
+    ConfigureOtp,
+    OtpError,
+    BurnRecoveryCode,
+    UpdatePassword,
+    RequiredActionCompleted,
+}
+
+impl LoginEventType {
+    const ALL: [Self; 9] = [
+        Self::Login,
+        Self::LoginError,
+        Self::RefreshToken,
+        Self::Logout,
+        Self::ConfigureOtp,
+        Self::OtpError,
+        Self::BurnRecoveryCode,
+        Self::UpdatePassword,
+        Self::RequiredActionCompleted,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::LoginError => "login_error",
+            Self::RefreshToken => "refresh_token",
+            Self::Logout => "logout",
+            Self::ConfigureOtp => "configure_otp",
+            Self::OtpError => "otp_error",
+            Self::BurnRecoveryCode => "burn_recovery_code",
+            Self::UpdatePassword => "update_password",
+            Self::RequiredActionCompleted => "required_action_completed",
+        }
+    }
+
+    /// Login event recorded along with the webhooks sent for `trigger`, if
+    /// any.
+    pub fn from_trigger(trigger: &WebhookTrigger) -> Option<Self> {
+        match trigger {
+            WebhookTrigger::AuthLoginSucceeded => Some(Self::Login),
+            WebhookTrigger::AuthLoginFailed => Some(Self::LoginError),
+            WebhookTrigger::AuthTokenRefreshed => Some(Self::RefreshToken),
+            WebhookTrigger::AuthLogout => Some(Self::Logout),
+            WebhookTrigger::AuthOtpConfigured => Some(Self::ConfigureOtp),
+            WebhookTrigger::AuthOtpFailed => Some(Self::OtpError),
+            WebhookTrigger::AuthRecoveryCodeBurned => Some(Self::BurnRecoveryCode),
+            WebhookTrigger::AuthPasswordUpdated => Some(Self::UpdatePassword),
+            WebhookTrigger::AuthRequiredActionCompleted => Some(Self::RequiredActionCompleted),
+            _ => None,
+        }
+    }
+}
+
+impl Display for LoginEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<String> for LoginEventType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|event_type| event_type.as_str() == value)
+            .ok_or_else(|| format!("Invalid login event type: {value}"))
+    }
+}
+
+/// Kind of resource an admin event is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceType {
+    Realm,
+    RealmSettings,
+    RealmKey,
+    Client,
+    RedirectUri,
+    ProtocolMapper,
+    ClientScope,
+    ClientScopeMapping,
+    User,
+    UserCredential,
+    UserRoleMapping,
+    UserConsent,
+    Role,
+    RoleComposite,
+    DefaultRole,
+    Group,
+    GroupMembership,
+    GroupRoleMapping,
+    Webhook,
+    SmtpServer,
+}
+
+impl ResourceType {
+    const ALL: [Self; 20] = [
+        Self::Realm,
+        Self::RealmSettings,
+        Self::RealmKey,
+        Self::Client,
+        Self::RedirectUri,
+        Self::ProtocolMapper,
+        Self::ClientScope,
+        Self::ClientScopeMapping,
+        Self::User,
+        Self::UserCredential,
+        Self::UserRoleMapping,
+        Self::UserConsent,
+        Self::Role,
+        Self::RoleComposite,
+        Self::DefaultRole,
+        Self::Group,
+        Self::GroupMembership,
+        Self::GroupRoleMapping,
+        Self::Webhook,
+        Self::SmtpServer,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Realm => "realm",
+            Self::RealmSettings => "realm_settings",
+            Self::RealmKey => "realm_key",
+            Self::Client => "client",
+            Self::RedirectUri => "redirect_uri",
+            Self::ProtocolMapper => "protocol_mapper",
+            Self::ClientScope => "client_scope",
+            Self::ClientScopeMapping => "client_scope_mapping",
+            Self::User => "user",
+            Self::UserCredential => "user_credential",
+            Self::UserRoleMapping => "user_role_mapping",
+            Self::UserConsent => "user_consent",
+            Self::Role => "role",
+            Self::RoleComposite => "role_composite",
+            Self::DefaultRole => "default_role",
+            Self::Group => "group",
+            Self::GroupMembership => "group_membership",
+            Self::GroupRoleMapping => "group_role_mapping",
+            Self::Webhook => "webhook",
+            Self::SmtpServer => "smtp_server",
+        }
+    }
+}
+
+impl Display for ResourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<String> for ResourceType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|resource_type| resource_type.as_str() == value)
+            .ok_or_else(|| format!("Invalid resource type: {value}"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationType {
+    Create,
+    Update,
+    Delete,
+    /// An operation that does not create, change or remove the resource
+    /// itself, such as rotating a key.
+    Action,
+}
+
+impl Display for OperationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperationType::Create => write!(f, "create"),
+            OperationType::Update => write!(f, "update"),
+            OperationType::Delete => write!(f, "delete"),
+            OperationType::Action => write!(f, "action"),
+        }
+    }
+}
+
+impl TryFrom<String> for OperationType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "create" => Ok(OperationType::Create),
+            "update" => Ok(OperationType::Update),
+            "delete" => Ok(OperationType::Delete),
+            "action" => Ok(OperationType::Action),
+            _ => Err(format!("Invalid operation type: {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ActorType {
+    User,
+    Client,
+}
+
+impl Display for ActorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActorType::User => write!(f, "user"),
+            ActorType::Client => write!(f, "client"),
+        }
+    }
+}
+
+impl TryFrom<String> for ActorType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "user" => Ok(ActorType::User),
+            "client" => Ok(ActorType::Client),
+            _ => Err(format!("Invalid actor type: {value}")),
+        }
+    }
+}
+
+/// Identity that performed an admin operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct EventActor {
+    #[serde(rename = "type")]
+    pub actor_type: ActorType,
+    /// Id of the user, or of the client for service accounts.
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    /// Username of the user, or `client:{client_id}` for service accounts.
+    pub name: String,
+}
+
+impl From<&Identity> for EventActor {
+    fn from(identity: &Identity) -> Self {
+        let actor_type = match identity {
+            Identity::User(_) => ActorType::User,
+            Identity::Client(_) => ActorType::Client,
+        };
+
+        Self {
+            actor_type,
+            id: identity.id(),
+            realm_id: identity.realm_id(),
+            name: identity.display_name(),
+        }
+    }
+}
+
+/// A change made to the configuration of a realm through the admin API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AdminEvent {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub resource_type: ResourceType,
+    pub resource_id: Option<Uuid>,
+    pub operation: OperationType,
+    pub actor: EventActor,
+    /// Top-level fields of the resource changed by the operation, each with
+    /// its `old` and `new` value. Secrets and passwords are redacted.
+    #[schema(value_type = Option<Object>)]
+    pub representation: Option<Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AdminEvent {
+    pub fn new(
+        realm_id: Uuid,
+        identity: &Identity,
+        resource_type: ResourceType,
+        operation: OperationType,
+    ) -> Self {
+        let (now, timestamp) = generate_timestamp();
+
+        Self {
+            id: Uuid::new_v7(timestamp),
+            realm_id,
+            resource_type,
+            resource_id: None,
+            operation,
+            actor: EventActor::from(identity),
+            representation: None,
+            created_at: now,
+        }
+    }
+
+    pub fn with_resource(mut self, resource_id: Uuid) -> Self {
+        self.resource_id = Some(resource_id);
+        self
+    }
+
+    /// Records every field of a created resource.
+    pub fn with_created(self, after: &impl Serialize) -> Self {
+        self.with_diff(None, serde_json::to_value(after).ok())
+    }
+
+    /// Records the fields that differ between two states of the resource.
+    pub fn with_changes(self, before: &impl Serialize, after: &impl Serialize) -> Self {
+        self.with_diff(
+            serde_json::to_value(before).ok(),
+            serde_json::to_value(after).ok(),
+        )
+    }
+
+    /// Records every field of a deleted resource.
+    pub fn with_deleted(self, before: &impl Serialize) -> Self {
+        self.with_diff(serde_json::to_value(before).ok(), None)
+    }
+
+    fn with_diff(mut self, before: Option<Value>, after: Option<Value>) -> Self {
+        self.representation = representation_diff(before, after);
+        self
+    }
+}
+
+/// Fields of the two representations that differ, as `{"old", "new"}`
+/// pairs. Representations that are not JSON objects are compared as a
+/// whole.
+fn representation_diff(before: Option<Value>, after: Option<Value>) -> Option<Value> {
+    let as_fields = |value: Option<Value>| match value {
+        Some(Value::Object(fields)) => fields,
+        Some(Value::Null) | None => Map::new(),
+        Some(value) => Map::from_iter([("value".to_string(), value)]),
+    };
+
+    let before = as_fields(before);
+    let after = as_fields(after);
+
+    let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    let diff: Map<String, Value> = names
+        .into_iter()
+        .filter_map(|name| {
+            let old = before.get(name).cloned().unwrap_or(Value::Null);
+            let new = after.get(name).cloned().unwrap_or(Value::Null);
+
+            if old == new {
+                return None;
+            }
+
+            let (old, new) = if REDACTED_FIELDS.contains(&name.as_str()) {
+                (redact(old), redact(new))
+            } else {
+                (old, new)
+            };
+
+            Some((name.clone(), json!({ "old": old, "new": new })))
+        })
+        .collect();
+
+    (!diff.is_empty()).then_some(Value::Object(diff))
+}
+
+fn redact(value: Value) -> Value {
+    match value {
+        Value::Null => Value::Null,
+        _ => Value::String(REDACTED_VALUE.to_string()),
+    }
+}
+
+/// Authentication event of a user, recorded along with the webhooks it
+/// triggers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LoginEvent {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub event_type: LoginEventType,
+    pub user_id: Option<Uuid>,
+    /// Username of the user, or the one attempted when no user matched it.
+    pub username: Option<String>,
+    pub client_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    /// Why the attempt was rejected, for error events.
+    pub error: Option<String>,
+    pub required_action: Option<RequiredAction>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoginEvent {
+    pub fn new(realm_id: Uuid, event_type: LoginEventType, event: AuthEvent) -> Self {
+        let (now, timestamp) = generate_timestamp();
+
+        Self {
+            id: Uuid::new_v7(timestamp),
+            realm_id,
+            event_type,
+            user_id: event.user_id,
+            username: event.username,
+            client_id: event.client_id,
+            ip_address: event.ip_address,
+            user_agent: event.user_agent,
+            error: event.reason.map(|reason| reason.to_string()),
+            required_action: event.required_action,
+            created_at: now,
+        }
+    }
+}
+
+/// Criteria of a login event search. All the filters that are set must
+/// match; `from` and `to` bound the time the events were recorded at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginEventQuery {
+    pub event_type: Option<LoginEventType>,
+    pub user_id: Option<Uuid>,
+    pub client_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+impl Default for LoginEventQuery {
+    fn default() -> Self {
+        Self {
+            event_type: None,
+            user_id: None,
+            client_id: None,
+            ip_address: None,
+            from: None,
+            to: None,
+            offset: 0,
+            limit: DEFAULT_EVENT_PAGE_SIZE,
+        }
+    }
+}
+
+/// Criteria of an admin event search. All the filters that are set must
+/// match; `from` and `to` bound the time the events were recorded at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminEventQuery {
+    pub operation: Option<OperationType>,
+    pub resource_type: Option<ResourceType>,
+    pub resource_id: Option<Uuid>,
+    /// Id of the user or client that performed the operations.
+    pub actor_id: Option<Uuid>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+impl Default for AdminEventQuery {
+    fn default() -> Self {
+        Self {
+            operation: None,
+            resource_type: None,
+            resource_id: None,
+            actor_id: None,
+            from: None,
+            to: None,
+            offset: 0,
+            limit: DEFAULT_EVENT_PAGE_SIZE,
+        }
+    }
+}
+
+/// One page of login events, most recent first, with the number of events
+/// matching the search.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct LoginEventPage {
+    pub data: Vec<LoginEvent>,
+    pub total: u64,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+/// One page of admin events, most recent first, with the number of events
+/// matching the search.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AdminEventPage {
+    pub data: Vec<AdminEvent>,
+    pub total: u64,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+pub struct GetLoginEventsInput {
+    pub realm_name: String,
+    pub query: LoginEventQuery,
+}
+
+pub struct GetAdminEventsInput {
+    pub realm_name: String,
+    pub query: AdminEventQuery,
+}
+
+pub struct ClearEventsInput {
+    pub realm_name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_representation_diff_keeps_changed_fields_only() {
+        let before = json!({ "name": "app", "enabled": true, "secret": "s3cr3t" });
+        let after = json!({ "name": "app", "enabled": false, "secret": "rotated" });
+
+        assert_eq!(
+            representation_diff(Some(before.clone()), Some(after)),
+            Some(json!({
+                "enabled": { "old": true, "new": false },
+                "secret": { "old": REDACTED_VALUE, "new": REDACTED_VALUE },
+            }))
+        );
+        assert_eq!(
+            representation_diff(Some(before.clone()), Some(before)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_representation_diff_of_created_resource() {
+        let after = json!({ "name": "app", "password": null });
+
+        assert_eq!(
+            representation_diff(None, Some(after)),
+            Some(json!({ "name": { "old": null, "new": "app" } }))
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    event::entities::{
+        AdminEvent, AdminEventPage, AdminEventQuery, ClearEventsInput, GetAdminEventsInput,
+        GetLoginEventsInput, LoginEvent, LoginEventPage, LoginEventQuery,
+    },
+    realm::entities::Realm,
+};
+
+pub trait EventService: Clone + Send + Sync + 'static {
+    fn get_login_events(
+        &self,
+        identity: Identity,
+        input: GetLoginEventsInput,
+    ) -> impl Future<Output = Result<LoginEventPage, CoreError>> + Send;
+
+    fn get_admin_events(
+        &self,
+        identity: Identity,
+        input: GetAdminEventsInput,
+    ) -> impl Future<Output = Result<AdminEventPage, CoreError>> + Send;
+
+    /// Deletes every login event of the realm and returns how many there
+    /// were.
+    fn clear_login_events(
+        &self,
+        identity: Identity,
+        input: ClearEventsInput,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Deletes every admin event of the realm and returns how many there
+    /// were.
+    fn clear_admin_events(
+        &self,
+        identity: Identity,
+        input: ClearEventsInput,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Deletes the events older than the retention period of their realm.
+    fn purge_expired_events(&self) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait EventPolicy: Send + Sync + Clone {
+    fn can_view_events(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_events(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait EventRepository: Clone + Send + Sync + 'static {
+    fn create_login_event(
+        &self,
+        event: LoginEvent,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn create_admin_event(
+        &self,
+        event: AdminEvent,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Login events of the realm matching `query`, most recent first.
+    fn search_login_events(
+        &self,
+        realm_id: Uuid,
+        query: LoginEventQuery,
+    ) -> impl Future<Output = Result<LoginEventPage, CoreError>> + Send;
+
+    /// Admin events of the realm matching `query`, most recent first.
+    fn search_admin_events(
+        &self,
+        realm_id: Uuid,
+        query: AdminEventQuery,
+    ) -> impl Future<Output = Result<AdminEventPage, CoreError>> + Send;
+
+    /// Deletes the login events of the realm recorded before `before`, or
+    /// all of them when unset.
+    fn delete_login_events(
+        &self,
+        realm_id: Uuid,
+        before: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+
+    /// Deletes the admin events of the realm recorded before `before`, or
+    /// all of them when unset.
+    fn delete_admin_events(
+        &self,
+        realm_id: Uuid,
+        before: Option<DateTime<Utc>>,
+    ) -> impl Future<Output = Result<u64, CoreError>> + Send;
+}
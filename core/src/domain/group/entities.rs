@@ -0,0 +1,130 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Free-form attributes of a group, each key holding a list of values.
+pub type GroupAttributes = BTreeMap<String, Vec<String>>;
+
+/// A set of users of a realm. Members inherit the roles mapped to the group
+/// and to each of its ancestors.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, ToSchema)]
+pub struct Group {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub description: Option<String>,
+    pub attributes: GroupAttributes,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct CreateGroupInput {
+    pub realm_name: String,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub description: Option<String>,
+    pub attributes: GroupAttributes,
+}
+
+pub struct UpdateGroupInput {
+    pub realm_name: String,
+    pub group_id: Uuid,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub attributes: Option<GroupAttributes>,
+}
+
+pub struct GetGroupInput {
+    pub realm_name: String,
+    pub group_id: Uuid,
+}
+
+pub struct DeleteGroupInput {
+    pub realm_name: String,
+    pub group_id: Uuid,
+}
+
+pub struct GroupMemberInput {
+    pub realm_name: String,
+    pub group_id: Uuid,
+    pub user_id: Uuid,
+}
+
+pub struct GroupRoleInput {
+    pub realm_name: String,
+    pub group_id: Uuid,
+    pub role_id: Uuid,
+}
+
+pub struct GetUserGroupsInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+}
+
+impl Group {
+    /// Full path of the group from the root of its hierarchy, such as
+    /// `/engineering/backend`, resolving ancestors from `realm_groups`.
+    pub fn path(&self, realm_groups: &[Group]) -> String {
+        let groups: HashMap<Uuid, &Group> =
+            realm_groups.iter().map(|group| (group.id, group)).collect();
+
+        let mut names = vec![self.name.as_str()];
+        let mut seen = HashSet::from([self.id]);
+        let mut current = self.parent_id;
+
+        while let Some(parent) = current.and_then(|id| groups.get(&id)) {
+            if !seen.insert(parent.id) {
+                break;
+            }
+
+            names.push(parent.name.as_str());
+            current = parent.parent_id;
+        }
+
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(id: u128, parent_id: Option<u128>) -> Group {
+        Group {
+            id: Uuid::from_u128(id),
+            realm_id: Uuid::nil(),
+            parent_id: parent_id.map(Uuid::from_u128),
+            name: id.to_string(),
+            description: None,
+            attributes: GroupAttributes::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn path_walks_up_to_the_root() {
+        let mut backend = group(3, Some(2));
+        backend.name = "backend".to_string();
+        let mut engineering = group(2, Some(1));
+        engineering.name = "engineering".to_string();
+        let mut company = group(1, None);
+        company.name = "company".to_string();
+
+        let realm_groups = vec![company, engineering, backend.clone()];
+
+        assert_eq!(backend.path(&realm_groups), "/company/engineering/backend");
+    }
+
+    #[test]
+    fn path_stops_on_cycles() {
+        let realm_groups = vec![group(1, Some(2)), group(2, Some(1))];
+
+        assert_eq!(realm_groups[0].path(&realm_groups), "/2/1");
+    }
+}
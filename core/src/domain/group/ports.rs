@@ -0,0 +1,187 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    authentication::value_objects::Identity,
+    common::entities::app_errors::CoreError,
+    group::{
+        entities::{
+            CreateGroupInput, DeleteGroupInput, GetGroupInput, GetUserGroupsInput, Group,
+            GroupMemberInput, GroupRoleInput, UpdateGroupInput,
+        },
+        value_objects::{CreateGroupRequest, UpdateGroupRequest},
+    },
+    realm::entities::Realm,
+    role::entities::Role,
+    user::entities::User,
+};
+
+pub trait GroupService: Clone + Send + Sync + 'static {
+    fn get_groups(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<Group>, CoreError>> + Send;
+
+    fn get_group(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> impl Future<Output = Result<Group, CoreError>> + Send;
+
+    fn get_group_children(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> impl Future<Output = Result<Vec<Group>, CoreError>> + Send;
+
+    fn create_group(
+        &self,
+        identity: Identity,
+        input: CreateGroupInput,
+    ) -> impl Future<Output = Result<Group, CoreError>> + Send;
+
+    fn update_group(
+        &self,
+        identity: Identity,
+        input: UpdateGroupInput,
+    ) -> impl Future<Output = Result<Group, CoreError>> + Send;
+
+    fn delete_group(
+        &self,
+        identity: Identity,
+        input: DeleteGroupInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_group_members(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> impl Future<Output = Result<Vec<User>, CoreError>> + Send;
+
+    fn add_group_member(
+        &self,
+        identity: Identity,
+        input: GroupMemberInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn remove_group_member(
+        &self,
+        identity: Identity,
+        input: GroupMemberInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_user_groups(
+        &self,
+        identity: Identity,
+        input: GetUserGroupsInput,
+    ) -> impl Future<Output = Result<Vec<Group>, CoreError>> + Send;
+
+    fn get_group_roles(
+        &self,
+        identity: Identity,
+        input: GetGroupInput,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+
+    fn assign_group_role(
+        &self,
+        identity: Identity,
+        input: GroupRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn unassign_group_role(
+        &self,
+        identity: Identity,
+        input: GroupRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+}
+
+pub trait GroupPolicy: Send + Sync + Clone {
+    fn can_view_group(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+
+    fn can_manage_group(
+        &self,
+        identity: Identity,
+        target_realm: Realm,
+    ) -> impl Future<Output = Result<bool, CoreError>> + Send;
+}
+
+pub trait GroupRepository: Clone + Send + Sync + 'static {
+    fn create_group(
+        &self,
+        payload: CreateGroupRequest,
+    ) -> impl Future<Output = Result<Group, CoreError>> + Send;
+
+    fn get_by_id(
+        &self,
+        group_id: Uuid,
+    ) -> impl Future<Output = Result<Option<Group>, CoreError>> + Send;
+
+    fn find_by_realm_id(
+        &self,
+        realm_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Group>, CoreError>> + Send;
+
+    fn find_children(
+        &self,
+        group_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Group>, CoreError>> + Send;
+
+    fn update_group(
+        &self,
+        group_id: Uuid,
+        payload: UpdateGroupRequest,
+    ) -> impl Future<Output = Result<Group, CoreError>> + Send;
+
+    /// Deletes the group along with its subgroups.
+    fn delete_group(&self, group_id: Uuid) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn add_member(
+        &self,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn remove_member(
+        &self,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_members(
+        &self,
+        group_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<User>, CoreError>> + Send;
+
+    fn get_user_groups(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Group>, CoreError>> + Send;
+
+    fn assign_role(
+        &self,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn unassign_role(
+        &self,
+        group_id: Uuid,
+        role_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_group_roles(
+        &self,
+        group_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+
+    /// Roles a user inherits from the groups it belongs to and from all of
+    /// their ancestors.
+    fn get_inherited_roles(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+}
@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    group::ports::GroupRepository,
+    role::{entities::Role, ports::RoleRepository, services::expand_composites},
+    user::ports::UserRoleRepository,
+};
+
+/// Roles assigned directly to the user plus the ones inherited from its
+/// groups and their ancestors, expanded through composite roles and without
+/// duplicates.
+pub async fn effective_roles<UR, G, R>(
+    user_role_repository: &UR,
+    group_repository: &G,
+    role_repository: &R,
+    user_id: Uuid,
+) -> Result<Vec<Role>, CoreError>
+where
+    UR: UserRoleRepository,
+    G: GroupRepository,
+    R: RoleRepository,
+{
+    let mut roles = user_role_repository.get_user_roles(user_id).await?;
+    let inherited_roles = group_repository.get_inherited_roles(user_id).await?;
+
+    let mut seen: HashSet<Uuid> = roles.iter().map(|role| role.id).collect();
+    roles.extend(
+        inherited_roles
+            .into_iter()
+            .filter(|role| seen.insert(role.id)),
+    );
+
+    expand_composites(role_repository, roles).await
+}
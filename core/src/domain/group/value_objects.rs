@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::group::entities::GroupAttributes;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreateGroupRequest {
+    pub realm_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub description: Option<String>,
+    pub attributes: GroupAttributes,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpdateGroupRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub attributes: Option<GroupAttributes>,
+}
@@ -1,25 +1,42 @@
+use std::fmt::Display;
+
 use base64::Engine;
 use base64::prelude::BASE64_URL_SAFE_NO_PAD;
 use chrono::{DateTime, Utc};
-use jsonwebtoken::{DecodingKey, EncodingKey};
-use rsa::pkcs8::DecodePublicKey;
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use ring::rand::SystemRandom;
+use ring::signature::{
+    ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P384_SHA384_FIXED_SIGNING, EcdsaKeyPair,
+    EcdsaSigningAlgorithm, Ed25519KeyPair, KeyPair,
+};
+use rsa::pkcs8::der::asn1::{AnyRef, BitStringRef};
+use rsa::pkcs8::der::{EncodePem, pem};
+use rsa::pkcs8::{
+    AlgorithmIdentifierRef, DecodePublicKey, ObjectIdentifier, SubjectPublicKeyInfoRef,
+};
 use rsa::traits::PublicKeyParts;
 use rsa::{
     RsaPrivateKey, RsaPublicKey,
     pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding},
 };
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use thiserror::Error;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::{
+    client::entities::protocol_mapper::{MappedClaims, MapperOutput},
+    realm::entities::{DEFAULT_ACCESS_TOKEN_LIFESPAN, DEFAULT_REFRESH_TOKEN_LIFESPAN},
+};
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ClaimsTyp {
     Refresh,
     Bearer,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct JwtClaim {
     pub sub: Uuid,
     pub iat: i64,
@@ -35,6 +52,12 @@ pub struct JwtClaim {
     pub email: Option<String>,
 
     pub client_id: Option<String>,
+
+    pub scope: Option<String>,
+
+    /// Claims added by the protocol mappers of the client.
+    #[serde(flatten)]
+    pub claims: MappedClaims,
 }
 
 impl JwtClaim {
@@ -53,13 +76,15 @@ impl JwtClaim {
             preferred_username: Some(preferred_username),
             iat: timestamp,
             jti: Uuid::new_v4(),
-            exp: Some(timestamp + 60 * 5), // 5 minutes
+            exp: Some(timestamp + i64::from(DEFAULT_ACCESS_TOKEN_LIFESPAN)),
             iss,
             aud,
             typ,
             azp,
             email,
             client_id: None,
+            scope: None,
+            claims: MappedClaims::new(),
         }
     }
 
@@ -74,8 +99,44 @@ impl JwtClaim {
             azp,
             preferred_username: None,
             email: None,
-            exp: Some(chrono::Utc::now().timestamp() + 86400), // 24 hours
+            exp: Some(chrono::Utc::now().timestamp() + i64::from(DEFAULT_REFRESH_TOKEN_LIFESPAN)),
             client_id: None,
+            scope: None,
+            claims: MappedClaims::new(),
+        }
+    }
+
+    /// Sets the expiration to `lifespan` seconds after the token was issued.
+    pub fn with_lifespan(mut self, lifespan: i64) -> Self {
+        self.exp = Some(self.iat + lifespan);
+        self
+    }
+
+    pub fn with_scope(mut self, scope: Option<String>) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Adds the claims and audiences produced by protocol mappers.
+    pub fn with_mapped_claims(mut self, mapped: MapperOutput) -> Self {
+        for audience in mapped.audiences {
+            if !self.aud.contains(&audience) {
+                self.aud.push(audience);
+            }
+        }
+
+        self.claims.extend(mapped.claims);
+        self
+    }
+
+    /// Check if the given scope was granted to this token.
+    ///
+    /// Tokens issued without a `scope` claim are treated as carrying the
+    /// default `openid profile email` scopes.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        match &self.scope {
+            Some(scopes) => scopes.split_whitespace().any(|s| s == scope),
+            None => matches!(scope, "openid" | "profile" | "email"),
         }
     }
 
@@ -84,30 +145,238 @@ impl JwtClaim {
     }
 }
 
+/// Claims of an OpenID Connect ID token.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IdTokenClaim {
+    pub iss: String,
+    pub sub: Uuid,
+    pub aud: String,
+    pub exp: i64,
+    pub iat: i64,
+    pub azp: String,
+    pub at_hash: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acr: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub amr: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_verified: Option<bool>,
+
+    /// Claims added by the protocol mappers of the client.
+    #[serde(flatten)]
+    pub claims: MappedClaims,
+}
+
+impl IdTokenClaim {
+    /// Computes the `at_hash` claim: the base64url encoded left-most half of
+    /// the SHA-256 hash of the access token.
+    pub fn hash_access_token(access_token: &str) -> String {
+        let digest = Sha256::digest(access_token.as_bytes());
+        BASE64_URL_SAFE_NO_PAD.encode(&digest[..digest.len() / 2])
+    }
+
+    /// Authentication context class reference derived from the `amr` claim.
+    ///
+    /// `0` means the user was signed in from an existing session, `1` that a
+    /// single factor was presented and `2` that a second factor was verified.
+    pub fn acr_from_amr(amr: &[String]) -> String {
+        match amr {
+            [] => "0".to_string(),
+            amr if amr.iter().any(|method| method == "otp") => "2".to_string(),
+            _ => "1".to_string(),
+        }
+    }
+
+    /// Decodes an ID token presented back by a client, such as the
+    /// `id_token_hint` of a logout request. Expired tokens are accepted, as
+    /// the hint only tells which user and client the request is about.
+    pub fn decode_hint(token: &str, key: &JwtKeyPair, issuer: &str) -> Result<Self, JwtError> {
+        let mut validation = Validation::new(key.algorithm.into());
+        validation.validate_aud = false;
+        validation.validate_exp = false;
+        validation.set_required_spec_claims(&["iss", "sub"]);
+        validation.set_issuer(&[issuer]);
+
+        jsonwebtoken::decode::<IdTokenClaim>(token, &key.decoding_key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| JwtError::ValidationError(e.to_string()))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, PartialOrd, Ord)]
 pub struct Jwt {
     pub token: String,
     pub expires_at: i64,
 }
 
+const ID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const SECP256R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const SECP384R1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+const ID_ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// Algorithms realm keys can sign tokens with.
+///
+/// RS256 and PS256 use RSA keys, ES256 and ES384 EC keys on the P-256 and
+/// P-384 curves, and EdDSA Ed25519 keys.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum SigningAlgorithm {
+    #[default]
+    RS256,
+    PS256,
+    ES256,
+    ES384,
+    EdDSA,
+}
+
+impl Display for SigningAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SigningAlgorithm::RS256 => write!(f, "RS256"),
+            SigningAlgorithm::PS256 => write!(f, "PS256"),
+            SigningAlgorithm::ES256 => write!(f, "ES256"),
+            SigningAlgorithm::ES384 => write!(f, "ES384"),
+            SigningAlgorithm::EdDSA => write!(f, "EdDSA"),
+        }
+    }
+}
+
+impl TryFrom<String> for SigningAlgorithm {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "RS256" => Ok(SigningAlgorithm::RS256),
+            "PS256" => Ok(SigningAlgorithm::PS256),
+            "ES256" => Ok(SigningAlgorithm::ES256),
+            "ES384" => Ok(SigningAlgorithm::ES384),
+            "EdDSA" => Ok(SigningAlgorithm::EdDSA),
+            _ => Err(format!("Unsupported signing algorithm: {value}")),
+        }
+    }
+}
+
+impl From<SigningAlgorithm> for Algorithm {
+    fn from(value: SigningAlgorithm) -> Self {
+        match value {
+            SigningAlgorithm::RS256 => Algorithm::RS256,
+            SigningAlgorithm::PS256 => Algorithm::PS256,
+            SigningAlgorithm::ES256 => Algorithm::ES256,
+            SigningAlgorithm::ES384 => Algorithm::ES384,
+            SigningAlgorithm::EdDSA => Algorithm::EdDSA,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct JwtKeyPair {
     pub id: Uuid,
     pub realm_id: Uuid,
+    pub algorithm: SigningAlgorithm,
     pub encoding_key: EncodingKey,
     pub decoding_key: DecodingKey,
     pub public_key: String,
+    pub version: i32,
+    pub status: KeyStatus,
+    pub created_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+}
+
+/// Lifecycle of a realm signing key.
+///
+/// The active key signs new tokens. Passive keys no longer sign but still
+/// verify the tokens they issued, until they are retired.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyStatus {
+    #[default]
+    Active,
+    Passive,
+    Retired,
+}
+
+impl Display for KeyStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyStatus::Active => write!(f, "active"),
+            KeyStatus::Passive => write!(f, "passive"),
+            KeyStatus::Retired => write!(f, "retired"),
+        }
+    }
+}
+
+impl TryFrom<String> for KeyStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "active" => Ok(KeyStatus::Active),
+            "passive" => Ok(KeyStatus::Passive),
+            "retired" => Ok(KeyStatus::Retired),
+            _ => Err(format!("Unsupported key status: {value}")),
+        }
+    }
+}
+
+/// Public view of a realm signing key, as listed by the admin API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RealmKey {
+    pub id: Uuid,
+    pub realm_id: Uuid,
+    pub version: i32,
+    pub status: KeyStatus,
+    pub algorithm: SigningAlgorithm,
+    pub public_key: String,
+    pub created_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+}
+
+impl From<JwtKeyPair> for RealmKey {
+    fn from(value: JwtKeyPair) -> Self {
+        Self {
+            id: value.id,
+            realm_id: value.realm_id,
+            version: value.version,
+            status: value.status,
+            algorithm: value.algorithm,
+            public_key: value.public_key,
+            created_at: value.created_at,
+            rotated_at: value.rotated_at,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
 pub struct JwkKey {
     pub kid: String,
     pub kty: String,
+    #[serde(rename = "use")]
     pub use_: String,
     pub alg: String,
     pub x5c: String,
-    pub n: String,
-    pub e: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -141,17 +410,33 @@ pub struct RefreshToken {
     pub id: Uuid,
     pub jti: Uuid,
     pub user_id: Uuid,
+    pub client_id: Option<String>,
+    /// Refresh tokens obtained by rotating one another share a family.
+    pub family_id: Option<Uuid>,
     pub revoked: bool,
+    /// When the token was exchanged for a new one. Only tokens revoked this
+    /// way count as reused when presented again.
+    pub rotated_at: Option<DateTime<Utc>>,
+    /// When and how the user authenticated, carried into the ID tokens
+    /// issued when the token is refreshed.
+    pub auth_time: Option<DateTime<Utc>>,
+    pub amr: Vec<String>,
     pub expires_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
 }
 
 impl RefreshToken {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: Uuid,
         jti: Uuid,
         user_id: Uuid,
+        client_id: Option<String>,
+        family_id: Option<Uuid>,
         revoked: bool,
+        rotated_at: Option<DateTime<Utc>>,
+        auth_time: Option<DateTime<Utc>>,
+        amr: Vec<String>,
         expires_at: Option<DateTime<Utc>>,
         created_at: DateTime<Utc>,
     ) -> Self {
@@ -159,36 +444,84 @@ impl RefreshToken {
             id,
             jti,
             user_id,
+            client_id,
+            family_id,
             revoked,
+            rotated_at,
+            auth_time,
+            amr,
             expires_at,
             created_at,
         }
     }
 }
 
+/// Payload of the webhook sent when an already rotated refresh token is
+/// presented again.
+#[derive(Debug, Clone, Serialize)]
+pub struct RefreshTokenReuse {
+    pub user_id: Uuid,
+    pub client_id: Option<String>,
+    pub family_id: Uuid,
+    pub jti: Uuid,
+}
+
 impl JwtKeyPair {
     pub fn from_pem(
         private_pem: &str,
         public_pem: &str,
         realm_id: Uuid,
         id: Uuid,
+        algorithm: SigningAlgorithm,
     ) -> Result<Self, JwtError> {
-        let encoding_key = EncodingKey::from_rsa_pem(private_pem.as_bytes())
-            .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+        let (encoding_key, decoding_key) = match algorithm {
+            SigningAlgorithm::RS256 | SigningAlgorithm::PS256 => (
+                EncodingKey::from_rsa_pem(private_pem.as_bytes()),
+                DecodingKey::from_rsa_pem(public_pem.as_bytes()),
+            ),
+            SigningAlgorithm::ES256 | SigningAlgorithm::ES384 => (
+                EncodingKey::from_ec_pem(private_pem.as_bytes()),
+                DecodingKey::from_ec_pem(public_pem.as_bytes()),
+            ),
+            SigningAlgorithm::EdDSA => (
+                EncodingKey::from_ed_pem(private_pem.as_bytes()),
+                DecodingKey::from_ed_pem(public_pem.as_bytes()),
+            ),
+        };
 
-        let decoding_key = DecodingKey::from_rsa_pem(public_pem.as_bytes())
-            .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+        let encoding_key = encoding_key.map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+        let decoding_key = decoding_key.map_err(|e| JwtError::InvalidKey(e.to_string()))?;
 
         Ok(Self {
             id,
             realm_id,
+            algorithm,
             encoding_key,
             decoding_key,
             public_key: public_pem.to_string(),
+            version: 1,
+            status: KeyStatus::Active,
+            created_at: Utc::now(),
+            rotated_at: None,
         })
     }
 
-    pub fn generate() -> Result<(String, String), JwtError> {
+    /// Generates a key pair suited to `algorithm`, returned as PKCS#8
+    /// private and SPKI public PEM documents.
+    pub fn generate(algorithm: SigningAlgorithm) -> Result<(String, String), JwtError> {
+        match algorithm {
+            SigningAlgorithm::RS256 | SigningAlgorithm::PS256 => Self::generate_rsa(),
+            SigningAlgorithm::ES256 => {
+                Self::generate_ec(&ECDSA_P256_SHA256_FIXED_SIGNING, SECP256R1)
+            }
+            SigningAlgorithm::ES384 => {
+                Self::generate_ec(&ECDSA_P384_SHA384_FIXED_SIGNING, SECP384R1)
+            }
+            SigningAlgorithm::EdDSA => Self::generate_ed25519(),
+        }
+    }
+
+    fn generate_rsa() -> Result<(String, String), JwtError> {
         let mut rng = rand::thread_rng();
         let bits = 2048; // RSA key size in bits
         let private_key = RsaPrivateKey::new(&mut rng, bits)
@@ -207,22 +540,294 @@ impl JwtKeyPair {
         Ok((private_pem, public_pem))
     }
 
-    pub fn to_jwk_key(&self) -> Result<JwkKey, JwtError> {
-        let public_key = RsaPublicKey::from_public_key_pem(&self.public_key)
+    fn generate_ec(
+        signing_algorithm: &'static EcdsaSigningAlgorithm,
+        curve: ObjectIdentifier,
+    ) -> Result<(String, String), JwtError> {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(signing_algorithm, &rng)
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+        let key_pair = EcdsaKeyPair::from_pkcs8(signing_algorithm, pkcs8.as_ref(), &rng)
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        let private_pem = Self::private_key_pem(pkcs8.as_ref())?;
+        let public_pem = Self::public_key_pem(
+            AlgorithmIdentifierRef {
+                oid: ID_EC_PUBLIC_KEY,
+                parameters: Some(AnyRef::from(&curve)),
+            },
+            key_pair.public_key().as_ref(),
+        )?;
+
+        Ok((private_pem, public_pem))
+    }
+
+    fn generate_ed25519() -> Result<(String, String), JwtError> {
+        let rng = SystemRandom::new();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng)
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref())
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        let private_pem = Self::private_key_pem(pkcs8.as_ref())?;
+        let public_pem = Self::public_key_pem(
+            AlgorithmIdentifierRef {
+                oid: ID_ED25519,
+                parameters: None,
+            },
+            key_pair.public_key().as_ref(),
+        )?;
+
+        Ok((private_pem, public_pem))
+    }
+
+    fn private_key_pem(pkcs8: &[u8]) -> Result<String, JwtError> {
+        pem::encode_string("PRIVATE KEY", LineEnding::LF, pkcs8)
+            .map_err(|e| JwtError::GenerationError(e.to_string()))
+    }
+
+    fn public_key_pem(
+        algorithm: AlgorithmIdentifierRef<'_>,
+        public_key: &[u8],
+    ) -> Result<String, JwtError> {
+        let subject_public_key = BitStringRef::from_bytes(public_key)
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        SubjectPublicKeyInfoRef {
+            algorithm,
+            subject_public_key,
+        }
+        .to_pem(LineEnding::LF)
+        .map_err(|e| JwtError::GenerationError(e.to_string()))
+    }
+
+    /// Raw public key bytes carried by the SPKI public key PEM.
+    fn raw_public_key(&self) -> Result<Vec<u8>, JwtError> {
+        let (_, der) = pem::decode_vec(self.public_key.as_bytes())
+            .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+        let spki = SubjectPublicKeyInfoRef::try_from(der.as_slice())
             .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
 
-        let n = BASE64_URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be());
-        let e = BASE64_URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be());
+        Ok(spki.subject_public_key.raw_bytes().to_vec())
+    }
+
+    /// JWT header for tokens signed with this key, carrying its `kid`.
+    pub fn header(&self) -> Header {
+        let mut header = Header::new(self.algorithm.into());
+        header.kid = Some(self.id.to_string());
+        header
+    }
+
+    pub fn to_jwk_key(&self) -> Result<JwkKey, JwtError> {
         let x5c = BASE64_URL_SAFE_NO_PAD.encode(self.public_key.as_bytes());
 
-        Ok(JwkKey {
+        let mut jwk = JwkKey {
             kid: self.id.to_string(),
-            kty: "RSA".to_string(),
+            kty: String::new(),
             use_: "sig".to_string(),
-            alg: "RS256".to_string(),
+            alg: self.algorithm.to_string(),
             x5c,
-            n,
-            e,
-        })
+            n: None,
+            e: None,
+            crv: None,
+            x: None,
+            y: None,
+        };
+
+        match self.algorithm {
+            SigningAlgorithm::RS256 | SigningAlgorithm::PS256 => {
+                let public_key = RsaPublicKey::from_public_key_pem(&self.public_key)
+                    .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+
+                jwk.kty = "RSA".to_string();
+                jwk.n = Some(BASE64_URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be()));
+                jwk.e = Some(BASE64_URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be()));
+            }
+            SigningAlgorithm::ES256 | SigningAlgorithm::ES384 => {
+                // Uncompressed SEC1 point: 0x04 || x || y
+                let point = self.raw_public_key()?;
+                let Some((&0x04, coordinates)) = point.split_first() else {
+                    return Err(JwtError::InvalidKey(
+                        "Unsupported EC point format".to_string(),
+                    ));
+                };
+                let (x, y) = coordinates.split_at(coordinates.len() / 2);
+
+                jwk.kty = "EC".to_string();
+                jwk.crv = Some(match self.algorithm {
+                    SigningAlgorithm::ES256 => "P-256".to_string(),
+                    _ => "P-384".to_string(),
+                });
+                jwk.x = Some(BASE64_URL_SAFE_NO_PAD.encode(x));
+                jwk.y = Some(BASE64_URL_SAFE_NO_PAD.encode(y));
+            }
+            SigningAlgorithm::EdDSA => {
+                jwk.kty = "OKP".to_string();
+                jwk.crv = Some("Ed25519".to_string());
+                jwk.x = Some(BASE64_URL_SAFE_NO_PAD.encode(self.raw_public_key()?));
+            }
+        }
+
+        Ok(jwk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_pair(algorithm: SigningAlgorithm) -> JwtKeyPair {
+        let (private_pem, public_pem) = JwtKeyPair::generate(algorithm).unwrap();
+
+        JwtKeyPair::from_pem(
+            &private_pem,
+            &public_pem,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            algorithm,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sign_and_verify_with_each_algorithm() {
+        for algorithm in [
+            SigningAlgorithm::RS256,
+            SigningAlgorithm::PS256,
+            SigningAlgorithm::ES256,
+            SigningAlgorithm::ES384,
+            SigningAlgorithm::EdDSA,
+        ] {
+            let key = key_pair(algorithm);
+            let claims = JwtClaim::new_refresh_token(
+                Uuid::new_v4(),
+                "issuer".to_string(),
+                vec![],
+                "client".to_string(),
+            );
+
+            let token = jsonwebtoken::encode(&key.header(), &claims, &key.encoding_key).unwrap();
+            let mut validation = jsonwebtoken::Validation::new(algorithm.into());
+            validation.validate_aud = false;
+
+            let decoded =
+                jsonwebtoken::decode::<JwtClaim>(&token, &key.decoding_key, &validation).unwrap();
+            assert_eq!(decoded.claims, claims, "{algorithm}");
+        }
+    }
+
+    #[test]
+    fn test_claims_with_mapped_claims_round_trip() {
+        use crate::domain::client::entities::protocol_mapper::{
+            MapperContext, ProtocolMapper, ProtocolMapperConfig, ProtocolMapperOwner,
+            ProtocolMapperTargets, TokenTarget,
+        };
+
+        let hardcoded = |claim: &str| {
+            ProtocolMapper::new(
+                ProtocolMapperOwner::Client(Uuid::nil()),
+                claim.to_string(),
+                ProtocolMapperConfig::HardcodedClaim {
+                    claim: claim.to_string(),
+                    value: serde_json::json!("mapped"),
+                },
+                ProtocolMapperTargets {
+                    access_token: true,
+                    id_token: true,
+                    userinfo: true,
+                    introspection: true,
+                },
+            )
+        };
+        let mappers = vec![
+            hardcoded("department"),
+            hardcoded("email"),
+            hardcoded("preferred_username"),
+        ];
+        let output = MapperOutput::evaluate(
+            &mappers,
+            TokenTarget::AccessToken,
+            &MapperContext::default(),
+        );
+
+        let claims = JwtClaim::new(
+            Uuid::new_v4(),
+            "jane".to_string(),
+            "issuer".to_string(),
+            vec!["account".to_string()],
+            ClaimsTyp::Bearer,
+            "client".to_string(),
+            Some("jane@example.com".to_string()),
+        )
+        .with_mapped_claims(output);
+
+        let json = serde_json::to_string(&claims).unwrap();
+
+        assert_eq!(serde_json::from_str::<JwtClaim>(&json).unwrap(), claims);
+        assert_eq!(claims.email.as_deref(), Some("jane@example.com"));
+        assert_eq!(claims.preferred_username.as_deref(), Some("jane"));
+        assert_eq!(claims.claims.len(), 1);
+    }
+
+    #[test]
+    fn test_expired_id_token_is_accepted_as_hint() {
+        let key = key_pair(SigningAlgorithm::RS256);
+        let now = chrono::Utc::now().timestamp();
+        let claims = IdTokenClaim {
+            iss: "http://localhost/realms/master".to_string(),
+            sub: Uuid::new_v4(),
+            aud: "client".to_string(),
+            exp: now - 3_600,
+            iat: now - 3_900,
+            azp: "client".to_string(),
+            at_hash: IdTokenClaim::hash_access_token("access"),
+            auth_time: None,
+            nonce: None,
+            acr: None,
+            amr: Vec::new(),
+            preferred_username: Some("jane".to_string()),
+            given_name: None,
+            family_name: None,
+            email: None,
+            email_verified: None,
+            claims: MappedClaims::new(),
+        };
+        let token = jsonwebtoken::encode(&key.header(), &claims, &key.encoding_key).unwrap();
+
+        assert_eq!(
+            IdTokenClaim::decode_hint(&token, &key, "http://localhost/realms/master").unwrap(),
+            claims
+        );
+        assert!(IdTokenClaim::decode_hint(&token, &key, "http://localhost/realms/other").is_err());
+        assert!(
+            IdTokenClaim::decode_hint(
+                &token,
+                &key_pair(SigningAlgorithm::RS256),
+                "http://localhost/realms/master"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_ec_jwk_key() {
+        let jwk = key_pair(SigningAlgorithm::ES256).to_jwk_key().unwrap();
+
+        assert_eq!(jwk.kty, "EC");
+        assert_eq!(jwk.alg, "ES256");
+        assert_eq!(jwk.crv.as_deref(), Some("P-256"));
+        assert_eq!(jwk.x.map(|x| x.len()), Some(43));
+        assert_eq!(jwk.y.map(|y| y.len()), Some(43));
+        assert!(jwk.n.is_none());
+    }
+
+    #[test]
+    fn test_okp_jwk_key() {
+        let jwk = key_pair(SigningAlgorithm::EdDSA).to_jwk_key().unwrap();
+
+        assert_eq!(jwk.kty, "OKP");
+        assert_eq!(jwk.crv.as_deref(), Some("Ed25519"));
+        assert_eq!(jwk.x.map(|x| x.len()), Some(43));
+        assert!(jwk.y.is_none());
     }
 }
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::{
-    jwt::entities::{Jwt, JwtClaim, JwtError, JwtKeyPair, RefreshToken},
+    jwt::entities::{Jwt, JwtClaim, JwtError, JwtKeyPair, RefreshToken, SigningAlgorithm},
     realm::entities::Realm,
 };
 
@@ -32,19 +32,86 @@ pub trait JwtService: Clone + Send + Sync + 'static {
 }
 
 pub trait RefreshTokenRepository: Clone + Send + Sync + 'static {
+    #[allow(clippy::too_many_arguments)]
     fn create(
         &self,
         jti: Uuid,
         user_id: Uuid,
+        client_id: Option<String>,
+        family_id: Option<Uuid>,
+        auth_time: Option<DateTime<Utc>>,
+        amr: Vec<String>,
         expires_at: Option<DateTime<Utc>>,
     ) -> impl Future<Output = Result<RefreshToken, JwtError>> + Send;
     fn get_by_jti(&self, jti: Uuid) -> impl Future<Output = Result<RefreshToken, JwtError>> + Send;
     fn delete(&self, jti: Uuid) -> impl Future<Output = Result<(), JwtError>> + Send;
+    /// Revokes a single refresh token. Returns `false` when it was already
+    /// revoked, so concurrent uses of the same token cannot both succeed.
+    fn revoke(&self, jti: Uuid) -> impl Future<Output = Result<bool, JwtError>> + Send;
+    /// Revokes a refresh token exchanged for a new one, recording when. Returns
+    /// `false` when it was already revoked.
+    fn rotate(&self, jti: Uuid) -> impl Future<Output = Result<bool, JwtError>> + Send;
+    fn revoke_family(&self, family_id: Uuid) -> impl Future<Output = Result<(), JwtError>> + Send;
+    fn revoke_by_user_and_client(
+        &self,
+        user_id: Uuid,
+        client_id: String,
+    ) -> impl Future<Output = Result<(), JwtError>> + Send;
+    fn revoke_by_user_id(&self, user_id: Uuid)
+    -> impl Future<Output = Result<(), JwtError>> + Send;
 }
 
 pub trait KeyStoreRepository: Clone + Send + Sync + 'static {
+    /// Returns the active signing key of the realm, generating one signing
+    /// with `algorithm` when the realm has none. The active key is never
+    /// rotated here: see `rotate_key`.
     fn get_or_generate_key(
         &self,
         realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+    ) -> impl Future<Output = Result<JwtKeyPair, JwtError>> + Send;
+
+    fn get_key(
+        &self,
+        realm_id: Uuid,
+        key_id: Uuid,
+    ) -> impl Future<Output = Result<Option<JwtKeyPair>, JwtError>> + Send;
+
+    /// Lists every key of the realm, newest version first.
+    fn list_keys(
+        &self,
+        realm_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<JwtKeyPair>, JwtError>> + Send;
+
+    /// Demotes the active key to passive and generates a new active key
+    /// signing with `algorithm`.
+    fn rotate_key(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
     ) -> impl Future<Output = Result<JwtKeyPair, JwtError>> + Send;
+
+    /// Rotates the key like [`KeyStoreRepository::rotate_key`] only if the
+    /// active key was created before `created_before`, checked under the
+    /// lock of the realm's keys. Returns `None` when the active key is
+    /// recent, e.g. because another instance rotated it meanwhile.
+    fn rotate_key_created_before(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+        created_before: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Option<JwtKeyPair>, JwtError>> + Send;
+
+    fn retire_key(
+        &self,
+        realm_id: Uuid,
+        key_id: Uuid,
+    ) -> impl Future<Output = Result<JwtKeyPair, JwtError>> + Send;
+
+    /// Retires the passive keys rotated before `rotated_before`.
+    fn retire_passive_keys(
+        &self,
+        realm_id: Uuid,
+        rotated_before: DateTime<Utc>,
+    ) -> impl Future<Output = Result<(), JwtError>> + Send;
 }
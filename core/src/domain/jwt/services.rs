@@ -1,11 +1,15 @@
 use crate::domain::{
+    common::entities::app_errors::CoreError,
     jwt::{
-        entities::{Jwt, JwtClaim, JwtError, JwtKeyPair},
+        entities::{Jwt, JwtClaim, JwtError, JwtKeyPair, KeyStatus},
         ports::{JwtService, KeyStoreRepository, RefreshTokenRepository},
     },
-    realm::{entities::Realm, ports::RealmRepository},
+    realm::{
+        entities::{Realm, RealmSetting},
+        ports::RealmRepository,
+    },
 };
-use jsonwebtoken::{Algorithm, Header, Validation, decode};
+use jsonwebtoken::{Validation, decode, decode_header};
 use tracing::error;
 use uuid::Uuid;
 
@@ -34,6 +38,45 @@ where
             realm_repository,
         }
     }
+
+    async fn signing_key(&self, realm_id: Uuid) -> Result<JwtKeyPair, JwtError> {
+        let realm_setting = match self.realm_repository.get_realm_settings(realm_id).await {
+            Ok(realm_setting) => realm_setting,
+            Err(CoreError::NotFound) => RealmSetting::new(realm_id, None),
+            Err(_) => return Err(JwtError::RealmKeyNotFound),
+        };
+
+        self.keystore_repository
+            .get_or_generate_key(realm_id, realm_setting.signing_algorithm())
+            .await
+    }
+}
+
+/// Selects the key that verifies `token`: the non-retired key named by its
+/// `kid` header, or the active key for tokens issued without one.
+pub async fn find_verification_key<K: KeyStoreRepository>(
+    keystore_repository: &K,
+    token: &str,
+    realm_id: Uuid,
+) -> Result<JwtKeyPair, JwtError> {
+    let header = decode_header(token).map_err(|e| JwtError::ValidationError(e.to_string()))?;
+
+    let Some(kid) = header.kid else {
+        return keystore_repository
+            .list_keys(realm_id)
+            .await?
+            .into_iter()
+            .find(|key| key.status == KeyStatus::Active)
+            .ok_or(JwtError::InvalidToken);
+    };
+
+    let kid = Uuid::parse_str(&kid).map_err(|_| JwtError::InvalidToken)?;
+
+    keystore_repository
+        .get_key(realm_id, kid)
+        .await?
+        .filter(|key| key.status != KeyStatus::Retired)
+        .ok_or(JwtError::InvalidToken)
 }
 
 impl<RR, K, R> JwtService for JwtServiceImpl<RR, K, R>
@@ -43,18 +86,15 @@ where
     R: RealmRepository,
 {
     async fn generate_token(&self, claims: JwtClaim, realm_id: Uuid) -> Result<Jwt, JwtError> {
-        let jwt_key_pair = self
-            .keystore_repository
-            .get_or_generate_key(realm_id)
-            .await?;
+        let jwt_key_pair = self.signing_key(realm_id).await?;
 
-        let header = Header::new(Algorithm::RS256);
         let token =
-            jsonwebtoken::encode(&header, &claims, &jwt_key_pair.encoding_key).map_err(|e| {
-                error!("JWT generation error: {}", e);
+            jsonwebtoken::encode(&jwt_key_pair.header(), &claims, &jwt_key_pair.encoding_key)
+                .map_err(|e| {
+                    error!("JWT generation error: {}", e);
 
-                JwtError::GenerationError(e.to_string())
-            })?;
+                    JwtError::GenerationError(e.to_string())
+                })?;
 
         let exp = claims.exp.unwrap_or(0);
 
@@ -65,13 +105,10 @@ where
     }
 
     async fn verify_token(&self, token: String, realm_id: Uuid) -> Result<JwtClaim, JwtError> {
-        let mut validation = Validation::new(Algorithm::RS256);
-
-        let jwt_key_pair = self
-            .keystore_repository
-            .get_or_generate_key(realm_id)
-            .await?;
+        let jwt_key_pair =
+            find_verification_key(&self.keystore_repository, &token, realm_id).await?;
 
+        let mut validation = Validation::new(jwt_key_pair.algorithm.into());
         validation.validate_aud = false;
         let token_data = decode::<JwtClaim>(&token, &jwt_key_pair.decoding_key, &validation)
             .map_err(|e| JwtError::ValidationError(e.to_string()))?;
@@ -110,6 +147,6 @@ where
     }
 
     async fn retrieve_realm_rsa_keys(&self, realm: &Realm) -> Result<JwtKeyPair, JwtError> {
-        self.keystore_repository.get_or_generate_key(realm.id).await
+        self.signing_key(realm.id).await
     }
 }
@@ -1,8 +1,14 @@
+pub mod action_token;
 pub mod authentication;
+pub mod brute_force;
 pub mod client;
+pub mod client_scope;
 pub mod common;
 pub mod credential;
 pub mod crypto;
+pub mod email;
+pub mod event;
+pub mod group;
 pub mod health;
 pub mod jwt;
 pub mod realm;
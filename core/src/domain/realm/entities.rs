@@ -3,12 +3,28 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::domain::common::generate_timestamp;
+use crate::domain::{
+    brute_force::entities::BruteForcePolicy, client::entities::Client, common::generate_timestamp,
+    credential::entities::PasswordPolicy, jwt::entities::SigningAlgorithm,
+    user::entities::UserProfile,
+};
+
+pub const DEFAULT_ACCESS_TOKEN_LIFESPAN: i32 = 300;
+pub const DEFAULT_REFRESH_TOKEN_LIFESPAN: i32 = 86400;
+pub const DEFAULT_SESSION_LIFESPAN: i32 = 86400;
+pub const DEFAULT_AUTH_CODE_LIFESPAN: i32 = 60;
+pub const DEFAULT_KEY_RETIREMENT_DELAY: i32 = 604800;
+pub const DEFAULT_LOCALE: &str = "en";
+pub const DEFAULT_WEBHOOK_FAILURE_THRESHOLD: i32 = 20;
+pub const DEFAULT_LOGIN_EVENT_RETENTION: i32 = 2592000;
+pub const DEFAULT_ADMIN_EVENT_RETENTION: i32 = 7776000;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, ToSchema)]
 pub struct Realm {
     pub id: Uuid,
     pub name: String,
+    /// Composite role assigned to every new user of the realm.
+    pub default_role_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -18,9 +34,64 @@ pub struct RealmSetting {
     pub id: Uuid,
     pub realm_id: Uuid,
     pub default_signing_algorithm: Option<String>,
+    /// Lifespan of access tokens, in seconds.
+    pub access_token_lifespan: i32,
+    /// Lifespan of refresh tokens, in seconds.
+    pub refresh_token_lifespan: i32,
+    /// Lifespan of user sessions, in seconds.
+    pub session_lifespan: i32,
+    /// Time an authorization code can be exchanged after login, in seconds.
+    pub auth_code_lifespan: i32,
+    /// Revoke refresh tokens on use and detect reuse of rotated tokens.
+    pub refresh_token_rotation: bool,
+    /// Age after which the active signing key is rotated, in seconds. Keys
+    /// are only rotated on demand when unset.
+    pub key_rotation_interval: Option<i32>,
+    /// Time a rotated key keeps verifying tokens before it is retired, in
+    /// seconds. It should exceed the longest token lifespan of the realm.
+    pub key_retirement_delay: i32,
+    pub password_policy: PasswordPolicy,
+    pub brute_force_protection: BruteForcePolicy,
+    pub user_profile: UserProfile,
+    /// Locale of the emails sent to users without a supported `locale`
+    /// attribute.
+    pub default_locale: String,
+    /// Lets users sign up from the login page.
+    pub registration_allowed: bool,
+    /// Requires users to verify their email address before they can log in.
+    pub verify_email: bool,
+    /// Consecutive failed attempts after which a webhook is disabled; `0`
+    /// never disables them.
+    pub webhook_failure_threshold: i32,
+    /// Age after which login events are deleted, in seconds; `0` keeps them
+    /// forever.
+    pub login_event_retention: i32,
+    /// Age after which admin events are deleted, in seconds; `0` keeps them
+    /// forever.
+    pub admin_event_retention: i32,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Effective token lifespans for a client, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenLifespans {
+    pub access_token: i64,
+    pub refresh_token: i64,
+    pub session: i64,
+    pub auth_code: i64,
+}
+
+impl Default for TokenLifespans {
+    fn default() -> Self {
+        Self {
+            access_token: DEFAULT_ACCESS_TOKEN_LIFESPAN.into(),
+            refresh_token: DEFAULT_REFRESH_TOKEN_LIFESPAN.into(),
+            session: DEFAULT_SESSION_LIFESPAN.into(),
+            auth_code: DEFAULT_AUTH_CODE_LIFESPAN.into(),
+        }
+    }
+}
+
 impl RealmSetting {
     pub fn new(realm_id: Uuid, default_signing_algorithm: Option<String>) -> Self {
         let (now, timestamp) = generate_timestamp();
@@ -29,9 +100,55 @@ impl RealmSetting {
             id: Uuid::new_v7(timestamp),
             realm_id,
             default_signing_algorithm,
+            access_token_lifespan: DEFAULT_ACCESS_TOKEN_LIFESPAN,
+            refresh_token_lifespan: DEFAULT_REFRESH_TOKEN_LIFESPAN,
+            session_lifespan: DEFAULT_SESSION_LIFESPAN,
+            auth_code_lifespan: DEFAULT_AUTH_CODE_LIFESPAN,
+            refresh_token_rotation: false,
+            key_rotation_interval: None,
+            key_retirement_delay: DEFAULT_KEY_RETIREMENT_DELAY,
+            password_policy: PasswordPolicy::default(),
+            brute_force_protection: BruteForcePolicy::default(),
+            user_profile: UserProfile::default(),
+            default_locale: DEFAULT_LOCALE.to_string(),
+            registration_allowed: false,
+            verify_email: false,
+            webhook_failure_threshold: DEFAULT_WEBHOOK_FAILURE_THRESHOLD,
+            login_event_retention: DEFAULT_LOGIN_EVENT_RETENTION,
+            admin_event_retention: DEFAULT_ADMIN_EVENT_RETENTION,
             updated_at: now,
         }
     }
+
+    /// Algorithm the realm signs tokens with, RS256 unless configured.
+    pub fn signing_algorithm(&self) -> SigningAlgorithm {
+        self.default_signing_algorithm
+            .clone()
+            .and_then(|algorithm| SigningAlgorithm::try_from(algorithm).ok())
+            .unwrap_or_default()
+    }
+
+    /// Resolves the lifespans that apply to `client`, using the client's
+    /// overrides where set and the realm settings otherwise. Refresh tokens
+    /// never outlive the session lifespan.
+    pub fn lifespans_for(&self, client: &Client) -> TokenLifespans {
+        TokenLifespans {
+            access_token: client
+                .access_token_lifespan
+                .unwrap_or(self.access_token_lifespan)
+                .into(),
+            refresh_token: client
+                .refresh_token_lifespan
+                .unwrap_or(self.refresh_token_lifespan)
+                .min(self.session_lifespan)
+                .into(),
+            session: self.session_lifespan.into(),
+            auth_code: client
+                .auth_code_lifespan
+                .unwrap_or(self.auth_code_lifespan)
+                .into(),
+        }
+    }
 }
 
 impl Realm {
@@ -41,6 +158,7 @@ impl Realm {
         Self {
             id: Uuid::new_v7(timestamp),
             name,
+            default_role_id: None,
             created_at: now,
             updated_at: now,
         }
@@ -1,2 +1,3 @@
 pub mod entities;
 pub mod ports;
+pub mod value_objects;
@@ -2,9 +2,16 @@ use uuid::Uuid;
 
 use crate::domain::{
     authentication::value_objects::Identity,
+    brute_force::entities::BruteForcePolicy,
     common::entities::app_errors::CoreError,
-    realm::entities::{Realm, RealmSetting},
+    credential::entities::PasswordPolicy,
+    jwt::entities::RealmKey,
+    realm::{
+        entities::{Realm, RealmSetting},
+        value_objects::UpdateRealmSettingRequest,
+    },
     user::entities::User,
+    user::entities::UserProfile,
 };
 
 pub trait RealmService: Clone + Send + Sync {
@@ -54,6 +61,24 @@ pub trait RealmService: Clone + Send + Sync {
         identity: Identity,
         input: DeleteRealmInput,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn get_realm_keys(
+        &self,
+        identity: Identity,
+        input: GetRealmKeysInput,
+    ) -> impl Future<Output = Result<Vec<RealmKey>, CoreError>> + Send;
+
+    fn rotate_realm_key(
+        &self,
+        identity: Identity,
+        input: RotateRealmKeyInput,
+    ) -> impl Future<Output = Result<RealmKey, CoreError>> + Send;
+
+    fn retire_realm_key(
+        &self,
+        identity: Identity,
+        input: RetireRealmKeyInput,
+    ) -> impl Future<Output = Result<RealmKey, CoreError>> + Send;
 }
 
 pub trait RealmPolicy: Send + Sync + Clone {
@@ -96,6 +121,12 @@ pub trait RealmRepository: Clone + Send + Sync + 'static {
     ) -> impl Future<Output = Result<Realm, CoreError>> + Send;
     fn delete_by_name(&self, name: String) -> impl Future<Output = Result<(), CoreError>> + Send;
 
+    fn set_default_role(
+        &self,
+        realm_id: Uuid,
+        role_id: Uuid,
+    ) -> impl Future<Output = Result<Realm, CoreError>> + Send;
+
     fn create_realm_settings(
         &self,
         realm_id: Uuid,
@@ -105,7 +136,7 @@ pub trait RealmRepository: Clone + Send + Sync + 'static {
     fn update_realm_setting(
         &self,
         realm_id: Uuid,
-        algorithm: String,
+        data: UpdateRealmSettingRequest,
     ) -> impl Future<Output = Result<RealmSetting, CoreError>> + Send;
 
     fn get_realm_settings(
@@ -138,9 +169,38 @@ pub struct UpdateRealmInput {
 
 pub struct UpdateRealmSettingInput {
     pub realm_name: String,
-    pub algorithm: String,
+    pub algorithm: Option<String>,
+    pub access_token_lifespan: Option<i32>,
+    pub refresh_token_lifespan: Option<i32>,
+    pub session_lifespan: Option<i32>,
+    pub auth_code_lifespan: Option<i32>,
+    pub refresh_token_rotation: Option<bool>,
+    pub key_rotation_interval: Option<i32>,
+    pub key_retirement_delay: Option<i32>,
+    pub password_policy: Option<PasswordPolicy>,
+    pub brute_force_protection: Option<BruteForcePolicy>,
+    pub user_profile: Option<UserProfile>,
+    pub default_locale: Option<String>,
+    pub registration_allowed: Option<bool>,
+    pub verify_email: Option<bool>,
+    pub webhook_failure_threshold: Option<i32>,
+    pub login_event_retention: Option<i32>,
+    pub admin_event_retention: Option<i32>,
 }
 
 pub struct DeleteRealmInput {
     pub realm_name: String,
 }
+
+pub struct GetRealmKeysInput {
+    pub realm_name: String,
+}
+
+pub struct RotateRealmKeyInput {
+    pub realm_name: String,
+}
+
+pub struct RetireRealmKeyInput {
+    pub realm_name: String,
+    pub key_id: Uuid,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    brute_force::entities::BruteForcePolicy, credential::entities::PasswordPolicy,
+    user::entities::UserProfile,
+};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateRealmSettingRequest {
+    pub default_signing_algorithm: Option<String>,
+    pub access_token_lifespan: Option<i32>,
+    pub refresh_token_lifespan: Option<i32>,
+    pub session_lifespan: Option<i32>,
+    pub auth_code_lifespan: Option<i32>,
+    pub refresh_token_rotation: Option<bool>,
+    /// A value of `0` disables scheduled key rotation.
+    pub key_rotation_interval: Option<i32>,
+    pub key_retirement_delay: Option<i32>,
+    pub password_policy: Option<PasswordPolicy>,
+    pub brute_force_protection: Option<BruteForcePolicy>,
+    pub user_profile: Option<UserProfile>,
+    pub default_locale: Option<String>,
+    pub registration_allowed: Option<bool>,
+    pub verify_email: Option<bool>,
+    pub webhook_failure_threshold: Option<i32>,
+    pub login_event_retention: Option<i32>,
+    pub admin_event_retention: Option<i32>,
+}
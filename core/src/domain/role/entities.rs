@@ -31,3 +31,26 @@ pub struct GetUserRolesInput {
     pub realm_name: String,
     pub user_id: Uuid,
 }
+
+pub struct GetCompositeRolesInput {
+    pub realm_name: String,
+    pub role_id: Uuid,
+}
+
+pub struct CompositeRoleInput {
+    pub realm_name: String,
+    pub role_id: Uuid,
+    pub child_role_id: Uuid,
+}
+
+pub struct DefaultRoleInput {
+    pub realm_name: String,
+    pub role_id: Uuid,
+}
+
+impl Role {
+    /// Name of the composite role every new user of the realm receives.
+    pub fn default_role_name(realm_name: &str) -> String {
+        format!("default-roles-{realm_name}")
+    }
+}
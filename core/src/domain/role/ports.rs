@@ -5,7 +5,10 @@ use crate::domain::{
     common::entities::app_errors::CoreError,
     realm::entities::Realm,
     role::{
-        entities::{GetUserRolesInput, Role, UpdateRoleInput},
+        entities::{
+            CompositeRoleInput, DefaultRoleInput, GetCompositeRolesInput, GetUserRolesInput, Role,
+            UpdateRoleInput,
+        },
         value_objects::{CreateRoleRequest, UpdateRolePermissionsRequest, UpdateRoleRequest},
     },
 };
@@ -45,6 +48,37 @@ pub trait RoleService: Send + Sync + Clone {
         identity: Identity,
         input: GetUserRolesInput,
     ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    fn get_composite_roles(
+        &self,
+        identity: Identity,
+        input: GetCompositeRolesInput,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    fn add_composite_role(
+        &self,
+        identity: Identity,
+        input: CompositeRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn remove_composite_role(
+        &self,
+        identity: Identity,
+        input: CompositeRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    /// Roles included in the default role of the realm.
+    fn get_default_roles(
+        &self,
+        identity: Identity,
+        realm_name: String,
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+    fn add_default_role(
+        &self,
+        identity: Identity,
+        input: DefaultRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+    fn remove_default_role(
+        &self,
+        identity: Identity,
+        input: DefaultRoleInput,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
 pub trait RolePolicy: Send + Sync + Clone {
@@ -103,4 +137,22 @@ pub trait RoleRepository: Send + Sync + Clone {
         id: Uuid,
         payload: UpdateRolePermissionsRequest,
     ) -> impl Future<Output = Result<Role, CoreError>> + Send;
+
+    /// Roles directly included in any of the given composite roles.
+    fn get_composites(
+        &self,
+        role_ids: &[Uuid],
+    ) -> impl Future<Output = Result<Vec<Role>, CoreError>> + Send;
+
+    fn add_composite(
+        &self,
+        role_id: Uuid,
+        child_role_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    fn remove_composite(
+        &self,
+        role_id: Uuid,
+        child_role_id: Uuid,
+    ) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    common::entities::app_errors::CoreError,
+    role::{entities::Role, ports::RoleRepository},
+};
+
+/// Adds to `roles` every role they include through composites, at any depth
+/// and without duplicates.
+pub async fn expand_composites<R>(
+    role_repository: &R,
+    mut roles: Vec<Role>,
+) -> Result<Vec<Role>, CoreError>
+where
+    R: RoleRepository,
+{
+    let mut seen: HashSet<Uuid> = roles.iter().map(|role| role.id).collect();
+    let mut frontier: Vec<Uuid> = seen.iter().copied().collect();
+
+    while !frontier.is_empty() {
+        let children = role_repository.get_composites(&frontier).await?;
+
+        frontier.clear();
+        for child in children {
+            if seen.insert(child.id) {
+                frontier.push(child.id);
+                roles.push(child);
+            }
+        }
+    }
+
+    Ok(roles)
+}
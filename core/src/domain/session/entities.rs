@@ -18,15 +18,18 @@ impl UserSession {
         realm_id: Uuid,
         user_agent: Option<String>,
         ip_address: Option<String>,
+        lifespan: i64,
     ) -> Self {
+        let now = Utc::now();
+
         Self {
             id: Uuid::new_v4(),
             user_id,
             realm_id,
             user_agent,
             ip_address,
-            created_at: Utc::now(),
-            expires_at: Utc::now() + Duration::days(1),
+            created_at: now,
+            expires_at: now + Duration::seconds(lifespan),
         }
     }
     pub fn is_expired(&self) -> bool {
@@ -9,6 +9,7 @@ pub trait UserSessionService: Clone + Send + Sync + 'static {
         realm_id: Uuid,
         user_agent: Option<String>,
         ip_address: Option<String>,
+        lifespan: i64,
     ) -> impl Future<Output = Result<UserSession, SessionError>> + Send;
 }
 
@@ -32,8 +32,9 @@ where
         realm_id: uuid::Uuid,
         user_agent: Option<String>,
         ip_address: Option<String>,
+        lifespan: i64,
     ) -> Result<UserSession, SessionError> {
-        let session = UserSession::new(user_id, realm_id, user_agent, ip_address);
+        let session = UserSession::new(user_id, realm_id, user_agent, ip_address, lifespan);
 
         self.user_session_repository.create(&session).await?;
 
@@ -17,10 +17,13 @@ pub trait TotpService: Send + Sync + Clone + 'static {
 pub struct ChallengeOtpInput {
     pub session_code: String,
     pub code: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 pub struct ChallengeOtpOutput {
-    pub login_url: String,
+    /// `None` while the user still has to consent to the client's scopes.
+    pub login_url: Option<String>,
 }
 
 pub struct SetupOtpInput {
@@ -35,12 +38,16 @@ pub struct SetupOtpOutput {
 pub struct UpdatePasswordInput {
     pub realm_name: String,
     pub value: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 pub struct VerifyOtpInput {
     pub secret: String,
     pub code: String,
     pub label: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 pub struct VerifyOtpOutput {
@@ -61,10 +68,13 @@ pub struct BurnRecoveryCodeInput {
     pub session_code: String,
     pub format: String,
     pub code: String,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
 }
 
 pub struct BurnRecoveryCodeOutput {
-    pub login_url: String,
+    /// `None` while the user still has to consent to the client's scopes.
+    pub login_url: Option<String>,
 }
 
 pub trait RecoveryCodeRepository: Send + Sync + Clone + 'static {
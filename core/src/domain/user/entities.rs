@@ -1,6 +1,10 @@
-use std::fmt::Display;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt::Display,
+};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use utoipa::ToSchema;
@@ -22,10 +26,14 @@ pub struct User {
     pub roles: Vec<Role>,
     pub realm: Option<Realm>,
     pub required_actions: Vec<RequiredAction>,
+    pub attributes: UserAttributes,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Custom attributes of a user, each key holding a list of values.
+pub type UserAttributes = BTreeMap<String, Vec<String>>;
+
 pub struct UserConfig {
     pub realm_id: Uuid,
     pub client_id: Option<Uuid>,
@@ -35,6 +43,7 @@ pub struct UserConfig {
     pub email: String,
     pub email_verified: bool,
     pub enabled: bool,
+    pub attributes: UserAttributes,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
@@ -102,10 +111,19 @@ impl User {
             roles: Vec::new(),
             realm: None,
             required_actions: Vec::new(),
+            attributes: user_config.attributes,
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// Locale the user prefers, from their `locale` attribute.
+    pub fn locale(&self) -> Option<&str> {
+        self.attributes
+            .get("locale")
+            .and_then(|values| values.first())
+            .map(String::as_str)
+    }
 }
 
 pub struct ResetPasswordInput {
@@ -133,6 +151,7 @@ pub struct CreateUserInput {
     pub lastname: String,
     pub email: String,
     pub email_verified: Option<bool>,
+    pub attributes: Option<UserAttributes>,
 }
 
 pub struct GetUserInput {
@@ -149,6 +168,13 @@ pub struct UpdateUserInput {
     pub email_verified: Option<bool>,
     pub enabled: bool,
     pub required_actions: Option<Vec<String>>,
+    pub attributes: Option<UserAttributes>,
+}
+
+pub struct UpdateUserAttributesInput {
+    pub realm_name: String,
+    pub user_id: Uuid,
+    pub attributes: UserAttributes,
 }
 
 pub struct UnassignRoleInput {
@@ -156,3 +182,489 @@ pub struct UnassignRoleInput {
     pub user_id: Uuid,
     pub role_id: Uuid,
 }
+
+pub const DEFAULT_USER_PAGE_SIZE: u64 = 20;
+pub const MAX_USER_PAGE_SIZE: u64 = 100;
+
+/// Field a user search is sorted by.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSortField {
+    #[default]
+    Username,
+    Email,
+    Firstname,
+    Lastname,
+    CreatedAt,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Criteria of a user search. All the filters that are set must match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserQuery {
+    /// Free text matched against the username, email, first name and last
+    /// name. Every whitespace-separated term has to match one of them.
+    pub search: Option<String>,
+    pub enabled: Option<bool>,
+    pub email_verified: Option<bool>,
+    /// Name of a role assigned directly to the user.
+    pub role: Option<String>,
+    /// Attribute name and one of the values the user holds for it.
+    pub attribute: Option<(String, String)>,
+    pub sort: UserSortField,
+    pub order: SortOrder,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+impl Default for UserQuery {
+    fn default() -> Self {
+        Self {
+            search: None,
+            enabled: None,
+            email_verified: None,
+            role: None,
+            attribute: None,
+            sort: UserSortField::default(),
+            order: SortOrder::default(),
+            offset: 0,
+            limit: DEFAULT_USER_PAGE_SIZE,
+        }
+    }
+}
+
+impl UserQuery {
+    /// `LIKE` patterns for each search term, with wildcards in the terms
+    /// escaped.
+    pub fn search_patterns(&self) -> Vec<String> {
+        self.search
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|term| {
+                let escaped = term
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+
+                format!("%{escaped}%")
+            })
+            .collect()
+    }
+}
+
+/// One page of a user search, with the number of users matching it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct UserPage {
+    pub data: Vec<User>,
+    pub total: u64,
+    pub offset: u64,
+    pub limit: u64,
+}
+
+pub struct GetUsersInput {
+    pub realm_name: String,
+    pub query: UserQuery,
+}
+
+/// Who is changing the attributes of a user.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeEditor {
+    /// An administrator allowed to update users of the realm.
+    Admin,
+    /// The user editing their own profile.
+    User,
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeType {
+    #[default]
+    String,
+    Integer,
+    Boolean,
+    Email,
+    /// A calendar date formatted as `YYYY-MM-DD`.
+    Date,
+}
+
+impl AttributeType {
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            AttributeType::String => true,
+            AttributeType::Integer => value.parse::<i64>().is_ok(),
+            AttributeType::Boolean => matches!(value, "true" | "false"),
+            AttributeType::Email => value.split_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty() && domain.contains('.') && !domain.contains('@')
+            }),
+            AttributeType::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok(),
+        }
+    }
+}
+
+impl Display for AttributeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeType::String => write!(f, "string"),
+            AttributeType::Integer => write!(f, "integer"),
+            AttributeType::Boolean => write!(f, "boolean"),
+            AttributeType::Email => write!(f, "email"),
+            AttributeType::Date => write!(f, "date"),
+        }
+    }
+}
+
+/// Constraint applied to each value of an attribute.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AttributeValidator {
+    Length { min: Option<u32>, max: Option<u32> },
+    Pattern { pattern: String },
+    Options { options: Vec<String> },
+    Range { min: Option<i64>, max: Option<i64> },
+}
+
+/// Declaration of an attribute in the user profile of a realm.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct UserProfileAttribute {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub attribute_type: AttributeType,
+    pub required: bool,
+    pub multivalued: bool,
+    pub validators: Vec<AttributeValidator>,
+    /// Who may change the attribute.
+    pub edit: Vec<AttributeEditor>,
+}
+
+impl Default for UserProfileAttribute {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            display_name: None,
+            attribute_type: AttributeType::default(),
+            required: false,
+            multivalued: false,
+            validators: Vec::new(),
+            edit: vec![AttributeEditor::Admin],
+        }
+    }
+}
+
+/// Schema of the custom attributes of the users of a realm.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct UserProfile {
+    pub attributes: Vec<UserProfileAttribute>,
+    /// Accept attributes the profile does not declare. They can only be
+    /// changed by administrators.
+    pub unmanaged_attributes: bool,
+}
+
+impl Default for UserProfile {
+    fn default() -> Self {
+        Self {
+            attributes: Vec::new(),
+            unmanaged_attributes: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserAttributeViolation {
+    Unknown(String),
+    ReadOnly(String),
+    Required(String),
+    SingleValued(String),
+    InvalidType(String, AttributeType),
+    Invalid(String, AttributeValidator),
+}
+
+impl UserAttributeViolation {
+    pub fn attribute(&self) -> &str {
+        match self {
+            UserAttributeViolation::Unknown(name)
+            | UserAttributeViolation::ReadOnly(name)
+            | UserAttributeViolation::Required(name)
+            | UserAttributeViolation::SingleValued(name)
+            | UserAttributeViolation::InvalidType(name, _)
+            | UserAttributeViolation::Invalid(name, _) => name,
+        }
+    }
+}
+
+impl Display for UserAttributeViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserAttributeViolation::Unknown(_) => write!(f, "attribute is not part of the profile"),
+            UserAttributeViolation::ReadOnly(_) => write!(f, "attribute cannot be changed"),
+            UserAttributeViolation::Required(_) => write!(f, "attribute is required"),
+            UserAttributeViolation::SingleValued(_) => {
+                write!(f, "attribute accepts a single value")
+            }
+            UserAttributeViolation::InvalidType(_, attribute_type) => {
+                write!(f, "value must be a valid {attribute_type}")
+            }
+            UserAttributeViolation::Invalid(_, validator) => match validator {
+                AttributeValidator::Length { min, max } => write!(
+                    f,
+                    "value length must be between {} and {}",
+                    min.unwrap_or(0),
+                    max.map_or("unbounded".to_string(), |max| max.to_string())
+                ),
+                AttributeValidator::Pattern { pattern } => {
+                    write!(f, "value must match the pattern {pattern}")
+                }
+                AttributeValidator::Options { options } => {
+                    write!(f, "value must be one of {}", options.join(", "))
+                }
+                AttributeValidator::Range { min, max } => write!(
+                    f,
+                    "value must be a number between {} and {}",
+                    min.map_or("unbounded".to_string(), |min| min.to_string()),
+                    max.map_or("unbounded".to_string(), |max| max.to_string())
+                ),
+            },
+        }
+    }
+}
+
+impl AttributeValidator {
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            AttributeValidator::Length { min, max } => {
+                let length = value.chars().count();
+
+                min.is_none_or(|min| length >= min as usize)
+                    && max.is_none_or(|max| length <= max as usize)
+            }
+            AttributeValidator::Pattern { pattern } => {
+                Regex::new(pattern).is_ok_and(|regex| regex.is_match(value))
+            }
+            AttributeValidator::Options { options } => options.iter().any(|option| option == value),
+            AttributeValidator::Range { min, max } => value.parse::<i64>().is_ok_and(|number| {
+                min.is_none_or(|min| number >= min) && max.is_none_or(|max| number <= max)
+            }),
+        }
+    }
+}
+
+impl UserProfile {
+    /// Rejects profiles with unnamed or duplicated attributes and invalid
+    /// patterns.
+    pub fn is_valid(&self) -> bool {
+        let mut names = HashSet::new();
+
+        self.attributes.iter().all(|attribute| {
+            !attribute.name.is_empty()
+                && names.insert(attribute.name.as_str())
+                && attribute
+                    .validators
+                    .iter()
+                    .all(|validator| match validator {
+                        AttributeValidator::Pattern { pattern } => Regex::new(pattern).is_ok(),
+                        _ => true,
+                    })
+        })
+    }
+
+    /// Checks the attributes `editor` wants to give a user whose current
+    /// attributes are `previous`.
+    pub fn validate(
+        &self,
+        attributes: &UserAttributes,
+        previous: &UserAttributes,
+        editor: AttributeEditor,
+    ) -> Vec<UserAttributeViolation> {
+        let mut violations = Vec::new();
+
+        let changed = |name: &str| {
+            let values = attributes.get(name).filter(|values| !values.is_empty());
+            let previous_values = previous.get(name).filter(|values| !values.is_empty());
+
+            values != previous_values
+        };
+
+        for declared in &self.attributes {
+            let name = &declared.name;
+            let values = attributes.get(name).cloned().unwrap_or_default();
+            let can_edit = declared.edit.contains(&editor);
+
+            if !can_edit {
+                if changed(name) {
+                    violations.push(UserAttributeViolation::ReadOnly(name.clone()));
+                }
+
+                continue;
+            }
+
+            if values.is_empty() {
+                if declared.required {
+                    violations.push(UserAttributeViolation::Required(name.clone()));
+                }
+
+                continue;
+            }
+
+            if !declared.multivalued && values.len() > 1 {
+                violations.push(UserAttributeViolation::SingleValued(name.clone()));
+            }
+
+            if values
+                .iter()
+                .any(|value| !declared.attribute_type.accepts(value))
+            {
+                violations.push(UserAttributeViolation::InvalidType(
+                    name.clone(),
+                    declared.attribute_type,
+                ));
+            }
+
+            for validator in &declared.validators {
+                if values.iter().any(|value| !validator.accepts(value)) {
+                    violations.push(UserAttributeViolation::Invalid(
+                        name.clone(),
+                        validator.clone(),
+                    ));
+                }
+            }
+        }
+
+        let unmanaged = attributes
+            .keys()
+            .chain(previous.keys())
+            .filter(|name| {
+                !self
+                    .attributes
+                    .iter()
+                    .any(|declared| &declared.name == *name)
+            })
+            .collect::<BTreeSet<_>>();
+
+        for name in unmanaged {
+            if !changed(name) {
+                continue;
+            }
+
+            if !self.unmanaged_attributes {
+                violations.push(UserAttributeViolation::Unknown(name.clone()));
+            } else if editor != AttributeEditor::Admin {
+                violations.push(UserAttributeViolation::ReadOnly(name.clone()));
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes(entries: &[(&str, &[&str])]) -> UserAttributes {
+        entries
+            .iter()
+            .map(|(name, values)| {
+                (
+                    name.to_string(),
+                    values.iter().map(|value| value.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn profile() -> UserProfile {
+        UserProfile {
+            attributes: vec![
+                UserProfileAttribute {
+                    name: "employee_id".to_string(),
+                    attribute_type: AttributeType::Integer,
+                    required: true,
+                    ..Default::default()
+                },
+                UserProfileAttribute {
+                    name: "locale".to_string(),
+                    validators: vec![AttributeValidator::Options {
+                        options: vec!["en".to_string(), "fr".to_string()],
+                    }],
+                    edit: vec![AttributeEditor::Admin, AttributeEditor::User],
+                    ..Default::default()
+                },
+            ],
+            unmanaged_attributes: false,
+        }
+    }
+
+    #[test]
+    fn test_user_query_escapes_search_terms() {
+        let query = UserQuery {
+            search: Some("  jane 100%_off ".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            query.search_patterns(),
+            vec!["%jane%".to_string(), "%100\\%\\_off%".to_string()]
+        );
+        assert!(UserQuery::default().search_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_user_profile_accepts_valid_attributes() {
+        let attributes = attributes(&[("employee_id", &["42"]), ("locale", &["fr"])]);
+
+        let violations =
+            profile().validate(&attributes, &UserAttributes::new(), AttributeEditor::Admin);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_user_profile_reports_every_violation() {
+        let attributes = attributes(&[("locale", &["de", "en"]), ("nickname", &["bob"])]);
+
+        let violations =
+            profile().validate(&attributes, &UserAttributes::new(), AttributeEditor::Admin);
+
+        assert_eq!(
+            violations,
+            vec![
+                UserAttributeViolation::Required("employee_id".to_string()),
+                UserAttributeViolation::SingleValued("locale".to_string()),
+                UserAttributeViolation::Invalid(
+                    "locale".to_string(),
+                    AttributeValidator::Options {
+                        options: vec!["en".to_string(), "fr".to_string()],
+                    },
+                ),
+                UserAttributeViolation::Unknown("nickname".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_user_profile_restricts_self_service_edits() {
+        let previous = attributes(&[("employee_id", &["42"]), ("locale", &["en"])]);
+        let attributes = attributes(&[("employee_id", &["43"]), ("locale", &["fr"])]);
+
+        let violations = profile().validate(&attributes, &previous, AttributeEditor::User);
+
+        assert_eq!(
+            violations,
+            vec![UserAttributeViolation::ReadOnly("employee_id".to_string())]
+        );
+    }
+}
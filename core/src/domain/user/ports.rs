@@ -7,8 +7,9 @@ use crate::domain::{
     role::entities::Role,
     user::{
         entities::{
-            AssignRoleInput, BulkDeleteUsersInput, CreateUserInput, GetUserInput, RequiredAction,
-            RequiredActionError, ResetPasswordInput, UnassignRoleInput, UpdateUserInput, User,
+            AssignRoleInput, BulkDeleteUsersInput, CreateUserInput, GetUserInput, GetUsersInput,
+            RequiredAction, RequiredActionError, ResetPasswordInput, UnassignRoleInput,
+            UpdateUserAttributesInput, UpdateUserInput, User, UserPage, UserQuery,
         },
         value_objects::{CreateUserRequest, UpdateUserRequest},
     },
@@ -35,8 +36,8 @@ pub trait UserService: Clone + Send + Sync + 'static {
     fn get_users(
         &self,
         identity: Identity,
-        realm_name: String,
-    ) -> impl Future<Output = Result<Vec<User>, CoreError>> + Send;
+        input: GetUsersInput,
+    ) -> impl Future<Output = Result<UserPage, CoreError>> + Send;
     fn assign_role(
         &self,
         identity: Identity,
@@ -57,6 +58,13 @@ pub trait UserService: Clone + Send + Sync + 'static {
         identity: Identity,
         input: GetUserInput,
     ) -> impl Future<Output = Result<User, CoreError>> + Send;
+    /// Replaces the attributes of a user. Users without the permission to
+    /// update users may still change their own self-service attributes.
+    fn update_user_attributes(
+        &self,
+        identity: Identity,
+        input: UpdateUserAttributesInput,
+    ) -> impl Future<Output = Result<User, CoreError>> + Send;
     fn unassign_role(
         &self,
         identity: Identity,
@@ -76,6 +84,14 @@ pub trait UserRepository: Clone + Send + Sync + 'static {
         realm_id: Uuid,
     ) -> impl Future<Output = Result<User, CoreError>> + Send;
 
+    /// Finds the only user of the realm with this email address, ignoring
+    /// case. Addresses shared by several users match none of them.
+    fn get_by_email(
+        &self,
+        email: String,
+        realm_id: Uuid,
+    ) -> impl Future<Output = Result<User, CoreError>> + Send;
+
     fn get_by_client_id(
         &self,
         client_id: Uuid,
@@ -88,6 +104,13 @@ pub trait UserRepository: Clone + Send + Sync + 'static {
         realm_id: Uuid,
     ) -> impl Future<Output = Result<Vec<User>, CoreError>> + Send;
 
+    /// Users of the realm matching `query`, one page at a time.
+    fn search_users(
+        &self,
+        realm_id: Uuid,
+        query: UserQuery,
+    ) -> impl Future<Output = Result<UserPage, CoreError>> + Send;
+
     fn bulk_delete_user(
         &self,
         ids: Vec<Uuid>,
@@ -1,5 +1,7 @@
 use uuid::Uuid;
 
+use crate::domain::user::entities::UserAttributes;
+
 #[derive(Debug, Clone)]
 pub struct CreateUserRequest {
     pub realm_id: Uuid,
@@ -10,6 +12,7 @@ pub struct CreateUserRequest {
     pub email: String,
     pub email_verified: bool,
     pub enabled: bool,
+    pub attributes: UserAttributes,
 }
 
 #[derive(Debug, Clone)]
@@ -20,4 +23,5 @@ pub struct UpdateUserRequest {
     pub email_verified: bool,
     pub enabled: bool,
     pub required_actions: Option<Vec<String>>,
+    pub attributes: Option<UserAttributes>,
 }
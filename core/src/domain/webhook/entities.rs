@@ -1,5 +1,6 @@
 pub mod errors;
 pub mod webhook;
+pub mod webhook_delivery;
 pub mod webhook_payload;
 pub mod webhook_subscriber;
 pub mod webhook_trigger;
@@ -1,30 +1,72 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use rand::{Rng, distributions::Alphanumeric};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::domain::{
-    common::generate_timestamp, webhook::entities::webhook_subscriber::WebhookSubscriber,
+    common::{entities::app_errors::CoreError, generate_timestamp},
+    webhook::entities::{
+        webhook_payload::{WebhookPayload, render_payload_template},
+        webhook_subscriber::WebhookSubscriber,
+        webhook_trigger::WebhookTrigger,
+    },
 };
 
+/// Value the custom headers of a webhook are listed with. Sending it back on
+/// update keeps the value of the header.
+pub const MASKED_HEADER_VALUE: &str = "**********";
+
+/// Headers set on every delivery, which custom headers cannot replace.
+const RESERVED_HEADERS: [&str; 3] = ["content-type", "content-length", "host"];
+
+const RESERVED_HEADER_PREFIX: &str = "x-ferriskey-";
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, ToSchema)]
 pub struct Webhook {
     pub id: Uuid,
+    pub realm_id: Uuid,
     pub endpoint: String,
     pub name: Option<String>,
     pub description: Option<String>,
     pub subscribers: Vec<WebhookSubscriber>,
+    /// Key the deliveries to the endpoint are signed with. Never serialized,
+    /// so that it does not leak into payloads or listings.
+    #[serde(skip_serializing, default)]
+    pub secret: String,
+    /// Disabled webhooks are not notified of events.
+    pub enabled: bool,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    /// Failed attempts since the last successful one.
+    pub consecutive_failures: i32,
+    /// JSON pointers of the payload values sent to the endpoint, the whole
+    /// payload being sent when empty.
+    pub payload_fields: Vec<String>,
+    /// Handlebars template the payload is rendered through once its fields
+    /// are selected, which must produce JSON.
+    pub payload_template: Option<String>,
+    /// Static headers added to every delivery, such as an API key. Listed
+    /// with masked values.
+    #[serde(serialize_with = "serialize_masked_headers", default)]
+    pub headers: BTreeMap<String, String>,
     pub triggered_at: Option<DateTime<Utc>>,
     pub updated_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
 }
 
 impl Webhook {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        realm_id: Uuid,
         endpoint: String,
         subscribers: Vec<WebhookSubscriber>,
         name: Option<String>,
         description: Option<String>,
+        secret: String,
         triggered_at: Option<DateTime<Utc>>,
         updated_at: DateTime<Utc>,
         created_at: DateTime<Utc>,
@@ -33,13 +75,122 @@ impl Webhook {
 
         Self {
             id: Uuid::new_v7(timestamp),
+            realm_id,
             endpoint,
             name,
             description,
             subscribers,
+            secret,
+            enabled: true,
+            last_success_at: None,
+            last_failure_at: None,
+            consecutive_failures: 0,
+            payload_fields: Vec::new(),
+            payload_template: None,
+            headers: BTreeMap::new(),
             triggered_at,
             updated_at,
             created_at,
         }
     }
+
+    /// Random signing secret for webhooks created without one.
+    pub fn generate_secret() -> String {
+        rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(40)
+            .map(char::from)
+            .collect()
+    }
+}
+
+/// Checks that the payload fields are JSON pointers, that the template
+/// renders to JSON and that the headers are valid and not reserved.
+pub fn validate_webhook_options(
+    payload_fields: &[String],
+    payload_template: Option<&str>,
+    headers: &BTreeMap<String, String>,
+) -> Result<(), CoreError> {
+    if let Some(field) = payload_fields.iter().find(|field| !field.starts_with('/')) {
+        return Err(CoreError::InvalidWebhook(format!(
+            "payload field {field} is not a JSON pointer"
+        )));
+    }
+
+    if let Some(template) = payload_template {
+        let sample = serde_json::to_value(WebhookPayload::<Value>::new(
+            WebhookTrigger::WebhookTest,
+            Uuid::nil(),
+            None,
+        ))
+        .map_err(|_| CoreError::InternalServerError)?;
+
+        render_payload_template(template, &sample)
+            .map_err(|e| CoreError::InvalidWebhook(format!("invalid payload template: {e}")))?;
+    }
+
+    for (name, value) in headers {
+        let lowercase = name.to_ascii_lowercase();
+
+        if RESERVED_HEADERS.contains(&lowercase.as_str())
+            || lowercase.starts_with(RESERVED_HEADER_PREFIX)
+        {
+            return Err(CoreError::InvalidWebhook(format!(
+                "header {name} is reserved"
+            )));
+        }
+
+        if name.is_empty() || !name.bytes().all(is_header_name_byte) {
+            return Err(CoreError::InvalidWebhook(format!(
+                "invalid header name {name}"
+            )));
+        }
+
+        if value.bytes().any(|byte| byte.is_ascii_control()) {
+            return Err(CoreError::InvalidWebhook(format!(
+                "invalid value for header {name}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Token characters allowed in header names by RFC 9110.
+fn is_header_name_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte)
+}
+
+fn serialize_masked_headers<S: Serializer>(
+    headers: &BTreeMap<String, String>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(headers.keys().map(|name| (name, MASKED_HEADER_VALUE)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_webhook_options_rejects_reserved_headers_and_bad_templates() {
+        let headers = BTreeMap::from([("X-Api-Key".to_string(), "key".to_string())]);
+
+        assert!(
+            validate_webhook_options(
+                &["/data/id".to_string()],
+                Some(r#"{"text": "{{event}}"}"#),
+                &headers,
+            )
+            .is_ok()
+        );
+
+        for name in ["Content-Type", "X-FerrisKey-Signature", "Bad Name"] {
+            let headers = BTreeMap::from([(name.to_string(), "value".to_string())]);
+            assert!(validate_webhook_options(&[], None, &headers).is_err());
+        }
+
+        assert!(validate_webhook_options(&["data".to_string()], None, &BTreeMap::new()).is_err());
+        assert!(validate_webhook_options(&[], Some("{{event}}"), &BTreeMap::new()).is_err());
+    }
 }
@@ -0,0 +1,316 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::{
+    common::generate_timestamp,
+    webhook::entities::{
+        webhook::Webhook,
+        webhook_payload::{render_payload_template, select_payload_fields},
+        webhook_trigger::WebhookTrigger,
+    },
+};
+
+/// Attempts after which a delivery is abandoned.
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 8;
+
+/// Number of past deliveries kept visible in the history of a webhook.
+pub const WEBHOOK_DELIVERY_HISTORY_LIMIT: u64 = 100;
+
+/// Delay before the first retry, doubled after each failed attempt.
+const INITIAL_RETRY_DELAY: i64 = 30;
+
+/// Longest delay between two attempts, in seconds.
+const MAX_RETRY_DELAY: i64 = 3_600;
+
+/// Hex-encoded HMAC-SHA256 of `{timestamp}.{body}`, prefixed with `sha256=`.
+pub const SIGNATURE_HEADER: &str = "X-FerrisKey-Signature";
+
+/// Unix timestamp the signature was computed at.
+pub const TIMESTAMP_HEADER: &str = "X-FerrisKey-Timestamp";
+
+pub const EVENT_HEADER: &str = "X-FerrisKey-Event";
+
+pub const DELIVERY_HEADER: &str = "X-FerrisKey-Delivery";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first attempt or for a retry.
+    Pending,
+    Succeeded,
+    /// Abandoned after its last allowed attempt failed, or because its
+    /// webhook was disabled.
+    Failed,
+}
+
+impl Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookDeliveryStatus::Pending => write!(f, "pending"),
+            WebhookDeliveryStatus::Succeeded => write!(f, "succeeded"),
+            WebhookDeliveryStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl TryFrom<String> for WebhookDeliveryStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "pending" => Ok(WebhookDeliveryStatus::Pending),
+            "succeeded" => Ok(WebhookDeliveryStatus::Succeeded),
+            "failed" => Ok(WebhookDeliveryStatus::Failed),
+            _ => Err(format!("Invalid webhook delivery status: {value}")),
+        }
+    }
+}
+
+/// An event queued for a webhook, along with the outcome of its last
+/// attempt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub realm_id: Uuid,
+    pub event: WebhookTrigger,
+    /// Body posted to the endpoint.
+    #[schema(value_type = Object)]
+    pub payload: Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// HTTP status returned by the endpoint on the last attempt.
+    pub response_status: Option<u16>,
+    /// Duration of the last attempt in milliseconds.
+    pub latency_ms: Option<i64>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of posting a delivery to its endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDeliveryAttempt {
+    pub response_status: Option<u16>,
+    pub latency_ms: i64,
+    pub error: Option<String>,
+}
+
+impl WebhookDeliveryAttempt {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+            && self
+                .response_status
+                .is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+impl WebhookDelivery {
+    pub fn new(webhook_id: Uuid, realm_id: Uuid, event: WebhookTrigger, payload: Value) -> Self {
+        let (now, timestamp) = generate_timestamp();
+
+        Self {
+            id: Uuid::new_v7(timestamp),
+            webhook_id,
+            realm_id,
+            event,
+            payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: Some(now),
+            response_status: None,
+            latency_ms: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+            delivered_at: None,
+        }
+    }
+
+    /// A delivery of `payload` to `webhook`, with the fields the webhook
+    /// selects rendered through its template. Deliveries whose template
+    /// fails to render are abandoned right away.
+    pub fn for_webhook(webhook: &Webhook, event: WebhookTrigger, payload: &Value) -> Self {
+        let selected = select_payload_fields(payload, &webhook.payload_fields);
+
+        let Some(template) = &webhook.payload_template else {
+            return Self::new(webhook.id, webhook.realm_id, event, selected);
+        };
+
+        match render_payload_template(template, &selected) {
+            Ok(body) => Self::new(webhook.id, webhook.realm_id, event, body),
+            Err(e) => {
+                let mut delivery = Self::new(webhook.id, webhook.realm_id, event, selected);
+                delivery.abandon(&format!("payload template failed: {e}"), Utc::now());
+                delivery
+            }
+        }
+    }
+
+    /// A new delivery of the same event to the same webhook.
+    pub fn redeliver(&self) -> Self {
+        Self::new(
+            self.webhook_id,
+            self.realm_id,
+            self.event.clone(),
+            self.payload.clone(),
+        )
+    }
+
+    /// Test events are sent once, so that their outcome reflects the
+    /// endpoint as it is now.
+    pub fn max_attempts(&self) -> i32 {
+        match self.event {
+            WebhookTrigger::WebhookTest => 1,
+            _ => MAX_DELIVERY_ATTEMPTS,
+        }
+    }
+
+    /// Delay before the attempt following the `attempts`th failed one.
+    pub fn retry_delay(attempts: i32) -> Duration {
+        let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+
+        Duration::seconds((INITIAL_RETRY_DELAY << exponent).min(MAX_RETRY_DELAY))
+    }
+
+    /// Records `attempt`, scheduling a retry when it failed and attempts
+    /// are left.
+    pub fn record_attempt(&mut self, attempt: WebhookDeliveryAttempt, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.updated_at = now;
+
+        if attempt.is_success() {
+            self.status = WebhookDeliveryStatus::Succeeded;
+            self.next_attempt_at = None;
+            self.delivered_at = Some(now);
+        } else if self.attempts >= self.max_attempts() {
+            self.status = WebhookDeliveryStatus::Failed;
+            self.next_attempt_at = None;
+        } else {
+            self.next_attempt_at = Some(now + Self::retry_delay(self.attempts));
+        }
+
+        self.response_status = attempt.response_status;
+        self.latency_ms = Some(attempt.latency_ms);
+        self.error = attempt.error;
+    }
+
+    /// Gives up on the delivery without attempting it.
+    pub fn abandon(&mut self, reason: &str, now: DateTime<Utc>) {
+        self.status = WebhookDeliveryStatus::Failed;
+        self.next_attempt_at = None;
+        self.error = Some(reason.to_string());
+        self.updated_at = now;
+    }
+}
+
+/// Signature of a delivery `body` sent at `timestamp`, as carried by the
+/// [`SIGNATURE_HEADER`].
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+
+    let signature: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    format!("sha256={signature}")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn failure() -> WebhookDeliveryAttempt {
+        WebhookDeliveryAttempt {
+            response_status: Some(503),
+            latency_ms: 12,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_failed_attempts_back_off_until_abandoned() {
+        let mut delivery = WebhookDelivery::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            WebhookTrigger::UserCreated,
+            json!({ "event": "user.created" }),
+        );
+        let now = Utc::now();
+
+        delivery.record_attempt(failure(), now);
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, Some(now + Duration::seconds(30)));
+
+        delivery.record_attempt(failure(), now);
+        assert_eq!(delivery.next_attempt_at, Some(now + Duration::seconds(60)));
+        assert_eq!(WebhookDelivery::retry_delay(12), Duration::seconds(3_600));
+
+        while delivery.status == WebhookDeliveryStatus::Pending {
+            delivery.record_attempt(failure(), now);
+        }
+
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, MAX_DELIVERY_ATTEMPTS);
+        assert_eq!(delivery.next_attempt_at, None);
+
+        let mut retried = delivery.redeliver();
+        assert_ne!(retried.id, delivery.id);
+
+        retried.record_attempt(
+            WebhookDeliveryAttempt {
+                response_status: Some(204),
+                latency_ms: 8,
+                error: None,
+            },
+            now,
+        );
+        assert_eq!(retried.status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(retried.delivered_at, Some(now));
+    }
+
+    #[test]
+    fn test_test_events_are_attempted_once() {
+        let mut delivery = WebhookDelivery::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            WebhookTrigger::WebhookTest,
+            json!({ "event": "webhook.test" }),
+        );
+
+        delivery.record_attempt(failure(), Utc::now());
+
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(delivery.next_attempt_at, None);
+        assert_eq!(delivery.response_status, Some(503));
+    }
+
+    #[test]
+    fn test_signature_covers_timestamp_and_body() {
+        // Reference value computed with `openssl dgst -sha256 -hmac secret`
+        assert_eq!(
+            sign_webhook_payload("secret", 1_700_000_000, "{}"),
+            "sha256=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+        assert_ne!(
+            sign_webhook_payload("secret", 1_700_000_000, "{}"),
+            sign_webhook_payload("secret", 1_700_000_001, "{}")
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use chrono::Utc;
+use handlebars::Handlebars;
 use serde::Serialize;
+use serde_json::{Map, Value};
 use uuid::Uuid;
 
 use crate::domain::webhook::entities::webhook_trigger::WebhookTrigger;
@@ -28,3 +30,151 @@ where
         }
     }
 }
+
+/// Keeps the values of `payload` designated by the JSON pointers of
+/// `fields`, along with the objects and arrays leading to them. The whole
+/// payload is kept when `fields` is empty.
+pub fn select_payload_fields(payload: &Value, fields: &[String]) -> Value {
+    if fields.is_empty() {
+        return payload.clone();
+    }
+
+    let pointers: Vec<Vec<String>> = fields.iter().map(|field| pointer_segments(field)).collect();
+    let pointers: Vec<&[String]> = pointers.iter().map(Vec::as_slice).collect();
+
+    select_pointers(payload, &pointers).unwrap_or_else(|| Value::Object(Map::new()))
+}
+
+/// Renders `template` with `payload` as its data into the JSON body sent in
+/// its place. Values are escaped as the content of a JSON string, so that
+/// they can be placed between quotes.
+pub fn render_payload_template(template: &str, payload: &Value) -> Result<Value, String> {
+    let mut registry = Handlebars::new();
+    registry.register_escape_fn(escape_json_string);
+
+    let body = registry
+        .render_template(template, payload)
+        .map_err(|e| e.to_string())?;
+
+    serde_json::from_str(&body).map_err(|e| format!("rendered body is not valid JSON: {e}"))
+}
+
+/// Segments of a JSON pointer, unescaped as per RFC 6901.
+fn pointer_segments(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+fn select_pointers(value: &Value, pointers: &[&[String]]) -> Option<Value> {
+    if pointers.iter().any(|pointer| pointer.is_empty()) {
+        return Some(value.clone());
+    }
+
+    let children = |key: &str| -> Vec<&[String]> {
+        pointers
+            .iter()
+            .filter(|pointer| pointer[0] == key)
+            .map(|pointer| &pointer[1..])
+            .collect()
+    };
+
+    match value {
+        Value::Object(map) => {
+            let selected: Map<String, Value> = map
+                .iter()
+                .filter_map(|(key, child)| {
+                    let pointers = children(key);
+                    if pointers.is_empty() {
+                        return None;
+                    }
+
+                    select_pointers(child, &pointers).map(|child| (key.clone(), child))
+                })
+                .collect();
+
+            (!selected.is_empty()).then_some(Value::Object(selected))
+        }
+        Value::Array(items) => {
+            let selected: Vec<Value> = items
+                .iter()
+                .enumerate()
+                .filter_map(|(index, item)| {
+                    let pointers = children(&index.to_string());
+                    if pointers.is_empty() {
+                        return None;
+                    }
+
+                    select_pointers(item, &pointers)
+                })
+                .collect();
+
+            (!selected.is_empty()).then_some(Value::Array(selected))
+        }
+        _ => None,
+    }
+}
+
+fn escape_json_string(value: &str) -> String {
+    let quoted = serde_json::to_string(value).unwrap_or_default();
+
+    quoted
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_select_payload_fields_keeps_the_listed_pointers_only() {
+        let payload = json!({
+            "event": "user.created",
+            "resource_id": "realm",
+            "data": {
+                "id": "user",
+                "email": "jane@example.com",
+                "roles": [{ "name": "admin", "id": "a" }, { "name": "viewer", "id": "b" }],
+                "a/b": true,
+            },
+        });
+
+        let fields = [
+            "/event".to_string(),
+            "/data/id".to_string(),
+            "/data/roles/1/name".to_string(),
+            "/data/a~1b".to_string(),
+            "/data/missing".to_string(),
+        ];
+
+        assert_eq!(
+            select_payload_fields(&payload, &fields),
+            json!({
+                "event": "user.created",
+                "data": { "id": "user", "roles": [{ "name": "viewer" }], "a/b": true },
+            })
+        );
+        assert_eq!(select_payload_fields(&payload, &[]), payload);
+    }
+
+    #[test]
+    fn test_render_payload_template_escapes_values() {
+        let payload = json!({ "event": "user.created", "data": { "username": "jane \"j\"" } });
+
+        assert_eq!(
+            render_payload_template(
+                r#"{"text": "{{event}}: {{data.username}}", "missing": "{{data.email}}"}"#,
+                &payload,
+            ),
+            Ok(json!({ "text": "user.created: jane \"j\"", "missing": "" }))
+        );
+        assert!(render_payload_template("{{event}}", &payload).is_err());
+    }
+}
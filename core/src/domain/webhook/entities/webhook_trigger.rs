@@ -20,8 +20,32 @@ pub enum WebhookTrigger {
     UserUnassignRole,
     #[serde(rename = "user.credentials.deleted")]
     UserDeleteCredentials,
+    #[serde(rename = "user.registered")]
+    UserRegistered,
     #[serde(rename = "auth.reset_password")]
     AuthResetPassword,
+    #[serde(rename = "auth.refresh_token.reused")]
+    AuthRefreshTokenReused,
+    #[serde(rename = "auth.lockout")]
+    AuthLockout,
+    #[serde(rename = "auth.login.succeeded")]
+    AuthLoginSucceeded,
+    #[serde(rename = "auth.login.failed")]
+    AuthLoginFailed,
+    #[serde(rename = "auth.token.refreshed")]
+    AuthTokenRefreshed,
+    #[serde(rename = "auth.logout")]
+    AuthLogout,
+    #[serde(rename = "auth.otp.configured")]
+    AuthOtpConfigured,
+    #[serde(rename = "auth.otp.failed")]
+    AuthOtpFailed,
+    #[serde(rename = "auth.recovery_code.burned")]
+    AuthRecoveryCodeBurned,
+    #[serde(rename = "auth.password.updated")]
+    AuthPasswordUpdated,
+    #[serde(rename = "auth.required_action.completed")]
+    AuthRequiredActionCompleted,
     #[serde(rename = "client.created")]
     ClientCreated,
     #[serde(rename = "client.updated")]
@@ -56,6 +80,10 @@ pub enum WebhookTrigger {
     WebhookUpdated,
     #[serde(rename = "webhook.deleted")]
     WebhookDeleted,
+    #[serde(rename = "webhook.disabled")]
+    WebhookDisabled,
+    #[serde(rename = "webhook.test")]
+    WebhookTest,
 }
 
 impl Display for WebhookTrigger {
@@ -68,7 +96,21 @@ impl Display for WebhookTrigger {
             WebhookTrigger::UserAssignRole => write!(f, "user.assign.role"),
             WebhookTrigger::UserUnassignRole => write!(f, "user.unassign.role"),
             WebhookTrigger::UserDeleteCredentials => write!(f, "user.credentials.deleted"),
+            WebhookTrigger::UserRegistered => write!(f, "user.registered"),
             WebhookTrigger::AuthResetPassword => write!(f, "auth.reset_password"),
+            WebhookTrigger::AuthRefreshTokenReused => write!(f, "auth.refresh_token.reused"),
+            WebhookTrigger::AuthLockout => write!(f, "auth.lockout"),
+            WebhookTrigger::AuthLoginSucceeded => write!(f, "auth.login.succeeded"),
+            WebhookTrigger::AuthLoginFailed => write!(f, "auth.login.failed"),
+            WebhookTrigger::AuthTokenRefreshed => write!(f, "auth.token.refreshed"),
+            WebhookTrigger::AuthLogout => write!(f, "auth.logout"),
+            WebhookTrigger::AuthOtpConfigured => write!(f, "auth.otp.configured"),
+            WebhookTrigger::AuthOtpFailed => write!(f, "auth.otp.failed"),
+            WebhookTrigger::AuthRecoveryCodeBurned => write!(f, "auth.recovery_code.burned"),
+            WebhookTrigger::AuthPasswordUpdated => write!(f, "auth.password.updated"),
+            WebhookTrigger::AuthRequiredActionCompleted => {
+                write!(f, "auth.required_action.completed")
+            }
             WebhookTrigger::ClientCreated => write!(f, "client.created"),
             WebhookTrigger::ClientUpdated => write!(f, "client.updated"),
             WebhookTrigger::ClientDeleted => write!(f, "client.deleted"),
@@ -86,6 +128,8 @@ impl Display for WebhookTrigger {
             WebhookTrigger::WebhookCreated => write!(f, "webhook.created"),
             WebhookTrigger::WebhookUpdated => write!(f, "webhook.updated"),
             WebhookTrigger::WebhookDeleted => write!(f, "webhook.deleted"),
+            WebhookTrigger::WebhookDisabled => write!(f, "webhook.disabled"),
+            WebhookTrigger::WebhookTest => write!(f, "webhook.test"),
         }
     }
 }
@@ -102,7 +146,19 @@ impl TryFrom<String> for WebhookTrigger {
             "user.assign.role" => Ok(WebhookTrigger::UserAssignRole),
             "user.unassign.role" => Ok(WebhookTrigger::UserUnassignRole),
             "user.credentials.deleted" => Ok(WebhookTrigger::UserDeleteCredentials),
+            "user.registered" => Ok(WebhookTrigger::UserRegistered),
             "auth.reset_password" => Ok(WebhookTrigger::AuthResetPassword),
+            "auth.refresh_token.reused" => Ok(WebhookTrigger::AuthRefreshTokenReused),
+            "auth.lockout" => Ok(WebhookTrigger::AuthLockout),
+            "auth.login.succeeded" => Ok(WebhookTrigger::AuthLoginSucceeded),
+            "auth.login.failed" => Ok(WebhookTrigger::AuthLoginFailed),
+            "auth.token.refreshed" => Ok(WebhookTrigger::AuthTokenRefreshed),
+            "auth.logout" => Ok(WebhookTrigger::AuthLogout),
+            "auth.otp.configured" => Ok(WebhookTrigger::AuthOtpConfigured),
+            "auth.otp.failed" => Ok(WebhookTrigger::AuthOtpFailed),
+            "auth.recovery_code.burned" => Ok(WebhookTrigger::AuthRecoveryCodeBurned),
+            "auth.password.updated" => Ok(WebhookTrigger::AuthPasswordUpdated),
+            "auth.required_action.completed" => Ok(WebhookTrigger::AuthRequiredActionCompleted),
             "client.created" => Ok(WebhookTrigger::ClientCreated),
             "client.updated" => Ok(WebhookTrigger::ClientUpdated),
             "client.deleted" => Ok(WebhookTrigger::ClientDeleted),
@@ -120,6 +176,8 @@ impl TryFrom<String> for WebhookTrigger {
             "webhook.created" => Ok(WebhookTrigger::WebhookCreated),
             "webhook.updated" => Ok(WebhookTrigger::WebhookUpdated),
             "webhook.deleted" => Ok(WebhookTrigger::WebhookDeleted),
+            "webhook.disabled" => Ok(WebhookTrigger::WebhookDisabled),
+            "webhook.test" => Ok(WebhookTrigger::WebhookTest),
             _ => Err("Invalid webhook trigger".to_string()),
         }
     }
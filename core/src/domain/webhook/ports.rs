@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, Utc};
 use serde::Serialize;
 use uuid::Uuid;
 
@@ -6,7 +9,10 @@ use crate::domain::{
     common::entities::app_errors::CoreError,
     realm::entities::Realm,
     webhook::entities::{
-        errors::WebhookError, webhook::Webhook, webhook_payload::WebhookPayload,
+        errors::WebhookError,
+        webhook::Webhook,
+        webhook_delivery::{WebhookDelivery, WebhookDeliveryAttempt},
+        webhook_payload::WebhookPayload,
         webhook_trigger::WebhookTrigger,
     },
 };
@@ -47,6 +53,35 @@ pub trait WebhookService: Clone + Send + Sync {
         identity: Identity,
         input: DeleteWebhookInput,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Latest deliveries of the webhook, newest first.
+    fn get_webhook_deliveries(
+        &self,
+        identity: Identity,
+        input: GetWebhookDeliveriesInput,
+    ) -> impl Future<Output = Result<Vec<WebhookDelivery>, CoreError>> + Send;
+
+    /// Queues the event of a past delivery to be sent again.
+    fn redeliver_webhook(
+        &self,
+        identity: Identity,
+        input: RedeliverWebhookInput,
+    ) -> impl Future<Output = Result<WebhookDelivery, CoreError>> + Send;
+
+    /// Sends a `webhook.test` event to the endpoint right away, even when the
+    /// webhook is disabled, and returns the outcome.
+    fn test_webhook(
+        &self,
+        identity: Identity,
+        input: TestWebhookInput,
+    ) -> impl Future<Output = Result<WebhookDelivery, CoreError>> + Send;
+
+    /// Attempts the queued deliveries that are due, scheduling retries for
+    /// the ones that fail.
+    fn deliver_pending_webhooks(&self) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Deletes the deliveries past the history of their webhook.
+    fn prune_webhook_deliveries(&self) -> impl Future<Output = Result<(), CoreError>> + Send;
 }
 
 pub trait WebhookRepository: Clone + Send + Sync + 'static {
@@ -55,6 +90,7 @@ pub trait WebhookRepository: Clone + Send + Sync + 'static {
         realm_id: Uuid,
     ) -> impl Future<Output = Result<Vec<Webhook>, WebhookError>> + Send;
 
+    /// Enabled webhooks of the realm subscribed to `subscriber`.
     fn fetch_webhooks_by_subscriber(
         &self,
         realm_id: Uuid,
@@ -67,33 +103,107 @@ pub trait WebhookRepository: Clone + Send + Sync + 'static {
         realm_id: Uuid,
     ) -> impl Future<Output = Result<Option<Webhook>, WebhookError>> + Send;
 
+    #[allow(clippy::too_many_arguments)]
     fn create_webhook(
         &self,
         realm_id: Uuid,
         name: Option<String>,
         description: Option<String>,
         endpoint: String,
+        secret: String,
+        enabled: bool,
         subscribers: Vec<WebhookTrigger>,
+        payload_fields: Vec<String>,
+        payload_template: Option<String>,
+        headers: BTreeMap<String, String>,
     ) -> impl Future<Output = Result<Webhook, WebhookError>> + Send;
 
+    /// Replaces the webhook, keeping its secret, its state, its payload
+    /// options and its headers when `secret`, `enabled`, `payload_fields`,
+    /// `payload_template` and `headers` are `None`. Enabling a webhook clears
+    /// its failure count.
+    #[allow(clippy::too_many_arguments)]
     fn update_webhook(
         &self,
         id: Uuid,
         name: Option<String>,
         description: Option<String>,
         endpoint: String,
+        secret: Option<String>,
+        enabled: Option<bool>,
         subscribers: Vec<WebhookTrigger>,
+        payload_fields: Option<Vec<String>>,
+        payload_template: Option<Option<String>>,
+        headers: Option<BTreeMap<String, String>>,
     ) -> impl Future<Output = Result<Webhook, WebhookError>> + Send;
 
     fn delete_webhook(&self, id: Uuid) -> impl Future<Output = Result<(), WebhookError>> + Send;
+
+    /// Updates the health of the webhook after an attempt made `at`, and
+    /// returns it.
+    fn record_delivery_outcome(
+        &self,
+        id: Uuid,
+        success: bool,
+        at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Webhook, WebhookError>> + Send;
+
+    /// Disables the webhook, returning whether it was enabled until now.
+    fn disable_webhook(&self, id: Uuid) -> impl Future<Output = Result<bool, WebhookError>> + Send;
 }
 
 pub trait WebhookNotifierRepository: Clone + Send + Sync + 'static {
+    /// Queues a delivery of `payload` to each of `webhooks`.
     fn notify<T: Send + Sync + Serialize + Clone + 'static>(
         &self,
         webhooks: Vec<Webhook>,
         payload: WebhookPayload<T>,
     ) -> impl Future<Output = Result<(), CoreError>> + Send;
+
+    /// Posts `delivery` to the endpoint of `webhook`, signed with its secret.
+    fn deliver(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+    ) -> impl Future<Output = WebhookDeliveryAttempt> + Send;
+}
+
+pub trait WebhookDeliveryRepository: Clone + Send + Sync + 'static {
+    fn create_deliveries(
+        &self,
+        deliveries: Vec<WebhookDelivery>,
+    ) -> impl Future<Output = Result<(), WebhookError>> + Send;
+
+    /// Takes up to `limit` pending deliveries that are due, pushing their
+    /// next attempt back by `lease` so that no other instance picks them up
+    /// meanwhile.
+    fn claim_due_deliveries(
+        &self,
+        limit: u64,
+        lease: Duration,
+    ) -> impl Future<Output = Result<Vec<WebhookDelivery>, WebhookError>> + Send;
+
+    fn update_delivery(
+        &self,
+        delivery: &WebhookDelivery,
+    ) -> impl Future<Output = Result<(), WebhookError>> + Send;
+
+    fn fetch_deliveries_by_webhook(
+        &self,
+        webhook_id: Uuid,
+        limit: u64,
+    ) -> impl Future<Output = Result<Vec<WebhookDelivery>, WebhookError>> + Send;
+
+    fn get_delivery(
+        &self,
+        delivery_id: Uuid,
+        webhook_id: Uuid,
+    ) -> impl Future<Output = Result<Option<WebhookDelivery>, WebhookError>> + Send;
+
+    /// Deletes the finished deliveries of every webhook past its `keep` most
+    /// recent ones, and returns how many were deleted.
+    fn prune_deliveries(&self, keep: u64)
+    -> impl Future<Output = Result<u64, WebhookError>> + Send;
 }
 
 pub trait WebhookNotifierService: Clone + Send + Sync {
@@ -149,7 +259,14 @@ pub struct CreateWebhookInput {
     pub name: Option<String>,
     pub description: Option<String>,
     pub endpoint: String,
+    /// Generated when unset.
+    pub secret: Option<String>,
+    /// Enabled when unset.
+    pub enabled: Option<bool>,
     pub subscribers: Vec<WebhookTrigger>,
+    pub payload_fields: Vec<String>,
+    pub payload_template: Option<String>,
+    pub headers: BTreeMap<String, String>,
 }
 
 pub struct UpdateWebhookInput {
@@ -158,10 +275,39 @@ pub struct UpdateWebhookInput {
     pub name: Option<String>,
     pub description: Option<String>,
     pub endpoint: String,
+    /// Kept when unset.
+    pub secret: Option<String>,
+    /// Kept when unset.
+    pub enabled: Option<bool>,
     pub subscribers: Vec<WebhookTrigger>,
+    /// Kept when unset.
+    pub payload_fields: Option<Vec<String>>,
+    /// Kept when unset, removed when empty.
+    pub payload_template: Option<String>,
+    /// Kept when unset. Headers set to [`MASKED_HEADER_VALUE`] keep their
+    /// current value.
+    ///
+    /// [`MASKED_HEADER_VALUE`]: crate::domain::webhook::entities::webhook::MASKED_HEADER_VALUE
+    pub headers: Option<BTreeMap<String, String>>,
 }
 
 pub struct DeleteWebhookInput {
     pub realm_name: String,
     pub webhook_id: Uuid,
 }
+
+pub struct GetWebhookDeliveriesInput {
+    pub realm_name: String,
+    pub webhook_id: Uuid,
+}
+
+pub struct RedeliverWebhookInput {
+    pub realm_name: String,
+    pub webhook_id: Uuid,
+    pub delivery_id: Uuid,
+}
+
+pub struct TestWebhookInput {
+    pub realm_name: String,
+    pub webhook_id: Uuid,
+}
@@ -26,6 +26,10 @@ pub struct Model {
     pub authenticated: bool,
     pub created_at: DateTime,
     pub expires_at: DateTime,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+    pub authenticated_at: Option<DateTime>,
+    pub amr: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -43,6 +47,10 @@ pub enum Column {
     Authenticated,
     CreatedAt,
     ExpiresAt,
+    CodeChallenge,
+    CodeChallengeMethod,
+    AuthenticatedAt,
+    Amr,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -84,6 +92,10 @@ impl ColumnTrait for Column {
             Self::Authenticated => ColumnType::Boolean.def(),
             Self::CreatedAt => ColumnType::DateTime.def(),
             Self::ExpiresAt => ColumnType::DateTime.def(),
+            Self::CodeChallenge => ColumnType::String(StringLen::N(255u32)).def().null(),
+            Self::CodeChallengeMethod => ColumnType::String(StringLen::N(10u32)).def().null(),
+            Self::AuthenticatedAt => ColumnType::DateTime.def().null(),
+            Self::Amr => ColumnType::String(StringLen::N(255u32)).def().null(),
         }
     }
 }
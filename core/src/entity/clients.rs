@@ -26,6 +26,11 @@ pub struct Model {
     pub created_at: DateTime,
     pub updated_at: DateTime,
     pub direct_access_grants_enabled: Option<bool>,
+    pub pkce_required: bool,
+    pub access_token_lifespan: Option<i32>,
+    pub refresh_token_lifespan: Option<i32>,
+    pub auth_code_lifespan: Option<i32>,
+    pub consent_required: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -43,6 +48,11 @@ pub enum Column {
     CreatedAt,
     UpdatedAt,
     DirectAccessGrantsEnabled,
+    PkceRequired,
+    AccessTokenLifespan,
+    RefreshTokenLifespan,
+    AuthCodeLifespan,
+    ConsentRequired,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -60,9 +70,12 @@ impl PrimaryKeyTrait for PrimaryKey {
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
     AuthSessions,
+    ClientScopeMappings,
+    ProtocolMappers,
     Realms,
     RedirectUris,
     Roles,
+    UserConsents,
     Users,
 }
 
@@ -83,6 +96,11 @@ impl ColumnTrait for Column {
             Self::CreatedAt => ColumnType::DateTime.def(),
             Self::UpdatedAt => ColumnType::DateTime.def(),
             Self::DirectAccessGrantsEnabled => ColumnType::Boolean.def().null(),
+            Self::PkceRequired => ColumnType::Boolean.def(),
+            Self::AccessTokenLifespan => ColumnType::Integer.def().null(),
+            Self::RefreshTokenLifespan => ColumnType::Integer.def().null(),
+            Self::AuthCodeLifespan => ColumnType::Integer.def().null(),
+            Self::ConsentRequired => ColumnType::Boolean.def(),
         }
     }
 }
@@ -91,12 +109,17 @@ impl RelationTrait for Relation {
     fn def(&self) -> RelationDef {
         match self {
             Self::AuthSessions => Entity::has_many(super::auth_sessions::Entity).into(),
+            Self::ClientScopeMappings => {
+                Entity::has_many(super::client_scope_mappings::Entity).into()
+            }
+            Self::ProtocolMappers => Entity::has_many(super::protocol_mappers::Entity).into(),
             Self::Realms => Entity::belongs_to(super::realms::Entity)
                 .from(Column::RealmId)
                 .to(super::realms::Column::Id)
                 .into(),
             Self::RedirectUris => Entity::has_many(super::redirect_uris::Entity).into(),
             Self::Roles => Entity::has_many(super::roles::Entity).into(),
+            Self::UserConsents => Entity::has_many(super::user_consents::Entity).into(),
             Self::Users => Entity::has_one(super::users::Entity).into(),
         }
     }
@@ -108,6 +131,18 @@ impl Related<super::auth_sessions::Entity> for Entity {
     }
 }
 
+impl Related<super::client_scope_mappings::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ClientScopeMappings.def()
+    }
+}
+
+impl Related<super::protocol_mappers::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ProtocolMappers.def()
+    }
+}
+
 impl Related<super::realms::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Realms.def()
@@ -126,6 +161,12 @@ impl Related<super::roles::Entity> for Entity {
     }
 }
 
+impl Related<super::user_consents::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserConsents.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
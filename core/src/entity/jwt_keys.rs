@@ -18,6 +18,10 @@ pub struct Model {
     pub private_key: String,
     pub public_key: String,
     pub created_at: DateTime,
+    pub version: i32,
+    pub status: String,
+    pub rotated_at: Option<DateTime>,
+    pub algorithm: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -27,6 +31,10 @@ pub enum Column {
     PrivateKey,
     PublicKey,
     CreatedAt,
+    Version,
+    Status,
+    RotatedAt,
+    Algorithm,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -55,6 +63,10 @@ impl ColumnTrait for Column {
             Self::PrivateKey => ColumnType::Text.def(),
             Self::PublicKey => ColumnType::Text.def(),
             Self::CreatedAt => ColumnType::DateTime.def(),
+            Self::Version => ColumnType::Integer.def(),
+            Self::Status => ColumnType::String(StringLen::N(32u32)).def(),
+            Self::RotatedAt => ColumnType::DateTime.def().null(),
+            Self::Algorithm => ColumnType::String(StringLen::N(16u32)).def(),
         }
     }
 }
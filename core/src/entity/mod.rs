@@ -2,18 +2,32 @@
 
 pub mod prelude;
 
+pub mod admin_events;
 pub mod auth_sessions;
+pub mod client_scope_mappings;
+pub mod client_scopes;
 pub mod clients;
 pub mod credentials;
+pub mod group_members;
+pub mod group_roles;
+pub mod groups;
 pub mod jwt_keys;
+pub mod login_events;
+pub mod login_failures;
+pub mod protocol_mappers;
 pub mod realm_settings;
 pub mod realms;
 pub mod redirect_uris;
 pub mod refresh_tokens;
+pub mod role_composites;
 pub mod roles;
+pub mod smtp_servers;
+pub mod used_action_tokens;
+pub mod user_consents;
 pub mod user_required_actions;
 pub mod user_role;
 pub mod user_sessions;
 pub mod users;
+pub mod webhook_deliveries;
 pub mod webhook_subscribers;
 pub mod webhooks;
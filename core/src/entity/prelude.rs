@@ -1,17 +1,31 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.14
 
+pub use super::admin_events::Entity as AdminEvents;
 pub use super::auth_sessions::Entity as AuthSessions;
+pub use super::client_scope_mappings::Entity as ClientScopeMappings;
+pub use super::client_scopes::Entity as ClientScopes;
 pub use super::clients::Entity as Clients;
 pub use super::credentials::Entity as Credentials;
+pub use super::group_members::Entity as GroupMembers;
+pub use super::group_roles::Entity as GroupRoles;
+pub use super::groups::Entity as Groups;
 pub use super::jwt_keys::Entity as JwtKeys;
+pub use super::login_events::Entity as LoginEvents;
+pub use super::login_failures::Entity as LoginFailures;
+pub use super::protocol_mappers::Entity as ProtocolMappers;
 pub use super::realm_settings::Entity as RealmSettings;
 pub use super::realms::Entity as Realms;
 pub use super::redirect_uris::Entity as RedirectUris;
 pub use super::refresh_tokens::Entity as RefreshTokens;
+pub use super::role_composites::Entity as RoleComposites;
 pub use super::roles::Entity as Roles;
+pub use super::smtp_servers::Entity as SmtpServers;
+pub use super::used_action_tokens::Entity as UsedActionTokens;
+pub use super::user_consents::Entity as UserConsents;
 pub use super::user_required_actions::Entity as UserRequiredActions;
 pub use super::user_role::Entity as UserRole;
 pub use super::user_sessions::Entity as UserSessions;
 pub use super::users::Entity as Users;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
 pub use super::webhook_subscribers::Entity as WebhookSubscribers;
 pub use super::webhooks::Entity as Webhooks;
@@ -17,6 +17,22 @@ pub struct Model {
     pub realm_id: Uuid,
     pub default_signing_algorithm: Option<String>,
     pub updated_at: DateTime,
+    pub access_token_lifespan: i32,
+    pub refresh_token_lifespan: i32,
+    pub session_lifespan: i32,
+    pub auth_code_lifespan: i32,
+    pub refresh_token_rotation: bool,
+    pub key_rotation_interval: Option<i32>,
+    pub key_retirement_delay: i32,
+    pub password_policy: Json,
+    pub brute_force_protection: Json,
+    pub user_profile: Json,
+    pub default_locale: String,
+    pub registration_allowed: bool,
+    pub verify_email: bool,
+    pub webhook_failure_threshold: i32,
+    pub login_event_retention: i32,
+    pub admin_event_retention: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -25,6 +41,22 @@ pub enum Column {
     RealmId,
     DefaultSigningAlgorithm,
     UpdatedAt,
+    AccessTokenLifespan,
+    RefreshTokenLifespan,
+    SessionLifespan,
+    AuthCodeLifespan,
+    RefreshTokenRotation,
+    KeyRotationInterval,
+    KeyRetirementDelay,
+    PasswordPolicy,
+    BruteForceProtection,
+    UserProfile,
+    DefaultLocale,
+    RegistrationAllowed,
+    VerifyEmail,
+    WebhookFailureThreshold,
+    LoginEventRetention,
+    AdminEventRetention,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -52,6 +84,22 @@ impl ColumnTrait for Column {
             Self::RealmId => ColumnType::Uuid.def(),
             Self::DefaultSigningAlgorithm => ColumnType::String(StringLen::N(255u32)).def().null(),
             Self::UpdatedAt => ColumnType::DateTime.def(),
+            Self::AccessTokenLifespan => ColumnType::Integer.def(),
+            Self::RefreshTokenLifespan => ColumnType::Integer.def(),
+            Self::SessionLifespan => ColumnType::Integer.def(),
+            Self::AuthCodeLifespan => ColumnType::Integer.def(),
+            Self::RefreshTokenRotation => ColumnType::Boolean.def(),
+            Self::KeyRotationInterval => ColumnType::Integer.def().null(),
+            Self::KeyRetirementDelay => ColumnType::Integer.def(),
+            Self::PasswordPolicy => ColumnType::JsonBinary.def(),
+            Self::BruteForceProtection => ColumnType::JsonBinary.def(),
+            Self::UserProfile => ColumnType::JsonBinary.def(),
+            Self::DefaultLocale => ColumnType::String(StringLen::N(16u32)).def(),
+            Self::RegistrationAllowed => ColumnType::Boolean.def(),
+            Self::VerifyEmail => ColumnType::Boolean.def(),
+            Self::WebhookFailureThreshold => ColumnType::Integer.def(),
+            Self::LoginEventRetention => ColumnType::Integer.def(),
+            Self::AdminEventRetention => ColumnType::Integer.def(),
         }
     }
 }
@@ -15,6 +15,7 @@ impl EntityName for Entity {
 pub struct Model {
     pub id: Uuid,
     pub name: String,
+    pub default_role_id: Option<Uuid>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
@@ -23,6 +24,7 @@ pub struct Model {
 pub enum Column {
     Id,
     Name,
+    DefaultRoleId,
     CreatedAt,
     UpdatedAt,
 }
@@ -41,13 +43,21 @@ impl PrimaryKeyTrait for PrimaryKey {
 
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
+    AdminEvents,
     AuthSessions,
+    ClientScopes,
     Clients,
+    Groups,
     JwtKeys,
+    LoginEvents,
+    LoginFailures,
     RealmSettings,
     Roles,
+    SmtpServers,
+    UsedActionTokens,
     UserSessions,
     Users,
+    WebhookDeliveries,
     Webhooks,
 }
 
@@ -57,6 +67,7 @@ impl ColumnTrait for Column {
         match self {
             Self::Id => ColumnType::Uuid.def(),
             Self::Name => ColumnType::String(StringLen::N(255u32)).def().unique(),
+            Self::DefaultRoleId => ColumnType::Uuid.def().null(),
             Self::CreatedAt => ColumnType::DateTime.def(),
             Self::UpdatedAt => ColumnType::DateTime.def(),
         }
@@ -66,36 +77,74 @@ impl ColumnTrait for Column {
 impl RelationTrait for Relation {
     fn def(&self) -> RelationDef {
         match self {
+            Self::AdminEvents => Entity::has_many(super::admin_events::Entity).into(),
             Self::AuthSessions => Entity::has_many(super::auth_sessions::Entity).into(),
+            Self::ClientScopes => Entity::has_many(super::client_scopes::Entity).into(),
             Self::Clients => Entity::has_many(super::clients::Entity).into(),
+            Self::Groups => Entity::has_many(super::groups::Entity).into(),
             Self::JwtKeys => Entity::has_many(super::jwt_keys::Entity).into(),
+            Self::LoginEvents => Entity::has_many(super::login_events::Entity).into(),
+            Self::LoginFailures => Entity::has_many(super::login_failures::Entity).into(),
             Self::RealmSettings => Entity::has_many(super::realm_settings::Entity).into(),
             Self::Roles => Entity::has_many(super::roles::Entity).into(),
+            Self::SmtpServers => Entity::has_one(super::smtp_servers::Entity).into(),
+            Self::UsedActionTokens => Entity::has_many(super::used_action_tokens::Entity).into(),
             Self::UserSessions => Entity::has_many(super::user_sessions::Entity).into(),
             Self::Users => Entity::has_many(super::users::Entity).into(),
+            Self::WebhookDeliveries => Entity::has_many(super::webhook_deliveries::Entity).into(),
             Self::Webhooks => Entity::has_many(super::webhooks::Entity).into(),
         }
     }
 }
 
+impl Related<super::admin_events::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AdminEvents.def()
+    }
+}
+
 impl Related<super::auth_sessions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::AuthSessions.def()
     }
 }
 
+impl Related<super::client_scopes::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ClientScopes.def()
+    }
+}
+
 impl Related<super::clients::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Clients.def()
     }
 }
 
+impl Related<super::groups::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Groups.def()
+    }
+}
+
 impl Related<super::jwt_keys::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::JwtKeys.def()
     }
 }
 
+impl Related<super::login_events::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoginEvents.def()
+    }
+}
+
+impl Related<super::login_failures::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoginFailures.def()
+    }
+}
+
 impl Related<super::realm_settings::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::RealmSettings.def()
@@ -108,6 +157,18 @@ impl Related<super::roles::Entity> for Entity {
     }
 }
 
+impl Related<super::smtp_servers::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SmtpServers.def()
+    }
+}
+
+impl Related<super::used_action_tokens::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UsedActionTokens.def()
+    }
+}
+
 impl Related<super::user_sessions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserSessions.def()
@@ -120,6 +181,12 @@ impl Related<super::users::Entity> for Entity {
     }
 }
 
+impl Related<super::webhook_deliveries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookDeliveries.def()
+    }
+}
+
 impl Related<super::webhooks::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Webhooks.def()
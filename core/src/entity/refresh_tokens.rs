@@ -16,9 +16,14 @@ pub struct Model {
     pub id: Uuid,
     pub jti: Uuid,
     pub user_id: Uuid,
+    pub client_id: Option<String>,
     pub revoked: bool,
     pub expires_at: Option<DateTime>,
     pub created_at: DateTime,
+    pub family_id: Option<Uuid>,
+    pub rotated_at: Option<DateTime>,
+    pub auth_time: Option<DateTime>,
+    pub amr: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -26,9 +31,14 @@ pub enum Column {
     Id,
     Jti,
     UserId,
+    ClientId,
     Revoked,
     ExpiresAt,
     CreatedAt,
+    FamilyId,
+    RotatedAt,
+    AuthTime,
+    Amr,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -55,7 +65,12 @@ impl ColumnTrait for Column {
             Self::Id => ColumnType::Uuid.def(),
             Self::Jti => ColumnType::Uuid.def().unique(),
             Self::UserId => ColumnType::Uuid.def(),
+            Self::ClientId => ColumnType::String(StringLen::N(255u32)).def().null(),
             Self::Revoked => ColumnType::Boolean.def(),
+            Self::FamilyId => ColumnType::Uuid.def().null(),
+            Self::RotatedAt => ColumnType::DateTime.def().null(),
+            Self::AuthTime => ColumnType::DateTime.def().null(),
+            Self::Amr => ColumnType::String(StringLen::N(255u32)).def().null(),
             Self::ExpiresAt => ColumnType::DateTime.def().null(),
             Self::CreatedAt => ColumnType::DateTime.def(),
         }
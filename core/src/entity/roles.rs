@@ -50,6 +50,7 @@ impl PrimaryKeyTrait for PrimaryKey {
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
     Clients,
+    GroupRoles,
     Realms,
     UserRole,
 }
@@ -77,6 +78,7 @@ impl RelationTrait for Relation {
                 .from(Column::ClientId)
                 .to(super::clients::Column::Id)
                 .into(),
+            Self::GroupRoles => Entity::has_many(super::group_roles::Entity).into(),
             Self::Realms => Entity::belongs_to(super::realms::Entity)
                 .from(Column::RealmId)
                 .to(super::realms::Column::Id)
@@ -92,6 +94,12 @@ impl Related<super::clients::Entity> for Entity {
     }
 }
 
+impl Related<super::group_roles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GroupRoles.def()
+    }
+}
+
 impl Related<super::realms::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Realms.def()
@@ -24,6 +24,7 @@ pub struct Model {
     pub enabled: bool,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    pub attributes: Json,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -39,6 +40,7 @@ pub enum Column {
     Enabled,
     CreatedAt,
     UpdatedAt,
+    Attributes,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -58,8 +60,11 @@ pub enum Relation {
     AuthSessions,
     Clients,
     Credentials,
+    GroupMembers,
+    LoginFailures,
     Realms,
     RefreshTokens,
+    UserConsents,
     UserRequiredActions,
     UserRole,
     UserSessions,
@@ -80,6 +85,7 @@ impl ColumnTrait for Column {
             Self::Enabled => ColumnType::Boolean.def(),
             Self::CreatedAt => ColumnType::DateTime.def(),
             Self::UpdatedAt => ColumnType::DateTime.def(),
+            Self::Attributes => ColumnType::JsonBinary.def(),
         }
     }
 }
@@ -93,11 +99,14 @@ impl RelationTrait for Relation {
                 .to(super::clients::Column::Id)
                 .into(),
             Self::Credentials => Entity::has_many(super::credentials::Entity).into(),
+            Self::GroupMembers => Entity::has_many(super::group_members::Entity).into(),
+            Self::LoginFailures => Entity::has_many(super::login_failures::Entity).into(),
             Self::Realms => Entity::belongs_to(super::realms::Entity)
                 .from(Column::RealmId)
                 .to(super::realms::Column::Id)
                 .into(),
             Self::RefreshTokens => Entity::has_many(super::refresh_tokens::Entity).into(),
+            Self::UserConsents => Entity::has_many(super::user_consents::Entity).into(),
             Self::UserRequiredActions => {
                 Entity::has_many(super::user_required_actions::Entity).into()
             }
@@ -125,6 +134,18 @@ impl Related<super::credentials::Entity> for Entity {
     }
 }
 
+impl Related<super::group_members::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GroupMembers.def()
+    }
+}
+
+impl Related<super::login_failures::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LoginFailures.def()
+    }
+}
+
 impl Related<super::realms::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Realms.def()
@@ -137,6 +158,12 @@ impl Related<super::refresh_tokens::Entity> for Entity {
     }
 }
 
+impl Related<super::user_consents::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserConsents.def()
+    }
+}
+
 impl Related<super::user_required_actions::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserRequiredActions.def()
@@ -21,6 +21,14 @@ pub struct Model {
     pub created_at: DateTime,
     pub name: Option<String>,
     pub description: Option<String>,
+    pub secret: String,
+    pub enabled: bool,
+    pub last_success_at: Option<DateTime>,
+    pub last_failure_at: Option<DateTime>,
+    pub consecutive_failures: i32,
+    pub payload_fields: Json,
+    pub payload_template: Option<String>,
+    pub headers: Json,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
@@ -33,6 +41,14 @@ pub enum Column {
     CreatedAt,
     Name,
     Description,
+    Secret,
+    Enabled,
+    LastSuccessAt,
+    LastFailureAt,
+    ConsecutiveFailures,
+    PayloadFields,
+    PayloadTemplate,
+    Headers,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DerivePrimaryKey)]
@@ -50,6 +66,7 @@ impl PrimaryKeyTrait for PrimaryKey {
 #[derive(Copy, Clone, Debug, EnumIter)]
 pub enum Relation {
     Realms,
+    WebhookDeliveries,
     WebhookSubscribers,
 }
 
@@ -65,6 +82,14 @@ impl ColumnTrait for Column {
             Self::CreatedAt => ColumnType::DateTime.def(),
             Self::Name => ColumnType::String(StringLen::N(255u32)).def().null(),
             Self::Description => ColumnType::String(StringLen::N(255u32)).def().null(),
+            Self::Secret => ColumnType::String(StringLen::N(255u32)).def(),
+            Self::Enabled => ColumnType::Boolean.def(),
+            Self::LastSuccessAt => ColumnType::DateTime.def().null(),
+            Self::LastFailureAt => ColumnType::DateTime.def().null(),
+            Self::ConsecutiveFailures => ColumnType::Integer.def(),
+            Self::PayloadFields => ColumnType::JsonBinary.def(),
+            Self::PayloadTemplate => ColumnType::Text.def().null(),
+            Self::Headers => ColumnType::JsonBinary.def(),
         }
     }
 }
@@ -76,6 +101,7 @@ impl RelationTrait for Relation {
                 .from(Column::RealmId)
                 .to(super::realms::Column::Id)
                 .into(),
+            Self::WebhookDeliveries => Entity::has_many(super::webhook_deliveries::Entity).into(),
             Self::WebhookSubscribers => Entity::has_many(super::webhook_subscribers::Entity).into(),
         }
     }
@@ -87,6 +113,12 @@ impl Related<super::realms::Entity> for Entity {
     }
 }
 
+impl Related<super::webhook_deliveries::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookDeliveries.def()
+    }
+}
+
 impl Related<super::webhook_subscribers::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::WebhookSubscribers.def()
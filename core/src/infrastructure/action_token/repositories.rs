@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        action_token::ports::UsedActionTokenRepository, common::entities::app_errors::CoreError,
+    },
+    infrastructure::action_token::repositories::used_action_token_postgres_repository::PostgresUsedActionTokenRepository,
+};
+
+pub mod used_action_token_postgres_repository;
+
+#[derive(Clone)]
+pub enum UsedActionTokenRepoAny {
+    Postgres(PostgresUsedActionTokenRepository),
+}
+
+impl UsedActionTokenRepository for UsedActionTokenRepoAny {
+    async fn is_used(&self, jti: Uuid) -> Result<bool, CoreError> {
+        match self {
+            UsedActionTokenRepoAny::Postgres(repo) => repo.is_used(jti).await,
+        }
+    }
+
+    async fn mark_used(
+        &self,
+        jti: Uuid,
+        realm_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, CoreError> {
+        match self {
+            UsedActionTokenRepoAny::Postgres(repo) => {
+                repo.mark_used(jti, realm_id, expires_at).await
+            }
+        }
+    }
+}
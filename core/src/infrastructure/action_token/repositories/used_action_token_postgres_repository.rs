@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    sea_query::OnConflict,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        action_token::ports::UsedActionTokenRepository, common::entities::app_errors::CoreError,
+    },
+    entity::used_action_tokens::{ActiveModel, Column, Entity},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresUsedActionTokenRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresUsedActionTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl UsedActionTokenRepository for PostgresUsedActionTokenRepository {
+    async fn is_used(&self, jti: Uuid) -> Result<bool, CoreError> {
+        let token = Entity::find_by_id(jti)
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(token.is_some())
+    }
+
+    async fn mark_used(
+        &self,
+        jti: Uuid,
+        realm_id: Uuid,
+        expires_at: DateTime<Utc>,
+    ) -> Result<bool, CoreError> {
+        let now = Utc::now().naive_utc();
+
+        Entity::delete_many()
+            .filter(Column::ExpiresAt.lt(now))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to prune used action tokens: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        let model = ActiveModel {
+            jti: Set(jti),
+            realm_id: Set(realm_id),
+            expires_at: Set(expires_at.naive_utc()),
+            used_at: Set(now),
+        };
+
+        let rows_affected = Entity::insert(model)
+            .on_conflict(OnConflict::column(Column::Jti).do_nothing().to_owned())
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to mark action token as used: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(rows_affected == 1)
+    }
+}
@@ -35,12 +35,32 @@ impl AuthSessionRepository for AuthSessionRepoAny {
         session_code: Uuid,
         code: String,
         user_id: Uuid,
+        amr: Vec<String>,
     ) -> Result<AuthSession, AuthenticationError> {
         match self {
             AuthSessionRepoAny::Postgres(repo) => {
-                repo.update_code_and_user_id(session_code, code, user_id)
+                repo.update_code_and_user_id(session_code, code, user_id, amr)
                     .await
             }
         }
     }
+
+    async fn mark_authenticated(
+        &self,
+        session_code: Uuid,
+        user_id: Uuid,
+        amr: Vec<String>,
+    ) -> Result<AuthSession, AuthenticationError> {
+        match self {
+            AuthSessionRepoAny::Postgres(repo) => {
+                repo.mark_authenticated(session_code, user_id, amr).await
+            }
+        }
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), AuthenticationError> {
+        match self {
+            AuthSessionRepoAny::Postgres(repo) => repo.delete_by_user_id(user_id).await,
+        }
+    }
 }
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    brute_force::{
+        entities::{LoginFailure, LoginFailureSubject},
+        ports::LoginFailureRepository,
+    },
+    common::entities::app_errors::CoreError,
+};
+use crate::infrastructure::repositories::login_failure_repository::PostgresLoginFailureRepository;
+
+#[derive(Clone)]
+pub enum LoginFailureRepoAny {
+    Postgres(PostgresLoginFailureRepository),
+}
+
+impl LoginFailureRepository for LoginFailureRepoAny {
+    async fn get_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+    ) -> Result<Option<LoginFailure>, CoreError> {
+        match self {
+            LoginFailureRepoAny::Postgres(repo) => repo.get_login_failure(realm_id, subject).await,
+        }
+    }
+
+    async fn increment_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+        now: DateTime<Utc>,
+        reset_after: Duration,
+    ) -> Result<LoginFailure, CoreError> {
+        match self {
+            LoginFailureRepoAny::Postgres(repo) => {
+                repo.increment_login_failure(realm_id, subject, now, reset_after)
+                    .await
+            }
+        }
+    }
+
+    async fn lock_login_failure(&self, failure: &LoginFailure) -> Result<LoginFailure, CoreError> {
+        match self {
+            LoginFailureRepoAny::Postgres(repo) => repo.lock_login_failure(failure).await,
+        }
+    }
+
+    async fn delete_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+    ) -> Result<(), CoreError> {
+        match self {
+            LoginFailureRepoAny::Postgres(repo) => {
+                repo.delete_login_failure(realm_id, subject).await
+            }
+        }
+    }
+}
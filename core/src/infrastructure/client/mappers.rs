@@ -1,2 +1,3 @@
 pub mod client_mapper;
+pub mod protocol_mapper_mapper;
 pub mod redirect_uri_mapper;
@@ -18,6 +18,11 @@ impl From<Model> for Client {
             public_client: model.public_client,
             service_account_enabled: model.service_account_enabled,
             direct_access_grants_enabled: model.direct_access_grants_enabled.unwrap_or(false),
+            pkce_required: model.pkce_required,
+            access_token_lifespan: model.access_token_lifespan,
+            refresh_token_lifespan: model.refresh_token_lifespan,
+            auth_code_lifespan: model.auth_code_lifespan,
+            consent_required: model.consent_required,
             client_type: model.client_type,
             redirect_uris: None,
             created_at,
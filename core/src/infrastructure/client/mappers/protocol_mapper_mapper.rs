@@ -0,0 +1,33 @@
+use chrono::{TimeZone, Utc};
+
+use crate::{
+    domain::{
+        client::entities::protocol_mapper::ProtocolMapper, common::entities::app_errors::CoreError,
+    },
+    entity::protocol_mappers::Model,
+};
+
+impl TryFrom<Model> for ProtocolMapper {
+    type Error = CoreError;
+
+    fn try_from(model: Model) -> Result<Self, Self::Error> {
+        let config = serde_json::from_value(model.config).map_err(|e| {
+            tracing::error!("invalid config for protocol mapper {}: {}", model.id, e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(ProtocolMapper {
+            id: model.id,
+            client_id: model.client_id,
+            client_scope_id: model.client_scope_id,
+            name: model.name,
+            config,
+            access_token: model.access_token,
+            id_token: model.id_token,
+            userinfo: model.userinfo,
+            introspection: model.introspection,
+            created_at: Utc.from_utc_datetime(&model.created_at),
+            updated_at: Utc.from_utc_datetime(&model.updated_at),
+        })
+    }
+}
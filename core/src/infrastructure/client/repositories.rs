@@ -1,13 +1,18 @@
 use crate::domain::client::entities::Client;
+use crate::domain::client::entities::protocol_mapper::ProtocolMapper;
 use crate::domain::client::entities::redirect_uri::RedirectUri;
-use crate::domain::client::ports::{ClientRepository, RedirectUriRepository};
+use crate::domain::client::ports::{
+    ClientRepository, ProtocolMapperRepository, RedirectUriRepository,
+};
 use crate::domain::client::value_objects::{CreateClientRequest, UpdateClientRequest};
 use crate::domain::common::entities::app_errors::CoreError;
 use crate::infrastructure::client::repositories::client_postgres_repository::PostgresClientRepository;
+use crate::infrastructure::client::repositories::protocol_mapper_postgres_repository::PostgresProtocolMapperRepository;
 use crate::infrastructure::client::repositories::redirect_uri_postgres_repository::PostgresRedirectUriRepository;
 use uuid::Uuid;
 
 pub mod client_postgres_repository;
+pub mod protocol_mapper_postgres_repository;
 pub mod redirect_uri_postgres_repository;
 
 #[derive(Clone)]
@@ -107,3 +112,55 @@ impl RedirectUriRepository for RedirectUriRepoAny {
         }
     }
 }
+
+#[derive(Clone)]
+pub enum ProtocolMapperRepoAny {
+    Postgres(PostgresProtocolMapperRepository),
+}
+
+impl ProtocolMapperRepository for ProtocolMapperRepoAny {
+    async fn create_protocol_mapper(
+        &self,
+        mapper: ProtocolMapper,
+    ) -> Result<ProtocolMapper, CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.create_protocol_mapper(mapper).await,
+        }
+    }
+
+    async fn get_by_client_id(&self, client_id: Uuid) -> Result<Vec<ProtocolMapper>, CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.get_by_client_id(client_id).await,
+        }
+    }
+
+    async fn get_by_client_scope_ids(
+        &self,
+        client_scope_ids: &[Uuid],
+    ) -> Result<Vec<ProtocolMapper>, CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.get_by_client_scope_ids(client_scope_ids).await,
+        }
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<ProtocolMapper, CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.get_by_id(id).await,
+        }
+    }
+
+    async fn update_protocol_mapper(
+        &self,
+        mapper: ProtocolMapper,
+    ) -> Result<ProtocolMapper, CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.update_protocol_mapper(mapper).await,
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.delete(id).await,
+        }
+    }
+}
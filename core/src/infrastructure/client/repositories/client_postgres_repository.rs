@@ -22,6 +22,11 @@ pub struct PostgresClientRepository {
     pub db: DatabaseConnection,
 }
 
+/// A lifespan of `0` clears the client override.
+fn lifespan_override(lifespan: i32) -> Option<i32> {
+    (lifespan > 0).then_some(lifespan)
+}
+
 impl PostgresClientRepository {
     pub fn new(db: DatabaseConnection) -> Self {
         Self { db }
@@ -43,6 +48,11 @@ impl ClientRepository for PostgresClientRepository {
             public_client: Set(data.public_client),
             service_account_enabled: Set(data.service_account_enabled),
             direct_access_grants_enabled: Set(Some(data.direct_access_grants_enabled)),
+            pkce_required: Set(false),
+            access_token_lifespan: Set(None),
+            refresh_token_lifespan: Set(None),
+            auth_code_lifespan: Set(None),
+            consent_required: Set(false),
             client_type: Set(data.client_type),
             created_at: Set(now.naive_utc()),
             updated_at: Set(now.naive_local()),
@@ -146,6 +156,31 @@ impl ClientRepository for PostgresClientRepository {
             None => client.direct_access_grants_enabled,
         };
 
+        client.pkce_required = match data.pkce_required {
+            Some(pkce_required) => Set(pkce_required),
+            None => client.pkce_required,
+        };
+
+        client.access_token_lifespan = match data.access_token_lifespan {
+            Some(lifespan) => Set(lifespan_override(lifespan)),
+            None => client.access_token_lifespan,
+        };
+
+        client.refresh_token_lifespan = match data.refresh_token_lifespan {
+            Some(lifespan) => Set(lifespan_override(lifespan)),
+            None => client.refresh_token_lifespan,
+        };
+
+        client.auth_code_lifespan = match data.auth_code_lifespan {
+            Some(lifespan) => Set(lifespan_override(lifespan)),
+            None => client.auth_code_lifespan,
+        };
+
+        client.consent_required = match data.consent_required {
+            Some(consent_required) => Set(consent_required),
+            None => client.consent_required,
+        };
+
         client.updated_at = Set(Utc::now().naive_utc());
 
         let client = client
@@ -0,0 +1,134 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, SqlErr,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        client::{entities::protocol_mapper::ProtocolMapper, ports::ProtocolMapperRepository},
+        common::entities::app_errors::CoreError,
+    },
+    entity::protocol_mappers::{ActiveModel, Column, Entity as ProtocolMapperEntity},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresProtocolMapperRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresProtocolMapperRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn map_write_error(e: DbErr) -> CoreError {
+    match e.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => CoreError::AlreadyExists,
+        Some(SqlErr::ForeignKeyConstraintViolation(_)) => CoreError::NotFound,
+        _ => {
+            error!("protocol mapper write failed: {:?}", e);
+            CoreError::InternalServerError
+        }
+    }
+}
+
+fn to_active_model(mapper: ProtocolMapper) -> Result<ActiveModel, CoreError> {
+    let config = serde_json::to_value(mapper.config).map_err(|_| CoreError::InternalServerError)?;
+
+    Ok(ActiveModel {
+        id: Set(mapper.id),
+        client_id: Set(mapper.client_id),
+        client_scope_id: Set(mapper.client_scope_id),
+        name: Set(mapper.name),
+        config: Set(config),
+        access_token: Set(mapper.access_token),
+        id_token: Set(mapper.id_token),
+        userinfo: Set(mapper.userinfo),
+        introspection: Set(mapper.introspection),
+        created_at: Set(mapper.created_at.naive_utc()),
+        updated_at: Set(mapper.updated_at.naive_utc()),
+    })
+}
+
+impl ProtocolMapperRepository for PostgresProtocolMapperRepository {
+    async fn create_protocol_mapper(
+        &self,
+        mapper: ProtocolMapper,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let model = to_active_model(mapper)?
+            .insert(&self.db)
+            .await
+            .map_err(map_write_error)?;
+
+        model.try_into()
+    }
+
+    async fn get_by_client_id(&self, client_id: Uuid) -> Result<Vec<ProtocolMapper>, CoreError> {
+        ProtocolMapperEntity::find()
+            .filter(Column::ClientId.eq(client_id))
+            .order_by_asc(Column::CreatedAt)
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(ProtocolMapper::try_from)
+            .collect()
+    }
+
+    async fn get_by_client_scope_ids(
+        &self,
+        client_scope_ids: &[Uuid],
+    ) -> Result<Vec<ProtocolMapper>, CoreError> {
+        if client_scope_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        ProtocolMapperEntity::find()
+            .filter(Column::ClientScopeId.is_in(client_scope_ids.to_vec()))
+            .order_by_asc(Column::CreatedAt)
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(ProtocolMapper::try_from)
+            .collect()
+    }
+
+    async fn get_by_id(&self, id: Uuid) -> Result<ProtocolMapper, CoreError> {
+        ProtocolMapperEntity::find_by_id(id)
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::NotFound)?
+            .try_into()
+    }
+
+    async fn update_protocol_mapper(
+        &self,
+        mapper: ProtocolMapper,
+    ) -> Result<ProtocolMapper, CoreError> {
+        let model = to_active_model(mapper)?
+            .update(&self.db)
+            .await
+            .map_err(|e| match e {
+                DbErr::RecordNotUpdated => CoreError::NotFound,
+                e => map_write_error(e),
+            })?;
+
+        model.try_into()
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), CoreError> {
+        ProtocolMapperEntity::delete_by_id(id)
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{TimeZone, Utc};
+
+use crate::{
+    domain::client_scope::entities::{ClientScope, ClientScopeDefaultType, UserConsent},
+    entity::{client_scopes, user_consents},
+};
+
+impl From<client_scopes::Model> for ClientScope {
+    fn from(model: client_scopes::Model) -> Self {
+        ClientScope {
+            id: model.id,
+            realm_id: model.realm_id,
+            name: model.name,
+            description: model.description,
+            include_in_token_scope: model.include_in_token_scope,
+            display_on_consent_screen: model.display_on_consent_screen,
+            consent_text: model.consent_text,
+            default_type: ClientScopeDefaultType::try_from(model.default_type).unwrap_or_default(),
+            created_at: Utc.from_utc_datetime(&model.created_at),
+            updated_at: Utc.from_utc_datetime(&model.updated_at),
+        }
+    }
+}
+
+impl From<user_consents::Model> for UserConsent {
+    fn from(model: user_consents::Model) -> Self {
+        UserConsent {
+            id: model.id,
+            user_id: model.user_id,
+            client_id: model.client_id,
+            scopes: serde_json::from_value(model.scopes).unwrap_or_default(),
+            created_at: Utc.from_utc_datetime(&model.created_at),
+            updated_at: Utc.from_utc_datetime(&model.updated_at),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod mapper;
+pub mod repositories;
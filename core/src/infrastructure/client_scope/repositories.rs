@@ -0,0 +1,130 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        client_scope::{
+            entities::{ClientScope, ClientScopeMapping, UserConsent},
+            ports::{ClientScopeRepository, UserConsentRepository},
+            value_objects::{CreateClientScopeRequest, UpdateClientScopeRequest},
+        },
+        common::entities::app_errors::CoreError,
+    },
+    infrastructure::client_scope::repositories::{
+        client_scope_postgres_repository::PostgresClientScopeRepository,
+        user_consent_postgres_repository::PostgresUserConsentRepository,
+    },
+};
+
+pub mod client_scope_postgres_repository;
+pub mod user_consent_postgres_repository;
+
+#[derive(Clone)]
+pub enum ClientScopeRepoAny {
+    Postgres(PostgresClientScopeRepository),
+}
+
+impl ClientScopeRepository for ClientScopeRepoAny {
+    async fn create_client_scope(
+        &self,
+        payload: CreateClientScopeRequest,
+    ) -> Result<ClientScope, CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => repo.create_client_scope(payload).await,
+        }
+    }
+
+    async fn get_by_id(&self, scope_id: Uuid) -> Result<Option<ClientScope>, CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => repo.get_by_id(scope_id).await,
+        }
+    }
+
+    async fn find_by_realm_id(&self, realm_id: Uuid) -> Result<Vec<ClientScope>, CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => repo.find_by_realm_id(realm_id).await,
+        }
+    }
+
+    async fn update_client_scope(
+        &self,
+        scope_id: Uuid,
+        payload: UpdateClientScopeRequest,
+    ) -> Result<ClientScope, CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => repo.update_client_scope(scope_id, payload).await,
+        }
+    }
+
+    async fn delete_client_scope(&self, scope_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => repo.delete_client_scope(scope_id).await,
+        }
+    }
+
+    async fn get_client_mappings(
+        &self,
+        client_id: Uuid,
+    ) -> Result<Vec<ClientScopeMapping>, CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => repo.get_client_mappings(client_id).await,
+        }
+    }
+
+    async fn assign_to_client(
+        &self,
+        client_id: Uuid,
+        scope_id: Uuid,
+        default_scope: bool,
+    ) -> Result<(), CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => {
+                repo.assign_to_client(client_id, scope_id, default_scope)
+                    .await
+            }
+        }
+    }
+
+    async fn remove_from_client(&self, client_id: Uuid, scope_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            ClientScopeRepoAny::Postgres(repo) => {
+                repo.remove_from_client(client_id, scope_id).await
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum UserConsentRepoAny {
+    Postgres(PostgresUserConsentRepository),
+}
+
+impl UserConsentRepository for UserConsentRepoAny {
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<UserConsent>, CoreError> {
+        match self {
+            UserConsentRepoAny::Postgres(repo) => repo.get_by_user_id(user_id).await,
+        }
+    }
+
+    async fn get(&self, user_id: Uuid, client_id: Uuid) -> Result<Option<UserConsent>, CoreError> {
+        match self {
+            UserConsentRepoAny::Postgres(repo) => repo.get(user_id, client_id).await,
+        }
+    }
+
+    async fn upsert(
+        &self,
+        user_id: Uuid,
+        client_id: Uuid,
+        scopes: Vec<String>,
+    ) -> Result<UserConsent, CoreError> {
+        match self {
+            UserConsentRepoAny::Postgres(repo) => repo.upsert(user_id, client_id, scopes).await,
+        }
+    }
+
+    async fn delete(&self, user_id: Uuid, client_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            UserConsentRepoAny::Postgres(repo) => repo.delete(user_id, client_id).await,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter, QueryOrder, SqlErr, sea_query::OnConflict,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        client_scope::{
+            entities::{ClientScope, ClientScopeMapping},
+            ports::ClientScopeRepository,
+            value_objects::{CreateClientScopeRequest, UpdateClientScopeRequest},
+        },
+        common::{entities::app_errors::CoreError, generate_uuid_v7},
+    },
+    entity::{client_scope_mappings, client_scopes},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresClientScopeRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresClientScopeRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn map_write_error(e: DbErr) -> CoreError {
+    match e.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => CoreError::AlreadyExists,
+        Some(SqlErr::ForeignKeyConstraintViolation(_)) => CoreError::NotFound,
+        _ => {
+            error!("client scope write failed: {:?}", e);
+            CoreError::InternalServerError
+        }
+    }
+}
+
+impl ClientScopeRepository for PostgresClientScopeRepository {
+    async fn create_client_scope(
+        &self,
+        payload: CreateClientScopeRequest,
+    ) -> Result<ClientScope, CoreError> {
+        let model = client_scopes::ActiveModel {
+            id: Set(generate_uuid_v7()),
+            realm_id: Set(payload.realm_id),
+            name: Set(payload.name),
+            description: Set(payload.description),
+            include_in_token_scope: Set(payload.include_in_token_scope),
+            display_on_consent_screen: Set(payload.display_on_consent_screen),
+            consent_text: Set(payload.consent_text),
+            default_type: Set(payload.default_type.to_string()),
+            created_at: Set(Utc::now().naive_utc()),
+            updated_at: Set(Utc::now().naive_utc()),
+        };
+
+        let client_scope = model.insert(&self.db).await.map_err(map_write_error)?;
+
+        Ok(client_scope.into())
+    }
+
+    async fn get_by_id(&self, scope_id: Uuid) -> Result<Option<ClientScope>, CoreError> {
+        let client_scope = client_scopes::Entity::find_by_id(scope_id)
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .map(ClientScope::from);
+
+        Ok(client_scope)
+    }
+
+    async fn find_by_realm_id(&self, realm_id: Uuid) -> Result<Vec<ClientScope>, CoreError> {
+        let client_scopes = client_scopes::Entity::find()
+            .filter(client_scopes::Column::RealmId.eq(realm_id))
+            .order_by_asc(client_scopes::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(ClientScope::from)
+            .collect();
+
+        Ok(client_scopes)
+    }
+
+    async fn update_client_scope(
+        &self,
+        scope_id: Uuid,
+        payload: UpdateClientScopeRequest,
+    ) -> Result<ClientScope, CoreError> {
+        let client_scope = client_scopes::Entity::find_by_id(scope_id)
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::NotFound)?;
+
+        let mut client_scope: client_scopes::ActiveModel = client_scope.into();
+
+        if let Some(name) = payload.name {
+            client_scope.name = Set(name);
+        }
+
+        if let Some(description) = payload.description {
+            client_scope.description = Set(Some(description));
+        }
+
+        if let Some(include_in_token_scope) = payload.include_in_token_scope {
+            client_scope.include_in_token_scope = Set(include_in_token_scope);
+        }
+
+        if let Some(display_on_consent_screen) = payload.display_on_consent_screen {
+            client_scope.display_on_consent_screen = Set(display_on_consent_screen);
+        }
+
+        if let Some(consent_text) = payload.consent_text {
+            client_scope.consent_text = Set(Some(consent_text));
+        }
+
+        if let Some(default_type) = payload.default_type {
+            client_scope.default_type = Set(default_type.to_string());
+        }
+
+        client_scope.updated_at = Set(Utc::now().naive_utc());
+
+        let client_scope = client_scope
+            .update(&self.db)
+            .await
+            .map_err(map_write_error)?;
+
+        Ok(client_scope.into())
+    }
+
+    async fn delete_client_scope(&self, scope_id: Uuid) -> Result<(), CoreError> {
+        let result = client_scopes::Entity::delete_by_id(scope_id)
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_client_mappings(
+        &self,
+        client_id: Uuid,
+    ) -> Result<Vec<ClientScopeMapping>, CoreError> {
+        let mappings = client_scope_mappings::Entity::find()
+            .filter(client_scope_mappings::Column::ClientId.eq(client_id))
+            .find_also_related(client_scopes::Entity)
+            .order_by_asc(client_scopes::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .filter_map(|(mapping, client_scope)| {
+                client_scope.map(|client_scope| ClientScopeMapping {
+                    client_scope: client_scope.into(),
+                    default_scope: mapping.default_scope,
+                })
+            })
+            .collect();
+
+        Ok(mappings)
+    }
+
+    async fn assign_to_client(
+        &self,
+        client_id: Uuid,
+        scope_id: Uuid,
+        default_scope: bool,
+    ) -> Result<(), CoreError> {
+        let mapping = client_scope_mappings::ActiveModel {
+            client_id: Set(client_id),
+            client_scope_id: Set(scope_id),
+            default_scope: Set(default_scope),
+        };
+
+        client_scope_mappings::Entity::insert(mapping)
+            .on_conflict(
+                OnConflict::columns([
+                    client_scope_mappings::Column::ClientId,
+                    client_scope_mappings::Column::ClientScopeId,
+                ])
+                .update_column(client_scope_mappings::Column::DefaultScope)
+                .to_owned(),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(map_write_error)?;
+
+        Ok(())
+    }
+
+    async fn remove_from_client(&self, client_id: Uuid, scope_id: Uuid) -> Result<(), CoreError> {
+        let result = client_scope_mappings::Entity::delete_many()
+            .filter(
+                Condition::all()
+                    .add(client_scope_mappings::Column::ClientId.eq(client_id))
+                    .add(client_scope_mappings::Column::ClientScopeId.eq(scope_id)),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+}
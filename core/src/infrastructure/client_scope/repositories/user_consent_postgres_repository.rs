@@ -0,0 +1,114 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, sea_query::OnConflict,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        client_scope::{entities::UserConsent, ports::UserConsentRepository},
+        common::{entities::app_errors::CoreError, generate_uuid_v7},
+    },
+    entity::user_consents,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresUserConsentRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresUserConsentRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl UserConsentRepository for PostgresUserConsentRepository {
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<UserConsent>, CoreError> {
+        let consents = user_consents::Entity::find()
+            .filter(user_consents::Column::UserId.eq(user_id))
+            .order_by_asc(user_consents::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(UserConsent::from)
+            .collect();
+
+        Ok(consents)
+    }
+
+    async fn get(&self, user_id: Uuid, client_id: Uuid) -> Result<Option<UserConsent>, CoreError> {
+        let consent = user_consents::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(user_consents::Column::UserId.eq(user_id))
+                    .add(user_consents::Column::ClientId.eq(client_id)),
+            )
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .map(UserConsent::from);
+
+        Ok(consent)
+    }
+
+    async fn upsert(
+        &self,
+        user_id: Uuid,
+        client_id: Uuid,
+        scopes: Vec<String>,
+    ) -> Result<UserConsent, CoreError> {
+        let scopes = serde_json::to_value(scopes).map_err(|_| CoreError::InternalServerError)?;
+
+        let consent = user_consents::ActiveModel {
+            id: Set(generate_uuid_v7()),
+            user_id: Set(user_id),
+            client_id: Set(client_id),
+            scopes: Set(scopes),
+            created_at: Set(Utc::now().naive_utc()),
+            updated_at: Set(Utc::now().naive_utc()),
+        };
+
+        let consent = user_consents::Entity::insert(consent)
+            .on_conflict(
+                OnConflict::columns([
+                    user_consents::Column::UserId,
+                    user_consents::Column::ClientId,
+                ])
+                .update_columns([
+                    user_consents::Column::Scopes,
+                    user_consents::Column::UpdatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("user consent write failed: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(consent.into())
+    }
+
+    async fn delete(&self, user_id: Uuid, client_id: Uuid) -> Result<(), CoreError> {
+        let result = user_consents::Entity::delete_many()
+            .filter(
+                Condition::all()
+                    .add(user_consents::Column::UserId.eq(user_id))
+                    .add(user_consents::Column::ClientId.eq(client_id)),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+}
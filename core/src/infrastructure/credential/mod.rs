@@ -39,6 +39,31 @@ impl CredentialRepository for CredentialRepoAny {
         }
     }
 
+    async fn archive_password_credential(&self, user_id: Uuid) -> Result<(), CredentialError> {
+        match self {
+            CredentialRepoAny::Postgres(repo) => repo.archive_password_credential(user_id).await,
+        }
+    }
+
+    async fn get_password_history(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<Credential>, CredentialError> {
+        match self {
+            CredentialRepoAny::Postgres(repo) => repo.get_password_history(user_id).await,
+        }
+    }
+
+    async fn prune_password_history(
+        &self,
+        user_id: Uuid,
+        keep: usize,
+    ) -> Result<(), CredentialError> {
+        match self {
+            CredentialRepoAny::Postgres(repo) => repo.prune_password_history(user_id, keep).await,
+        }
+    }
+
     async fn get_credentials_by_user_id(
         &self,
         user_id: Uuid,
@@ -0,0 +1,24 @@
+use chrono::{TimeZone, Utc};
+
+use crate::{
+    domain::email::entities::{SmtpEncryption, SmtpServer},
+    entity::smtp_servers,
+};
+
+impl From<smtp_servers::Model> for SmtpServer {
+    fn from(model: smtp_servers::Model) -> Self {
+        SmtpServer {
+            realm_id: model.realm_id,
+            host: model.host,
+            port: u16::try_from(model.port).unwrap_or_default(),
+            encryption: SmtpEncryption::try_from(model.encryption).unwrap_or_default(),
+            from_address: model.from_address,
+            from_display_name: model.from_display_name,
+            reply_to: model.reply_to,
+            username: model.username,
+            password: model.password,
+            created_at: Utc.from_utc_datetime(&model.created_at),
+            updated_at: Utc.from_utc_datetime(&model.updated_at),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod mapper;
+pub mod repositories;
+pub mod senders;
@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        email::{entities::SmtpServer, ports::SmtpServerRepository},
+    },
+    infrastructure::email::repositories::smtp_server_postgres_repository::PostgresSmtpServerRepository,
+};
+
+pub mod smtp_server_postgres_repository;
+
+#[derive(Clone)]
+pub enum SmtpServerRepoAny {
+    Postgres(PostgresSmtpServerRepository),
+}
+
+impl SmtpServerRepository for SmtpServerRepoAny {
+    async fn get_by_realm_id(&self, realm_id: Uuid) -> Result<Option<SmtpServer>, CoreError> {
+        match self {
+            SmtpServerRepoAny::Postgres(repo) => repo.get_by_realm_id(realm_id).await,
+        }
+    }
+
+    async fn save_smtp_server(&self, server: &SmtpServer) -> Result<SmtpServer, CoreError> {
+        match self {
+            SmtpServerRepoAny::Postgres(repo) => repo.save_smtp_server(server).await,
+        }
+    }
+
+    async fn delete_by_realm_id(&self, realm_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            SmtpServerRepoAny::Postgres(repo) => repo.delete_by_realm_id(realm_id).await,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use sea_orm::{ActiveValue::Set, DatabaseConnection, EntityTrait, sea_query::OnConflict};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        email::{entities::SmtpServer, ports::SmtpServerRepository},
+    },
+    entity::smtp_servers::{ActiveModel, Column, Entity},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresSmtpServerRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresSmtpServerRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl SmtpServerRepository for PostgresSmtpServerRepository {
+    async fn get_by_realm_id(&self, realm_id: Uuid) -> Result<Option<SmtpServer>, CoreError> {
+        let server = Entity::find_by_id(realm_id)
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .map(SmtpServer::from);
+
+        Ok(server)
+    }
+
+    async fn save_smtp_server(&self, server: &SmtpServer) -> Result<SmtpServer, CoreError> {
+        let model = ActiveModel {
+            realm_id: Set(server.realm_id),
+            host: Set(server.host.clone()),
+            port: Set(server.port.into()),
+            encryption: Set(server.encryption.to_string()),
+            from_address: Set(server.from_address.clone()),
+            from_display_name: Set(server.from_display_name.clone()),
+            reply_to: Set(server.reply_to.clone()),
+            username: Set(server.username.clone()),
+            password: Set(server.password.clone()),
+            created_at: Set(server.created_at.naive_utc()),
+            updated_at: Set(server.updated_at.naive_utc()),
+        };
+
+        let server = Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(Column::RealmId)
+                    .update_columns([
+                        Column::Host,
+                        Column::Port,
+                        Column::Encryption,
+                        Column::FromAddress,
+                        Column::FromDisplayName,
+                        Column::ReplyTo,
+                        Column::Username,
+                        Column::Password,
+                        Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to save SMTP server: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(server.into())
+    }
+
+    async fn delete_by_realm_id(&self, realm_id: Uuid) -> Result<(), CoreError> {
+        let result = Entity::delete_by_id(realm_id)
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use lettre::{
+    Message,
+    message::{Mailbox, MultiPart},
+};
+use tracing::error;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        email::{
+            entities::{EmailMessage, SmtpServer},
+            ports::EmailSender,
+        },
+    },
+    infrastructure::email::senders::{
+        file_email_sender::FileEmailSender, smtp_email_sender::SmtpEmailSender,
+    },
+};
+
+pub mod file_email_sender;
+pub mod smtp_email_sender;
+
+#[derive(Clone)]
+pub enum EmailSenderAny {
+    Smtp(SmtpEmailSender),
+    File(FileEmailSender),
+}
+
+impl EmailSender for EmailSenderAny {
+    async fn send(
+        &self,
+        server: Option<SmtpServer>,
+        message: EmailMessage,
+    ) -> Result<(), CoreError> {
+        match self {
+            EmailSenderAny::Smtp(sender) => sender.send(server, message).await,
+            EmailSenderAny::File(sender) => sender.send(server, message).await,
+        }
+    }
+}
+
+fn parse_mailbox(address: &str) -> Result<Mailbox, CoreError> {
+    address.parse().map_err(|e| {
+        error!("invalid email address {address}: {:?}", e);
+        CoreError::EmailDeliveryFailed(format!("invalid email address: {address}"))
+    })
+}
+
+/// Builds the MIME message, with the text and HTML bodies as alternatives.
+pub(crate) fn build_message(message: EmailMessage) -> Result<Message, CoreError> {
+    let mut builder = Message::builder()
+        .from(parse_mailbox(&message.from)?)
+        .to(parse_mailbox(&message.to)?)
+        .subject(message.subject);
+
+    if let Some(reply_to) = &message.reply_to {
+        builder = builder.reply_to(parse_mailbox(reply_to)?);
+    }
+
+    builder
+        .multipart(MultiPart::alternative_plain_html(
+            message.text_body,
+            message.html_body,
+        ))
+        .map_err(|e| {
+            error!("failed to build email: {:?}", e);
+            CoreError::InternalServerError
+        })
+}
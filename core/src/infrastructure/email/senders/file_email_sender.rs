@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use chrono::Utc;
+use tracing::{error, info};
+
+use crate::domain::{
+    common::{entities::app_errors::CoreError, generate_uuid_v7},
+    email::{
+        entities::{EmailMessage, SmtpServer},
+        ports::EmailSender,
+    },
+};
+use crate::infrastructure::email::senders::build_message;
+
+/// Logs emails instead of delivering them, and writes them as `.eml` files
+/// when a directory is set. Meant for development and tests.
+#[derive(Debug, Clone, Default)]
+pub struct FileEmailSender {
+    pub directory: Option<PathBuf>,
+}
+
+impl FileEmailSender {
+    pub fn new(directory: Option<PathBuf>) -> Self {
+        Self { directory }
+    }
+}
+
+impl EmailSender for FileEmailSender {
+    async fn send(
+        &self,
+        _server: Option<SmtpServer>,
+        message: EmailMessage,
+    ) -> Result<(), CoreError> {
+        info!(
+            to = %message.to,
+            subject = %message.subject,
+            "email not delivered, file transport enabled:\n{}",
+            message.text_body
+        );
+
+        let Some(directory) = &self.directory else {
+            return Ok(());
+        };
+
+        let path = directory.join(format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%dT%H%M%S"),
+            generate_uuid_v7()
+        ));
+        let formatted = build_message(message)?.formatted();
+
+        std::fs::create_dir_all(directory)
+            .and_then(|_| std::fs::write(&path, formatted))
+            .map_err(|e| {
+                error!("failed to write email to {}: {:?}", path.display(), e);
+                CoreError::EmailDeliveryFailed(e.to_string())
+            })
+    }
+}
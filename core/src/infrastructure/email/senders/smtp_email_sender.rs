@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+    transport::smtp::authentication::Credentials,
+};
+use tracing::error;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        email::{
+            entities::{EmailMessage, SmtpEncryption, SmtpServer},
+            ports::EmailSender,
+        },
+    },
+    infrastructure::email::senders::build_message,
+};
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delivers emails through the SMTP server configured on the realm.
+#[derive(Debug, Clone, Default)]
+pub struct SmtpEmailSender;
+
+impl SmtpEmailSender {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn transport(server: &SmtpServer) -> Result<AsyncSmtpTransport<Tokio1Executor>, CoreError> {
+        let builder = match server.encryption {
+            SmtpEncryption::None => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&server.host)
+            }
+            SmtpEncryption::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&server.host)
+                    .map_err(|e| CoreError::EmailDeliveryFailed(e.to_string()))?
+            }
+            SmtpEncryption::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&server.host)
+                .map_err(|e| CoreError::EmailDeliveryFailed(e.to_string()))?,
+        };
+
+        let builder = builder.port(server.port).timeout(Some(SMTP_TIMEOUT));
+
+        let builder = match (&server.username, &server.password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.clone(), password.clone()))
+            }
+            _ => builder,
+        };
+
+        Ok(builder.build())
+    }
+}
+
+impl EmailSender for SmtpEmailSender {
+    async fn send(
+        &self,
+        server: Option<SmtpServer>,
+        message: EmailMessage,
+    ) -> Result<(), CoreError> {
+        let server = server.ok_or(CoreError::EmailNotConfigured)?;
+        let transport = Self::transport(&server)?;
+
+        transport.send(build_message(message)?).await.map_err(|e| {
+            error!("failed to send email through {}: {:?}", server.host, e);
+            CoreError::EmailDeliveryFailed(e.to_string())
+        })?;
+
+        Ok(())
+    }
+}
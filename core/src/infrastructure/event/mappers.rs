@@ -0,0 +1,54 @@
+use chrono::{TimeZone, Utc};
+
+use crate::{
+    domain::event::entities::{AdminEvent, EventActor, LoginEvent},
+    entity::{admin_events, login_events},
+};
+
+impl TryFrom<login_events::Model> for LoginEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(model: login_events::Model) -> Result<Self, Self::Error> {
+        let event_type = model.event_type.try_into().map_err(anyhow::Error::msg)?;
+        let required_action = model
+            .required_action
+            .map(|action| action.try_into())
+            .transpose()?;
+
+        Ok(Self {
+            id: model.id,
+            realm_id: model.realm_id,
+            event_type,
+            user_id: model.user_id,
+            username: model.username,
+            client_id: model.client_id,
+            ip_address: model.ip_address,
+            user_agent: model.user_agent,
+            error: model.error,
+            required_action,
+            created_at: Utc.from_utc_datetime(&model.created_at),
+        })
+    }
+}
+
+impl TryFrom<admin_events::Model> for AdminEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(model: admin_events::Model) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: model.id,
+            realm_id: model.realm_id,
+            resource_type: model.resource_type.try_into().map_err(anyhow::Error::msg)?,
+            resource_id: model.resource_id,
+            operation: model.operation.try_into().map_err(anyhow::Error::msg)?,
+            actor: EventActor {
+                actor_type: model.actor_type.try_into().map_err(anyhow::Error::msg)?,
+                id: model.actor_id,
+                realm_id: model.actor_realm_id,
+                name: model.actor_name,
+            },
+            representation: model.representation,
+            created_at: Utc.from_utc_datetime(&model.created_at),
+        })
+    }
+}
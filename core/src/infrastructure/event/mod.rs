@@ -0,0 +1,2 @@
+pub mod mappers;
+pub mod repositories;
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        event::{
+            entities::{
+                AdminEvent, AdminEventPage, AdminEventQuery, LoginEvent, LoginEventPage,
+                LoginEventQuery,
+            },
+            ports::EventRepository,
+        },
+    },
+    infrastructure::event::repositories::event_postgres_repository::PostgresEventRepository,
+};
+
+pub mod event_postgres_repository;
+
+#[derive(Clone)]
+pub enum EventRepoAny {
+    Postgres(PostgresEventRepository),
+}
+
+impl EventRepository for EventRepoAny {
+    async fn create_login_event(&self, event: LoginEvent) -> Result<(), CoreError> {
+        match self {
+            EventRepoAny::Postgres(repo) => repo.create_login_event(event).await,
+        }
+    }
+
+    async fn create_admin_event(&self, event: AdminEvent) -> Result<(), CoreError> {
+        match self {
+            EventRepoAny::Postgres(repo) => repo.create_admin_event(event).await,
+        }
+    }
+
+    async fn search_login_events(
+        &self,
+        realm_id: Uuid,
+        query: LoginEventQuery,
+    ) -> Result<LoginEventPage, CoreError> {
+        match self {
+            EventRepoAny::Postgres(repo) => repo.search_login_events(realm_id, query).await,
+        }
+    }
+
+    async fn search_admin_events(
+        &self,
+        realm_id: Uuid,
+        query: AdminEventQuery,
+    ) -> Result<AdminEventPage, CoreError> {
+        match self {
+            EventRepoAny::Postgres(repo) => repo.search_admin_events(realm_id, query).await,
+        }
+    }
+
+    async fn delete_login_events(
+        &self,
+        realm_id: Uuid,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<u64, CoreError> {
+        match self {
+            EventRepoAny::Postgres(repo) => repo.delete_login_events(realm_id, before).await,
+        }
+    }
+
+    async fn delete_admin_events(
+        &self,
+        realm_id: Uuid,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<u64, CoreError> {
+        match self {
+            EventRepoAny::Postgres(repo) => repo.delete_admin_events(realm_id, before).await,
+        }
+    }
+}
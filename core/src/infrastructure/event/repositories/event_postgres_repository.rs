@@ -0,0 +1,263 @@
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        event::{
+            entities::{
+                AdminEvent, AdminEventPage, AdminEventQuery, LoginEvent, LoginEventPage,
+                LoginEventQuery,
+            },
+            ports::EventRepository,
+        },
+    },
+    entity::{admin_events, login_events},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresEventRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresEventRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl EventRepository for PostgresEventRepository {
+    async fn create_login_event(&self, event: LoginEvent) -> Result<(), CoreError> {
+        login_events::ActiveModel {
+            id: Set(event.id),
+            realm_id: Set(event.realm_id),
+            event_type: Set(event.event_type.to_string()),
+            user_id: Set(event.user_id),
+            username: Set(event.username),
+            client_id: Set(event.client_id),
+            ip_address: Set(event.ip_address),
+            user_agent: Set(event.user_agent),
+            error: Set(event.error),
+            required_action: Set(event.required_action.map(|action| action.to_string())),
+            created_at: Set(event.created_at.naive_utc()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(|e| {
+            error!("error recording login event: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(())
+    }
+
+    async fn create_admin_event(&self, event: AdminEvent) -> Result<(), CoreError> {
+        admin_events::ActiveModel {
+            id: Set(event.id),
+            realm_id: Set(event.realm_id),
+            resource_type: Set(event.resource_type.to_string()),
+            resource_id: Set(event.resource_id),
+            operation: Set(event.operation.to_string()),
+            actor_type: Set(event.actor.actor_type.to_string()),
+            actor_id: Set(event.actor.id),
+            actor_realm_id: Set(event.actor.realm_id),
+            actor_name: Set(event.actor.name),
+            representation: Set(event.representation),
+            created_at: Set(event.created_at.naive_utc()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(|e| {
+            error!("error recording admin event: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(())
+    }
+
+    async fn search_login_events(
+        &self,
+        realm_id: Uuid,
+        query: LoginEventQuery,
+    ) -> Result<LoginEventPage, CoreError> {
+        let mut condition = Condition::all().add(login_events::Column::RealmId.eq(realm_id));
+
+        if let Some(event_type) = query.event_type {
+            condition = condition.add(login_events::Column::EventType.eq(event_type.to_string()));
+        }
+
+        if let Some(user_id) = query.user_id {
+            condition = condition.add(login_events::Column::UserId.eq(user_id));
+        }
+
+        if let Some(client_id) = query.client_id {
+            condition = condition.add(login_events::Column::ClientId.eq(client_id));
+        }
+
+        if let Some(ip_address) = query.ip_address {
+            condition = condition.add(login_events::Column::IpAddress.eq(ip_address));
+        }
+
+        if let Some(from) = query.from {
+            condition = condition.add(login_events::Column::CreatedAt.gte(from.naive_utc()));
+        }
+
+        if let Some(to) = query.to {
+            condition = condition.add(login_events::Column::CreatedAt.lte(to.naive_utc()));
+        }
+
+        let select = login_events::Entity::find().filter(condition);
+
+        let total = select.clone().count(&self.db).await.map_err(|e| {
+            error!("error counting login events: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let events = select
+            .order_by_desc(login_events::Column::CreatedAt)
+            .order_by_desc(login_events::Column::Id)
+            .offset(query.offset)
+            .limit(query.limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error searching login events: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        let events = events
+            .into_iter()
+            .map(LoginEvent::try_from)
+            .collect::<Result<Vec<LoginEvent>, _>>()
+            .map_err(|e| {
+                error!("error mapping login events: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(LoginEventPage {
+            data: events,
+            total,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn search_admin_events(
+        &self,
+        realm_id: Uuid,
+        query: AdminEventQuery,
+    ) -> Result<AdminEventPage, CoreError> {
+        let mut condition = Condition::all().add(admin_events::Column::RealmId.eq(realm_id));
+
+        if let Some(operation) = query.operation {
+            condition = condition.add(admin_events::Column::Operation.eq(operation.to_string()));
+        }
+
+        if let Some(resource_type) = query.resource_type {
+            condition =
+                condition.add(admin_events::Column::ResourceType.eq(resource_type.to_string()));
+        }
+
+        if let Some(resource_id) = query.resource_id {
+            condition = condition.add(admin_events::Column::ResourceId.eq(resource_id));
+        }
+
+        if let Some(actor_id) = query.actor_id {
+            condition = condition.add(admin_events::Column::ActorId.eq(actor_id));
+        }
+
+        if let Some(from) = query.from {
+            condition = condition.add(admin_events::Column::CreatedAt.gte(from.naive_utc()));
+        }
+
+        if let Some(to) = query.to {
+            condition = condition.add(admin_events::Column::CreatedAt.lte(to.naive_utc()));
+        }
+
+        let select = admin_events::Entity::find().filter(condition);
+
+        let total = select.clone().count(&self.db).await.map_err(|e| {
+            error!("error counting admin events: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let events = select
+            .order_by_desc(admin_events::Column::CreatedAt)
+            .order_by_desc(admin_events::Column::Id)
+            .offset(query.offset)
+            .limit(query.limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error searching admin events: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        let events = events
+            .into_iter()
+            .map(AdminEvent::try_from)
+            .collect::<Result<Vec<AdminEvent>, _>>()
+            .map_err(|e| {
+                error!("error mapping admin events: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(AdminEventPage {
+            data: events,
+            total,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn delete_login_events(
+        &self,
+        realm_id: Uuid,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<u64, CoreError> {
+        let mut condition = Condition::all().add(login_events::Column::RealmId.eq(realm_id));
+
+        if let Some(before) = before {
+            condition = condition.add(login_events::Column::CreatedAt.lt(before.naive_utc()));
+        }
+
+        let result = login_events::Entity::delete_many()
+            .filter(condition)
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error deleting login events: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn delete_admin_events(
+        &self,
+        realm_id: Uuid,
+        before: Option<DateTime<Utc>>,
+    ) -> Result<u64, CoreError> {
+        let mut condition = Condition::all().add(admin_events::Column::RealmId.eq(realm_id));
+
+        if let Some(before) = before {
+            condition = condition.add(admin_events::Column::CreatedAt.lt(before.naive_utc()));
+        }
+
+        let result = admin_events::Entity::delete_many()
+            .filter(condition)
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error deleting admin events: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected)
+    }
+}
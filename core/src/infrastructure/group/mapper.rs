@@ -0,0 +1,18 @@
+use chrono::{TimeZone, Utc};
+
+use crate::{domain::group::entities::Group, entity::groups::Model};
+
+impl From<Model> for Group {
+    fn from(model: Model) -> Self {
+        Group {
+            id: model.id,
+            realm_id: model.realm_id,
+            parent_id: model.parent_id,
+            name: model.name,
+            description: model.description,
+            attributes: serde_json::from_value(model.attributes).unwrap_or_default(),
+            created_at: Utc.from_utc_datetime(&model.created_at),
+            updated_at: Utc.from_utc_datetime(&model.updated_at),
+        }
+    }
+}
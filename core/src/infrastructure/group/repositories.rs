@@ -0,0 +1,112 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::entities::app_errors::CoreError,
+        group::{
+            entities::Group,
+            ports::GroupRepository,
+            value_objects::{CreateGroupRequest, UpdateGroupRequest},
+        },
+        role::entities::Role,
+        user::entities::User,
+    },
+    infrastructure::group::repositories::group_postgres_repository::PostgresGroupRepository,
+};
+
+pub mod group_postgres_repository;
+
+#[derive(Clone)]
+pub enum GroupRepoAny {
+    Postgres(PostgresGroupRepository),
+}
+
+impl GroupRepository for GroupRepoAny {
+    async fn create_group(&self, payload: CreateGroupRequest) -> Result<Group, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.create_group(payload).await,
+        }
+    }
+
+    async fn get_by_id(&self, group_id: Uuid) -> Result<Option<Group>, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.get_by_id(group_id).await,
+        }
+    }
+
+    async fn find_by_realm_id(&self, realm_id: Uuid) -> Result<Vec<Group>, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.find_by_realm_id(realm_id).await,
+        }
+    }
+
+    async fn find_children(&self, group_id: Uuid) -> Result<Vec<Group>, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.find_children(group_id).await,
+        }
+    }
+
+    async fn update_group(
+        &self,
+        group_id: Uuid,
+        payload: UpdateGroupRequest,
+    ) -> Result<Group, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.update_group(group_id, payload).await,
+        }
+    }
+
+    async fn delete_group(&self, group_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.delete_group(group_id).await,
+        }
+    }
+
+    async fn add_member(&self, group_id: Uuid, user_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.add_member(group_id, user_id).await,
+        }
+    }
+
+    async fn remove_member(&self, group_id: Uuid, user_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.remove_member(group_id, user_id).await,
+        }
+    }
+
+    async fn get_members(&self, group_id: Uuid) -> Result<Vec<User>, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.get_members(group_id).await,
+        }
+    }
+
+    async fn get_user_groups(&self, user_id: Uuid) -> Result<Vec<Group>, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.get_user_groups(user_id).await,
+        }
+    }
+
+    async fn assign_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.assign_role(group_id, role_id).await,
+        }
+    }
+
+    async fn unassign_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.unassign_role(group_id, role_id).await,
+        }
+    }
+
+    async fn get_group_roles(&self, group_id: Uuid) -> Result<Vec<Role>, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.get_group_roles(group_id).await,
+        }
+    }
+
+    async fn get_inherited_roles(&self, user_id: Uuid) -> Result<Vec<Role>, CoreError> {
+        match self {
+            GroupRepoAny::Postgres(repo) => repo.get_inherited_roles(user_id).await,
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use chrono::Utc;
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue::Set,
+    ColumnTrait, Condition, DatabaseConnection, DbErr, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait, SqlErr, Value,
+    sea_query::{Expr, SimpleExpr},
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        common::{entities::app_errors::CoreError, generate_uuid_v7},
+        group::{
+            entities::Group,
+            ports::GroupRepository,
+            value_objects::{CreateGroupRequest, UpdateGroupRequest},
+        },
+        role::entities::Role,
+        user::entities::User,
+    },
+    entity::{group_members, group_roles, groups},
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresGroupRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresGroupRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn map_write_error(e: DbErr) -> CoreError {
+    match e.sql_err() {
+        Some(SqlErr::UniqueConstraintViolation(_)) => CoreError::AlreadyExists,
+        Some(SqlErr::ForeignKeyConstraintViolation(_)) => CoreError::NotFound,
+        _ => {
+            error!("group write failed: {:?}", e);
+            CoreError::InternalServerError
+        }
+    }
+}
+
+impl GroupRepository for PostgresGroupRepository {
+    async fn create_group(&self, payload: CreateGroupRequest) -> Result<Group, CoreError> {
+        let attributes =
+            serde_json::to_value(payload.attributes).map_err(|_| CoreError::InternalServerError)?;
+
+        let model = groups::ActiveModel {
+            id: Set(generate_uuid_v7()),
+            realm_id: Set(payload.realm_id),
+            parent_id: Set(payload.parent_id),
+            name: Set(payload.name),
+            description: Set(payload.description),
+            attributes: Set(attributes),
+            created_at: Set(Utc::now().naive_utc()),
+            updated_at: Set(Utc::now().naive_utc()),
+        };
+
+        let group = model.insert(&self.db).await.map_err(map_write_error)?;
+
+        Ok(group.into())
+    }
+
+    async fn get_by_id(&self, group_id: Uuid) -> Result<Option<Group>, CoreError> {
+        let group = groups::Entity::find_by_id(group_id)
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .map(Group::from);
+
+        Ok(group)
+    }
+
+    async fn find_by_realm_id(&self, realm_id: Uuid) -> Result<Vec<Group>, CoreError> {
+        let groups = groups::Entity::find()
+            .filter(groups::Column::RealmId.eq(realm_id))
+            .order_by_asc(groups::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(Group::from)
+            .collect();
+
+        Ok(groups)
+    }
+
+    async fn find_children(&self, group_id: Uuid) -> Result<Vec<Group>, CoreError> {
+        let groups = groups::Entity::find()
+            .filter(groups::Column::ParentId.eq(group_id))
+            .order_by_asc(groups::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(Group::from)
+            .collect();
+
+        Ok(groups)
+    }
+
+    async fn update_group(
+        &self,
+        group_id: Uuid,
+        payload: UpdateGroupRequest,
+    ) -> Result<Group, CoreError> {
+        let group = groups::Entity::find_by_id(group_id)
+            .one(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .ok_or(CoreError::NotFound)?;
+
+        let mut group: groups::ActiveModel = group.into();
+
+        if let Some(name) = payload.name {
+            group.name = Set(name);
+        }
+
+        if let Some(description) = payload.description {
+            group.description = Set(Some(description));
+        }
+
+        if let Some(attributes) = payload.attributes {
+            group.attributes =
+                Set(serde_json::to_value(attributes).map_err(|_| CoreError::InternalServerError)?);
+        }
+
+        group.updated_at = Set(Utc::now().naive_utc());
+
+        let group = group.update(&self.db).await.map_err(map_write_error)?;
+
+        Ok(group.into())
+    }
+
+    async fn delete_group(&self, group_id: Uuid) -> Result<(), CoreError> {
+        let result = groups::Entity::delete_by_id(group_id)
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn add_member(&self, group_id: Uuid, user_id: Uuid) -> Result<(), CoreError> {
+        let member = group_members::ActiveModel {
+            group_id: Set(group_id),
+            user_id: Set(user_id),
+            created_at: Set(Utc::now().naive_utc()),
+        };
+
+        member.insert(&self.db).await.map_err(map_write_error)?;
+
+        Ok(())
+    }
+
+    async fn remove_member(&self, group_id: Uuid, user_id: Uuid) -> Result<(), CoreError> {
+        let result = group_members::Entity::delete_many()
+            .filter(
+                Condition::all()
+                    .add(group_members::Column::GroupId.eq(group_id))
+                    .add(group_members::Column::UserId.eq(user_id)),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_members(&self, group_id: Uuid) -> Result<Vec<User>, CoreError> {
+        let users = crate::entity::users::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                group_members::Relation::Users.def().rev(),
+            )
+            .filter(group_members::Column::GroupId.eq(group_id))
+            .order_by_asc(crate::entity::users::Column::Username)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(User::from)
+            .collect();
+
+        Ok(users)
+    }
+
+    async fn get_user_groups(&self, user_id: Uuid) -> Result<Vec<Group>, CoreError> {
+        let groups = groups::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                group_members::Relation::Groups.def().rev(),
+            )
+            .filter(group_members::Column::UserId.eq(user_id))
+            .order_by_asc(groups::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?
+            .into_iter()
+            .map(Group::from)
+            .collect();
+
+        Ok(groups)
+    }
+
+    async fn assign_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), CoreError> {
+        let group_role = group_roles::ActiveModel {
+            group_id: Set(group_id),
+            role_id: Set(role_id),
+        };
+
+        group_role.insert(&self.db).await.map_err(map_write_error)?;
+
+        Ok(())
+    }
+
+    async fn unassign_role(&self, group_id: Uuid, role_id: Uuid) -> Result<(), CoreError> {
+        let result = group_roles::Entity::delete_many()
+            .filter(
+                Condition::all()
+                    .add(group_roles::Column::GroupId.eq(group_id))
+                    .add(group_roles::Column::RoleId.eq(role_id)),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|_| CoreError::InternalServerError)?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_group_roles(&self, group_id: Uuid) -> Result<Vec<Role>, CoreError> {
+        self.roles_of_groups(group_roles::Column::GroupId.eq(group_id))
+            .await
+    }
+
+    async fn get_inherited_roles(&self, user_id: Uuid) -> Result<Vec<Role>, CoreError> {
+        // The groups of the user and their ancestors. UNION drops the groups
+        // already visited, so a corrupted hierarchy cannot loop forever.
+        let user_group_ids = Expr::cust_with_values(
+            r#"group_roles.group_id IN (
+                WITH RECURSIVE user_groups(id) AS (
+                    SELECT group_id FROM group_members WHERE user_id = $1
+                    UNION
+                    SELECT groups.parent_id
+                    FROM groups
+                    INNER JOIN user_groups ON groups.id = user_groups.id
+                    WHERE groups.parent_id IS NOT NULL
+                )
+                SELECT id FROM user_groups
+            )"#,
+            [Value::from(user_id)],
+        );
+
+        self.roles_of_groups(user_group_ids).await
+    }
+}
+
+impl PostgresGroupRepository {
+    /// Roles mapped to the groups matching `groups`, a condition on
+    /// `group_roles`.
+    async fn roles_of_groups(&self, groups: SimpleExpr) -> Result<Vec<Role>, CoreError> {
+        let roles = crate::entity::roles::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                group_roles::Relation::Roles.def().rev(),
+            )
+            .filter(groups)
+            .join(
+                JoinType::LeftJoin,
+                crate::entity::roles::Relation::Clients.def(),
+            )
+            .select_also(crate::entity::clients::Entity)
+            .distinct()
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error getting group roles: {:?}", e);
+                CoreError::InternalServerError
+            })?
+            .into_iter()
+            .map(|(model, client)| {
+                let mut role: Role = model.into();
+                if let Some(client) = client {
+                    role.client = Some(client.into());
+                }
+                role
+            })
+            .collect();
+
+        Ok(roles)
+    }
+}
@@ -1,6 +1,7 @@
-use crate::domain::jwt::entities::{JwtError, JwtKeyPair};
+use crate::domain::jwt::entities::{JwtError, JwtKeyPair, SigningAlgorithm};
 use crate::domain::jwt::ports::KeyStoreRepository;
 use crate::infrastructure::repositories::keystore_repository::PostgresKeyStoreRepository;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 #[derive(Clone)]
@@ -9,9 +10,67 @@ pub enum KeyStoreRepoAny {
 }
 
 impl KeyStoreRepository for KeyStoreRepoAny {
-    async fn get_or_generate_key(&self, realm_id: Uuid) -> Result<JwtKeyPair, JwtError> {
+    async fn get_or_generate_key(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+    ) -> Result<JwtKeyPair, JwtError> {
         match self {
-            KeyStoreRepoAny::Postgres(repo) => repo.get_or_generate_key(realm_id).await,
+            KeyStoreRepoAny::Postgres(repo) => repo.get_or_generate_key(realm_id, algorithm).await,
+        }
+    }
+
+    async fn get_key(&self, realm_id: Uuid, key_id: Uuid) -> Result<Option<JwtKeyPair>, JwtError> {
+        match self {
+            KeyStoreRepoAny::Postgres(repo) => repo.get_key(realm_id, key_id).await,
+        }
+    }
+
+    async fn list_keys(&self, realm_id: Uuid) -> Result<Vec<JwtKeyPair>, JwtError> {
+        match self {
+            KeyStoreRepoAny::Postgres(repo) => repo.list_keys(realm_id).await,
+        }
+    }
+
+    async fn rotate_key(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+    ) -> Result<JwtKeyPair, JwtError> {
+        match self {
+            KeyStoreRepoAny::Postgres(repo) => repo.rotate_key(realm_id, algorithm).await,
+        }
+    }
+
+    async fn rotate_key_created_before(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+        created_before: DateTime<Utc>,
+    ) -> Result<Option<JwtKeyPair>, JwtError> {
+        match self {
+            KeyStoreRepoAny::Postgres(repo) => {
+                repo.rotate_key_created_before(realm_id, algorithm, created_before)
+                    .await
+            }
+        }
+    }
+
+    async fn retire_key(&self, realm_id: Uuid, key_id: Uuid) -> Result<JwtKeyPair, JwtError> {
+        match self {
+            KeyStoreRepoAny::Postgres(repo) => repo.retire_key(realm_id, key_id).await,
+        }
+    }
+
+    async fn retire_passive_keys(
+        &self,
+        realm_id: Uuid,
+        rotated_before: DateTime<Utc>,
+    ) -> Result<(), JwtError> {
+        match self {
+            KeyStoreRepoAny::Postgres(repo) => {
+                repo.retire_passive_keys(realm_id, rotated_before).await
+            }
         }
     }
 }
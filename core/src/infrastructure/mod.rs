@@ -1,8 +1,14 @@
+pub mod action_token;
 pub mod auth_session;
+pub mod brute_force;
 pub mod client;
+pub mod client_scope;
 pub mod common;
 pub mod credential;
 pub mod db;
+pub mod email;
+pub mod event;
+pub mod group;
 pub mod hasher;
 pub mod health;
 pub mod jwt;
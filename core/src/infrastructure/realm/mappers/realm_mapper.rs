@@ -10,6 +10,7 @@ impl From<Model> for Realm {
         Realm {
             id: value.id,
             name: value.name,
+            default_role_id: value.default_role_id,
             created_at,
             updated_at,
         }
@@ -24,6 +25,7 @@ impl From<&Model> for Realm {
         Realm {
             id: model.id,
             name: model.name.clone(),
+            default_role_id: model.default_role_id,
             created_at,
             updated_at,
         }
@@ -10,6 +10,23 @@ impl From<Model> for RealmSetting {
             id: value.id,
             realm_id: value.realm_id,
             default_signing_algorithm: value.default_signing_algorithm,
+            access_token_lifespan: value.access_token_lifespan,
+            refresh_token_lifespan: value.refresh_token_lifespan,
+            session_lifespan: value.session_lifespan,
+            auth_code_lifespan: value.auth_code_lifespan,
+            refresh_token_rotation: value.refresh_token_rotation,
+            key_rotation_interval: value.key_rotation_interval,
+            key_retirement_delay: value.key_retirement_delay,
+            password_policy: serde_json::from_value(value.password_policy).unwrap_or_default(),
+            user_profile: serde_json::from_value(value.user_profile).unwrap_or_default(),
+            brute_force_protection: serde_json::from_value(value.brute_force_protection)
+                .unwrap_or_default(),
+            default_locale: value.default_locale,
+            registration_allowed: value.registration_allowed,
+            verify_email: value.verify_email,
+            webhook_failure_threshold: value.webhook_failure_threshold,
+            login_event_retention: value.login_event_retention,
+            admin_event_retention: value.admin_event_retention,
             updated_at,
         }
     }
@@ -10,6 +10,7 @@ use uuid::Uuid;
 use crate::domain::realm::{
     entities::{Realm, RealmSetting},
     ports::RealmRepository,
+    value_objects::UpdateRealmSettingRequest,
 };
 
 #[derive(Clone)]
@@ -48,6 +49,12 @@ impl RealmRepository for RealmRepoAny {
         }
     }
 
+    async fn set_default_role(&self, realm_id: Uuid, role_id: Uuid) -> Result<Realm, CoreError> {
+        match self {
+            Self::Postgres(r) => r.set_default_role(realm_id, role_id).await,
+        }
+    }
+
     async fn create_realm_settings(
         &self,
         realm_id: Uuid,
@@ -61,10 +68,10 @@ impl RealmRepository for RealmRepoAny {
     async fn update_realm_setting(
         &self,
         realm_id: Uuid,
-        algorithm: String,
+        data: UpdateRealmSettingRequest,
     ) -> Result<RealmSetting, CoreError> {
         match self {
-            Self::Postgres(r) => r.update_realm_setting(realm_id, algorithm).await,
+            Self::Postgres(r) => r.update_realm_setting(realm_id, data).await,
         }
     }
 
@@ -13,6 +13,7 @@ use uuid::Uuid;
 use crate::domain::realm::{
     entities::{Realm, RealmSetting},
     ports::RealmRepository,
+    value_objects::UpdateRealmSettingRequest,
 };
 
 #[derive(Debug, Clone)]
@@ -56,6 +57,7 @@ impl RealmRepository for PostgresRealmRepository {
         let new_realm = ActiveModel {
             id: Set(realm.id),
             name: Set(realm.name),
+            default_role_id: Set(realm.default_role_id),
             created_at: Set(realm.created_at.naive_utc()),
             updated_at: Set(realm.updated_at.naive_utc()),
         };
@@ -110,6 +112,22 @@ impl RealmRepository for PostgresRealmRepository {
         Ok(())
     }
 
+    async fn set_default_role(&self, realm_id: Uuid, role_id: Uuid) -> Result<Realm, CoreError> {
+        let realm = ActiveModel {
+            id: Set(realm_id),
+            default_role_id: Set(Some(role_id)),
+            updated_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        let realm = realm.update(&self.db).await.map_err(|e| {
+            tracing::error!("Failed to set realm default role: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        Ok(realm.into())
+    }
+
     async fn create_realm_settings(
         &self,
         realm_id: Uuid,
@@ -121,6 +139,27 @@ impl RealmRepository for PostgresRealmRepository {
             id: Set(realm_setting.id),
             realm_id: Set(realm_setting.realm_id),
             default_signing_algorithm: Set(realm_setting.default_signing_algorithm),
+            access_token_lifespan: Set(realm_setting.access_token_lifespan),
+            refresh_token_lifespan: Set(realm_setting.refresh_token_lifespan),
+            session_lifespan: Set(realm_setting.session_lifespan),
+            auth_code_lifespan: Set(realm_setting.auth_code_lifespan),
+            refresh_token_rotation: Set(realm_setting.refresh_token_rotation),
+            key_rotation_interval: Set(realm_setting.key_rotation_interval),
+            key_retirement_delay: Set(realm_setting.key_retirement_delay),
+            password_policy: Set(serde_json::to_value(&realm_setting.password_policy)
+                .map_err(|_| CoreError::InternalServerError)?),
+            brute_force_protection: Set(serde_json::to_value(
+                &realm_setting.brute_force_protection,
+            )
+            .map_err(|_| CoreError::InternalServerError)?),
+            user_profile: Set(serde_json::to_value(&realm_setting.user_profile)
+                .map_err(|_| CoreError::InternalServerError)?),
+            default_locale: Set(realm_setting.default_locale),
+            registration_allowed: Set(realm_setting.registration_allowed),
+            verify_email: Set(realm_setting.verify_email),
+            webhook_failure_threshold: Set(realm_setting.webhook_failure_threshold),
+            login_event_retention: Set(realm_setting.login_event_retention),
+            admin_event_retention: Set(realm_setting.admin_event_retention),
             updated_at: Set(realm_setting.updated_at.naive_utc()),
         };
 
@@ -139,7 +178,7 @@ impl RealmRepository for PostgresRealmRepository {
     async fn update_realm_setting(
         &self,
         realm_id: Uuid,
-        algorithm: String,
+        data: UpdateRealmSettingRequest,
     ) -> Result<RealmSetting, CoreError> {
         let realm_setting = crate::entity::realm_settings::Entity::find()
             .filter(crate::entity::realm_settings::Column::RealmId.eq(realm_id))
@@ -150,7 +189,80 @@ impl RealmRepository for PostgresRealmRepository {
 
         let mut realm_setting: crate::entity::realm_settings::ActiveModel = realm_setting.into();
 
-        realm_setting.default_signing_algorithm = Set(Some(algorithm));
+        if let Some(algorithm) = data.default_signing_algorithm {
+            realm_setting.default_signing_algorithm = Set(Some(algorithm));
+        }
+
+        if let Some(lifespan) = data.access_token_lifespan {
+            realm_setting.access_token_lifespan = Set(lifespan);
+        }
+
+        if let Some(lifespan) = data.refresh_token_lifespan {
+            realm_setting.refresh_token_lifespan = Set(lifespan);
+        }
+
+        if let Some(lifespan) = data.session_lifespan {
+            realm_setting.session_lifespan = Set(lifespan);
+        }
+
+        if let Some(lifespan) = data.auth_code_lifespan {
+            realm_setting.auth_code_lifespan = Set(lifespan);
+        }
+
+        if let Some(rotation) = data.refresh_token_rotation {
+            realm_setting.refresh_token_rotation = Set(rotation);
+        }
+
+        if let Some(interval) = data.key_rotation_interval {
+            realm_setting.key_rotation_interval = Set((interval > 0).then_some(interval));
+        }
+
+        if let Some(delay) = data.key_retirement_delay {
+            realm_setting.key_retirement_delay = Set(delay);
+        }
+
+        if let Some(password_policy) = data.password_policy {
+            realm_setting.password_policy = Set(serde_json::to_value(&password_policy)
+                .map_err(|_| CoreError::InternalServerError)?);
+        }
+
+        if let Some(brute_force_protection) = data.brute_force_protection {
+            realm_setting.brute_force_protection =
+                Set(serde_json::to_value(&brute_force_protection)
+                    .map_err(|_| CoreError::InternalServerError)?);
+        }
+
+        if let Some(user_profile) = data.user_profile {
+            realm_setting.user_profile =
+                Set(serde_json::to_value(&user_profile)
+                    .map_err(|_| CoreError::InternalServerError)?);
+        }
+
+        if let Some(default_locale) = data.default_locale {
+            realm_setting.default_locale = Set(default_locale);
+        }
+
+        if let Some(registration_allowed) = data.registration_allowed {
+            realm_setting.registration_allowed = Set(registration_allowed);
+        }
+
+        if let Some(verify_email) = data.verify_email {
+            realm_setting.verify_email = Set(verify_email);
+        }
+
+        if let Some(threshold) = data.webhook_failure_threshold {
+            realm_setting.webhook_failure_threshold = Set(threshold);
+        }
+
+        if let Some(retention) = data.login_event_retention {
+            realm_setting.login_event_retention = Set(retention);
+        }
+
+        if let Some(retention) = data.admin_event_retention {
+            realm_setting.admin_event_retention = Set(retention);
+        }
+
+        realm_setting.updated_at = Set(Utc::now().naive_utc());
 
         let realm_setting = realm_setting
             .update(&self.db)
@@ -14,10 +14,19 @@ impl RefreshTokenRepository for RefreshTokenRepoAny {
         &self,
         jti: Uuid,
         user_id: Uuid,
+        client_id: Option<String>,
+        family_id: Option<Uuid>,
+        auth_time: Option<DateTime<Utc>>,
+        amr: Vec<String>,
         expires_at: Option<DateTime<Utc>>,
     ) -> Result<RefreshToken, JwtError> {
         match self {
-            RefreshTokenRepoAny::Postgres(repo) => repo.create(jti, user_id, expires_at).await,
+            RefreshTokenRepoAny::Postgres(repo) => {
+                repo.create(
+                    jti, user_id, client_id, family_id, auth_time, amr, expires_at,
+                )
+                .await
+            }
         }
     }
 
@@ -32,4 +41,40 @@ impl RefreshTokenRepository for RefreshTokenRepoAny {
             RefreshTokenRepoAny::Postgres(repo) => repo.delete(jti).await,
         }
     }
+
+    async fn revoke(&self, jti: Uuid) -> Result<bool, JwtError> {
+        match self {
+            RefreshTokenRepoAny::Postgres(repo) => repo.revoke(jti).await,
+        }
+    }
+
+    async fn rotate(&self, jti: Uuid) -> Result<bool, JwtError> {
+        match self {
+            RefreshTokenRepoAny::Postgres(repo) => repo.rotate(jti).await,
+        }
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), JwtError> {
+        match self {
+            RefreshTokenRepoAny::Postgres(repo) => repo.revoke_family(family_id).await,
+        }
+    }
+
+    async fn revoke_by_user_and_client(
+        &self,
+        user_id: Uuid,
+        client_id: String,
+    ) -> Result<(), JwtError> {
+        match self {
+            RefreshTokenRepoAny::Postgres(repo) => {
+                repo.revoke_by_user_and_client(user_id, client_id).await
+            }
+        }
+    }
+
+    async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<(), JwtError> {
+        match self {
+            RefreshTokenRepoAny::Postgres(repo) => repo.revoke_by_user_id(user_id).await,
+        }
+    }
 }
@@ -1,10 +1,28 @@
-use crate::domain::common::AppConfig;
+use crate::domain::common::{AppConfig, EmailConfig};
+use crate::infrastructure::action_token::repositories::UsedActionTokenRepoAny;
+use crate::infrastructure::action_token::repositories::used_action_token_postgres_repository::PostgresUsedActionTokenRepository;
 use crate::infrastructure::auth_session::AuthSessionRepoAny;
+use crate::infrastructure::brute_force::LoginFailureRepoAny;
 use crate::infrastructure::client::repositories::client_postgres_repository::PostgresClientRepository;
+use crate::infrastructure::client::repositories::protocol_mapper_postgres_repository::PostgresProtocolMapperRepository;
 use crate::infrastructure::client::repositories::redirect_uri_postgres_repository::PostgresRedirectUriRepository;
-use crate::infrastructure::client::repositories::{ClientRepoAny, RedirectUriRepoAny};
+use crate::infrastructure::client::repositories::{
+    ClientRepoAny, ProtocolMapperRepoAny, RedirectUriRepoAny,
+};
+use crate::infrastructure::client_scope::repositories::client_scope_postgres_repository::PostgresClientScopeRepository;
+use crate::infrastructure::client_scope::repositories::user_consent_postgres_repository::PostgresUserConsentRepository;
+use crate::infrastructure::client_scope::repositories::{ClientScopeRepoAny, UserConsentRepoAny};
 use crate::infrastructure::credential::CredentialRepoAny;
 use crate::infrastructure::db::postgres::{Postgres, PostgresConfig};
+use crate::infrastructure::email::repositories::SmtpServerRepoAny;
+use crate::infrastructure::email::repositories::smtp_server_postgres_repository::PostgresSmtpServerRepository;
+use crate::infrastructure::email::senders::EmailSenderAny;
+use crate::infrastructure::email::senders::file_email_sender::FileEmailSender;
+use crate::infrastructure::email::senders::smtp_email_sender::SmtpEmailSender;
+use crate::infrastructure::event::repositories::EventRepoAny;
+use crate::infrastructure::event::repositories::event_postgres_repository::PostgresEventRepository;
+use crate::infrastructure::group::repositories::GroupRepoAny;
+use crate::infrastructure::group::repositories::group_postgres_repository::PostgresGroupRepository;
 use crate::infrastructure::hasher::HasherRepoAny;
 use crate::infrastructure::health::HealthCheckRepoAny;
 use crate::infrastructure::health::repositories::PostgresHealthCheckRepository;
@@ -17,6 +35,7 @@ use crate::infrastructure::repositories::argon2_hasher::Argon2HasherRepository;
 use crate::infrastructure::repositories::auth_session_repository::PostgresAuthSessionRepository;
 use crate::infrastructure::repositories::credential_repository::PostgresCredentialRepository;
 use crate::infrastructure::repositories::keystore_repository::PostgresKeyStoreRepository;
+use crate::infrastructure::repositories::login_failure_repository::PostgresLoginFailureRepository;
 use crate::infrastructure::repositories::random_bytes_recovery_code::RandBytesRecoveryCodeRepository;
 use crate::infrastructure::repositories::refresh_token_repository::PostgresRefreshTokenRepository;
 use crate::infrastructure::role::repositories::RoleRepoAny;
@@ -29,6 +48,9 @@ use crate::infrastructure::user::repositories::user_role_repository::{
     PostgresUserRoleRepository, UserRoleRepoAny,
 };
 use crate::infrastructure::user::repository::PostgresUserRepository;
+use crate::infrastructure::webhook::repositories::webhook_delivery_repository::{
+    PostgresWebhookDeliveryRepository, WebhookDeliveryRepoAny,
+};
 use crate::infrastructure::webhook::repositories::webhook_notifier_repository::{
     PostgresWebhookNotifierRepository, WebhookNotifierRepoAny,
 };
@@ -40,6 +62,7 @@ pub mod argon2_hasher;
 pub mod auth_session_repository;
 pub mod credential_repository;
 pub mod keystore_repository;
+pub mod login_failure_repository;
 pub mod random_bytes_recovery_code;
 pub mod refresh_token_repository;
 
@@ -60,6 +83,16 @@ pub struct RepoBundle {
     pub health_check_repository: HealthCheckRepoAny,
     pub webhook_repository: WebhookRepoAny,
     pub webhook_notifier_repository: WebhookNotifierRepoAny,
+    pub webhook_delivery_repository: WebhookDeliveryRepoAny,
+    pub login_failure_repository: LoginFailureRepoAny,
+    pub group_repository: GroupRepoAny,
+    pub protocol_mapper_repository: ProtocolMapperRepoAny,
+    pub client_scope_repository: ClientScopeRepoAny,
+    pub user_consent_repository: UserConsentRepoAny,
+    pub smtp_server_repository: SmtpServerRepoAny,
+    pub email_sender: EmailSenderAny,
+    pub used_action_token_repository: UsedActionTokenRepoAny,
+    pub event_repository: EventRepoAny,
 }
 
 pub async fn build_repos_from_env(cfg: AppConfig) -> Result<RepoBundle, anyhow::Error> {
@@ -97,8 +130,29 @@ pub async fn build_repos_from_env(cfg: AppConfig) -> Result<RepoBundle, anyhow::
 
     let webhook_repository =
         WebhookRepoAny::Postgres(PostgresWebhookRepository::new(postgres.get_db()));
-    let webhook_notifier_repository =
-        WebhookNotifierRepoAny::Postgres(PostgresWebhookNotifierRepository::new());
+    let webhook_delivery_repository = PostgresWebhookDeliveryRepository::new(postgres.get_db());
+    let webhook_notifier_repository = WebhookNotifierRepoAny::Postgres(
+        PostgresWebhookNotifierRepository::new(webhook_delivery_repository.clone())?,
+    );
+    let webhook_delivery_repository = WebhookDeliveryRepoAny::Postgres(webhook_delivery_repository);
+    let login_failure_repository =
+        LoginFailureRepoAny::Postgres(PostgresLoginFailureRepository::new(postgres.get_db()));
+    let group_repository = GroupRepoAny::Postgres(PostgresGroupRepository::new(postgres.get_db()));
+    let protocol_mapper_repository =
+        ProtocolMapperRepoAny::Postgres(PostgresProtocolMapperRepository::new(postgres.get_db()));
+    let client_scope_repository =
+        ClientScopeRepoAny::Postgres(PostgresClientScopeRepository::new(postgres.get_db()));
+    let user_consent_repository =
+        UserConsentRepoAny::Postgres(PostgresUserConsentRepository::new(postgres.get_db()));
+    let smtp_server_repository =
+        SmtpServerRepoAny::Postgres(PostgresSmtpServerRepository::new(postgres.get_db()));
+    let email_sender = match cfg.email {
+        EmailConfig::Smtp => EmailSenderAny::Smtp(SmtpEmailSender::new()),
+        EmailConfig::File { directory } => EmailSenderAny::File(FileEmailSender::new(directory)),
+    };
+    let used_action_token_repository =
+        UsedActionTokenRepoAny::Postgres(PostgresUsedActionTokenRepository::new(postgres.get_db()));
+    let event_repository = EventRepoAny::Postgres(PostgresEventRepository::new(postgres.get_db()));
 
     Ok(RepoBundle {
         realm_repository,
@@ -117,5 +171,15 @@ pub async fn build_repos_from_env(cfg: AppConfig) -> Result<RepoBundle, anyhow::
         health_check_repository,
         webhook_repository,
         webhook_notifier_repository,
+        webhook_delivery_repository,
+        login_failure_repository,
+        group_repository,
+        protocol_mapper_repository,
+        client_scope_repository,
+        user_consent_repository,
+        smtp_server_repository,
+        email_sender,
+        used_action_token_repository,
+        event_repository,
     })
 }
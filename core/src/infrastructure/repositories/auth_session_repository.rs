@@ -30,6 +30,15 @@ impl From<crate::entity::auth_sessions::Model> for AuthSession {
             user_id: model.user_id,
             created_at,
             expires_at,
+            code_challenge: model.code_challenge,
+            code_challenge_method: model
+                .code_challenge_method
+                .and_then(|method| method.try_into().ok()),
+            authenticated_at: model.authenticated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            amr: model
+                .amr
+                .map(|amr| amr.split_whitespace().map(String::from).collect())
+                .unwrap_or_default(),
         }
     }
 }
@@ -61,6 +70,13 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
             user_id: Set(None),
             created_at: Set(session.created_at.naive_utc()),
             expires_at: Set(session.expires_at.naive_utc()),
+            code_challenge: Set(session.code_challenge.clone()),
+            code_challenge_method: Set(session
+                .code_challenge_method
+                .as_ref()
+                .map(|method| method.to_string())),
+            authenticated_at: Set(None),
+            amr: Set(None),
         };
 
         let t = model
@@ -81,6 +97,7 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
     ) -> Result<AuthSession, AuthenticationError> {
         let session = crate::entity::auth_sessions::Entity::find()
             .filter(crate::entity::auth_sessions::Column::Id.eq(session_code))
+            .filter(crate::entity::auth_sessions::Column::ExpiresAt.gt(Utc::now().naive_utc()))
             .one(&self.db)
             .await
             .map_err(|e| {
@@ -96,6 +113,7 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
     async fn get_by_code(&self, code: String) -> Result<Option<AuthSession>, AuthenticationError> {
         let session = crate::entity::auth_sessions::Entity::find()
             .filter(crate::entity::auth_sessions::Column::Code.eq(code))
+            .filter(crate::entity::auth_sessions::Column::ExpiresAt.gt(Utc::now().naive_utc()))
             .one(&self.db)
             .await
             .map_err(|e| {
@@ -113,6 +131,7 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
         session_code: Uuid,
         code: String,
         user_id: Uuid,
+        amr: Vec<String>,
     ) -> Result<AuthSession, AuthenticationError> {
         let session = crate::entity::auth_sessions::Entity::update_many()
             .col_expr(
@@ -123,6 +142,18 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
                 crate::entity::auth_sessions::Column::UserId,
                 Expr::value(user_id),
             )
+            .col_expr(
+                crate::entity::auth_sessions::Column::Authenticated,
+                Expr::value(true),
+            )
+            .col_expr(
+                crate::entity::auth_sessions::Column::AuthenticatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .col_expr(
+                crate::entity::auth_sessions::Column::Amr,
+                Expr::value(amr.join(" ")),
+            )
             .filter(crate::entity::auth_sessions::Column::Id.eq(session_code))
             .exec_with_returning(&self.db)
             .await
@@ -137,4 +168,51 @@ impl AuthSessionRepository for PostgresAuthSessionRepository {
 
         Ok(session)
     }
+
+    async fn mark_authenticated(
+        &self,
+        session_code: Uuid,
+        user_id: Uuid,
+        amr: Vec<String>,
+    ) -> Result<AuthSession, AuthenticationError> {
+        let session = crate::entity::auth_sessions::Entity::update_many()
+            .col_expr(
+                crate::entity::auth_sessions::Column::UserId,
+                Expr::value(user_id),
+            )
+            .col_expr(
+                crate::entity::auth_sessions::Column::Authenticated,
+                Expr::value(true),
+            )
+            .col_expr(
+                crate::entity::auth_sessions::Column::Amr,
+                Expr::value(amr.join(" ")),
+            )
+            .filter(crate::entity::auth_sessions::Column::Id.eq(session_code))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Error updating session: {:?}", e);
+                AuthenticationError::Invalid
+            })?
+            .into_iter()
+            .next()
+            .ok_or(AuthenticationError::NotFound)?
+            .into();
+
+        Ok(session)
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<(), AuthenticationError> {
+        crate::entity::auth_sessions::Entity::delete_many()
+            .filter(crate::entity::auth_sessions::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Error deleting sessions: {:?}", e);
+                AuthenticationError::InternalServerError
+            })?;
+
+        Ok(())
+    }
 }
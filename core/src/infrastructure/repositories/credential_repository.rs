@@ -2,7 +2,7 @@ use crate::entity::credentials::{ActiveModel, Entity as CredentialEntity};
 use chrono::{TimeZone, Utc};
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, ModelTrait,
-    QueryFilter,
+    QueryFilter, QueryOrder,
 };
 use serde_json::Value;
 use tracing::error;
@@ -10,7 +10,7 @@ use tracing::error;
 use crate::domain::{
     common::{generate_timestamp, generate_uuid_v7},
     credential::{
-        entities::{Credential, CredentialData, CredentialError},
+        entities::{Credential, CredentialData, CredentialError, PASSWORD_HISTORY_CREDENTIAL_TYPE},
         ports::CredentialRepository,
     },
     crypto::entities::HashResult,
@@ -124,12 +124,87 @@ impl CredentialRepository for PostgresCredentialRepository {
         Ok(())
     }
 
+    async fn archive_password_credential(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> Result<(), CredentialError> {
+        let credential = CredentialEntity::find()
+            .filter(crate::entity::credentials::Column::UserId.eq(user_id))
+            .filter(crate::entity::credentials::Column::CredentialType.eq("password"))
+            .one(&self.db)
+            .await
+            .map_err(|_| CredentialError::DeletePasswordCredentialError)?
+            .ok_or(CredentialError::DeletePasswordCredentialError)?;
+
+        let mut credential: ActiveModel = credential.into();
+        credential.credential_type = Set(PASSWORD_HISTORY_CREDENTIAL_TYPE.to_string());
+        credential.updated_at = Set(Utc::now().naive_utc());
+
+        credential.update(&self.db).await.map_err(|e| {
+            error!("Error archiving password credential: {:?}", e);
+            CredentialError::DeletePasswordCredentialError
+        })?;
+
+        Ok(())
+    }
+
+    async fn get_password_history(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> Result<Vec<Credential>, CredentialError> {
+        let credentials = CredentialEntity::find()
+            .filter(crate::entity::credentials::Column::UserId.eq(user_id))
+            .filter(
+                crate::entity::credentials::Column::CredentialType
+                    .eq(PASSWORD_HISTORY_CREDENTIAL_TYPE),
+            )
+            .order_by_desc(crate::entity::credentials::Column::UpdatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|_| CredentialError::GetUserCredentialsError)?
+            .into_iter()
+            .map(Credential::from)
+            .collect();
+
+        Ok(credentials)
+    }
+
+    async fn prune_password_history(
+        &self,
+        user_id: uuid::Uuid,
+        keep: usize,
+    ) -> Result<(), CredentialError> {
+        let expired: Vec<uuid::Uuid> = self
+            .get_password_history(user_id)
+            .await?
+            .into_iter()
+            .skip(keep)
+            .map(|credential| credential.id)
+            .collect();
+
+        if expired.is_empty() {
+            return Ok(());
+        }
+
+        CredentialEntity::delete_many()
+            .filter(crate::entity::credentials::Column::Id.is_in(expired))
+            .exec(&self.db)
+            .await
+            .map_err(|_| CredentialError::DeleteCredentialError)?;
+
+        Ok(())
+    }
+
     async fn get_credentials_by_user_id(
         &self,
         user_id: uuid::Uuid,
     ) -> Result<Vec<Credential>, CredentialError> {
         let credentials = CredentialEntity::find()
             .filter(crate::entity::credentials::Column::UserId.eq(user_id))
+            .filter(
+                crate::entity::credentials::Column::CredentialType
+                    .ne(PASSWORD_HISTORY_CREDENTIAL_TYPE),
+            )
             .all(&self.db)
             .await
             .map_err(|_| CredentialError::GetUserCredentialsError)?
@@ -204,7 +279,7 @@ impl CredentialRepository for PostgresCredentialRepository {
 
         let models = hashes
             .into_iter()
-            .zip(credential_data.into_iter())
+            .zip(credential_data)
             .map(|(h, cred_data)| ActiveModel {
                 id: Set(generate_uuid_v7()),
                 salt: Set(Some(h.salt)),
@@ -1,12 +1,15 @@
+use chrono::{DateTime, TimeZone, Utc};
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DatabaseBackend,
+    DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Statement, TransactionTrait,
+    prelude::Expr,
 };
 use uuid::Uuid;
 
 use crate::domain::{
     common::generate_uuid_v7,
     jwt::{
-        entities::{JwtError, JwtKeyPair},
+        entities::{JwtError, JwtKeyPair, KeyStatus, SigningAlgorithm},
         ports::KeyStoreRepository,
     },
 };
@@ -15,13 +18,22 @@ impl TryFrom<crate::entity::jwt_keys::Model> for JwtKeyPair {
     type Error = JwtError;
 
     fn try_from(value: crate::entity::jwt_keys::Model) -> Result<Self, Self::Error> {
-        let jwt_key_pair = JwtKeyPair::from_pem(
+        let algorithm =
+            SigningAlgorithm::try_from(value.algorithm).map_err(JwtError::InvalidKey)?;
+
+        let mut jwt_key_pair = JwtKeyPair::from_pem(
             &value.private_key,
             &value.public_key,
             value.realm_id,
             value.id,
+            algorithm,
         )?;
 
+        jwt_key_pair.version = value.version;
+        jwt_key_pair.status = KeyStatus::try_from(value.status).map_err(JwtError::InvalidKey)?;
+        jwt_key_pair.created_at = Utc.from_utc_datetime(&value.created_at);
+        jwt_key_pair.rotated_at = value.rotated_at.map(|dt| Utc.from_utc_datetime(&dt));
+
         Ok(jwt_key_pair)
     }
 }
@@ -35,39 +47,258 @@ impl PostgresKeyStoreRepository {
     pub fn new(db: DatabaseConnection) -> Self {
         Self { db }
     }
-}
 
-impl KeyStoreRepository for PostgresKeyStoreRepository {
-    async fn get_or_generate_key(&self, realm_id: Uuid) -> Result<JwtKeyPair, JwtError> {
+    /// Demotes the active key of a realm to passive and inserts a new active
+    /// key. Expects the keys of the realm to be locked.
+    async fn replace_active_key<C: ConnectionTrait>(
+        db: &C,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+    ) -> Result<JwtKeyPair, JwtError> {
+        crate::entity::jwt_keys::Entity::update_many()
+            .col_expr(
+                crate::entity::jwt_keys::Column::Status,
+                Expr::value(KeyStatus::Passive.to_string()),
+            )
+            .col_expr(
+                crate::entity::jwt_keys::Column::RotatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
+            .filter(crate::entity::jwt_keys::Column::Status.eq(KeyStatus::Active.to_string()))
+            .exec(db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        let version = Self::next_version(db, realm_id).await?;
+
+        Self::insert_active_key(db, realm_id, version, algorithm).await
+    }
+
+    /// Serializes the key changes of a realm until the end of the
+    /// transaction, so concurrent rotations cannot leave two active keys.
+    async fn lock_realm_keys<C: ConnectionTrait>(db: &C, realm_id: Uuid) -> Result<(), JwtError> {
+        db.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            "SELECT pg_advisory_xact_lock(hashtext($1))",
+            [format!("jwt_keys:{realm_id}").into()],
+        ))
+        .await
+        .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn active_key<C: ConnectionTrait>(
+        db: &C,
+        realm_id: Uuid,
+    ) -> Result<Option<JwtKeyPair>, JwtError> {
         let key = crate::entity::jwt_keys::Entity::find()
             .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
-            .one(&self.db)
+            .filter(crate::entity::jwt_keys::Column::Status.eq(KeyStatus::Active.to_string()))
+            .order_by_desc(crate::entity::jwt_keys::Column::Version)
+            .one(db)
             .await
             .map_err(|_| JwtError::RealmKeyNotFound)?;
 
-        if let Some(key) = key {
-            return key.try_into();
-        }
+        key.map(JwtKeyPair::try_from).transpose()
+    }
+
+    async fn next_version<C: ConnectionTrait>(db: &C, realm_id: Uuid) -> Result<i32, JwtError> {
+        let latest = crate::entity::jwt_keys::Entity::find()
+            .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
+            .order_by_desc(crate::entity::jwt_keys::Column::Version)
+            .one(db)
+            .await
+            .map_err(|_| JwtError::RealmKeyNotFound)?;
 
-        let id = generate_uuid_v7();
+        Ok(latest.map(|key| key.version + 1).unwrap_or(1))
+    }
 
+    async fn insert_active_key<C: ConnectionTrait>(
+        db: &C,
+        realm_id: Uuid,
+        version: i32,
+        algorithm: SigningAlgorithm,
+    ) -> Result<JwtKeyPair, JwtError> {
         // Generate a new key pair
-        let (private_key, public_key) = JwtKeyPair::generate()?;
+        let (private_key, public_key) = JwtKeyPair::generate(algorithm)?;
 
         let new_key = crate::entity::jwt_keys::ActiveModel {
-            id: Set(id),
+            id: Set(generate_uuid_v7()),
             realm_id: Set(realm_id),
             public_key: Set(public_key),
             private_key: Set(private_key),
-            created_at: Set(chrono::Utc::now().naive_utc()),
+            created_at: Set(Utc::now().naive_utc()),
+            version: Set(version),
+            status: Set(KeyStatus::Active.to_string()),
+            rotated_at: Set(None),
+            algorithm: Set(algorithm.to_string()),
         };
 
         // Insert the new key into the database
         let result = new_key
-            .insert(&self.db)
+            .insert(db)
             .await
             .map_err(|e| JwtError::GenerationError(e.to_string()))?;
 
         result.try_into()
     }
 }
+
+impl KeyStoreRepository for PostgresKeyStoreRepository {
+    async fn get_or_generate_key(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+    ) -> Result<JwtKeyPair, JwtError> {
+        if let Some(key) = Self::active_key(&self.db, realm_id).await? {
+            return Ok(key);
+        }
+
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Self::lock_realm_keys(&txn, realm_id).await?;
+
+        // Another request may have generated the key while we were waiting
+        // for the lock.
+        let key = match Self::active_key(&txn, realm_id).await? {
+            Some(key) => key,
+            None => {
+                let version = Self::next_version(&txn, realm_id).await?;
+                Self::insert_active_key(&txn, realm_id, version, algorithm).await?
+            }
+        };
+
+        txn.commit()
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(key)
+    }
+
+    async fn get_key(&self, realm_id: Uuid, key_id: Uuid) -> Result<Option<JwtKeyPair>, JwtError> {
+        let key = crate::entity::jwt_keys::Entity::find()
+            .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
+            .filter(crate::entity::jwt_keys::Column::Id.eq(key_id))
+            .one(&self.db)
+            .await
+            .map_err(|_| JwtError::RealmKeyNotFound)?;
+
+        key.map(JwtKeyPair::try_from).transpose()
+    }
+
+    async fn list_keys(&self, realm_id: Uuid) -> Result<Vec<JwtKeyPair>, JwtError> {
+        let keys = crate::entity::jwt_keys::Entity::find()
+            .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
+            .order_by_desc(crate::entity::jwt_keys::Column::Version)
+            .all(&self.db)
+            .await
+            .map_err(|_| JwtError::RealmKeyNotFound)?;
+
+        keys.into_iter().map(JwtKeyPair::try_from).collect()
+    }
+
+    async fn rotate_key(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+    ) -> Result<JwtKeyPair, JwtError> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Self::lock_realm_keys(&txn, realm_id).await?;
+
+        let key = Self::replace_active_key(&txn, realm_id, algorithm).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(key)
+    }
+
+    async fn rotate_key_created_before(
+        &self,
+        realm_id: Uuid,
+        algorithm: SigningAlgorithm,
+        created_before: DateTime<Utc>,
+    ) -> Result<Option<JwtKeyPair>, JwtError> {
+        let txn = self
+            .db
+            .begin()
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Self::lock_realm_keys(&txn, realm_id).await?;
+
+        let active_key = crate::entity::jwt_keys::Entity::find()
+            .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
+            .filter(crate::entity::jwt_keys::Column::Status.eq(KeyStatus::Active.to_string()))
+            .one(&txn)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        if active_key.is_some_and(|key| key.created_at > created_before.naive_utc()) {
+            return Ok(None);
+        }
+
+        let key = Self::replace_active_key(&txn, realm_id, algorithm).await?;
+
+        txn.commit()
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(Some(key))
+    }
+
+    async fn retire_key(&self, realm_id: Uuid, key_id: Uuid) -> Result<JwtKeyPair, JwtError> {
+        let key = crate::entity::jwt_keys::Entity::find()
+            .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
+            .filter(crate::entity::jwt_keys::Column::Id.eq(key_id))
+            .one(&self.db)
+            .await
+            .map_err(|_| JwtError::RealmKeyNotFound)?
+            .ok_or(JwtError::RealmKeyNotFound)?;
+
+        let rotated_at = key.rotated_at.unwrap_or_else(|| Utc::now().naive_utc());
+
+        let mut key: crate::entity::jwt_keys::ActiveModel = key.into();
+        key.status = Set(KeyStatus::Retired.to_string());
+        key.rotated_at = Set(Some(rotated_at));
+
+        let key = key
+            .update(&self.db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        key.try_into()
+    }
+
+    async fn retire_passive_keys(
+        &self,
+        realm_id: Uuid,
+        rotated_before: DateTime<Utc>,
+    ) -> Result<(), JwtError> {
+        crate::entity::jwt_keys::Entity::update_many()
+            .col_expr(
+                crate::entity::jwt_keys::Column::Status,
+                Expr::value(KeyStatus::Retired.to_string()),
+            )
+            .filter(crate::entity::jwt_keys::Column::RealmId.eq(realm_id))
+            .filter(crate::entity::jwt_keys::Column::Status.eq(KeyStatus::Passive.to_string()))
+            .filter(crate::entity::jwt_keys::Column::RotatedAt.lt(rotated_before.naive_utc()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseBackend, DatabaseConnection, EntityTrait, QueryFilter,
+    Statement,
+};
+use uuid::Uuid;
+
+use crate::domain::{
+    brute_force::{
+        entities::{LoginFailure, LoginFailureSubject},
+        ports::LoginFailureRepository,
+    },
+    common::entities::app_errors::CoreError,
+};
+use crate::entity::login_failures::{Column, Entity, Model};
+
+impl From<Model> for LoginFailure {
+    fn from(model: Model) -> Self {
+        LoginFailure {
+            id: model.id,
+            realm_id: model.realm_id,
+            user_id: model.user_id,
+            ip_address: model.ip_address,
+            num_failures: u32::try_from(model.num_failures).unwrap_or_default(),
+            last_failure: Utc.from_utc_datetime(&model.last_failure),
+            locked_until: model.locked_until.map(|dt| Utc.from_utc_datetime(&dt)),
+            permanently_locked: model.permanently_locked,
+            created_at: Utc.from_utc_datetime(&model.created_at),
+        }
+    }
+}
+
+fn subject_condition(realm_id: Uuid, subject: &LoginFailureSubject) -> Condition {
+    let condition = Condition::all().add(Column::RealmId.eq(realm_id));
+
+    match subject {
+        LoginFailureSubject::User(user_id) => condition.add(Column::UserId.eq(*user_id)),
+        LoginFailureSubject::Ip(ip_address) => {
+            condition.add(Column::IpAddress.eq(ip_address.clone()))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresLoginFailureRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresLoginFailureRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+impl LoginFailureRepository for PostgresLoginFailureRepository {
+    async fn get_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+    ) -> Result<Option<LoginFailure>, CoreError> {
+        let failure = Entity::find()
+            .filter(subject_condition(realm_id, subject))
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get login failure: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(failure.map(LoginFailure::from))
+    }
+
+    async fn increment_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+        now: DateTime<Utc>,
+        reset_after: Duration,
+    ) -> Result<LoginFailure, CoreError> {
+        let failure = LoginFailure::new(realm_id, subject.clone());
+
+        // The partial unique index the conflict is resolved against depends
+        // on the subject.
+        let conflict_target = match subject {
+            LoginFailureSubject::User(_) => "(realm_id, user_id) WHERE user_id IS NOT NULL",
+            LoginFailureSubject::Ip(_) => "(realm_id, ip_address) WHERE ip_address IS NOT NULL",
+        };
+
+        let statement = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                r#"INSERT INTO login_failures
+                    (id, realm_id, user_id, ip_address, num_failures, last_failure, created_at)
+                VALUES ($1, $2, $3, $4, 1, $5, $5)
+                ON CONFLICT {conflict_target} DO UPDATE SET
+                    num_failures = CASE
+                        WHEN NOT login_failures.permanently_locked
+                            AND (login_failures.locked_until IS NULL
+                                OR login_failures.locked_until <= EXCLUDED.last_failure)
+                            AND login_failures.last_failure < $6
+                        THEN 1
+                        ELSE login_failures.num_failures + 1
+                    END,
+                    last_failure = EXCLUDED.last_failure
+                RETURNING *"#
+            ),
+            [
+                failure.id.into(),
+                realm_id.into(),
+                failure.user_id.into(),
+                failure.ip_address.into(),
+                now.naive_utc().into(),
+                (now - reset_after).naive_utc().into(),
+            ],
+        );
+
+        let failure = Entity::find()
+            .from_raw_sql(statement)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to increment login failure: {:?}", e);
+                CoreError::InternalServerError
+            })?
+            .ok_or(CoreError::InternalServerError)?;
+
+        Ok(failure.into())
+    }
+
+    async fn lock_login_failure(&self, failure: &LoginFailure) -> Result<LoginFailure, CoreError> {
+        let statement = Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            r#"UPDATE login_failures SET
+                locked_until = GREATEST(locked_until, $2),
+                permanently_locked = permanently_locked OR $3
+            WHERE id = $1
+            RETURNING *"#,
+            [
+                failure.id.into(),
+                failure.locked_until.map(|dt| dt.naive_utc()).into(),
+                failure.permanently_locked.into(),
+            ],
+        );
+
+        let failure = Entity::find()
+            .from_raw_sql(statement)
+            .one(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to lock login failure: {:?}", e);
+                CoreError::InternalServerError
+            })?
+            .ok_or(CoreError::NotFound)?;
+
+        Ok(failure.into())
+    }
+
+    async fn delete_login_failure(
+        &self,
+        realm_id: Uuid,
+        subject: &LoginFailureSubject,
+    ) -> Result<(), CoreError> {
+        Entity::delete_many()
+            .filter(subject_condition(realm_id, subject))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete login failure: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
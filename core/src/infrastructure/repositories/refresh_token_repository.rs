@@ -1,6 +1,7 @@
 use chrono::{DateTime, TimeZone, Utc};
 use sea_orm::{
     ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    prelude::Expr,
 };
 use uuid::Uuid;
 
@@ -21,7 +22,15 @@ impl From<crate::entity::refresh_tokens::Model> for RefreshToken {
             id: model.id,
             jti: model.jti,
             user_id: model.user_id,
+            client_id: model.client_id,
+            family_id: model.family_id,
             revoked: model.revoked,
+            rotated_at: model.rotated_at.map(|dt| Utc.from_utc_datetime(&dt)),
+            auth_time: model.auth_time.map(|dt| Utc.from_utc_datetime(&dt)),
+            amr: model
+                .amr
+                .map(|amr| amr.split_whitespace().map(String::from).collect())
+                .unwrap_or_default(),
             created_at,
             expires_at,
         }
@@ -44,13 +53,22 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
         &self,
         jti: Uuid,
         user_id: Uuid,
+        client_id: Option<String>,
+        family_id: Option<Uuid>,
+        auth_time: Option<DateTime<Utc>>,
+        amr: Vec<String>,
         expires_at: Option<DateTime<Utc>>,
     ) -> Result<RefreshToken, JwtError> {
         let model = crate::entity::refresh_tokens::ActiveModel {
             id: Set(generate_uuid_v7()),
             jti: Set(jti),
             user_id: Set(user_id),
+            client_id: Set(client_id),
+            family_id: Set(family_id),
             revoked: Set(false),
+            rotated_at: Set(None),
+            auth_time: Set(auth_time.map(|dt| dt.naive_utc())),
+            amr: Set(Some(amr.join(" ")).filter(|amr| !amr.is_empty())),
             created_at: Set(Utc::now().naive_utc()),
             expires_at: Set(expires_at.map(|dt| dt.naive_utc())),
         };
@@ -83,4 +101,85 @@ impl RefreshTokenRepository for PostgresRefreshTokenRepository {
 
         Ok(())
     }
+
+    async fn revoke(&self, jti: Uuid) -> Result<bool, JwtError> {
+        let result = crate::entity::refresh_tokens::Entity::update_many()
+            .col_expr(
+                crate::entity::refresh_tokens::Column::Revoked,
+                Expr::value(true),
+            )
+            .filter(crate::entity::refresh_tokens::Column::Jti.eq(jti))
+            .filter(crate::entity::refresh_tokens::Column::Revoked.eq(false))
+            .exec(&self.db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn rotate(&self, jti: Uuid) -> Result<bool, JwtError> {
+        let result = crate::entity::refresh_tokens::Entity::update_many()
+            .col_expr(
+                crate::entity::refresh_tokens::Column::Revoked,
+                Expr::value(true),
+            )
+            .col_expr(
+                crate::entity::refresh_tokens::Column::RotatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(crate::entity::refresh_tokens::Column::Jti.eq(jti))
+            .filter(crate::entity::refresh_tokens::Column::Revoked.eq(false))
+            .exec(&self.db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<(), JwtError> {
+        crate::entity::refresh_tokens::Entity::update_many()
+            .col_expr(
+                crate::entity::refresh_tokens::Column::Revoked,
+                Expr::value(true),
+            )
+            .filter(crate::entity::refresh_tokens::Column::FamilyId.eq(family_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn revoke_by_user_and_client(
+        &self,
+        user_id: Uuid,
+        client_id: String,
+    ) -> Result<(), JwtError> {
+        crate::entity::refresh_tokens::Entity::update_many()
+            .col_expr(
+                crate::entity::refresh_tokens::Column::Revoked,
+                Expr::value(true),
+            )
+            .filter(crate::entity::refresh_tokens::Column::UserId.eq(user_id))
+            .filter(crate::entity::refresh_tokens::Column::ClientId.eq(client_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<(), JwtError> {
+        crate::entity::refresh_tokens::Entity::update_many()
+            .col_expr(
+                crate::entity::refresh_tokens::Column::Revoked,
+                Expr::value(true),
+            )
+            .filter(crate::entity::refresh_tokens::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| JwtError::GenerationError(e.to_string()))?;
+
+        Ok(())
+    }
 }
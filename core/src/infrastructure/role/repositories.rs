@@ -71,4 +71,22 @@ impl RoleRepository for RoleRepoAny {
             RoleRepoAny::Postgres(repo) => repo.update_permissions_by_id(id, payload).await,
         }
     }
+
+    async fn get_composites(&self, role_ids: &[Uuid]) -> Result<Vec<Role>, CoreError> {
+        match self {
+            RoleRepoAny::Postgres(repo) => repo.get_composites(role_ids).await,
+        }
+    }
+
+    async fn add_composite(&self, role_id: Uuid, child_role_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            RoleRepoAny::Postgres(repo) => repo.add_composite(role_id, child_role_id).await,
+        }
+    }
+
+    async fn remove_composite(&self, role_id: Uuid, child_role_id: Uuid) -> Result<(), CoreError> {
+        match self {
+            RoleRepoAny::Postgres(repo) => repo.remove_composite(role_id, child_role_id).await,
+        }
+    }
 }
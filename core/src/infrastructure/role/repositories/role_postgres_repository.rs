@@ -1,7 +1,9 @@
 use chrono::Utc;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, DatabaseConnection, EntityTrait, JoinType,
+    QueryFilter, QuerySelect, RelationTrait, sea_query::OnConflict,
 };
+use tracing::error;
 use uuid::Uuid;
 
 use crate::domain::{
@@ -12,6 +14,7 @@ use crate::domain::{
         value_objects::{CreateRoleRequest, UpdateRolePermissionsRequest, UpdateRoleRequest},
     },
 };
+use crate::entity::role_composites;
 
 #[derive(Debug, Clone)]
 pub struct PostgresRoleRepository {
@@ -187,4 +190,85 @@ impl RoleRepository for PostgresRoleRepository {
 
         Ok(updated_role)
     }
+
+    async fn get_composites(&self, role_ids: &[Uuid]) -> Result<Vec<Role>, CoreError> {
+        if role_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let roles = crate::entity::roles::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                role_composites::Relation::ChildRole.def().rev(),
+            )
+            .filter(role_composites::Column::CompositeRoleId.is_in(role_ids.to_vec()))
+            .join(
+                JoinType::LeftJoin,
+                crate::entity::roles::Relation::Clients.def(),
+            )
+            .select_also(crate::entity::clients::Entity)
+            .distinct()
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error getting composite roles: {:?}", e);
+                CoreError::InternalServerError
+            })?
+            .into_iter()
+            .map(|(model, client)| {
+                let mut role: Role = model.into();
+                if let Some(client) = client {
+                    role.client = Some(client.into());
+                }
+                role
+            })
+            .collect();
+
+        Ok(roles)
+    }
+
+    async fn add_composite(&self, role_id: Uuid, child_role_id: Uuid) -> Result<(), CoreError> {
+        let composite = role_composites::ActiveModel {
+            composite_role_id: Set(role_id),
+            child_role_id: Set(child_role_id),
+            created_at: Set(Utc::now().naive_utc()),
+        };
+
+        role_composites::Entity::insert(composite)
+            .on_conflict(
+                OnConflict::columns([
+                    role_composites::Column::CompositeRoleId,
+                    role_composites::Column::ChildRoleId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .do_nothing()
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error adding composite role: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    async fn remove_composite(&self, role_id: Uuid, child_role_id: Uuid) -> Result<(), CoreError> {
+        let result = role_composites::Entity::delete_many()
+            .filter(role_composites::Column::CompositeRoleId.eq(role_id))
+            .filter(role_composites::Column::ChildRoleId.eq(child_role_id))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error removing composite role: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        if result.rows_affected == 0 {
+            return Err(CoreError::NotFound);
+        }
+
+        Ok(())
+    }
 }
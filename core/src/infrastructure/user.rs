@@ -1,5 +1,5 @@
 use crate::domain::common::entities::app_errors::CoreError;
-use crate::domain::user::entities::User;
+use crate::domain::user::entities::{User, UserPage, UserQuery};
 use crate::domain::user::ports::UserRepository;
 use crate::domain::user::value_objects::{CreateUserRequest, UpdateUserRequest};
 use crate::infrastructure::user::repository::PostgresUserRepository;
@@ -27,6 +27,12 @@ impl UserRepository for UserRepoAny {
         }
     }
 
+    async fn get_by_email(&self, email: String, realm_id: Uuid) -> Result<User, CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.get_by_email(email, realm_id).await,
+        }
+    }
+
     async fn get_by_client_id(&self, client_id: Uuid) -> Result<User, CoreError> {
         match self {
             Self::Postgres(repo) => repo.get_by_client_id(client_id).await,
@@ -45,6 +51,12 @@ impl UserRepository for UserRepoAny {
         }
     }
 
+    async fn search_users(&self, realm_id: Uuid, query: UserQuery) -> Result<UserPage, CoreError> {
+        match self {
+            Self::Postgres(repo) => repo.search_users(realm_id, query).await,
+        }
+    }
+
     async fn bulk_delete_user(&self, ids: Vec<Uuid>) -> Result<u64, CoreError> {
         match self {
             Self::Postgres(repo) => repo.bulk_delete_user(ids).await,
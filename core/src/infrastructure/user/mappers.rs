@@ -20,6 +20,7 @@ impl From<crate::entity::users::Model> for User {
             roles: Vec::new(),
             realm: None,
             required_actions: Vec::new(),
+            attributes: serde_json::from_value(value.attributes).unwrap_or_default(),
             created_at,
             updated_at,
         }
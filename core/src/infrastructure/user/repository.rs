@@ -1,6 +1,9 @@
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, DatabaseConnection, EntityTrait,
-    ModelTrait, QueryFilter,
+    ActiveModelTrait,
+    ActiveValue::Set,
+    ColumnTrait, Condition, DatabaseConnection, EntityTrait, ModelTrait, Order, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+    sea_query::{Expr, Func, Query, extension::postgres::PgExpr},
 };
 use tracing::error;
 use uuid::Uuid;
@@ -8,7 +11,9 @@ use uuid::Uuid;
 use crate::domain::{
     common::entities::app_errors::CoreError,
     user::{
-        entities::{RequiredAction, User, UserConfig},
+        entities::{
+            RequiredAction, SortOrder, User, UserConfig, UserPage, UserQuery, UserSortField,
+        },
         ports::UserRepository,
         value_objects::{CreateUserRequest, UpdateUserRequest},
     },
@@ -36,8 +41,12 @@ impl UserRepository for PostgresUserRepository {
             lastname: dto.lastname,
             username: dto.username,
             realm_id: dto.realm_id,
+            attributes: dto.attributes,
         });
 
+        let attributes =
+            serde_json::to_value(&user.attributes).map_err(|_| CoreError::InternalServerError)?;
+
         let model = crate::entity::users::ActiveModel {
             id: Set(user.id),
             realm_id: Set(user.realm_id),
@@ -50,6 +59,7 @@ impl UserRepository for PostgresUserRepository {
             client_id: Set(user.client_id),
             created_at: Set(user.created_at.naive_utc()),
             updated_at: Set(user.updated_at.naive_utc()),
+            attributes: Set(attributes),
         };
 
         let t = model
@@ -115,6 +125,27 @@ impl UserRepository for PostgresUserRepository {
         Ok(user)
     }
 
+    async fn get_by_email(&self, email: String, realm_id: Uuid) -> Result<User, CoreError> {
+        let users_model = crate::entity::users::Entity::find()
+            .filter(crate::entity::users::Column::RealmId.eq(realm_id))
+            .filter(
+                Expr::expr(Func::lower(Expr::col(crate::entity::users::Column::Email)))
+                    .eq(email.to_lowercase()),
+            )
+            .limit(2)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error retrieving user by email: {:?}", e);
+                CoreError::NotFound
+            })?;
+
+        match users_model.as_slice() {
+            [user_model] => self.get_by_id(user_model.id).await,
+            _ => Err(CoreError::NotFound),
+        }
+    }
+
     async fn get_by_id(&self, id: Uuid) -> Result<User, CoreError> {
         let users_model = crate::entity::users::Entity::find()
             .filter(crate::entity::users::Column::Id.eq(id))
@@ -167,6 +198,96 @@ impl UserRepository for PostgresUserRepository {
         Ok(users)
     }
 
+    async fn search_users(&self, realm_id: Uuid, query: UserQuery) -> Result<UserPage, CoreError> {
+        use crate::entity::{roles, user_role, users};
+
+        let mut condition = Condition::all().add(users::Column::RealmId.eq(realm_id));
+
+        for pattern in query.search_patterns() {
+            let matches =
+                |column: users::Column| Expr::col((users::Entity, column)).ilike(pattern.as_str());
+
+            condition = condition.add(
+                Condition::any()
+                    .add(matches(users::Column::Username))
+                    .add(matches(users::Column::Email))
+                    .add(matches(users::Column::Firstname))
+                    .add(matches(users::Column::Lastname)),
+            );
+        }
+
+        if let Some(enabled) = query.enabled {
+            condition = condition.add(users::Column::Enabled.eq(enabled));
+        }
+
+        if let Some(email_verified) = query.email_verified {
+            condition = condition.add(users::Column::EmailVerified.eq(email_verified));
+        }
+
+        if let Some(role) = query.role {
+            condition = condition.add(
+                users::Column::Id.in_subquery(
+                    Query::select()
+                        .column((user_role::Entity, user_role::Column::UserId))
+                        .from(user_role::Entity)
+                        .inner_join(
+                            roles::Entity,
+                            Expr::col((roles::Entity, roles::Column::Id))
+                                .equals((user_role::Entity, user_role::Column::RoleId)),
+                        )
+                        .and_where(Expr::col((roles::Entity, roles::Column::Name)).eq(role))
+                        .and_where(Expr::col((roles::Entity, roles::Column::RealmId)).eq(realm_id))
+                        .to_owned(),
+                ),
+            );
+        }
+
+        if let Some((name, value)) = query.attribute {
+            condition = condition.add(
+                Expr::col((users::Entity, users::Column::Attributes))
+                    .contains(serde_json::json!({ name: [value] })),
+            );
+        }
+
+        let select = users::Entity::find().filter(condition);
+
+        let total = select.clone().count(&self.db).await.map_err(|e| {
+            error!("error counting users: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let column = match query.sort {
+            UserSortField::Username => users::Column::Username,
+            UserSortField::Email => users::Column::Email,
+            UserSortField::Firstname => users::Column::Firstname,
+            UserSortField::Lastname => users::Column::Lastname,
+            UserSortField::CreatedAt => users::Column::CreatedAt,
+        };
+        let order = match query.order {
+            SortOrder::Asc => Order::Asc,
+            SortOrder::Desc => Order::Desc,
+        };
+
+        let users = select
+            .order_by(column, order.clone())
+            .order_by(users::Column::Id, order)
+            .offset(query.offset)
+            .limit(query.limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("error searching users: {:?}", e);
+                CoreError::InternalServerError
+            })?;
+
+        Ok(UserPage {
+            data: users.into_iter().map(User::from).collect(),
+            total,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
     async fn bulk_delete_user(&self, ids: Vec<Uuid>) -> Result<u64, CoreError> {
         let rows = crate::entity::users::Entity::delete_many()
             .filter(
@@ -210,6 +331,11 @@ impl UserRepository for PostgresUserRepository {
         active_model.email_verified = Set(dto.email_verified);
         active_model.enabled = Set(dto.enabled);
 
+        if let Some(attributes) = dto.attributes {
+            active_model.attributes =
+                Set(serde_json::to_value(attributes).map_err(|_| CoreError::InternalServerError)?);
+        }
+
         let updated_user = active_model
             .update(&self.db)
             .await
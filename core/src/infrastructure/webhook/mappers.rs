@@ -1,7 +1,9 @@
 use chrono::{TimeZone, Utc};
 
+use crate::domain::webhook::entities::webhook_delivery::WebhookDelivery;
 use crate::domain::webhook::entities::webhook_trigger::WebhookTrigger;
 use crate::domain::webhook::entities::{webhook::Webhook, webhook_subscriber::WebhookSubscriber};
+use crate::entity::webhook_deliveries::Model as WebhookDeliveryModel;
 use crate::entity::webhook_subscribers::Model as WebhookSubscriberModel;
 use crate::entity::webhooks::Model as WebhookModel;
 
@@ -15,10 +17,24 @@ impl From<&WebhookModel> for Webhook {
 
         Self {
             id: value.id,
+            realm_id: value.realm_id,
             endpoint: value.endpoint.clone(),
             subscribers: Vec::new(),
+            secret: value.secret.clone(),
             description: value.description.clone(),
             name: value.name.clone(),
+            enabled: value.enabled,
+            last_success_at: value
+                .last_success_at
+                .map(|last_success_at| Utc.from_utc_datetime(&last_success_at)),
+            last_failure_at: value
+                .last_failure_at
+                .map(|last_failure_at| Utc.from_utc_datetime(&last_failure_at)),
+            consecutive_failures: value.consecutive_failures,
+            payload_fields: serde_json::from_value(value.payload_fields.clone())
+                .unwrap_or_default(),
+            payload_template: value.payload_template.clone(),
+            headers: serde_json::from_value(value.headers.clone()).unwrap_or_default(),
             triggered_at,
             created_at,
             updated_at,
@@ -36,10 +52,23 @@ impl From<WebhookModel> for Webhook {
 
         Self {
             id: value.id,
+            realm_id: value.realm_id,
             endpoint: value.endpoint.clone(),
             subscribers: Vec::new(),
+            secret: value.secret,
             description: value.description,
             name: value.name,
+            enabled: value.enabled,
+            last_success_at: value
+                .last_success_at
+                .map(|last_success_at| Utc.from_utc_datetime(&last_success_at)),
+            last_failure_at: value
+                .last_failure_at
+                .map(|last_failure_at| Utc.from_utc_datetime(&last_failure_at)),
+            consecutive_failures: value.consecutive_failures,
+            payload_fields: serde_json::from_value(value.payload_fields).unwrap_or_default(),
+            payload_template: value.payload_template,
+            headers: serde_json::from_value(value.headers).unwrap_or_default(),
             triggered_at,
             created_at,
             updated_at,
@@ -63,3 +92,37 @@ impl TryFrom<WebhookSubscriberModel> for WebhookSubscriber {
         })
     }
 }
+
+impl TryFrom<WebhookDeliveryModel> for WebhookDelivery {
+    type Error = anyhow::Error;
+
+    fn try_from(value: WebhookDeliveryModel) -> Result<Self, Self::Error> {
+        let event: WebhookTrigger = value
+            .event
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid webhook trigger"))?;
+
+        Ok(Self {
+            id: value.id,
+            webhook_id: value.webhook_id,
+            realm_id: value.realm_id,
+            event,
+            payload: value.payload,
+            status: value.status.try_into().map_err(anyhow::Error::msg)?,
+            attempts: value.attempts,
+            next_attempt_at: value
+                .next_attempt_at
+                .map(|next_attempt_at| Utc.from_utc_datetime(&next_attempt_at)),
+            response_status: value
+                .response_status
+                .and_then(|status| u16::try_from(status).ok()),
+            latency_ms: value.latency_ms,
+            error: value.error,
+            created_at: Utc.from_utc_datetime(&value.created_at),
+            updated_at: Utc.from_utc_datetime(&value.updated_at),
+            delivered_at: value
+                .delivered_at
+                .map(|delivered_at| Utc.from_utc_datetime(&delivered_at)),
+        })
+    }
+}
@@ -1,2 +1,3 @@
+pub mod webhook_delivery_repository;
 pub mod webhook_notifier_repository;
 pub mod webhook_repository;
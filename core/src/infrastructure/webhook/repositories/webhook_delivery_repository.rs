@@ -0,0 +1,261 @@
+use chrono::{Duration, Utc};
+use sea_orm::ActiveValue::Set;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, EntityTrait, QueryFilter,
+    QueryOrder, QuerySelect, Statement,
+};
+use tracing::error;
+use uuid::Uuid;
+
+use crate::domain::webhook::{
+    entities::{
+        errors::WebhookError,
+        webhook_delivery::{WebhookDelivery, WebhookDeliveryStatus},
+    },
+    ports::WebhookDeliveryRepository,
+};
+use crate::entity::webhook_deliveries::{
+    ActiveModel as WebhookDeliveryActiveModel, Column as WebhookDeliveryColumn,
+    Entity as WebhookDeliveryEntity, Model as WebhookDeliveryModel,
+};
+
+#[derive(Clone)]
+pub enum WebhookDeliveryRepoAny {
+    Postgres(PostgresWebhookDeliveryRepository),
+}
+
+impl WebhookDeliveryRepository for WebhookDeliveryRepoAny {
+    async fn create_deliveries(
+        &self,
+        deliveries: Vec<WebhookDelivery>,
+    ) -> Result<(), WebhookError> {
+        match self {
+            Self::Postgres(r) => r.create_deliveries(deliveries).await,
+        }
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        limit: u64,
+        lease: Duration,
+    ) -> Result<Vec<WebhookDelivery>, WebhookError> {
+        match self {
+            Self::Postgres(r) => r.claim_due_deliveries(limit, lease).await,
+        }
+    }
+
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> Result<(), WebhookError> {
+        match self {
+            Self::Postgres(r) => r.update_delivery(delivery).await,
+        }
+    }
+
+    async fn fetch_deliveries_by_webhook(
+        &self,
+        webhook_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookError> {
+        match self {
+            Self::Postgres(r) => r.fetch_deliveries_by_webhook(webhook_id, limit).await,
+        }
+    }
+
+    async fn get_delivery(
+        &self,
+        delivery_id: Uuid,
+        webhook_id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, WebhookError> {
+        match self {
+            Self::Postgres(r) => r.get_delivery(delivery_id, webhook_id).await,
+        }
+    }
+
+    async fn prune_deliveries(&self, keep: u64) -> Result<u64, WebhookError> {
+        match self {
+            Self::Postgres(r) => r.prune_deliveries(keep).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresWebhookDeliveryRepository {
+    pub db: DatabaseConnection,
+}
+
+impl PostgresWebhookDeliveryRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+fn to_active_model(delivery: &WebhookDelivery) -> WebhookDeliveryActiveModel {
+    WebhookDeliveryActiveModel {
+        id: Set(delivery.id),
+        webhook_id: Set(delivery.webhook_id),
+        realm_id: Set(delivery.realm_id),
+        event: Set(delivery.event.to_string()),
+        payload: Set(delivery.payload.clone()),
+        status: Set(delivery.status.to_string()),
+        attempts: Set(delivery.attempts),
+        next_attempt_at: Set(delivery.next_attempt_at.map(|at| at.naive_utc())),
+        response_status: Set(delivery.response_status.map(i32::from)),
+        latency_ms: Set(delivery.latency_ms),
+        error: Set(delivery.error.clone()),
+        created_at: Set(delivery.created_at.naive_utc()),
+        updated_at: Set(delivery.updated_at.naive_utc()),
+        delivered_at: Set(delivery.delivered_at.map(|at| at.naive_utc())),
+    }
+}
+
+fn to_deliveries(models: Vec<WebhookDeliveryModel>) -> Result<Vec<WebhookDelivery>, WebhookError> {
+    models
+        .into_iter()
+        .map(WebhookDelivery::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            error!("Invalid webhook delivery: {}", e);
+            WebhookError::InternalServerError
+        })
+}
+
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    async fn create_deliveries(
+        &self,
+        deliveries: Vec<WebhookDelivery>,
+    ) -> Result<(), WebhookError> {
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        WebhookDeliveryEntity::insert_many(deliveries.iter().map(to_active_model))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to queue webhook deliveries: {}", e);
+                WebhookError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    async fn claim_due_deliveries(
+        &self,
+        limit: u64,
+        lease: Duration,
+    ) -> Result<Vec<WebhookDelivery>, WebhookError> {
+        let now = Utc::now().naive_utc();
+
+        let due: Vec<Uuid> = WebhookDeliveryEntity::find()
+            .select_only()
+            .column(WebhookDeliveryColumn::Id)
+            .filter(WebhookDeliveryColumn::Status.eq(WebhookDeliveryStatus::Pending.to_string()))
+            .filter(WebhookDeliveryColumn::NextAttemptAt.lte(now))
+            .order_by_asc(WebhookDeliveryColumn::NextAttemptAt)
+            .limit(limit)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to fetch due webhook deliveries: {}", e);
+                WebhookError::InternalServerError
+            })?;
+
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Rows another instance claimed in between no longer match the
+        // condition on the next attempt and are left out
+        let claimed = WebhookDeliveryEntity::update_many()
+            .col_expr(
+                WebhookDeliveryColumn::NextAttemptAt,
+                Expr::value(now + lease),
+            )
+            .filter(WebhookDeliveryColumn::Id.is_in(due))
+            .filter(WebhookDeliveryColumn::Status.eq(WebhookDeliveryStatus::Pending.to_string()))
+            .filter(WebhookDeliveryColumn::NextAttemptAt.lte(now))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to claim webhook deliveries: {}", e);
+                WebhookError::InternalServerError
+            })?;
+
+        to_deliveries(claimed)
+    }
+
+    async fn update_delivery(&self, delivery: &WebhookDelivery) -> Result<(), WebhookError> {
+        WebhookDeliveryEntity::update(to_active_model(delivery))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to update webhook delivery: {}", e);
+                WebhookError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    async fn fetch_deliveries_by_webhook(
+        &self,
+        webhook_id: Uuid,
+        limit: u64,
+    ) -> Result<Vec<WebhookDelivery>, WebhookError> {
+        let deliveries = WebhookDeliveryEntity::find()
+            .filter(WebhookDeliveryColumn::WebhookId.eq(webhook_id))
+            .order_by_desc(WebhookDeliveryColumn::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|_| WebhookError::InternalServerError)?;
+
+        to_deliveries(deliveries)
+    }
+
+    async fn get_delivery(
+        &self,
+        delivery_id: Uuid,
+        webhook_id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, WebhookError> {
+        let delivery = WebhookDeliveryEntity::find()
+            .filter(WebhookDeliveryColumn::Id.eq(delivery_id))
+            .filter(WebhookDeliveryColumn::WebhookId.eq(webhook_id))
+            .one(&self.db)
+            .await
+            .map_err(|_| WebhookError::InternalServerError)?;
+
+        Ok(to_deliveries(delivery.into_iter().collect())?.pop())
+    }
+
+    async fn prune_deliveries(&self, keep: u64) -> Result<u64, WebhookError> {
+        // Ranked like the history lists them, so pending deliveries count
+        // towards the kept ones without ever being deleted
+        let result = self
+            .db
+            .execute(Statement::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                r#"DELETE FROM webhook_deliveries
+                WHERE status <> $1 AND id IN (
+                    SELECT id FROM (
+                        SELECT id, ROW_NUMBER() OVER (
+                            PARTITION BY webhook_id ORDER BY created_at DESC
+                        ) AS rank
+                        FROM webhook_deliveries
+                    ) ranked
+                    WHERE rank > $2
+                )"#,
+                [
+                    WebhookDeliveryStatus::Pending.to_string().into(),
+                    i64::try_from(keep).unwrap_or(i64::MAX).into(),
+                ],
+            ))
+            .await
+            .map_err(|e| {
+                error!("Failed to prune webhook deliveries: {:?}", e);
+                WebhookError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,15 +1,29 @@
-use reqwest::Client;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use reqwest::{Client, header::CONTENT_TYPE};
 use serde::Serialize;
 
 use crate::domain::{
     common::entities::app_errors::CoreError,
     webhook::{
-        entities::{webhook::Webhook, webhook_payload::WebhookPayload},
-        ports::WebhookNotifierRepository,
+        entities::{
+            webhook::Webhook,
+            webhook_delivery::{
+                DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, WebhookDelivery,
+                WebhookDeliveryAttempt, sign_webhook_payload,
+            },
+            webhook_payload::WebhookPayload,
+        },
+        ports::{WebhookDeliveryRepository, WebhookNotifierRepository},
     },
 };
+use crate::infrastructure::webhook::repositories::webhook_delivery_repository::PostgresWebhookDeliveryRepository;
 use tracing::error;
 
+/// How long an endpoint has to answer a delivery.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Clone)]
 pub enum WebhookNotifierRepoAny {
     Postgres(PostgresWebhookNotifierRepository),
@@ -25,18 +39,34 @@ impl WebhookNotifierRepository for WebhookNotifierRepoAny {
             WebhookNotifierRepoAny::Postgres(r) => r.notify(webhooks, payload).await,
         }
     }
+
+    async fn deliver(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+    ) -> WebhookDeliveryAttempt {
+        match self {
+            WebhookNotifierRepoAny::Postgres(r) => r.deliver(webhook, delivery).await,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct PostgresWebhookNotifierRepository {
     pub http_client: Client,
+    pub delivery_repository: PostgresWebhookDeliveryRepository,
 }
 
 impl PostgresWebhookNotifierRepository {
-    pub fn new() -> Self {
-        Self {
-            http_client: Client::new(),
-        }
+    /// Fails when the HTTP client cannot be built, rather than delivering
+    /// without a timeout.
+    pub fn new(
+        delivery_repository: PostgresWebhookDeliveryRepository,
+    ) -> Result<Self, reqwest::Error> {
+        Ok(Self {
+            http_client: Client::builder().timeout(DELIVERY_TIMEOUT).build()?,
+            delivery_repository,
+        })
     }
 }
 
@@ -46,23 +76,75 @@ impl WebhookNotifierRepository for PostgresWebhookNotifierRepository {
         webhooks: Vec<Webhook>,
         payload: WebhookPayload<T>,
     ) -> Result<(), CoreError> {
-        let client = self.http_client.clone();
-
-        tokio::spawn(async move {
-            for webhook in webhooks {
-                let response = client
-                    .clone()
-                    .post(webhook.endpoint)
-                    .json(&payload.clone())
-                    .send()
-                    .await;
-
-                if let Err(err) = response {
-                    error!("Webhook POST failed: {:?}", err);
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+
+        let body = serde_json::to_value(&payload).map_err(|e| {
+            error!("Failed to serialize webhook payload: {:?}", e);
+            CoreError::InternalServerError
+        })?;
+
+        let deliveries = webhooks
+            .iter()
+            .map(|webhook| WebhookDelivery::for_webhook(webhook, payload.event.clone(), &body))
+            .collect();
+
+        self.delivery_repository
+            .create_deliveries(deliveries)
+            .await
+            .map_err(|e| CoreError::FailedWebhookNotification(e.to_string()))
+    }
+
+    async fn deliver(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+    ) -> WebhookDeliveryAttempt {
+        let body = delivery.payload.to_string();
+        let timestamp = Utc::now().timestamp();
+        let started_at = Instant::now();
+
+        let request = webhook.headers.iter().fold(
+            self.http_client.post(&webhook.endpoint),
+            |request, (name, value)| request.header(name, value),
+        );
+
+        let response = request
+            .header(CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, delivery.event.to_string())
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(
+                SIGNATURE_HEADER,
+                sign_webhook_payload(&webhook.secret, timestamp, &body),
+            )
+            .body(body)
+            .send()
+            .await;
+
+        let latency_ms = started_at.elapsed().as_millis() as i64;
+
+        match response {
+            Ok(response) => {
+                let status = response.status();
+
+                WebhookDeliveryAttempt {
+                    response_status: Some(status.as_u16()),
+                    latency_ms,
+                    error: (!status.is_success())
+                        .then(|| format!("endpoint responded with {status}")),
                 }
             }
-        });
+            Err(err) => {
+                error!("Webhook POST failed: {:?}", err);
 
-        Ok(())
+                WebhookDeliveryAttempt {
+                    response_status: None,
+                    latency_ms,
+                    error: Some(err.to_string()),
+                }
+            }
+        }
     }
 }
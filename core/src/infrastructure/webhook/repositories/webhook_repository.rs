@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use uuid::Uuid;
 
 use crate::domain::webhook::{
@@ -5,8 +7,9 @@ use crate::domain::webhook::{
     ports::WebhookRepository,
 };
 
-use chrono::Utc;
-use sea_orm::ActiveValue::Set;
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::sea_query::Expr;
 use sea_orm::{
     ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect, RelationTrait,
 };
@@ -62,12 +65,28 @@ impl WebhookRepository for WebhookRepoAny {
         name: Option<String>,
         description: Option<String>,
         endpoint: String,
+        secret: String,
+        enabled: bool,
         subscribers: Vec<WebhookTrigger>,
+        payload_fields: Vec<String>,
+        payload_template: Option<String>,
+        headers: BTreeMap<String, String>,
     ) -> Result<Webhook, WebhookError> {
         match self {
             Self::Postgres(r) => {
-                r.create_webhook(realm_id, name, description, endpoint, subscribers)
-                    .await
+                r.create_webhook(
+                    realm_id,
+                    name,
+                    description,
+                    endpoint,
+                    secret,
+                    enabled,
+                    subscribers,
+                    payload_fields,
+                    payload_template,
+                    headers,
+                )
+                .await
             }
         }
     }
@@ -78,12 +97,28 @@ impl WebhookRepository for WebhookRepoAny {
         name: Option<String>,
         description: Option<String>,
         endpoint: String,
+        secret: Option<String>,
+        enabled: Option<bool>,
         subscribers: Vec<WebhookTrigger>,
+        payload_fields: Option<Vec<String>>,
+        payload_template: Option<Option<String>>,
+        headers: Option<BTreeMap<String, String>>,
     ) -> Result<Webhook, WebhookError> {
         match self {
             Self::Postgres(r) => {
-                r.update_webhook(id, name, description, endpoint, subscribers)
-                    .await
+                r.update_webhook(
+                    id,
+                    name,
+                    description,
+                    endpoint,
+                    secret,
+                    enabled,
+                    subscribers,
+                    payload_fields,
+                    payload_template,
+                    headers,
+                )
+                .await
             }
         }
     }
@@ -93,6 +128,23 @@ impl WebhookRepository for WebhookRepoAny {
             Self::Postgres(r) => r.delete_webhook(id).await,
         }
     }
+
+    async fn record_delivery_outcome(
+        &self,
+        id: Uuid,
+        success: bool,
+        at: DateTime<Utc>,
+    ) -> Result<Webhook, WebhookError> {
+        match self {
+            Self::Postgres(r) => r.record_delivery_outcome(id, success, at).await,
+        }
+    }
+
+    async fn disable_webhook(&self, id: Uuid) -> Result<bool, WebhookError> {
+        match self {
+            Self::Postgres(r) => r.disable_webhook(id).await,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -131,6 +183,7 @@ impl WebhookRepository for PostgresWebhookRepository {
                 WebhookRelation::WebhookSubscribers.def(),
             )
             .filter(WebhookColumn::RealmId.eq(realm_id))
+            .filter(WebhookColumn::Enabled.eq(true))
             .filter(WebhookSubscriberColumn::Name.eq(subscriber.to_string()))
             .all(&self.db)
             .await
@@ -167,7 +220,12 @@ impl WebhookRepository for PostgresWebhookRepository {
         name: Option<String>,
         description: Option<String>,
         endpoint: String,
+        secret: String,
+        enabled: bool,
         subscribers: Vec<WebhookTrigger>,
+        payload_fields: Vec<String>,
+        payload_template: Option<String>,
+        headers: BTreeMap<String, String>,
     ) -> Result<Webhook, WebhookError> {
         let (_, timestamp) = generate_timestamp();
         let subscription_id = Uuid::new_v7(timestamp);
@@ -177,6 +235,14 @@ impl WebhookRepository for PostgresWebhookRepository {
             endpoint: Set(endpoint),
             name: Set(name),
             description: Set(description),
+            secret: Set(secret),
+            enabled: Set(enabled),
+            last_success_at: Set(None),
+            last_failure_at: Set(None),
+            consecutive_failures: Set(0),
+            payload_fields: Set(serde_json::json!(payload_fields)),
+            payload_template: Set(payload_template),
+            headers: Set(serde_json::json!(headers)),
             realm_id: Set(realm_id),
             triggered_at: Set(None),
             created_at: Set(Utc::now().naive_utc()),
@@ -217,16 +283,32 @@ impl WebhookRepository for PostgresWebhookRepository {
         name: Option<String>,
         description: Option<String>,
         endpoint: String,
+        secret: Option<String>,
+        enabled: Option<bool>,
         subscribers: Vec<WebhookTrigger>,
+        payload_fields: Option<Vec<String>>,
+        payload_template: Option<Option<String>>,
+        headers: Option<BTreeMap<String, String>>,
     ) -> Result<Webhook, WebhookError> {
         let mut webhook = WebhookEntity::update(WebhookActiveModel {
+            id: Set(id),
             name: Set(name),
             description: Set(description),
             endpoint: Set(endpoint),
+            secret: secret.map_or(NotSet, Set),
+            enabled: enabled.map_or(NotSet, Set),
+            consecutive_failures: match enabled {
+                Some(true) => Set(0),
+                _ => NotSet,
+            },
+            payload_fields: payload_fields.map_or(NotSet, |payload_fields| {
+                Set(serde_json::json!(payload_fields))
+            }),
+            payload_template: payload_template.map_or(NotSet, Set),
+            headers: headers.map_or(NotSet, |headers| Set(serde_json::json!(headers))),
             updated_at: Set(Utc::now().naive_utc()),
             ..Default::default()
         })
-        .filter(WebhookColumn::Id.eq(id))
         .exec(&self.db)
         .await
         .map(Webhook::from)
@@ -246,7 +328,7 @@ impl WebhookRepository for PostgresWebhookRepository {
             let subscriber = WebhookSubscriberActiveModel {
                 id: Set(subscription_id),
                 name: Set(subscriber.to_string()),
-                webhook_id: Set(subscription_id),
+                webhook_id: Set(id),
             };
 
             derived_subscribers.push(subscriber);
@@ -273,4 +355,63 @@ impl WebhookRepository for PostgresWebhookRepository {
 
         Ok(())
     }
+
+    async fn record_delivery_outcome(
+        &self,
+        id: Uuid,
+        success: bool,
+        at: DateTime<Utc>,
+    ) -> Result<Webhook, WebhookError> {
+        let at = at.naive_utc();
+
+        // Counted in the statement itself, as deliveries of the same
+        // webhook are attempted concurrently
+        let update = WebhookEntity::update_many()
+            .col_expr(WebhookColumn::TriggeredAt, Expr::value(at))
+            .filter(WebhookColumn::Id.eq(id));
+
+        let update = if success {
+            update
+                .col_expr(WebhookColumn::LastSuccessAt, Expr::value(at))
+                .col_expr(WebhookColumn::ConsecutiveFailures, Expr::value(0))
+        } else {
+            update
+                .col_expr(WebhookColumn::LastFailureAt, Expr::value(at))
+                .col_expr(
+                    WebhookColumn::ConsecutiveFailures,
+                    Expr::col(WebhookColumn::ConsecutiveFailures).add(1),
+                )
+        };
+
+        update
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to record webhook delivery outcome: {}", e);
+                WebhookError::InternalServerError
+            })?
+            .into_iter()
+            .next()
+            .map(Webhook::from)
+            .ok_or(WebhookError::NotFound)
+    }
+
+    async fn disable_webhook(&self, id: Uuid) -> Result<bool, WebhookError> {
+        let result = WebhookEntity::update_many()
+            .col_expr(WebhookColumn::Enabled, Expr::value(false))
+            .col_expr(
+                WebhookColumn::UpdatedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(WebhookColumn::Id.eq(id))
+            .filter(WebhookColumn::Enabled.eq(true))
+            .exec(&self.db)
+            .await
+            .map_err(|e| {
+                error!("Failed to disable webhook: {}", e);
+                WebhookError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected > 0)
+    }
 }
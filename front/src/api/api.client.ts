@@ -239,7 +239,7 @@ export namespace Schemas {
   }>
   export type UserRealmsResponse = { data: Array<Realm> }
   export type UserResponse = { data: User }
-  export type UsersResponse = { data: Array<User> }
+  export type UsersResponse = { data: Array<User>; total: number; offset: number; limit: number }
   export type VerifyOtpResponse = { message: string }
 
   // </Schemas>
@@ -557,6 +557,17 @@ export namespace Endpoints {
     path: '/realms/{realm_name}/users'
     requestFormat: 'json'
     parameters: {
+      query: Partial<{
+        search: string | null
+        enabled: boolean | null
+        email_verified: boolean | null
+        role: string | null
+        attribute: string | null
+        sort: 'username' | 'email' | 'firstname' | 'lastname' | 'created_at' | null
+        order: 'asc' | 'desc' | null
+        offset: number | null
+        limit: number | null
+      }>
       path: { realm_name: string }
     }
     response: Schemas.UsersResponse
@@ -12,12 +12,24 @@ export interface GetUserQueryParams {
   userId?: string
 }
 
-export const useGetUsers = ({ realm }: BaseQuery) => {
+/** Users fetched per page of the users list. */
+export const USERS_PAGE_SIZE = 50
+
+export interface GetUsersQueryParams extends BaseQuery {
+  offset?: number
+  limit?: number
+}
+
+export const useGetUsers = ({ realm, offset = 0, limit = USERS_PAGE_SIZE }: GetUsersQueryParams) => {
   return useQuery({
     ...window.tanstackApi.get('/realms/{realm_name}/users', {
       path: {
         realm_name: realm || 'master',
       },
+      query: {
+        offset,
+        limit,
+      },
     }).queryOptions,
   })
 }
@@ -1,7 +1,7 @@
 import { RouterParams } from '@/routes/router'
 import { useNavigate, useParams } from 'react-router'
 import { toast } from 'sonner'
-import { useBulkDeleteUser, useGetUsers } from '../../../api/user.api'
+import { USERS_PAGE_SIZE, useBulkDeleteUser, useGetUsers } from '../../../api/user.api'
 import PageUsersOverview from '../ui/page-users-overview'
 import { USER_OVERVIEW_URL, USER_URL } from '@/routes/sub-router/user.router'
 import { useState } from 'react'
@@ -10,7 +10,12 @@ import User = Schemas.User
 
 export default function PageUsersOverviewFeature() {
   const { realm_name } = useParams<RouterParams>()
-  const { data: responseGetUsers, isLoading } = useGetUsers({ realm: realm_name ?? 'master' })
+  const [offset, setOffset] = useState(0)
+  const { data: responseGetUsers, isLoading } = useGetUsers({
+    realm: realm_name ?? 'master',
+    offset,
+    limit: USERS_PAGE_SIZE,
+  })
   const { mutate: bulkDeleteUser } = useBulkDeleteUser()
   const [openCreateUserModal, setOpenCreateUserModal] = useState(false)
   const navigate = useNavigate()
@@ -40,6 +45,10 @@ export default function PageUsersOverviewFeature() {
   return (
     <PageUsersOverview
       data={responseGetUsers?.data || []}
+      total={responseGetUsers?.total ?? 0}
+      offset={offset}
+      limit={USERS_PAGE_SIZE}
+      onOffsetChange={setOffset}
       isLoading={isLoading}
       realmName={realm_name ?? 'master'}
       handleDeleteSelected={handleDeleteSelected}
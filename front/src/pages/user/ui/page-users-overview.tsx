@@ -1,5 +1,6 @@
+import { Button } from '@/components/ui/button'
 import { DataTable } from '@/components/ui/data-table'
-import { Edit, ExternalLink, Trash2 } from 'lucide-react'
+import { ChevronLeft, ChevronRight, Edit, ExternalLink, Trash2 } from 'lucide-react'
 import { useNavigate } from 'react-router'
 import { Fragment } from 'react/jsx-runtime'
 import { columns } from '../columns/list-user.column'
@@ -11,6 +12,10 @@ import User = Schemas.User
 export interface PageUsersOverviewOverviewProps {
   isLoading?: boolean
   data: User[]
+  total: number
+  offset: number
+  limit: number
+  onOffsetChange: (offset: number) => void
   realmName: string
   handleDeleteSelected: (items: User[]) => void
   handleClickRow: (userId: string) => void
@@ -21,6 +26,10 @@ export interface PageUsersOverviewOverviewProps {
 export default function PageUsersOverview({
   isLoading,
   data,
+  total,
+  offset,
+  limit,
+  onOffsetChange,
   realmName,
   handleClickRow,
   handleDeleteSelected,
@@ -67,6 +76,34 @@ export default function PageUsersOverview({
         ]}
       />
 
+      {total > limit && (
+        <div className='flex items-center justify-between py-2'>
+          <div className='text-sm text-muted-foreground'>
+            {offset + 1}-{Math.min(offset + limit, total)} of {total} users
+          </div>
+          <div className='flex items-center gap-2'>
+            <Button
+              variant='outline'
+              size='sm'
+              onClick={() => onOffsetChange(Math.max(offset - limit, 0))}
+              disabled={offset === 0}
+            >
+              <ChevronLeft className='h-4 w-4' />
+              <span className='sr-only'>Previous users</span>
+            </Button>
+            <Button
+              variant='outline'
+              size='sm'
+              onClick={() => onOffsetChange(offset + limit)}
+              disabled={offset + limit >= total}
+            >
+              <ChevronRight className='h-4 w-4' />
+              <span className='sr-only'>Next users</span>
+            </Button>
+          </div>
+        </div>
+      )}
+
       <CreateUserModalFeature
         realm={realmName}
         open={openCreateUserModal}